@@ -0,0 +1,171 @@
+//! A minimal writer of JSON documents.
+
+use std::fmt::{Display, Formatter, Write};
+
+/// A value.
+#[derive(Clone, Debug)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Create an object from pairs of keys and values.
+    pub fn object<I, K>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, Value)>,
+        K: Into<String>,
+    {
+        Value::Object(
+            pairs
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+
+    fn write(&self, value: &mut String, level: usize) {
+        let indentation = "  ".repeat(level + 1);
+        match self {
+            Value::Null => value.push_str("null"),
+            Value::Bool(inner) => value.push_str(if *inner { "true" } else { "false" }),
+            Value::Number(inner) if inner.is_finite() => {
+                let _ = write!(value, "{inner}");
+            }
+            Value::Number(_) => value.push_str("null"),
+            Value::String(inner) => escape(inner, value),
+            Value::Array(inner) if inner.is_empty() => value.push_str("[]"),
+            // Arrays of scalars are kept on one line.
+            Value::Array(inner) if inner.iter().all(Value::scalar) => {
+                value.push('[');
+                for (i, item) in inner.iter().enumerate() {
+                    if i > 0 {
+                        value.push_str(", ");
+                    }
+                    item.write(value, level);
+                }
+                value.push(']');
+            }
+            Value::Array(inner) => {
+                value.push_str("[\n");
+                for (i, item) in inner.iter().enumerate() {
+                    value.push_str(&indentation);
+                    item.write(value, level + 1);
+                    value.push_str(if i + 1 < inner.len() { ",\n" } else { "\n" });
+                }
+                value.push_str(&"  ".repeat(level));
+                value.push(']');
+            }
+            Value::Object(inner) if inner.is_empty() => value.push_str("{}"),
+            Value::Object(inner) => {
+                value.push_str("{\n");
+                for (i, (key, item)) in inner.iter().enumerate() {
+                    value.push_str(&indentation);
+                    escape(key, value);
+                    value.push_str(": ");
+                    item.write(value, level + 1);
+                    value.push_str(if i + 1 < inner.len() { ",\n" } else { "\n" });
+                }
+                value.push_str(&"  ".repeat(level));
+                value.push('}');
+            }
+        }
+    }
+
+    fn scalar(&self) -> bool {
+        !matches!(self, Value::Array(_) | Value::Object(_))
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut value = String::new();
+        self.write(&mut value, 0);
+        formatter.write_str(&value)
+    }
+}
+
+impl From<&str> for Value {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Value::String(value.into())
+    }
+}
+
+impl From<String> for Value {
+    #[inline]
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<bool> for Value {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<f32> for Value {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Value::Number(value as f64)
+    }
+}
+
+impl From<f64> for Value {
+    #[inline]
+    fn from(value: f64) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<usize> for Value {
+    #[inline]
+    fn from(value: usize) -> Self {
+        Value::Number(value as f64)
+    }
+}
+
+impl From<u16> for Value {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Value::Number(value as f64)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    #[inline]
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Value::Null)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    #[inline]
+    fn from(value: Vec<T>) -> Self {
+        Value::Array(value.into_iter().map(Into::into).collect())
+    }
+}
+
+fn escape(inner: &str, value: &mut String) {
+    value.push('"');
+    for character in inner.chars() {
+        match character {
+            '"' => value.push_str("\\\""),
+            '\\' => value.push_str("\\\\"),
+            '\n' => value.push_str("\\n"),
+            '\r' => value.push_str("\\r"),
+            '\t' => value.push_str("\\t"),
+            character if (character as u32) < 0x20 => {
+                let _ = write!(value, "\\u{:04x}", character as u32);
+            }
+            character => value.push(character),
+        }
+    }
+    value.push('"');
+}
@@ -0,0 +1,467 @@
+//! A command-line tool for inspecting fonts in the Compact Font Format.
+//!
+//! The input is either a bare font set or an OpenType font with a `CFF ` table, and the output
+//! is human-readable unless `--json` is given.
+
+mod json;
+mod sfnt;
+
+use std::io::Cursor;
+use std::process::ExitCode;
+
+use postscript::compact1::font_set::Record;
+use postscript::compact1::validation::{self, Severity};
+use postscript::compact1::{FontSet, GlyphID, Number, Operations, Operator};
+use postscript::export::{self, Format};
+use postscript::tape::{Read, Write};
+use postscript::type2::outline::Segment;
+use postscript::Result;
+
+use json::Value;
+
+const USAGE: &str = "\
+Usage: postscript <command> [--json] [--index <index>] <font> [<argument>...]
+
+Commands:
+    info <font>                          Show the names, collections, and dictionaries
+    dump <font>                          Show the dictionaries and interpreted charstrings
+    glyph <font> <glyph>                 Show the outline, width, and bounds of a glyph
+    subset <font> <output> <glyph>...    Write a font set with the given glyphs only
+    extract <font> <output>              Write the font set of an OpenType font
+    validate <font>                      Check a font set against the specification
+
+Glyphs are given by name, by glyph identifier as in `gid:42` or `42`, or by CID as in
+`cid:42`. The index selects the record for `glyph` and `subset` and is 0 by default.";
+
+struct Arguments {
+    command: String,
+    json: bool,
+    index: usize,
+    rest: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let arguments = match parse(std::env::args().skip(1)) {
+        Some(arguments) => arguments,
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(&arguments) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse<I: Iterator<Item = String>>(mut values: I) -> Option<Arguments> {
+    let mut arguments = Arguments {
+        command: String::new(),
+        json: false,
+        index: 0,
+        rest: vec![],
+    };
+    while let Some(value) = values.next() {
+        match value.as_str() {
+            "--json" => arguments.json = true,
+            "--index" => arguments.index = values.next()?.parse().ok()?,
+            "-h" | "--help" => return None,
+            _ if arguments.command.is_empty() => arguments.command = value,
+            _ => arguments.rest.push(value),
+        }
+    }
+    Some(arguments)
+}
+
+// Run a command and return whether it succeeded.
+fn run(arguments: &Arguments) -> Result<bool> {
+    let rest = &arguments.rest;
+    match (arguments.command.as_str(), rest.len()) {
+        ("info", 1) => info(&load(&rest[0])?, arguments.json),
+        ("dump", 1) => dump(&load(&rest[0])?, arguments.json),
+        ("glyph", 2) => glyph(&load(&rest[0])?, arguments, &rest[1]),
+        ("subset", 3..) => subset(load(&rest[0])?, arguments, &rest[1], &rest[2..]),
+        ("extract", 2) => extract(&rest[0], &rest[1], arguments.json),
+        ("validate", 1) => validate(&read(&rest[0])?, arguments.json),
+        _ => {
+            eprintln!("{USAGE}");
+            Ok(false)
+        }
+    }
+}
+
+fn info(set: &FontSet, json: bool) -> Result<bool> {
+    let mut records = vec![];
+    for (index, record) in set.records.iter().enumerate() {
+        let mut pairs = vec![
+            ("index", index.into()),
+            ("name", name(set, index).into()),
+            ("glyph_count", set.character_strings[index].count.into()),
+        ];
+        match record {
+            Record::CharacterNameKeyed(ref record) => {
+                pairs.push(("keying", "name".into()));
+                pairs.push(("top", dictionary(set, &set.operations[index])));
+                pairs.push(("private", dictionary(set, &record.operations)));
+            }
+            Record::CharacterIDKeyed(ref record) => {
+                pairs.push(("keying", "cid".into()));
+                if let Some(collection) = set.collection(index) {
+                    pairs.push((
+                        "collection",
+                        format!(
+                            "{}-{}-{}",
+                            collection.registry, collection.ordering, collection.supplement,
+                        )
+                        .into(),
+                    ));
+                }
+                pairs.push(("top", dictionary(set, &set.operations[index])));
+                pairs.push(("font_dictionary_count", record.operations.len().into()));
+            }
+        }
+        records.push(Value::object(pairs));
+    }
+    print(Value::Array(records), json);
+    Ok(true)
+}
+
+fn dump(set: &FontSet, json: bool) -> Result<bool> {
+    let mut records = vec![];
+    for (index, record) in set.records.iter().enumerate() {
+        let mut pairs = vec![
+            ("index", index.into()),
+            ("name", name(set, index).into()),
+            ("top", dictionary(set, &set.operations[index])),
+        ];
+        match record {
+            Record::CharacterNameKeyed(ref record) => {
+                pairs.push(("private", dictionary(set, &record.operations)));
+                pairs.push(("local_subroutine_count", record.subroutines.len().into()));
+            }
+            Record::CharacterIDKeyed(ref record) => {
+                let mut dictionaries = vec![];
+                for (operations, inner) in record.operations.iter().zip(record.records.iter()) {
+                    dictionaries.push(Value::object([
+                        ("font", dictionary(set, operations)),
+                        ("private", dictionary(set, &inner.operations)),
+                        ("local_subroutine_count", inner.subroutines.len().into()),
+                    ]));
+                }
+                pairs.push(("font_dictionaries", Value::Array(dictionaries)));
+            }
+        }
+        let mut glyphs = vec![];
+        for glyph_id in 0..set.character_strings[index].count {
+            let mut glyph = vec![
+                ("glyph_id", glyph_id.into()),
+                ("name", glyph_name(set, index, glyph_id).into()),
+            ];
+            let mut operations = vec![];
+            let mut program = set.program(index, glyph_id)?;
+            loop {
+                match program.next() {
+                    Ok(Some((operator, operands))) => {
+                        let mut operation = vec![Value::from(format!("{operator:?}"))];
+                        operation.extend(operands.into_iter().map(Value::from));
+                        operations.push(Value::Array(operation));
+                    }
+                    Ok(None) => break,
+                    Err(error) => {
+                        glyph.push(("error", error.to_string().into()));
+                        break;
+                    }
+                }
+            }
+            glyph.push(("operations", Value::Array(operations)));
+            glyphs.push(Value::object(glyph));
+        }
+        pairs.push(("glyphs", Value::Array(glyphs)));
+        records.push(Value::object(pairs));
+    }
+    print(
+        Value::object([
+            ("global_subroutine_count", set.subroutines.len().into()),
+            ("records", Value::Array(records)),
+        ]),
+        json,
+    );
+    Ok(true)
+}
+
+fn glyph(set: &FontSet, arguments: &Arguments, value: &str) -> Result<bool> {
+    let index = arguments.index;
+    let glyph_id = find(set, index, value)?;
+    let outline = set.filled_outline(index, glyph_id)?;
+    let segments = outline
+        .segments
+        .iter()
+        .map(|segment| {
+            let (operator, points) = match *segment {
+                Segment::Move(a) => ("M", vec![a]),
+                Segment::Line(a) => ("L", vec![a]),
+                Segment::Curve(a, b, c) => ("C", vec![a, b, c]),
+                Segment::Close => ("Z", vec![]),
+            };
+            let mut values = vec![Value::from(operator)];
+            for (x, y) in points {
+                values.push(x.into());
+                values.push(y.into());
+            }
+            Value::Array(values)
+        })
+        .collect::<Vec<_>>();
+    let mut pairs = vec![
+        ("glyph_id", glyph_id.into()),
+        ("name", glyph_name(set, index, glyph_id).into()),
+    ];
+    if let Record::CharacterIDKeyed(_) = set.records[index] {
+        let character_id = set.character_sets[index].get_string_id(glyph_id);
+        pairs.push(("cid", character_id.into()));
+    }
+    pairs.push(("width", set.width(index, glyph_id)?.into()));
+    pairs.push((
+        "bounds",
+        outline
+            .bounds()
+            .map(|((x0, y0), (x1, y1))| Value::from(vec![x0, y0, x1, y1]))
+            .unwrap_or(Value::Null),
+    ));
+    if arguments.json {
+        pairs.push(("segments", Value::Array(segments)));
+    } else {
+        pairs.push(("path", export::path(&outline, Format::Svg).into()));
+    }
+    print(Value::object(pairs), arguments.json);
+    Ok(true)
+}
+
+fn subset(mut set: FontSet, arguments: &Arguments, path: &str, values: &[String]) -> Result<bool> {
+    let index = arguments.index;
+    let mut glyph_ids = vec![];
+    for value in values.iter() {
+        glyph_ids.push(find(&set, index, value)?);
+    }
+    let glyph_ids = set.subset(index, &glyph_ids)?;
+    let mut data = vec![];
+    data.give(&set)?;
+    std::fs::write(path, &data)?;
+    print(
+        Value::object([
+            ("glyph_ids", Value::from(glyph_ids)),
+            ("size", data.len().into()),
+        ]),
+        arguments.json,
+    );
+    Ok(true)
+}
+
+fn extract(path: &str, output: &str, json: bool) -> Result<bool> {
+    let data = std::fs::read(path)?;
+    let (start, end) = match sfnt::find(&data, b"CFF ")? {
+        Some(range) => range,
+        _ => return Err(std::io::Error::other("found no CFF table")),
+    };
+    std::fs::write(output, &data[start..end])?;
+    print(
+        Value::object([("offset", start.into()), ("size", (end - start).into())]),
+        json,
+    );
+    Ok(true)
+}
+
+fn validate(data: &[u8], json: bool) -> Result<bool> {
+    let issues = validation::validate_bytes(data);
+    let valid = issues.iter().all(|issue| issue.severity != Severity::Error);
+    if json {
+        let issues = issues
+            .iter()
+            .map(|issue| {
+                Value::object([
+                    ("severity", format!("{:?}", issue.severity).into()),
+                    ("rule", format!("{:?}", issue.rule).into()),
+                    ("record", Value::from(issue.record)),
+                    ("glyph_id", Value::from(issue.glyph_id)),
+                    ("description", issue.description.clone().into()),
+                ])
+            })
+            .collect();
+        println!("{}", Value::Array(issues));
+    } else {
+        for issue in issues.iter() {
+            let mut location = String::new();
+            if let Some(record) = issue.record {
+                location.push_str(&format!(" in record {record}"));
+            }
+            if let Some(glyph_id) = issue.glyph_id {
+                location.push_str(&format!(" of glyph {glyph_id}"));
+            }
+            println!(
+                "{:?} ({:?}){location}: {}",
+                issue.severity, issue.rule, issue.description,
+            );
+        }
+        if issues.is_empty() {
+            println!("No issues found.");
+        }
+    }
+    Ok(valid)
+}
+
+// Read a file and return the font set it contains, extracting it from an OpenType font if needed.
+fn read(path: &str) -> Result<Vec<u8>> {
+    let mut data = std::fs::read(path)?;
+    if let Some((start, end)) = sfnt::find(&data, b"CFF ")? {
+        data.truncate(end);
+        data.drain(..start);
+    }
+    Ok(data)
+}
+
+fn load(path: &str) -> Result<FontSet> {
+    Cursor::new(read(path)?).take()
+}
+
+// Find a glyph given by name, glyph identifier, or CID.
+fn find(set: &FontSet, index: usize, value: &str) -> Result<GlyphID> {
+    if index >= set.records.len() {
+        return Err(std::io::Error::other(format!("found no record {index}")));
+    }
+    let count = set.character_strings[index].count;
+    let glyph_id = if let Some(character_id) = value.strip_prefix("cid:") {
+        match character_id.parse() {
+            Ok(character_id) => set.map_character_ids(index)?.get(&character_id).copied(),
+            _ => None,
+        }
+    } else if let Ok(glyph_id) = value.strip_prefix("gid:").unwrap_or(value).parse() {
+        Some(glyph_id).filter(|&glyph_id| glyph_id < count)
+    } else {
+        (0..count).find(|&glyph_id| glyph_name(set, index, glyph_id).as_deref() == Some(value))
+    };
+    match glyph_id {
+        Some(glyph_id) => Ok(glyph_id),
+        _ => Err(std::io::Error::other(format!("found no glyph {value}"))),
+    }
+}
+
+fn name(set: &FontSet, index: usize) -> Option<String> {
+    set.names
+        .get(index)
+        .map(|name| String::from_utf8_lossy(name).into_owned())
+}
+
+fn glyph_name(set: &FontSet, index: usize, glyph_id: GlyphID) -> Option<String> {
+    if glyph_id == 0 {
+        return Some(".notdef".into());
+    }
+    let string_id = set.character_sets[index].get_string_id(glyph_id)?;
+    match set.records[index] {
+        Record::CharacterNameKeyed(_) => set.strings.get(string_id),
+        Record::CharacterIDKeyed(_) => Some(format!("cid{string_id:05}")),
+    }
+}
+
+fn dictionary(set: &FontSet, operations: &Operations) -> Value {
+    let mut operators = operations.keys().copied().collect::<Vec<_>>();
+    operators.sort_by_key(Operator::code);
+    let pairs = operators.into_iter().map(|operator| {
+        let operands = &operations[&operator];
+        let value = match (operator.is_string(), operands.as_slice()) {
+            (true, &[Number::Integer(string_id)]) => u16::try_from(string_id)
+                .ok()
+                .and_then(|string_id| set.strings.get(string_id))
+                .map(Value::from),
+            _ => None,
+        };
+        let value = value.unwrap_or_else(|| {
+            Value::Array(
+                operands
+                    .iter()
+                    .map(|operand| match operand {
+                        Number::Integer(value) => Value::Number(*value as f64),
+                        Number::Real(value) => Value::Number(value.to_f64()),
+                    })
+                    .collect(),
+            )
+        });
+        (format!("{operator:?}"), value)
+    });
+    Value::object(pairs)
+}
+
+fn print(value: Value, json: bool) {
+    if json {
+        println!("{value}");
+    } else {
+        let mut text = String::new();
+        describe(&value, 0, &mut text);
+        print!("{text}");
+    }
+}
+
+// Write a value as indented text with keys turned into capitalized words.
+fn describe(value: &Value, level: usize, text: &mut String) {
+    let indentation = "  ".repeat(level);
+    match value {
+        Value::Object(pairs) => {
+            for (key, value) in pairs.iter() {
+                let mut key = key
+                    .split('_')
+                    .map(|word| match word {
+                        "id" | "ids" | "cid" => word.to_uppercase().replace("IDS", "IDs"),
+                        _ => word.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if let Some(first) = key.get(..1) {
+                    key.replace_range(..1, &first.to_uppercase());
+                }
+                match value {
+                    Value::Object(inner) if !inner.is_empty() => {
+                        text.push_str(&format!("{indentation}{key}:\n"));
+                        describe(value, level + 1, text);
+                    }
+                    Value::Array(inner) if inner.iter().any(compound) => {
+                        text.push_str(&format!("{indentation}{key}:\n"));
+                        describe(value, level + 1, text);
+                    }
+                    _ => text.push_str(&format!("{indentation}{key}: {}\n", inline(value))),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for (i, value) in values.iter().enumerate() {
+                match value {
+                    Value::Object(_) => {
+                        if i > 0 && level == 0 {
+                            text.push('\n');
+                        }
+                        describe(value, level, text);
+                        if level > 0 {
+                            text.push_str(&format!("{indentation}--\n"));
+                        }
+                    }
+                    _ => text.push_str(&format!("{indentation}{}\n", inline(value))),
+                }
+            }
+        }
+        value => text.push_str(&format!("{indentation}{}\n", inline(value))),
+    }
+}
+
+fn inline(value: &Value) -> String {
+    match value {
+        Value::Null => "-".into(),
+        Value::String(value) => value.clone(),
+        Value::Array(values) => values.iter().map(inline).collect::<Vec<_>>().join(" "),
+        value => value.to_string(),
+    }
+}
+
+fn compound(value: &Value) -> bool {
+    matches!(value, Value::Array(_) | Value::Object(_))
+}
@@ -0,0 +1,34 @@
+//! The locating of tables in OpenType fonts.
+
+use postscript::Result;
+
+/// Return the range of a table in an OpenType font or `None` if the data are not a font of this
+/// kind or lack the table.
+pub fn find(data: &[u8], tag: &[u8; 4]) -> Result<Option<(usize, usize)>> {
+    if !matches!(&data[..data.len().min(4)], b"OTTO" | [0, 1, 0, 0] | b"true") {
+        return Ok(None);
+    }
+    let read = |position: usize, size: usize| -> Result<u32> {
+        match data.get(position..position + size) {
+            Some(bytes) => Ok(bytes.iter().fold(0, |sum, &byte| sum << 8 | byte as u32)),
+            _ => Err(std::io::ErrorKind::UnexpectedEof.into()),
+        }
+    };
+    let count = read(4, 2)? as usize;
+    for i in 0..count {
+        let position = 12 + 16 * i;
+        if data.get(position..position + 4) != Some(&tag[..]) {
+            continue;
+        }
+        let offset = read(position + 8, 4)? as usize;
+        let length = read(position + 12, 4)? as usize;
+        if offset
+            .checked_add(length)
+            .is_none_or(|end| end > data.len())
+        {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        return Ok(Some((offset, offset + length)));
+    }
+    Ok(None)
+}
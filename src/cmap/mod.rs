@@ -0,0 +1,614 @@
+//! The [CMap resources][1].
+//!
+//! A CMap maps character codes, which are sequences of bytes, to character IDs of a
+//! character-ID-keyed font or, in the case of a ToUnicode CMap, to Unicode characters.
+//!
+//! [1]: https://adobe-type-tools.github.io/font-tech-notes/pdfs/5014.CIDFont_Spec.pdf
+
+mod scanner;
+mod writing;
+
+use crate::compact1::font_set::character_id_keyed::Collection;
+use crate::compact1::CharacterID;
+use crate::error::Kind;
+use crate::unicode::glyph_name;
+use crate::Result;
+
+use scanner::Token;
+
+/// A CMap.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CMap {
+    /// The name given by `CMapName`.
+    pub name: Option<String>,
+    /// The character collection given by `CIDSystemInfo`.
+    pub collection: Option<Collection>,
+    /// The writing mode given by `WMode`, which is zero for horizontal and one for vertical.
+    pub writing_mode: i32,
+    /// The name of the CMap referred to by `usecmap`.
+    pub parent: Option<String>,
+    /// The code spaces.
+    pub code_spaces: Vec<CodeSpace>,
+    /// The mappings to character IDs given by `cidrange` and `cidchar`.
+    pub character_ids: Vec<CharacterIDRange>,
+    /// The mappings for undefined codes given by `notdefrange` and `notdefchar`.
+    pub notdefs: Vec<CharacterIDRange>,
+    /// The mappings to characters given by `bfrange` and `bfchar`.
+    pub characters: Vec<CharacterRange>,
+}
+
+/// A code space.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CodeSpace {
+    pub lower: Vec<u8>,
+    pub upper: Vec<u8>,
+}
+
+/// A range of codes mapped to consecutive character IDs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharacterIDRange {
+    pub lower: Vec<u8>,
+    pub upper: Vec<u8>,
+    pub character_id: CharacterID,
+}
+
+/// A range of codes mapped to characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CharacterRange {
+    pub lower: Vec<u8>,
+    pub upper: Vec<u8>,
+    pub destination: Destination,
+}
+
+/// A destination of a range of codes mapped to characters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Destination {
+    /// Characters whose last character is incremented for each subsequent code.
+    Consecutive(String),
+    /// Characters given for each code separately.
+    Separate(Vec<String>),
+}
+
+impl CMap {
+    /// Parse a CMap.
+    pub fn parse(text: &[u8]) -> Result<Self> {
+        let mut cmap = Self::default();
+        let mut registry = None;
+        let mut ordering = None;
+        let mut supplement = None;
+        let mut stack = vec![];
+        let mut tokens = scanner::scan(text)?.into_iter();
+        macro_rules! assign(
+            ($key:expr, $value:expr) => (
+                match ($key, $value) {
+                    (Token::Name(key), value) => match (key.as_str(), value) {
+                        ("CMapName", Token::Name(value)) => cmap.name = Some(value),
+                        ("WMode", Token::Integer(value)) => cmap.writing_mode = value as i32,
+                        ("Registry", Token::String(value)) => registry = Some(decode(value)?),
+                        ("Ordering", Token::String(value)) => ordering = Some(decode(value)?),
+                        ("Supplement", Token::Integer(value)) => supplement = Some(value as i32),
+                        _ => {}
+                    },
+                    _ => {}
+                }
+            );
+        );
+        while let Some(token) = tokens.next() {
+            let operator = match token {
+                Token::Operator(operator) => operator,
+                Token::Delimiter(">>") => {
+                    let start = match stack
+                        .iter()
+                        .rposition(|token| token == &Token::Delimiter("<<"))
+                    {
+                        Some(start) => start,
+                        _ => raise!(Kind::MalformedMapping),
+                    };
+                    let mut pairs = stack.split_off(start).into_iter().skip(1);
+                    while let (Some(key), Some(value)) = (pairs.next(), pairs.next()) {
+                        assign!(key, value);
+                    }
+                    stack.push(Token::Delimiter(">>"));
+                    continue;
+                }
+                token => {
+                    stack.push(token);
+                    continue;
+                }
+            };
+            match operator.as_str() {
+                "def" => {
+                    if stack.len() >= 2 {
+                        let value = stack.pop().unwrap();
+                        let key = stack.pop().unwrap();
+                        assign!(key, value);
+                    }
+                    stack.clear();
+                }
+                "usecmap" => match stack.pop() {
+                    Some(Token::Name(name)) => {
+                        cmap.parent = Some(name);
+                        stack.clear();
+                    }
+                    _ => raise!(Kind::MalformedMapping),
+                },
+                "begincodespacerange" => {
+                    let mut values = section(&mut tokens, "endcodespacerange")?.into_iter();
+                    while let Some(lower) = values.next() {
+                        match (lower, values.next()) {
+                            (Value::String(lower), Some(Value::String(upper)))
+                                if lower.len() == upper.len() =>
+                            {
+                                cmap.code_spaces.push(CodeSpace { lower, upper });
+                            }
+                            _ => raise!(Kind::MalformedMapping),
+                        }
+                    }
+                    stack.clear();
+                }
+                "begincidrange" | "beginnotdefrange" => {
+                    let end = operator.replace("begin", "end");
+                    let mut values = section(&mut tokens, &end)?.into_iter();
+                    while let Some(lower) = values.next() {
+                        let range = match (lower, values.next(), values.next()) {
+                            (
+                                Value::String(lower),
+                                Some(Value::String(upper)),
+                                Some(Value::Integer(character_id)),
+                            ) if lower.len() == upper.len() => CharacterIDRange {
+                                lower,
+                                upper,
+                                character_id: character_id_from(character_id)?,
+                            },
+                            _ => raise!(Kind::MalformedMapping),
+                        };
+                        match operator.as_str() {
+                            "begincidrange" => cmap.character_ids.push(range),
+                            _ => cmap.notdefs.push(range),
+                        }
+                    }
+                    stack.clear();
+                }
+                "begincidchar" | "beginnotdefchar" => {
+                    let end = operator.replace("begin", "end");
+                    let mut values = section(&mut tokens, &end)?.into_iter();
+                    while let Some(code) = values.next() {
+                        let range = match (code, values.next()) {
+                            (Value::String(code), Some(Value::Integer(character_id))) => {
+                                CharacterIDRange {
+                                    lower: code.clone(),
+                                    upper: code,
+                                    character_id: character_id_from(character_id)?,
+                                }
+                            }
+                            _ => raise!(Kind::MalformedMapping),
+                        };
+                        match operator.as_str() {
+                            "begincidchar" => cmap.character_ids.push(range),
+                            _ => cmap.notdefs.push(range),
+                        }
+                    }
+                    stack.clear();
+                }
+                "beginbfchar" => {
+                    let mut values = section(&mut tokens, "endbfchar")?.into_iter();
+                    while let Some(code) = values.next() {
+                        let characters = match (code, values.next()) {
+                            (Value::String(code), Some(Value::String(value))) => {
+                                (code, decode_utf16(&value)?)
+                            }
+                            (Value::String(code), Some(Value::Name(name))) => {
+                                match glyph_name::decode(&name) {
+                                    Some(value) => (code, value),
+                                    _ => continue,
+                                }
+                            }
+                            _ => raise!(Kind::MalformedMapping),
+                        };
+                        cmap.characters.push(CharacterRange {
+                            lower: characters.0.clone(),
+                            upper: characters.0,
+                            destination: Destination::Consecutive(characters.1),
+                        });
+                    }
+                    stack.clear();
+                }
+                "beginbfrange" => {
+                    let mut values = section(&mut tokens, "endbfrange")?.into_iter();
+                    while let Some(lower) = values.next() {
+                        let range = match (lower, values.next(), values.next()) {
+                            (
+                                Value::String(lower),
+                                Some(Value::String(upper)),
+                                Some(Value::String(value)),
+                            ) if lower.len() == upper.len() => CharacterRange {
+                                lower,
+                                upper,
+                                destination: Destination::Consecutive(decode_utf16(&value)?),
+                            },
+                            (
+                                Value::String(lower),
+                                Some(Value::String(upper)),
+                                Some(Value::Array(values)),
+                            ) if lower.len() == upper.len() => CharacterRange {
+                                lower,
+                                upper,
+                                destination: Destination::Separate(
+                                    values
+                                        .iter()
+                                        .map(|value| decode_utf16(value))
+                                        .collect::<Result<_>>()?,
+                                ),
+                            },
+                            _ => raise!(Kind::MalformedMapping),
+                        };
+                        cmap.characters.push(range);
+                    }
+                    stack.clear();
+                }
+                _ => {}
+            }
+        }
+        if let (Some(registry), Some(ordering)) = (registry, ordering) {
+            cmap.collection = Some(Collection {
+                registry,
+                ordering,
+                supplement: supplement.unwrap_or(0),
+            });
+        }
+        Ok(cmap)
+    }
+
+    /// Create the `Identity-H` or `Identity-V` CMap.
+    ///
+    /// Following the CMaps published by Adobe, the mapping is given as one range per high byte.
+    pub fn identity(writing_mode: i32) -> Self {
+        Self {
+            name: Some(
+                if writing_mode == 0 {
+                    "Identity-H"
+                } else {
+                    "Identity-V"
+                }
+                .into(),
+            ),
+            collection: Some(Default::default()),
+            writing_mode,
+            code_spaces: vec![CodeSpace {
+                lower: vec![0x00, 0x00],
+                upper: vec![0xff, 0xff],
+            }],
+            character_ids: (0..=0xff)
+                .map(|byte| CharacterIDRange {
+                    lower: vec![byte, 0x00],
+                    upper: vec![byte, 0xff],
+                    character_id: (byte as CharacterID) << 8,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Inherit the code spaces and mappings of the CMap referred to by `usecmap`.
+    ///
+    /// The mappings of the CMap itself take precedence.
+    pub fn inherit(&mut self, parent: &CMap) {
+        macro_rules! prepend(
+            ($field:ident) => (
+                self.$field = parent.$field.iter().cloned().chain(self.$field.drain(..)).collect()
+            );
+        );
+        prepend!(code_spaces);
+        prepend!(character_ids);
+        prepend!(notdefs);
+        prepend!(characters);
+        if self.collection.is_none() {
+            self.collection = parent.collection.clone();
+        }
+        self.parent = parent.parent.clone();
+    }
+
+    /// Split data into codes according to the code spaces.
+    ///
+    /// A sequence of bytes that does not match any code space is taken as a code whose length is
+    /// the length of the shortest code space.
+    pub fn split<'l>(&'l self, mut data: &'l [u8]) -> impl Iterator<Item = &'l [u8]> + 'l {
+        let fallback = self
+            .code_spaces
+            .iter()
+            .map(|space| space.lower.len())
+            .min()
+            .unwrap_or(1)
+            .max(1);
+        std::iter::from_fn(move || {
+            if data.is_empty() {
+                return None;
+            }
+            let length = (1..=4)
+                .find(|&length| {
+                    self.code_spaces
+                        .iter()
+                        .any(|space| space.contains(&data[..length.min(data.len())]))
+                })
+                .unwrap_or(fallback)
+                .min(data.len());
+            let (code, rest) = data.split_at(length);
+            data = rest;
+            Some(code)
+        })
+    }
+
+    /// Return the character ID of a code if it is mapped.
+    pub fn get_character_id(&self, code: &[u8]) -> Option<CharacterID> {
+        find(&self.character_ids, code)
+    }
+
+    /// Return the characters of a code if it is mapped.
+    pub fn get_characters(&self, code: &[u8]) -> Option<String> {
+        let range = self
+            .characters
+            .iter()
+            .rev()
+            .find(|range| contains(&range.lower, &range.upper, code))?;
+        let offset = offset(&range.lower, code)?;
+        match range.destination {
+            Destination::Consecutive(ref value) => {
+                let mut value = value.clone();
+                let last = value.pop()?;
+                value.push(char::from_u32((last as u32).checked_add(offset)?)?);
+                Some(value)
+            }
+            Destination::Separate(ref values) => values.get(offset as usize).cloned(),
+        }
+    }
+
+    /// Decode data into character IDs.
+    ///
+    /// Codes that are not mapped are mapped according to `notdefrange` and `notdefchar` and to
+    /// zero otherwise.
+    pub fn decode(&self, data: &[u8]) -> Vec<CharacterID> {
+        self.split(data)
+            .map(|code| {
+                self.get_character_id(code)
+                    .or_else(|| notdef(&self.notdefs, code))
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+impl CodeSpace {
+    /// Check if a code belongs to the code space.
+    pub fn contains(&self, code: &[u8]) -> bool {
+        code.len() == self.lower.len()
+            && code
+                .iter()
+                .zip(self.lower.iter().zip(self.upper.iter()))
+                .all(|(byte, (lower, upper))| lower <= byte && byte <= upper)
+    }
+}
+
+enum Value {
+    Integer(i64),
+    Name(String),
+    String(Vec<u8>),
+    Array(Vec<Vec<u8>>),
+}
+
+fn section<T>(tokens: &mut T, end: &str) -> Result<Vec<Value>>
+where
+    T: Iterator<Item = Token>,
+{
+    let mut values = vec![];
+    loop {
+        let value = match tokens.next() {
+            Some(Token::Operator(operator)) if operator == end => return Ok(values),
+            Some(Token::Integer(value)) => Value::Integer(value),
+            Some(Token::Name(value)) => Value::Name(value),
+            Some(Token::String(value)) => Value::String(value),
+            Some(Token::Delimiter("[")) => {
+                let mut array = vec![];
+                loop {
+                    match tokens.next() {
+                        Some(Token::String(value)) => array.push(value),
+                        Some(Token::Delimiter("]")) => break,
+                        Some(_) => raise!(Kind::MalformedMapping),
+                        _ => raise!(Kind::UnexpectedEnd),
+                    }
+                }
+                Value::Array(array)
+            }
+            Some(_) => raise!(Kind::MalformedMapping),
+            _ => raise!(Kind::UnexpectedEnd),
+        };
+        values.push(value);
+    }
+}
+
+fn character_id_from(value: i64) -> Result<CharacterID> {
+    match CharacterID::try_from(value) {
+        Ok(value) => Ok(value),
+        _ => raise!(Kind::MalformedMapping),
+    }
+}
+
+fn contains(lower: &[u8], upper: &[u8], code: &[u8]) -> bool {
+    code.len() == lower.len() && lower <= code && code <= upper
+}
+
+fn decode(value: Vec<u8>) -> Result<String> {
+    match String::from_utf8(value) {
+        Ok(value) => Ok(value),
+        _ => raise!(Kind::MalformedMapping),
+    }
+}
+
+fn decode_utf16(value: &[u8]) -> Result<String> {
+    if value.len() % 2 == 1 {
+        raise!(Kind::MalformedMapping);
+    }
+    let units = value
+        .chunks(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+    match char::decode_utf16(units).collect() {
+        Ok(value) => Ok(value),
+        _ => raise!(Kind::MalformedMapping),
+    }
+}
+
+fn find(ranges: &[CharacterIDRange], code: &[u8]) -> Option<CharacterID> {
+    let range = ranges
+        .iter()
+        .rev()
+        .find(|range| contains(&range.lower, &range.upper, code))?;
+    let value = (range.character_id as u32).checked_add(offset(&range.lower, code)?)?;
+    CharacterID::try_from(value).ok()
+}
+
+fn notdef(ranges: &[CharacterIDRange], code: &[u8]) -> Option<CharacterID> {
+    ranges
+        .iter()
+        .rev()
+        .find(|range| contains(&range.lower, &range.upper, code))
+        .map(|range| range.character_id)
+}
+
+fn offset(lower: &[u8], code: &[u8]) -> Option<u32> {
+    if code.len() > 4 {
+        return None;
+    }
+    let value = |bytes: &[u8]| bytes.iter().fold(0u32, |sum, &byte| sum << 8 | byte as u32);
+    Some(value(code) - value(lower))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CMap, Destination};
+
+    const TEXT: &[u8] = br#"%!PS-Adobe-3.0 Resource-CMap
+%%BeginResource: CMap (Test-H)
+/CIDInit /ProcSet findresource begin
+12 dict begin
+begincmap
+/CIDSystemInfo 3 dict dup begin
+  /Registry (Adobe) def
+  /Ordering (Japan1) def
+  /Supplement 6 def
+end def
+/CMapName /Test-H def
+/WMode 0 def
+/Parent-H usecmap
+2 begincodespacerange
+<00> <80>
+<8140> <9ffc>
+endcodespacerange
+1 beginnotdefrange
+<00> <1f> 231
+endnotdefrange
+2 begincidrange
+<20> <7e> 231
+<8140> <817e> 633
+endcidrange
+1 begincidchar
+<8180> 696
+endcidchar
+endcmap
+CMapName currentdict /CMap defineresource pop
+end
+end
+"#;
+
+    #[test]
+    fn parse() {
+        let cmap = CMap::parse(TEXT).unwrap();
+        assert_eq!(cmap.name.as_deref(), Some("Test-H"));
+        assert_eq!(cmap.parent.as_deref(), Some("Parent-H"));
+        assert_eq!(cmap.writing_mode, 0);
+        let collection = cmap.collection.as_ref().unwrap();
+        assert_eq!(collection.ordering, "Japan1");
+        assert_eq!(collection.supplement, 6);
+        assert_eq!(cmap.code_spaces.len(), 2);
+        assert_eq!(cmap.character_ids.len(), 3);
+        let codes = cmap.split(b"A\x81\x41\x81").collect::<Vec<_>>();
+        assert_eq!(codes, [&b"A"[..], &b"\x81\x41"[..], &b"\x81"[..]]);
+        assert_eq!(
+            cmap.decode(b"A\x81\x41\x81\x80\x01\x90"),
+            [264, 634, 696, 231, 0]
+        );
+    }
+
+    #[test]
+    fn inherit() {
+        let mut cmap = CMap::parse(TEXT).unwrap();
+        let parent = CMap::parse(
+            b"/CMapName /Parent-H def /WMode 1 def\n\
+              1 begincodespacerange <a0> <df> endcodespacerange\n\
+              1 begincidchar <a1> 327 endcidchar",
+        )
+        .unwrap();
+        assert_eq!(parent.writing_mode, 1);
+        assert!(parent.collection.is_none());
+        cmap.inherit(&parent);
+        assert!(cmap.parent.is_none());
+        assert_eq!(cmap.decode(b"\xa1A"), [327, 264]);
+    }
+
+    #[test]
+    fn unicode() {
+        let cmap = CMap::parse(
+            b"/CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+              1 begincodespacerange <0000> <ffff> endcodespacerange\n\
+              2 beginbfchar <0003> <0020> <0010> /f_i endbfchar\n\
+              2 beginbfrange\n\
+              <0024> <0026> <0041>\n\
+              <0030> <0031> [<00660066> <d835dc00>]\n\
+              endbfrange",
+        )
+        .unwrap();
+        assert_eq!(cmap.collection.as_ref().unwrap().ordering, "UCS");
+        assert_eq!(cmap.get_characters(b"\x00\x03").as_deref(), Some(" "));
+        assert_eq!(cmap.get_characters(b"\x00\x10").as_deref(), Some("fi"));
+        assert_eq!(cmap.get_characters(b"\x00\x26").as_deref(), Some("C"));
+        assert_eq!(cmap.get_characters(b"\x00\x30").as_deref(), Some("ff"));
+        assert_eq!(
+            cmap.get_characters(b"\x00\x31").as_deref(),
+            Some("\u{1d400}"),
+        );
+        assert_eq!(cmap.get_characters(b"\x00\x27"), None);
+        assert!(matches!(
+            cmap.characters[2].destination,
+            Destination::Consecutive(..),
+        ));
+    }
+
+    #[test]
+    fn identity() {
+        let cmap = CMap::identity(1);
+        assert_eq!(cmap.name.as_deref(), Some("Identity-V"));
+        assert_eq!(cmap.character_ids.len(), 256);
+        assert_eq!(cmap.decode(b"\x01\x02\xff\xff\x00"), [0x0102, 0xffff, 0]);
+    }
+
+    #[test]
+    fn overflow() {
+        let cmap = CMap::parse(
+            b"1 begincodespacerange <00000000> <ffffffff> endcodespacerange\n\
+              1 begincidrange <00000000> <ffffffff> 65535 endcidrange\n\
+              1 beginbfrange <00000000> <ffffffff> <0010ffff> endbfrange",
+        )
+        .unwrap();
+        assert_eq!(cmap.get_character_id(b"\xff\xff\xff\xff"), None);
+        assert_eq!(cmap.get_characters(b"\xff\xff\xff\xff"), None);
+        assert!(
+            CMap::parse(b"1 begincidrange <00000000> <ffffffff> 4294967295 endcidrange").is_err()
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(CMap::parse(b"1 begincodespacerange <00> endcodespacerange").is_err());
+        assert!(CMap::parse(b"1 begincidrange <00> <ff> 0").is_err());
+        assert!(CMap::parse(b"1 beginbfchar <00> <0> endbfchar").is_err());
+        assert!(CMap::parse(b"(unterminated").is_err());
+    }
+}
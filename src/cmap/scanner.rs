@@ -0,0 +1,176 @@
+use crate::error::Kind;
+use crate::Result;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Token {
+    Integer(i64),
+    Real(f64),
+    Name(String),
+    String(Vec<u8>),
+    Operator(String),
+    Delimiter(&'static str),
+}
+
+pub fn scan(text: &[u8]) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < text.len() {
+        match text[i] {
+            byte if is_space(byte) => i += 1,
+            b'%' => {
+                while i < text.len() && text[i] != b'\n' && text[i] != b'\r' {
+                    i += 1;
+                }
+            }
+            b'(' => {
+                let (value, next) = scan_literal(text, i + 1)?;
+                tokens.push(Token::String(value));
+                i = next;
+            }
+            b'<' if text.get(i + 1) == Some(&b'<') => {
+                tokens.push(Token::Delimiter("<<"));
+                i += 2;
+            }
+            b'>' if text.get(i + 1) == Some(&b'>') => {
+                tokens.push(Token::Delimiter(">>"));
+                i += 2;
+            }
+            b'<' => {
+                let (value, next) = scan_hexadecimal(text, i + 1)?;
+                tokens.push(Token::String(value));
+                i = next;
+            }
+            b'[' => {
+                tokens.push(Token::Delimiter("["));
+                i += 1;
+            }
+            b']' => {
+                tokens.push(Token::Delimiter("]"));
+                i += 1;
+            }
+            b'{' => {
+                tokens.push(Token::Delimiter("{"));
+                i += 1;
+            }
+            b'}' => {
+                tokens.push(Token::Delimiter("}"));
+                i += 1;
+            }
+            b'/' => {
+                let next = scan_regular(text, i + 1);
+                tokens.push(Token::Name(decode(&text[i + 1..next])?));
+                i = next;
+            }
+            b')' | b'>' => raise!(Kind::MalformedMapping),
+            _ => {
+                let next = scan_regular(text, i);
+                let value = decode(&text[i..next])?;
+                tokens.push(if let Ok(value) = value.parse() {
+                    Token::Integer(value)
+                } else if let (true, Ok(value)) = (
+                    value.starts_with(|c: char| c.is_ascii_digit() || "+-.".contains(c)),
+                    value.parse(),
+                ) {
+                    Token::Real(value)
+                } else {
+                    Token::Operator(value)
+                });
+                i = next;
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn scan_literal(text: &[u8], mut i: usize) -> Result<(Vec<u8>, usize)> {
+    let mut value = vec![];
+    let mut depth = 0;
+    while i < text.len() {
+        let byte = text[i];
+        i += 1;
+        match byte {
+            b'(' => depth += 1,
+            b')' if depth == 0 => return Ok((value, i)),
+            b')' => depth -= 1,
+            b'\\' => {
+                let byte = match text.get(i) {
+                    Some(&byte) => byte,
+                    _ => break,
+                };
+                i += 1;
+                match byte {
+                    b'n' => value.push(b'\n'),
+                    b'r' => value.push(b'\r'),
+                    b't' => value.push(b'\t'),
+                    b'b' => value.push(0x08),
+                    b'f' => value.push(0x0c),
+                    b'0'..=b'7' => {
+                        let mut code = (byte - b'0') as u32;
+                        for _ in 0..2 {
+                            match text.get(i) {
+                                Some(&byte @ b'0'..=b'7') => {
+                                    code = code * 8 + (byte - b'0') as u32;
+                                    i += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+                        value.push(code as u8);
+                    }
+                    b'\r' | b'\n' => {
+                        if byte == b'\r' && text.get(i) == Some(&b'\n') {
+                            i += 1;
+                        }
+                    }
+                    byte => value.push(byte),
+                }
+                continue;
+            }
+            _ => {}
+        }
+        value.push(byte);
+    }
+    raise!(Kind::UnexpectedEnd)
+}
+
+fn scan_hexadecimal(text: &[u8], mut i: usize) -> Result<(Vec<u8>, usize)> {
+    let mut digits = vec![];
+    while i < text.len() {
+        let byte = text[i];
+        i += 1;
+        match byte {
+            b'>' => {
+                if digits.len() % 2 == 1 {
+                    digits.push(0);
+                }
+                let value = digits.chunks(2).map(|pair| pair[0] << 4 | pair[1]);
+                return Ok((value.collect(), i));
+            }
+            byte if is_space(byte) => {}
+            byte => match (byte as char).to_digit(16) {
+                Some(digit) => digits.push(digit as u8),
+                _ => raise!(Kind::MalformedMapping),
+            },
+        }
+    }
+    raise!(Kind::UnexpectedEnd)
+}
+
+fn scan_regular(text: &[u8], mut i: usize) -> usize {
+    while i < text.len() && !is_space(text[i]) && !b"()<>[]{}/%".contains(&text[i]) {
+        i += 1;
+    }
+    i
+}
+
+fn decode(value: &[u8]) -> Result<String> {
+    match std::str::from_utf8(value) {
+        Ok(value) => Ok(value.into()),
+        _ => raise!(Kind::MalformedMapping),
+    }
+}
+
+#[inline]
+fn is_space(byte: u8) -> bool {
+    matches!(byte, b'\0' | b'\t' | b'\n' | b'\x0c' | b'\r' | b' ')
+}
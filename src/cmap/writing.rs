@@ -0,0 +1,246 @@
+//! The writing of CMaps.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result};
+
+use crate::cmap::{CMap, CharacterIDRange, CharacterRange, CodeSpace, Destination};
+use crate::compact1::font_set::character_id_keyed::Collection;
+
+// The maximum number of entries in a section.
+const SECTION_SIZE: usize = 100;
+
+impl CMap {
+    /// Create a ToUnicode CMap given a mapping of two-byte codes to characters.
+    ///
+    /// The codes are typically glyph IDs or character IDs. Consecutive codes mapped to
+    /// consecutive characters are combined into ranges, which do not cross boundaries of the last
+    /// byte in either the codes or the characters. Codes mapped to several characters, such as
+    /// ligatures, are written separately.
+    pub fn to_unicode(characters: &BTreeMap<u16, String>) -> Self {
+        let mut ranges: Vec<(u16, u16, &String)> = vec![];
+        for (&code, value) in characters.iter() {
+            if let Some((first, last, start)) = ranges.last_mut() {
+                if *last as u32 + 1 == code as u32
+                    && code & 0xff != 0
+                    && follows(start, *last - *first + 1, value)
+                {
+                    *last = code;
+                    continue;
+                }
+            }
+            ranges.push((code, code, value));
+        }
+        Self {
+            name: Some("Adobe-Identity-UCS".into()),
+            collection: Some(Collection {
+                registry: "Adobe".into(),
+                ordering: "UCS".into(),
+                supplement: 0,
+            }),
+            code_spaces: vec![CodeSpace {
+                lower: vec![0x00, 0x00],
+                upper: vec![0xff, 0xff],
+            }],
+            characters: ranges
+                .into_iter()
+                .map(|(first, last, value)| CharacterRange {
+                    lower: first.to_be_bytes().to_vec(),
+                    upper: last.to_be_bytes().to_vec(),
+                    destination: Destination::Consecutive(value.clone()),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Display for CMap {
+    /// Write the CMap as a PostScript resource.
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        writeln!(formatter, "%!PS-Adobe-3.0 Resource-CMap")?;
+        writeln!(formatter, "/CIDInit /ProcSet findresource begin")?;
+        writeln!(formatter, "12 dict begin")?;
+        writeln!(formatter, "begincmap")?;
+        if let Some(ref parent) = self.parent {
+            writeln!(formatter, "/{parent} usecmap")?;
+        }
+        if let Some(ref collection) = self.collection {
+            writeln!(
+                formatter,
+                "/CIDSystemInfo << /Registry ({}) /Ordering ({}) /Supplement {} >> def",
+                escape(&collection.registry),
+                escape(&collection.ordering),
+                collection.supplement,
+            )?;
+        }
+        if let Some(ref name) = self.name {
+            writeln!(formatter, "/CMapName /{name} def")?;
+        }
+        let unicode = self.character_ids.is_empty() && !self.characters.is_empty();
+        writeln!(formatter, "/CMapType {} def", if unicode { 2 } else { 1 })?;
+        if self.writing_mode != 0 {
+            writeln!(formatter, "/WMode {} def", self.writing_mode)?;
+        }
+        for spaces in self.code_spaces.chunks(SECTION_SIZE) {
+            writeln!(formatter, "{} begincodespacerange", spaces.len())?;
+            for space in spaces {
+                writeln!(formatter, "<{}> <{}>", hex(&space.lower), hex(&space.upper))?;
+            }
+            writeln!(formatter, "endcodespacerange")?;
+        }
+        write_character_ids(formatter, &self.character_ids, "cid")?;
+        write_character_ids(formatter, &self.notdefs, "notdef")?;
+        let (singles, ranges): (Vec<_>, Vec<_>) = self.characters.iter().partition(|range| {
+            range.lower == range.upper && matches!(range.destination, Destination::Consecutive(_))
+        });
+        for singles in singles.chunks(SECTION_SIZE) {
+            writeln!(formatter, "{} beginbfchar", singles.len())?;
+            for single in singles {
+                if let Destination::Consecutive(ref value) = single.destination {
+                    writeln!(formatter, "<{}> <{}>", hex(&single.lower), utf16(value))?;
+                }
+            }
+            writeln!(formatter, "endbfchar")?;
+        }
+        for ranges in ranges.chunks(SECTION_SIZE) {
+            writeln!(formatter, "{} beginbfrange", ranges.len())?;
+            for range in ranges {
+                write!(
+                    formatter,
+                    "<{}> <{}> ",
+                    hex(&range.lower),
+                    hex(&range.upper)
+                )?;
+                match range.destination {
+                    Destination::Consecutive(ref value) => {
+                        writeln!(formatter, "<{}>", utf16(value))?
+                    }
+                    Destination::Separate(ref values) => {
+                        let values = values.iter().map(|value| format!("<{}>", utf16(value)));
+                        writeln!(formatter, "[{}]", values.collect::<Vec<_>>().join(" "))?;
+                    }
+                }
+            }
+            writeln!(formatter, "endbfrange")?;
+        }
+        writeln!(formatter, "endcmap")?;
+        writeln!(formatter, "CMapName currentdict /CMap defineresource pop")?;
+        writeln!(formatter, "end")?;
+        writeln!(formatter, "end")
+    }
+}
+
+fn write_character_ids(
+    formatter: &mut Formatter<'_>,
+    ranges: &[CharacterIDRange],
+    kind: &str,
+) -> Result {
+    let (singles, ranges): (Vec<_>, Vec<_>) =
+        ranges.iter().partition(|range| range.lower == range.upper);
+    for singles in singles.chunks(SECTION_SIZE) {
+        writeln!(formatter, "{} begin{kind}char", singles.len())?;
+        for single in singles {
+            writeln!(
+                formatter,
+                "<{}> {}",
+                hex(&single.lower),
+                single.character_id
+            )?;
+        }
+        writeln!(formatter, "end{kind}char")?;
+    }
+    for ranges in ranges.chunks(SECTION_SIZE) {
+        writeln!(formatter, "{} begin{kind}range", ranges.len())?;
+        for range in ranges {
+            writeln!(
+                formatter,
+                "<{}> <{}> {}",
+                hex(&range.lower),
+                hex(&range.upper),
+                range.character_id,
+            )?;
+        }
+        writeln!(formatter, "end{kind}range")?;
+    }
+    Ok(())
+}
+
+fn follows(start: &str, offset: u16, value: &str) -> bool {
+    let mut start = start.chars();
+    let mut value = value.chars();
+    match (start.next(), start.next(), value.next(), value.next()) {
+        (Some(start), None, Some(value), None) => {
+            let (start, value) = (start as u32, value as u32);
+            value <= 0xffff && value == start + offset as u32 && value & 0xff != 0
+        }
+        _ => false,
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('(', "\\(")
+        .replace(')', "\\)")
+}
+
+fn hex(value: &[u8]) -> String {
+    value.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+fn utf16(value: &str) -> String {
+    value
+        .encode_utf16()
+        .map(|unit| format!("{unit:04X}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::cmap::{CMap, Destination};
+
+    #[test]
+    fn to_unicode() {
+        let mut characters = BTreeMap::new();
+        for code in 0x20..0x30 {
+            characters.insert(code, char::from_u32(code as u32 + 0x21).unwrap().into());
+        }
+        characters.insert(0x30, "fi".into());
+        characters.insert(0x31, "\u{1d400}".into());
+        characters.insert(0xff, "\u{1ff}".into());
+        characters.insert(0x100, "\u{200}".into());
+        let cmap = CMap::to_unicode(&characters);
+        assert_eq!(cmap.characters.len(), 5);
+        assert!(matches!(
+            cmap.characters[0].destination,
+            Destination::Consecutive(ref value) if value == "A",
+        ));
+        let text = cmap.to_string();
+        assert!(text.contains("1 beginbfrange\n<0020> <002F> <0041>\nendbfrange"));
+        assert!(text.contains("<0030> <00660069>\n"));
+        assert!(text.contains("<0031> <D835DC00>\n"));
+        let other = CMap::parse(text.as_bytes()).unwrap();
+        assert_eq!(other.characters.len(), cmap.characters.len());
+        for (code, value) in characters.iter() {
+            assert_eq!(
+                other.get_characters(&code.to_be_bytes()).as_ref(),
+                Some(value)
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut cmap = CMap::identity(1);
+        cmap.parent = Some("Parent-V".into());
+        cmap.notdefs = cmap.character_ids.clone();
+        let text = cmap.to_string();
+        assert!(text.contains("100 begincidrange\n<0000> <00FF> 0\n"));
+        assert!(text.contains("56 begincidrange\n<C800> <C8FF> 51200\n"));
+        assert!(text.contains("<FF00> <FFFF> 65280\nendcidrange\n"));
+        let other = CMap::parse(text.as_bytes()).unwrap();
+        assert_eq!(other, cmap);
+    }
+}
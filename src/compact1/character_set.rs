@@ -1,6 +1,7 @@
 //! The character sets.
 
 use crate::compact1::{GlyphID, StringID};
+use crate::error::Kind;
 use crate::Result;
 
 /// A character set.
@@ -36,6 +37,7 @@ pub struct CharacterSet2 {
 }
 
 table! {
+    @write
     /// A range of a character set in format 1.
     #[derive(Copy)]
     pub Range1 {
@@ -45,6 +47,7 @@ table! {
 }
 
 table! {
+    @write
     /// A range of a character set in format 2.
     #[derive(Copy)]
     pub Range2 {
@@ -66,6 +69,76 @@ impl CharacterSet {
             CharacterSet::Format2(ref char_set) => char_set.get(glyph_id),
         }
     }
+
+    /// Return the string identifier of the name of a glyph.
+    pub fn get_string_id(&self, glyph_id: GlyphID) -> Option<StringID> {
+        match self {
+            CharacterSet::ISOAdobe => match glyph_id {
+                0..=228 => Some(glyph_id),
+                _ => None,
+            },
+            CharacterSet::Expert => get_predefined(&EXPERT, glyph_id),
+            CharacterSet::ExpertSubset => get_predefined(&EXPERT_SUBSET, glyph_id),
+            CharacterSet::Format0(ref char_set) => char_set.get_string_id(glyph_id),
+            CharacterSet::Format1(ref char_set) => char_set.get_string_id(glyph_id),
+            CharacterSet::Format2(ref char_set) => char_set.get_string_id(glyph_id),
+        }
+    }
+}
+
+impl From<Vec<StringID>> for CharacterSet {
+    /// Create a character set in the most compact format given the string identifiers of all
+    /// glyphs except for the first one, which is always `.notdef`.
+    fn from(string_ids: Vec<StringID>) -> Self {
+        fn split(string_ids: &[StringID], limit: usize) -> Vec<(StringID, usize)> {
+            let mut ranges: Vec<(StringID, usize)> = vec![];
+            for &string_id in string_ids.iter() {
+                match ranges.last_mut() {
+                    Some((first, left))
+                        if *left < limit && *first as usize + *left + 1 == string_id as usize =>
+                    {
+                        *left += 1;
+                    }
+                    _ => ranges.push((string_id, 0)),
+                }
+            }
+            ranges
+        }
+
+        let ranges1 = split(&string_ids, u8::MAX as usize);
+        let ranges2 = split(&string_ids, u16::MAX as usize);
+        let size0 = 2 * string_ids.len();
+        let size1 = 3 * ranges1.len();
+        let size2 = 4 * ranges2.len();
+        if size0 <= size1 && size0 <= size2 {
+            CharacterSet::Format0(CharacterSet0 {
+                format: 0,
+                glyphs: string_ids,
+            })
+        } else if size1 <= size2 {
+            CharacterSet::Format1(CharacterSet1 {
+                format: 1,
+                ranges: ranges1
+                    .into_iter()
+                    .map(|(first_string_id, left_count)| Range1 {
+                        first_string_id,
+                        left_count: left_count as u8,
+                    })
+                    .collect(),
+            })
+        } else {
+            CharacterSet::Format2(CharacterSet2 {
+                format: 2,
+                ranges: ranges2
+                    .into_iter()
+                    .map(|(first_string_id, left_count)| Range2 {
+                        first_string_id,
+                        left_count: left_count as u16,
+                    })
+                    .collect(),
+            })
+        }
+    }
 }
 
 impl crate::walue::Read<'static> for CharacterSet {
@@ -76,16 +149,45 @@ impl crate::walue::Read<'static> for CharacterSet {
             0 => CharacterSet::Format0(tape.take_given(glyph_count)?),
             1 => CharacterSet::Format1(tape.take_given(glyph_count)?),
             2 => CharacterSet::Format2(tape.take_given(glyph_count)?),
-            format => raise!("found an unknown format of character sets ({format})"),
+            format => raise!(Kind::UnknownFormat(format as i32)),
         })
     }
 }
 
+impl crate::value::Write for CharacterSet {
+    /// Write the character set, which results in no data for the predefined character sets.
+    fn write<T: crate::tape::Write>(&self, tape: &mut T) -> Result<()> {
+        match self {
+            CharacterSet::ISOAdobe | CharacterSet::Expert | CharacterSet::ExpertSubset => Ok(()),
+            CharacterSet::Format0(ref char_set) => {
+                tape.give(&char_set.format)?;
+                tape.give(&*char_set.glyphs)
+            }
+            CharacterSet::Format1(ref char_set) => {
+                tape.give(&char_set.format)?;
+                tape.give(&*char_set.ranges)
+            }
+            CharacterSet::Format2(ref char_set) => {
+                tape.give(&char_set.format)?;
+                tape.give(&*char_set.ranges)
+            }
+        }
+    }
+}
+
 impl CharacterSet0 {
     #[inline]
     fn get(&self, _: GlyphID) -> Option<&'static str> {
         None
     }
+
+    #[inline]
+    fn get_string_id(&self, glyph_id: GlyphID) -> Option<StringID> {
+        match glyph_id {
+            0 => Some(0),
+            glyph_id => self.glyphs.get(glyph_id as usize - 1).copied(),
+        }
+    }
 }
 
 impl crate::walue::Read<'static> for CharacterSet0 {
@@ -93,8 +195,8 @@ impl crate::walue::Read<'static> for CharacterSet0 {
 
     fn read<T: crate::tape::Read>(tape: &mut T, glyph_count: usize) -> Result<Self> {
         let format = tape.take::<u8>()?;
-        if format != 0 {
-            raise!("found a malformed character set");
+        if format != 0 || glyph_count == 0 {
+            raise!(Kind::MalformedCharacterSet);
         }
         Ok(CharacterSet0 {
             format,
@@ -108,6 +210,23 @@ impl CharacterSet1 {
     fn get(&self, _: GlyphID) -> Option<&'static str> {
         None
     }
+
+    fn get_string_id(&self, glyph_id: GlyphID) -> Option<StringID> {
+        if glyph_id == 0 {
+            return Some(0);
+        }
+        let mut first_glyph_id = 1;
+        for range in self.ranges.iter() {
+            let offset = glyph_id as usize - first_glyph_id;
+            if offset <= range.left_count as usize {
+                return (range.first_string_id as usize)
+                    .checked_add(offset)
+                    .and_then(|string_id| StringID::try_from(string_id).ok());
+            }
+            first_glyph_id += 1 + range.left_count as usize;
+        }
+        None
+    }
 }
 
 impl crate::walue::Read<'static> for CharacterSet1 {
@@ -116,7 +235,7 @@ impl crate::walue::Read<'static> for CharacterSet1 {
     fn read<T: crate::tape::Read>(tape: &mut T, glyph_count: usize) -> Result<Self> {
         let format = tape.take::<u8>()?;
         if format != 1 {
-            raise!("found a malformed character set");
+            raise!(Kind::MalformedCharacterSet);
         }
         let mut ranges = vec![];
         #[allow(clippy::identity_op)]
@@ -127,7 +246,7 @@ impl crate::walue::Read<'static> for CharacterSet1 {
             ranges.push(range);
         }
         if found_count != glyph_count {
-            raise!("found a malformed character set");
+            raise!(Kind::MalformedCharacterSet);
         }
         Ok(CharacterSet1 { format, ranges })
     }
@@ -138,13 +257,30 @@ impl CharacterSet2 {
     fn get(&self, _: GlyphID) -> Option<&'static str> {
         None
     }
+
+    fn get_string_id(&self, glyph_id: GlyphID) -> Option<StringID> {
+        if glyph_id == 0 {
+            return Some(0);
+        }
+        let mut first_glyph_id = 1;
+        for range in self.ranges.iter() {
+            let offset = glyph_id as usize - first_glyph_id;
+            if offset <= range.left_count as usize {
+                return (range.first_string_id as usize)
+                    .checked_add(offset)
+                    .and_then(|string_id| StringID::try_from(string_id).ok());
+            }
+            first_glyph_id += 1 + range.left_count as usize;
+        }
+        None
+    }
 }
 
 impl crate::walue::Read<'static> for CharacterSet2 {
     type Parameter = usize;
 
     fn read<T: crate::tape::Read>(tape: &mut T, glyph_count: usize) -> Result<Self> {
-        macro_rules! reject(() => (raise!("found a malformed character set")));
+        macro_rules! reject(() => (raise!(Kind::MalformedCharacterSet)));
         let format = tape.take::<u8>()?;
         if format != 2 {
             reject!();
@@ -164,6 +300,33 @@ impl crate::walue::Read<'static> for CharacterSet2 {
     }
 }
 
+fn get_predefined(string_ids: &[StringID], glyph_id: GlyphID) -> Option<StringID> {
+    match glyph_id {
+        0 => Some(0),
+        glyph_id => string_ids.get(glyph_id as usize - 1).copied(),
+    }
+}
+
+const EXPERT: [StringID; 165] = [
+    1, 229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243,
+    244, 245, 246, 247, 248, 27, 28, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260,
+    261, 262, 263, 264, 265, 266, 109, 110, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 277,
+    278, 279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295, 296,
+    297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 313, 314, 315,
+    316, 317, 318, 158, 155, 163, 319, 320, 321, 322, 323, 324, 325, 326, 150, 164, 169, 327, 328,
+    329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345, 346, 347,
+    348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 360, 361, 362, 363, 364, 365, 366,
+    367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378,
+];
+
+const EXPERT_SUBSET: [StringID; 86] = [
+    1, 231, 232, 235, 236, 237, 238, 13, 14, 15, 99, 239, 240, 241, 242, 243, 244, 245, 246, 247,
+    248, 27, 28, 249, 250, 251, 253, 254, 255, 256, 257, 258, 259, 260, 261, 262, 263, 264, 265,
+    266, 109, 110, 267, 268, 269, 270, 272, 300, 301, 302, 305, 314, 315, 158, 155, 163, 320, 321,
+    322, 323, 324, 325, 326, 150, 164, 169, 327, 328, 329, 330, 331, 332, 333, 334, 335, 336, 337,
+    338, 339, 340, 341, 342, 343, 344, 345, 346,
+];
+
 fn get_iso_adobe(glyph_id: GlyphID) -> Option<&'static str> {
     Some(match glyph_id {
         1 => "space",
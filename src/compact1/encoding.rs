@@ -1,6 +1,7 @@
 //! The glyph encodings.
 
 use crate::compact1::{GlyphID, StringID};
+use crate::error::Kind;
 use crate::Result;
 
 /// A glyph encoding.
@@ -14,6 +15,7 @@ pub enum Encoding {
 }
 
 table! {
+    @write
     /// An encoding in format 0.
     pub Encoding0 { // Format 0
         format     (u8) = { 0 }, // format
@@ -26,6 +28,7 @@ table! {
 }
 
 table! {
+    @write
     /// An encoding in format 1.
     pub Encoding1 { // Format 1
         format      (u8) = { 1 }, // format
@@ -38,6 +41,7 @@ table! {
 }
 
 table! {
+    @write
     /// An encoding in the supplemental format.
     pub EncodingSupplemental { // Supplemental Encoding Data
         format           (u8),
@@ -50,6 +54,7 @@ table! {
 }
 
 table! {
+    @write
     /// A range of an encoding in format 1.
     #[derive(Copy)]
     pub Range1 {
@@ -59,6 +64,7 @@ table! {
 }
 
 table! {
+    @write
     /// A supplement of an encoding in the supplemental format.
     #[derive(Copy)]
     pub Supplement {
@@ -86,11 +92,23 @@ impl crate::value::Read for Encoding {
             0 => Encoding::Format0(tape.take()?),
             1 => Encoding::Format1(tape.take()?),
             format if format & 0x80 > 0 => Encoding::FormatSupplemental(tape.take()?),
-            format => raise!("found an unknown format of encodings ({format})"),
+            format => raise!(Kind::UnknownFormat(format as i32)),
         })
     }
 }
 
+impl crate::value::Write for Encoding {
+    /// Write the encoding, which results in no data for the predefined encodings.
+    fn write<T: crate::tape::Write>(&self, tape: &mut T) -> Result<()> {
+        match self {
+            Encoding::Standard | Encoding::Expert => Ok(()),
+            Encoding::Format0(ref encoding) => tape.give(encoding),
+            Encoding::Format1(ref encoding) => tape.give(encoding),
+            Encoding::FormatSupplemental(ref encoding) => tape.give(encoding),
+        }
+    }
+}
+
 impl Encoding0 {
     #[inline]
     fn get(&self, _: GlyphID) -> Option<StringID> {
@@ -2,9 +2,11 @@
 
 use std::io::Cursor;
 
-use crate::compact1::index::{CharacterStrings, Dictionaries, Subroutines};
+use crate::compact1::font_set::span::Recorder;
+use crate::compact1::index::{CharacterStrings, Dictionaries, Index, Subroutines};
 use crate::compact1::{GlyphID, Number, Operations, Operator, StringID};
-use crate::Result;
+use crate::error::Kind;
+use crate::{Limits, Result};
 
 /// A character-ID-keyed record in a font set.
 #[derive(Clone, Debug)]
@@ -17,6 +19,14 @@ pub struct Record {
     pub records: Vec<RecordInner>,
 }
 
+/// A character collection.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Collection {
+    pub registry: String,
+    pub ordering: String,
+    pub supplement: i32,
+}
+
 /// A record in a character-ID-keyed record in a font set.
 #[derive(Clone, Debug)]
 pub struct RecordInner {
@@ -41,6 +51,7 @@ pub struct Encoding0 {
 }
 
 table! {
+    @write
     /// A glyph-to-dictionary encoding in format 3.
     pub Encoding3 {
         format      (u8 ) = { 3 }, // format
@@ -55,6 +66,7 @@ table! {
 }
 
 table! {
+    @write
     /// A range of a glyph-to-dictionary encoding in format 3.
     #[derive(Copy)]
     pub Range3 {
@@ -63,25 +75,131 @@ table! {
     }
 }
 
+impl Default for Collection {
+    /// Create the `Adobe-Identity-0` collection.
+    fn default() -> Self {
+        Self {
+            registry: "Adobe".into(),
+            ordering: "Identity".into(),
+            supplement: 0,
+        }
+    }
+}
+
+impl Record {
+    /// Return the record of the font dictionary used by a glyph.
+    #[inline]
+    pub fn get(&self, glyph_id: GlyphID) -> Option<&RecordInner> {
+        self.encoding
+            .get(glyph_id)
+            .and_then(|index| self.records.get(index))
+    }
+}
+
+impl Encoding {
+    /// Return the index of the font dictionary used by a glyph.
+    #[inline]
+    pub fn get(&self, glyph_id: GlyphID) -> Option<usize> {
+        match self {
+            Encoding::Format0(ref encoding) => encoding.get(glyph_id),
+            Encoding::Format3(ref encoding) => encoding.get(glyph_id),
+        }
+    }
+}
+
+impl From<Vec<u8>> for Encoding {
+    /// Create an encoding in the most compact format given the font dictionaries of all glyphs.
+    fn from(dictionary_ids: Vec<u8>) -> Self {
+        let mut ranges: Vec<Range3> = vec![];
+        for (glyph_id, &dictionary_id) in dictionary_ids.iter().enumerate() {
+            match ranges.last() {
+                Some(range) if range.dictionary_id == dictionary_id => {}
+                _ => ranges.push(Range3 {
+                    first_glyph_id: glyph_id as GlyphID,
+                    dictionary_id,
+                }),
+            }
+        }
+        if dictionary_ids.len() <= 4 + 3 * ranges.len() {
+            Encoding::Format0(Encoding0 {
+                format: 0,
+                dictionary_ids,
+            })
+        } else {
+            Encoding::Format3(Encoding3 {
+                format: 3,
+                range_count: ranges.len() as u16,
+                ranges,
+                glyph_count: dictionary_ids.len() as GlyphID,
+            })
+        }
+    }
+}
+
+impl Encoding0 {
+    #[inline]
+    fn get(&self, glyph_id: GlyphID) -> Option<usize> {
+        self.dictionary_ids
+            .get(glyph_id as usize)
+            .map(|&index| index as usize)
+    }
+}
+
+impl Encoding3 {
+    fn get(&self, glyph_id: GlyphID) -> Option<usize> {
+        if glyph_id >= self.glyph_count {
+            return None;
+        }
+        let index = match self
+            .ranges
+            .binary_search_by_key(&glyph_id, |range| range.first_glyph_id)
+        {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+        Some(self.ranges[index].dictionary_id as usize)
+    }
+}
+
 impl<'l> crate::walue::Read<'l> for Record {
-    type Parameter = (u64, &'l Operations, &'l CharacterStrings);
+    type Parameter = (u64, &'l Operations, &'l CharacterStrings, &'l mut Limits);
 
-    fn read<T: crate::tape::Read>(
+    #[inline]
+    fn read<T: crate::tape::Read>(tape: &mut T, parameter: Self::Parameter) -> Result<Self> {
+        Record::read_recorded(tape, parameter, &mut Recorder::default())
+    }
+}
+
+impl Record {
+    pub(super) fn read_recorded<T: crate::tape::Read>(
         tape: &mut T,
-        (position, top_operations, character_strings): Self::Parameter,
+        (position, top_operations, character_strings, limits): (
+            u64,
+            &Operations,
+            &CharacterStrings,
+            &mut Limits,
+        ),
+        recorder: &mut Recorder,
     ) -> Result<Self> {
         let operands = match top_operations.get(Operator::ROS) {
             Some(operands) if operands.len() == 3 => operands,
-            _ => raise!("found a malformed character-ID-keyed record"),
+            _ => raise!(Kind::MalformedRecord),
         };
         let offset = get!(@single top_operations, FDSelect);
-        let encoding = jump_take_given!(@unwrap tape, position, offset, character_strings);
+        let encoding = span!(tape, recorder, DictionarySelector @ position + offset as u64 => {
+            jump_take_given!(@unwrap tape, position, offset, character_strings)
+        });
         let offset = get!(@single top_operations, FDArray);
-        let operations: Dictionaries = jump_take!(@unwrap tape, position, offset);
-        let operations: Vec<_> = (&operations).try_into()?;
+        let operations = span!(tape, recorder, FontDictionaries @ position + offset as u64 => {
+            tape.jump(position + offset as u64)?;
+            Vec::<Operations>::try_from(&Dictionaries::read_recorded(tape, limits, recorder)?)?
+        });
         let mut records = vec![];
         for top_operations in operations.iter() {
-            records.push(tape.take_given((position, top_operations))?);
+            records.push(locate!(FontDictionaries @ position + offset as u64 => {
+                RecordInner::read_recorded(tape, (position, top_operations, limits), recorder)?
+            }));
         }
         Ok(Self {
             registry: operands[0].try_into()?,
@@ -95,19 +213,36 @@ impl<'l> crate::walue::Read<'l> for Record {
 }
 
 impl<'l> crate::walue::Read<'l> for RecordInner {
-    type Parameter = (u64, &'l Operations);
+    type Parameter = (u64, &'l Operations, &'l mut Limits);
 
-    fn read<T: crate::tape::Read>(
+    #[inline]
+    fn read<T: crate::tape::Read>(tape: &mut T, parameter: Self::Parameter) -> Result<Self> {
+        RecordInner::read_recorded(tape, parameter, &mut Recorder::default())
+    }
+}
+
+impl RecordInner {
+    fn read_recorded<T: crate::tape::Read>(
         tape: &mut T,
-        (position, top_operations): Self::Parameter,
+        (position, top_operations, limits): (u64, &Operations, &mut Limits),
+        recorder: &mut Recorder,
     ) -> Result<Self> {
-        use crate::tape::Read;
-
         let (size, offset) = get!(@double top_operations, Private);
-        let chunk: Vec<u8> = jump_take_given!(@unwrap tape, position, offset, size as usize);
-        let operations = Cursor::new(chunk).take::<Operations>()?;
+        let operations = span!(tape, recorder, PrivateDictionary @ position + offset as u64 => {
+            limits.allocate(size as usize)?;
+            let chunk: Vec<u8> = jump_take_given!(@unwrap tape, position, offset, size as usize);
+            Operations::read_recorded(&mut Cursor::new(chunk), position + offset as u64, recorder)?
+        });
         let subroutines = match get!(@try @single operations, Subrs) {
-            Some(another_offset) => jump_take!(@unwrap tape, position, offset + another_offset),
+            Some(another_offset) => match offset.checked_add(another_offset) {
+                Some(offset) => {
+                    span!(tape, recorder, LocalSubroutines @ position + offset as u64 => {
+                        tape.jump(position + offset as u64)?;
+                        Subroutines(Index::read_recorded(tape, limits, recorder)?)
+                    })
+                }
+                _ => raise!(Kind::MalformedOperation(Operator::Subrs)),
+            },
             _ => Default::default(),
         };
         Ok(Self {
@@ -127,13 +262,23 @@ impl<'l> crate::walue::Read<'l> for Encoding {
         Ok(match tape.peek::<u8>()? {
             0 => Encoding::Format0(tape.take_given(character_strings)?),
             3 => Encoding::Format3(tape.take()?),
-            format => {
-                raise!("found an unknown format of the glyph-to-dictionary encoding ({format})")
-            }
+            format => raise!(Kind::UnknownFormat(format as i32)),
         })
     }
 }
 
+impl crate::value::Write for Encoding {
+    fn write<T: crate::tape::Write>(&self, tape: &mut T) -> Result<()> {
+        match self {
+            Encoding::Format0(ref encoding) => {
+                tape.give(&encoding.format)?;
+                tape.give_bytes(&encoding.dictionary_ids)
+            }
+            Encoding::Format3(ref encoding) => tape.give(encoding),
+        }
+    }
+}
+
 impl<'l> crate::walue::Read<'l> for Encoding0 {
     type Parameter = &'l CharacterStrings;
 
@@ -142,7 +287,9 @@ impl<'l> crate::walue::Read<'l> for Encoding0 {
         character_strings: Self::Parameter,
     ) -> Result<Self> {
         let format = tape.take()?;
-        debug_assert_eq!(format, 0);
+        if format != 0 {
+            raise!(Kind::MalformedEncoding);
+        }
         Ok(Self {
             format,
             dictionary_ids: tape.take_given(character_strings.count as usize)?,
@@ -2,9 +2,11 @@
 
 use std::io::Cursor;
 
-use crate::compact1::index::Subroutines;
-use crate::compact1::Operations;
-use crate::Result;
+use crate::compact1::font_set::span::Recorder;
+use crate::compact1::index::{Index, Subroutines};
+use crate::compact1::{Operations, Operator};
+use crate::error::Kind;
+use crate::{Limits, Result};
 
 /// A character-name-keyed record in a font set.
 #[derive(Clone, Debug)]
@@ -14,19 +16,36 @@ pub struct Record {
 }
 
 impl<'l> crate::walue::Read<'l> for Record {
-    type Parameter = (u64, &'l Operations);
+    type Parameter = (u64, &'l Operations, &'l mut Limits);
 
-    fn read<T: crate::tape::Read>(
+    #[inline]
+    fn read<T: crate::tape::Read>(tape: &mut T, parameter: Self::Parameter) -> Result<Self> {
+        Record::read_recorded(tape, parameter, &mut Recorder::default())
+    }
+}
+
+impl Record {
+    pub(super) fn read_recorded<T: crate::tape::Read>(
         tape: &mut T,
-        (position, top_operations): Self::Parameter,
+        (position, top_operations, limits): (u64, &Operations, &mut Limits),
+        recorder: &mut Recorder,
     ) -> Result<Self> {
-        use crate::tape::Read;
-
         let (size, offset) = get!(@double top_operations, Private);
-        let chunk: Vec<u8> = jump_take_given!(@unwrap tape, position, offset, size as usize);
-        let operations = Cursor::new(chunk).take::<Operations>()?;
+        let operations = span!(tape, recorder, PrivateDictionary @ position + offset as u64 => {
+            limits.allocate(size as usize)?;
+            let chunk: Vec<u8> = jump_take_given!(@unwrap tape, position, offset, size as usize);
+            Operations::read_recorded(&mut Cursor::new(chunk), position + offset as u64, recorder)?
+        });
         let subroutines = match get!(@try @single operations, Subrs) {
-            Some(another_offset) => jump_take!(@unwrap tape, position, offset + another_offset),
+            Some(another_offset) => match offset.checked_add(another_offset) {
+                Some(offset) => {
+                    span!(tape, recorder, LocalSubroutines @ position + offset as u64 => {
+                        tape.jump(position + offset as u64)?;
+                        Subroutines(Index::read_recorded(tape, limits, recorder)?)
+                    })
+                }
+                _ => raise!(Kind::MalformedOperation(Operator::Subrs)),
+            },
             _ => Default::default(),
         };
         Ok(Self {
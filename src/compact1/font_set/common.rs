@@ -0,0 +1,120 @@
+//! The helpers shared by the transformations of records.
+
+use crate::compact1::{GlyphID, Operations, Operator};
+use crate::error::Kind;
+use crate::type2::token::Token;
+use crate::type2::Operand;
+use crate::Result;
+
+// A change of the addresses of subroutines, which are given with respect to a bias.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct Shift {
+    pub old_bias: i32,
+    pub base: usize,
+    pub new_bias: i32,
+}
+
+impl Shift {
+    pub fn new(old_count: usize, base: usize, new_count: usize) -> Self {
+        Self {
+            old_bias: crate::type2::bias(old_count),
+            base,
+            new_bias: crate::type2::bias(new_count),
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            old_bias: 0,
+            base: 0,
+            new_bias: 0,
+        }
+    }
+
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        self.base == 0 && self.old_bias == self.new_bias
+    }
+
+    fn apply(&self, address: i32) -> i32 {
+        address + self.old_bias + self.base as i32 - self.new_bias
+    }
+}
+
+// Rewrite the addresses of the subroutine calls of a routine. The absence of a local shift means
+// that local calls cannot be rewritten.
+pub(super) fn rebase(tokens: &mut [Token], local: Option<Shift>, global: Shift) -> Result<()> {
+    for i in 0..tokens.len() {
+        let shift = match tokens[i] {
+            Token::Operator(crate::type2::Operator::CallSubr) => match local {
+                Some(local) => local,
+                _ => raise!(Kind::UnsupportedSubroutineCall),
+            },
+            Token::Operator(crate::type2::Operator::CallGSubr) => global,
+            _ => continue,
+        };
+        if shift.is_identity() {
+            continue;
+        }
+        let address = match i.checked_sub(1).and_then(|i| tokens[i].value()) {
+            Some(address) if address.fract() == 0.0 => address as i32,
+            _ => raise!(Kind::UnsupportedSubroutineCall),
+        };
+        tokens[i - 1] = Token::Integer(shift.apply(address));
+    }
+    Ok(())
+}
+
+// Encode the width of a charstring with respect to other widths given as default and nominal.
+// A width is only located when it is the first operand of the first operator, which then has to
+// take a width, as it might otherwise be pushed by a subroutine or computed.
+pub(super) fn rewidth(
+    tokens: &mut Vec<Token>,
+    glyph_id: GlyphID,
+    width: Option<Operand>,
+    (old_default_width, old_nominal_width): (Operand, Operand),
+    (default_width, nominal_width): (Operand, Operand),
+) -> Result<()> {
+    let value = match width {
+        Some(width) => old_nominal_width + width,
+        _ => old_default_width,
+    };
+    if width.is_some() {
+        use crate::type2::Operator::*;
+
+        let found = match tokens.iter().position(|token| token.value().is_none()) {
+            Some(0) | None => false,
+            Some(i) => matches!(
+                tokens[i],
+                Token::Operator(
+                    HStem
+                        | VStem
+                        | HStemHM
+                        | VStemHM
+                        | HintMask
+                        | CntrMask
+                        | RMoveTo
+                        | HMoveTo
+                        | VMoveTo
+                        | EndChar
+                )
+            ),
+        };
+        if !found {
+            raise!(Kind::UnsupportedWidth(glyph_id));
+        }
+        tokens.remove(0);
+    }
+    if value != default_width {
+        tokens.insert(0, Token::from(value - nominal_width));
+    }
+    Ok(())
+}
+
+pub(super) fn get(operations: &Operations, operator: Operator) -> Operand {
+    match operations.get_single(operator) {
+        Some(number) => number.to_f32(),
+        _ => 0.0,
+    }
+}
@@ -0,0 +1,379 @@
+//! The conversion between character-ID-keyed and character-name-keyed records.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::compact1::font_set::character_id_keyed::Collection;
+use crate::compact1::font_set::common::{get, rebase, rewidth, Shift};
+use crate::compact1::font_set::{character_id_keyed, character_name_keyed, FontSet, Record};
+use crate::compact1::index::{CharacterStrings, Index, StringTable, Subroutines};
+use crate::compact1::{CharacterID, CharacterSet, Encoding, GlyphID, Number, Operations, Operator};
+use crate::error::{Call, Kind};
+use crate::type2::outline::{multiply, IDENTITY};
+use crate::type2::token::{self, Token};
+use crate::type2::Program;
+use crate::Result;
+
+impl FontSet {
+    /// Convert a character-ID-keyed record into a character-name-keyed one.
+    ///
+    /// The private dictionaries of the font dictionaries are merged, which requires them to agree
+    /// on everything except for the local subroutines and the widths. The local subroutines are
+    /// concatenated, and the charstrings are rewritten to call them by their new addresses and to
+    /// encode their widths with respect to the widths of the first font dictionary. The font
+    /// matrices of the font dictionaries are folded into the one of the top dictionary, which
+    /// requires them to be the same. The glyphs are named after their CIDs, as in `cid01234`.
+    pub fn key_by_character_name(&mut self, index: usize) -> Result<()> {
+        let record = match self.records.get(index) {
+            Some(Record::CharacterIDKeyed(ref record)) => record,
+            _ => raise!(Kind::UnexpectedRecord),
+        };
+        if record.records.is_empty() || record.records.len() != record.operations.len() {
+            raise!(Kind::MalformedRecord);
+        }
+        let matrix = merge_matrices(&self.operations[index], &record.operations)?;
+        let mut operations = merge_private_operations(&record.records)?;
+
+        let widths = record
+            .records
+            .iter()
+            .map(|record| {
+                (
+                    get(&record.operations, Operator::DefaultWidthX),
+                    get(&record.operations, Operator::NominalWidthX),
+                )
+            })
+            .collect::<Vec<_>>();
+        // Identical local subroutines of different font dictionaries are included only once.
+        let mut bases = Vec::with_capacity(record.records.len());
+        let mut owners = Vec::with_capacity(record.records.len());
+        let mut subroutines = vec![];
+        for (i, inner) in record.records.iter().enumerate() {
+            let other = record.records[..i]
+                .iter()
+                .position(|other| other.subroutines.data == inner.subroutines.data);
+            match other {
+                Some(other) => bases.push(bases[other]),
+                _ => {
+                    bases.push(subroutines.len());
+                    subroutines.extend(inner.subroutines.iter().cloned());
+                }
+            }
+            owners.push(other.unwrap_or(i));
+        }
+        let bias = crate::type2::bias(subroutines.len());
+        let rebased = bases
+            .iter()
+            .zip(record.records.iter())
+            .any(|(&base, record)| {
+                (base != 0 && !record.subroutines.is_empty())
+                    || crate::type2::bias(record.subroutines.len()) != bias
+            });
+
+        let character_strings = &self.character_strings[index];
+        let mut masks: HashMap<(usize, Call), HashMap<u64, usize>> = HashMap::new();
+        let mut outcomes = Vec::with_capacity(character_strings.len());
+        for (glyph_id, code) in character_strings.iter().enumerate() {
+            let dictionary_id = match record.encoding.get(glyph_id as GlyphID) {
+                Some(dictionary_id) if dictionary_id < record.records.len() => dictionary_id,
+                Some(dictionary_id) => raise!(Kind::MissingDictionary(dictionary_id)),
+                _ => raise!(Kind::MalformedRecord),
+            };
+            let local = &record.records[dictionary_id].subroutines;
+            let mut program = Program::new(code, &self.subroutines, local);
+            program.set_glyph_id(glyph_id as GlyphID);
+            program.track_masks();
+            while program.next()?.is_some() {}
+            let mut own = HashMap::new();
+            for &(call, position, size) in program.masks().unwrap_or(&[]) {
+                let key = match call {
+                    Some(call) if call.global => (usize::MAX, call),
+                    Some(call) => (owners[dictionary_id], call),
+                    _ => {
+                        own.insert(position, size);
+                        continue;
+                    }
+                };
+                masks.entry(key).or_default().insert(position, size);
+            }
+            outcomes.push((dictionary_id, program.width(), own));
+        }
+
+        if rebased {
+            // Global subroutines are shared and hence cannot refer to local ones by new addresses.
+            for (i, code) in self.subroutines.iter().enumerate() {
+                let call = Call {
+                    global: true,
+                    index: i,
+                };
+                if let Some(masks) = masks.get(&(usize::MAX, call)) {
+                    let tokens = token::decode(code, |position| masks.get(&position).copied())?;
+                    if tokens.contains(&Token::Operator(crate::type2::Operator::CallSubr)) {
+                        raise!(Kind::UnsupportedSubroutineCall);
+                    }
+                }
+            }
+            for (dictionary_id, record) in record.records.iter().enumerate() {
+                if owners[dictionary_id] != dictionary_id {
+                    continue;
+                }
+                for (i, code) in record.subroutines.iter().enumerate() {
+                    let call = Call {
+                        global: false,
+                        index: i,
+                    };
+                    let masks = masks.get(&(dictionary_id, call));
+                    let size = |position| masks.and_then(|masks| masks.get(&position).copied());
+                    let mut tokens = match token::decode(code, size) {
+                        Ok(tokens) => tokens,
+                        // The subroutine is never called; hence, it is left as it is.
+                        _ => continue,
+                    };
+                    let shift = Shift::new(
+                        record.subroutines.len(),
+                        bases[dictionary_id],
+                        subroutines.len(),
+                    );
+                    rebase(&mut tokens, Some(shift), Shift::identity())?;
+                    subroutines[bases[dictionary_id] + i] = token::encode(&tokens);
+                }
+            }
+        }
+
+        let mut codes = Vec::with_capacity(character_strings.len());
+        let outcomes = character_strings.iter().zip(outcomes.iter()).enumerate();
+        for (glyph_id, (code, (dictionary_id, width, masks))) in outcomes {
+            let (dictionary_id, width) = (*dictionary_id, *width);
+            let rewidthed = widths[dictionary_id] != widths[0];
+            if !rebased && !rewidthed {
+                codes.push(code.clone());
+                continue;
+            }
+            let mut tokens = token::decode(code, |position| masks.get(&position).copied())?;
+            if rebased {
+                let local = &record.records[dictionary_id].subroutines;
+                let shift = Shift::new(local.len(), bases[dictionary_id], subroutines.len());
+                rebase(&mut tokens, Some(shift), Shift::identity())?;
+            }
+            if rewidthed {
+                let (old, new) = (widths[dictionary_id], widths[0]);
+                rewidth(&mut tokens, glyph_id as GlyphID, width, old, new)?;
+            }
+            codes.push(token::encode(&tokens));
+        }
+
+        let glyph_count = codes.len();
+        let character_set = &self.character_sets[index];
+        let mut names = Vec::with_capacity(glyph_count.saturating_sub(1));
+        for glyph_id in 1..glyph_count {
+            match character_set.get_string_id(glyph_id as GlyphID) {
+                Some(cid) => names.push(format!("cid{cid:05}")),
+                _ => raise!(Kind::MalformedCharacterSet),
+            }
+        }
+        let mut strings = StringTable::from(&self.strings);
+        let string_ids = names
+            .iter()
+            .map(|name| strings.insert(name))
+            .collect::<Result<Vec<_>>>()?;
+        let character_set = CharacterSet::from(string_ids);
+
+        if subroutines.is_empty() {
+            operations.0.remove(&Operator::Subrs);
+        } else {
+            operations
+                .0
+                .entry(Operator::Subrs)
+                .or_insert_with(|| vec![Number::Integer(0)]);
+        }
+        let private = record.operations[0]
+            .get(Operator::Private)
+            .map(<[Number]>::to_vec)
+            .unwrap_or_else(|| vec![Number::Integer(0), Number::Integer(0)]);
+        let top_operations = &mut self.operations[index];
+        for operator in [
+            Operator::ROS,
+            Operator::CIDFontVersion,
+            Operator::CIDFontRevision,
+            Operator::CIDFontType,
+            Operator::CIDCount,
+            Operator::UIDBase,
+            Operator::FDArray,
+            Operator::FDSelect,
+            Operator::FontMatrix,
+        ] {
+            top_operations.0.remove(&operator);
+        }
+        if Operator::FontMatrix.default().map(to_matrix) != Some(matrix) {
+            top_operations.0.insert(
+                Operator::FontMatrix,
+                matrix.iter().map(|&value| Number::from(value)).collect(),
+            );
+        }
+        top_operations.0.insert(Operator::Private, private);
+
+        self.strings = strings.into();
+        self.character_sets[index] = character_set;
+        self.character_strings[index] = CharacterStrings(Index::from(codes));
+        self.records[index] = Record::CharacterNameKeyed(character_name_keyed::Record {
+            operations,
+            subroutines: Subroutines(Index::from(subroutines)),
+        });
+        Ok(())
+    }
+
+    /// Convert a character-name-keyed record into a character-ID-keyed one.
+    ///
+    /// The CIDs are looked up in the given mapping by glyph name. In the absence of a mapping,
+    /// the CID of each glyph is its glyph identifier. The result has a single font dictionary,
+    /// which carries the private dictionary and the local subroutines of the original record.
+    pub fn key_by_character_id(
+        &mut self,
+        index: usize,
+        collection: &Collection,
+        mapping: Option<&HashMap<String, CharacterID>>,
+    ) -> Result<()> {
+        let record = match self.records.get(index) {
+            Some(Record::CharacterNameKeyed(ref record)) => record,
+            _ => raise!(Kind::UnexpectedRecord),
+        };
+        let glyph_count = self.character_strings[index].count as usize;
+        let mut cids = Vec::with_capacity(glyph_count.saturating_sub(1));
+        let mut seen = HashSet::new();
+        seen.insert(0);
+        for glyph_id in 1..glyph_count {
+            let glyph_id = glyph_id as GlyphID;
+            let cid = match mapping {
+                Some(mapping) => match self.character_sets[index]
+                    .get_string_id(glyph_id)
+                    .and_then(|string_id| self.strings.get(string_id))
+                    .and_then(|name| mapping.get(&name))
+                {
+                    Some(&cid) => cid,
+                    _ => raise!(Kind::MissingCharacterID(glyph_id)),
+                },
+                _ => glyph_id,
+            };
+            if !seen.insert(cid) {
+                raise!(Kind::DuplicateCharacterID(cid));
+            }
+            cids.push(cid);
+        }
+        let count = cids.iter().max().map(|&cid| cid as i32 + 1).unwrap_or(1);
+
+        let mut strings = StringTable::from(&self.strings);
+        let mut font_operations = Operations::default();
+        if let Some(name) = self.names.get(index) {
+            let string_id = strings.insert(name)?;
+            font_operations
+                .0
+                .insert(Operator::FontName, vec![Number::Integer(string_id as i32)]);
+        }
+        let private = self.operations[index]
+            .get(Operator::Private)
+            .map(<[Number]>::to_vec)
+            .unwrap_or_else(|| vec![Number::Integer(0), Number::Integer(0)]);
+        font_operations.0.insert(Operator::Private, private);
+        let registry = strings.insert(&collection.registry)?;
+        let ordering = strings.insert(&collection.ordering)?;
+        self.strings = strings.into();
+        let supplement = Number::Integer(collection.supplement);
+
+        let top_operations = &mut self.operations[index];
+        for operator in [Operator::Private, Operator::Encoding] {
+            top_operations.0.remove(&operator);
+        }
+        for (operator, operands) in [
+            (
+                Operator::ROS,
+                vec![
+                    Number::Integer(registry as i32),
+                    Number::Integer(ordering as i32),
+                    supplement,
+                ],
+            ),
+            (Operator::CIDCount, vec![Number::Integer(count)]),
+            (Operator::FDArray, vec![Number::Integer(0)]),
+            (Operator::FDSelect, vec![Number::Integer(0)]),
+        ] {
+            top_operations.0.insert(operator, operands);
+        }
+
+        let inner = character_id_keyed::RecordInner {
+            operations: record.operations.clone(),
+            subroutines: record.subroutines.clone(),
+        };
+        self.records[index] = Record::CharacterIDKeyed(character_id_keyed::Record {
+            registry,
+            ordering,
+            supplement,
+            encoding: character_id_keyed::Encoding::Format3(character_id_keyed::Encoding3 {
+                format: 3,
+                range_count: 1,
+                ranges: vec![character_id_keyed::Range3 {
+                    first_glyph_id: 0,
+                    dictionary_id: 0,
+                }],
+                glyph_count: glyph_count as GlyphID,
+            }),
+            operations: vec![font_operations],
+            records: vec![inner],
+        });
+        self.character_sets[index] = CharacterSet::from(cids);
+        self.encodings[index] = Encoding::Standard;
+        Ok(())
+    }
+}
+
+fn merge_matrices(top_operations: &Operations, operations: &[Operations]) -> Result<[f32; 6]> {
+    let top = top_operations
+        .0
+        .get(&Operator::FontMatrix)
+        .map(|operands| to_matrix(operands));
+    let mut result = None;
+    for operations in operations.iter() {
+        let other = operations
+            .0
+            .get(&Operator::FontMatrix)
+            .map(|operands| to_matrix(operands));
+        let matrix = match (top, other) {
+            (Some(top), Some(other)) => multiply(&other, &top),
+            (Some(matrix), _) | (_, Some(matrix)) => matrix,
+            _ => to_matrix(Operator::FontMatrix.default().unwrap_or(&[])),
+        };
+        match result {
+            Some(result) if result != matrix => raise!(Kind::IncompatibleDictionaries),
+            _ => result = Some(matrix),
+        }
+    }
+    Ok(result.unwrap_or(IDENTITY))
+}
+
+fn merge_private_operations(records: &[character_id_keyed::RecordInner]) -> Result<Operations> {
+    let strip = |operations: &Operations| {
+        let mut operations = operations.0.clone();
+        for operator in [
+            Operator::Subrs,
+            Operator::DefaultWidthX,
+            Operator::NominalWidthX,
+        ] {
+            operations.remove(&operator);
+        }
+        operations
+    };
+    let first = strip(&records[0].operations);
+    if records[1..]
+        .iter()
+        .any(|record| strip(&record.operations) != first)
+    {
+        raise!(Kind::IncompatibleDictionaries);
+    }
+    Ok(records[0].operations.clone())
+}
+
+pub(super) fn to_matrix(operands: &[Number]) -> [f32; 6] {
+    let mut matrix = IDENTITY;
+    for (value, operand) in matrix.iter_mut().zip(operands.iter()) {
+        *value = operand.to_f32();
+    }
+    matrix
+}
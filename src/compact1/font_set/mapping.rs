@@ -0,0 +1,156 @@
+//! The mapping of characters to glyphs.
+
+use std::collections::BTreeMap;
+
+use crate::compact1::font_set::character_id_keyed::Collection;
+use crate::compact1::font_set::{FontSet, Record};
+use crate::compact1::{CharacterID, GlyphID, Number};
+use crate::error::Kind;
+use crate::unicode::collection::{self, Mapping};
+use crate::unicode::glyph_name;
+use crate::Result;
+
+impl FontSet {
+    /// Map characters to the glyphs of a record.
+    ///
+    /// For a character-name-keyed record, the mapping is based on the glyph names. Glyphs whose
+    /// names represent several characters, such as ligatures, are not included. For a
+    /// character-ID-keyed record, the mapping is based on the built-in mapping of the character
+    /// collection, which is partial as described in [`Mapping::new`] and empty if the collection
+    /// is unknown; [`FontSet::map_characters_with`] accepts a complete mapping instead. If several
+    /// glyphs represent the same character, the first one is taken.
+    pub fn map_characters(&self, index: usize) -> Result<BTreeMap<char, GlyphID>> {
+        Ok(invert(self.map_glyphs(index)?))
+    }
+
+    /// Map characters to the glyphs of a character-ID-keyed record given a mapping of the
+    /// character collection.
+    pub fn map_characters_with(
+        &self,
+        index: usize,
+        mapping: &Mapping,
+    ) -> Result<BTreeMap<char, GlyphID>> {
+        Ok(invert(self.map_glyphs_with(index, mapping)?))
+    }
+
+    /// Map the glyphs of a record to characters.
+    ///
+    /// The mapping is derived in the same way as for [`FontSet::map_characters`], except that
+    /// glyphs representing several characters, such as ligatures, are included. It can be used
+    /// for generating a ToUnicode [`CMap`](crate::cmap::CMap).
+    pub fn map_glyphs(&self, index: usize) -> Result<BTreeMap<GlyphID, String>> {
+        let mapping = match self.records.get(index) {
+            Some(Record::CharacterNameKeyed(_)) => None,
+            Some(Record::CharacterIDKeyed(_)) => match self.collection(index) {
+                Some(collection) => Some(Mapping::new(&collection).unwrap_or_default()),
+                _ => raise!(Kind::MalformedRecord),
+            },
+            _ => raise!(Kind::UnexpectedRecord),
+        };
+        match mapping {
+            Some(mapping) => self.map_glyphs_with(index, &mapping),
+            _ => {
+                let mut glyphs = BTreeMap::new();
+                for glyph_id in 1..self.character_strings[index].count {
+                    if let Some(value) = self.character_sets[index]
+                        .get_string_id(glyph_id)
+                        .and_then(|string_id| self.strings.get(string_id))
+                        .and_then(|name| glyph_name::decode(&name))
+                    {
+                        glyphs.insert(glyph_id, value);
+                    }
+                }
+                Ok(glyphs)
+            }
+        }
+    }
+
+    /// Map the glyphs of a character-ID-keyed record to characters given a mapping of the
+    /// character collection.
+    pub fn map_glyphs_with(
+        &self,
+        index: usize,
+        mapping: &Mapping,
+    ) -> Result<BTreeMap<GlyphID, String>> {
+        match self.records.get(index) {
+            Some(Record::CharacterIDKeyed(_)) => {}
+            _ => raise!(Kind::UnexpectedRecord),
+        }
+        let mut glyphs = BTreeMap::new();
+        for glyph_id in 1..self.character_strings[index].count {
+            if let Some(character) = self.character_sets[index]
+                .get_string_id(glyph_id)
+                .and_then(|character_id| mapping.get(character_id))
+            {
+                glyphs.insert(glyph_id, character.into());
+            }
+        }
+        Ok(glyphs)
+    }
+
+    /// Map the character IDs of a character-ID-keyed record to glyphs.
+    ///
+    /// Together with a [`CMap`](crate::cmap::CMap), the mapping can be used for going from
+    /// encoded text to glyphs.
+    pub fn map_character_ids(&self, index: usize) -> Result<BTreeMap<CharacterID, GlyphID>> {
+        match self.records.get(index) {
+            Some(Record::CharacterIDKeyed(_)) => {}
+            _ => raise!(Kind::UnexpectedRecord),
+        }
+        let mut mapping = BTreeMap::new();
+        mapping.insert(0, 0);
+        for glyph_id in 1..self.character_strings[index].count {
+            if let Some(character_id) = self.character_sets[index].get_string_id(glyph_id) {
+                mapping.entry(character_id).or_insert(glyph_id);
+            }
+        }
+        Ok(mapping)
+    }
+
+    /// Return the character collection of a character-ID-keyed record.
+    pub fn collection(&self, index: usize) -> Option<Collection> {
+        let record = match self.records.get(index) {
+            Some(Record::CharacterIDKeyed(ref record)) => record,
+            _ => return None,
+        };
+        let supplement = match record.supplement {
+            Number::Integer(value) => value,
+            Number::Real(value) if value.exponent() >= 0 => value.to_f64() as i32,
+            _ => return None,
+        };
+        Some(Collection {
+            registry: self.strings.get(record.registry)?,
+            ordering: self.strings.get(record.ordering)?,
+            supplement,
+        })
+    }
+
+    /// Find the character IDs of a character-ID-keyed record that lie beyond the declared
+    /// supplement of the character collection.
+    ///
+    /// Nothing is reported if the number of character IDs in the supplement is unknown.
+    pub fn find_excess_character_ids(&self, index: usize) -> Result<Vec<CharacterID>> {
+        let count = match self.collection(index) {
+            Some(collection) => match collection::count(&collection) {
+                Some(count) => count,
+                _ => return Ok(vec![]),
+            },
+            _ => raise!(Kind::UnexpectedRecord),
+        };
+        Ok((1..self.character_strings[index].count)
+            .filter_map(|glyph_id| self.character_sets[index].get_string_id(glyph_id))
+            .filter(|&character_id| character_id as usize >= count)
+            .collect())
+    }
+}
+
+fn invert(glyphs: BTreeMap<GlyphID, String>) -> BTreeMap<char, GlyphID> {
+    let mut mapping = BTreeMap::new();
+    for (glyph_id, value) in glyphs {
+        let mut characters = value.chars();
+        if let (Some(character), None) = (characters.next(), characters.next()) {
+            mapping.entry(character).or_insert(glyph_id);
+        }
+    }
+    mapping
+}
@@ -0,0 +1,404 @@
+//! The merging of records.
+
+use std::collections::HashMap;
+
+use crate::compact1::font_set::common::{get, rebase, rewidth, Shift};
+use crate::compact1::font_set::{character_id_keyed, FontSet, Masks, Record};
+use crate::compact1::index::{CharacterStrings, Index, StringTable, Subroutines};
+use crate::compact1::{CharacterID, CharacterSet, GlyphID, Number, Operations, Operator};
+use crate::error::Kind;
+use crate::type2::token;
+use crate::Result;
+
+// The identity of a glyph across records.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Key {
+    Name(String),
+    CharacterID(CharacterID),
+}
+
+impl FontSet {
+    /// Merge a record of another font set into a record of this one and return the glyph
+    /// identifiers that the glyphs of the other record have in the result.
+    ///
+    /// The glyphs of the other record are appended unless this record already has glyphs with the
+    /// same names, for character-name-keyed records, or the same CIDs, for character-ID-keyed
+    /// ones, in which case the glyphs of this record are kept. The strings of the other font set
+    /// are added unless present. The global subroutines are concatenated unless identical, and so
+    /// are the local subroutines of character-name-keyed records; the subroutine calls are then
+    /// rewritten for the new addresses, including the change of the bias when the number of
+    /// subroutines crosses 1240 or 33900. For character-name-keyed records, the private
+    /// dictionary of this record is kept, and the widths of the appended glyphs are rewritten
+    /// with respect to it. For character-ID-keyed records, which need to share the registry and
+    /// ordering, the font dictionaries used by the appended glyphs are added unless identical,
+    /// and the glyph-to-dictionary encoding is rebuilt.
+    pub fn merge(
+        &mut self,
+        index: usize,
+        other: &FontSet,
+        other_index: usize,
+    ) -> Result<Vec<GlyphID>> {
+        if index >= self.records.len() || other_index >= other.records.len() {
+            raise!(Kind::MalformedRecord);
+        }
+        match (&self.records[index], &other.records[other_index]) {
+            (Record::CharacterNameKeyed(_), Record::CharacterNameKeyed(_)) => {}
+            (Record::CharacterIDKeyed(ref record), Record::CharacterIDKeyed(ref other_record)) => {
+                if self.strings.get(record.registry) != other.strings.get(other_record.registry)
+                    || self.strings.get(record.ordering) != other.strings.get(other_record.ordering)
+                {
+                    raise!(Kind::IncompatibleDictionaries);
+                }
+            }
+            _ => raise!(Kind::UnexpectedRecord),
+        }
+
+        let mut keys = HashMap::new();
+        for glyph_id in 0..self.character_strings[index].count {
+            keys.entry(key(self, index, glyph_id)?).or_insert(glyph_id);
+        }
+        let mut count = self.character_strings[index].count as usize;
+        let mut glyph_ids = Vec::with_capacity(other.character_strings[other_index].len());
+        let mut appended = vec![];
+        for glyph_id in 0..other.character_strings[other_index].count {
+            let key = key(other, other_index, glyph_id)?;
+            match keys.get(&key) {
+                Some(&glyph_id) => glyph_ids.push(glyph_id),
+                _ => {
+                    if count > GlyphID::MAX as usize {
+                        raise!(Kind::ExceededIndexCountLimit(GlyphID::MAX as usize));
+                    }
+                    keys.insert(key, count as GlyphID);
+                    glyph_ids.push(count as GlyphID);
+                    appended.push(glyph_id);
+                    count += 1;
+                }
+            }
+        }
+
+        let mut global_masks = HashMap::new();
+        let trackings = (0..self.records.len())
+            .map(|i| self.track(i, &mut global_masks))
+            .collect::<Result<Vec<_>>>()?;
+        let mut other_global_masks = HashMap::new();
+        let other_tracking = other.track(other_index, &mut other_global_masks)?;
+
+        // Subroutines are shared only if all the ones they might call are shared too.
+        let global_shared = other.subroutines.data == self.subroutines.data;
+        let global_base = if global_shared {
+            0
+        } else {
+            self.subroutines.len()
+        };
+        let global_count = global_base + other.subroutines.len();
+        let global_count = global_count.max(self.subroutines.len());
+        let global_shift = Shift::new(self.subroutines.len(), 0, global_count);
+        let other_global_shift = Shift::new(other.subroutines.len(), global_base, global_count);
+        let (local_shift, other_local_shift) =
+            match (&self.records[index], &other.records[other_index]) {
+                (Record::CharacterNameKeyed(ref record), Record::CharacterNameKeyed(ref other)) => {
+                    let shared = global_shared && other.subroutines.data == record.subroutines.data;
+                    let base = if shared { 0 } else { record.subroutines.len() };
+                    let count = (base + other.subroutines.len()).max(record.subroutines.len());
+                    (
+                        Shift::new(record.subroutines.len(), 0, count),
+                        Shift::new(other.subroutines.len(), base, count),
+                    )
+                }
+                _ => (Shift::identity(), Shift::identity()),
+            };
+
+        let mut global = Vec::with_capacity(global_count);
+        // Global subroutines are shared and hence cannot refer to local ones by new addresses.
+        let local = match local_shift.is_identity()
+            && (!global_shared || other_local_shift.is_identity())
+        {
+            true => Some(local_shift),
+            _ => None,
+        };
+        for (i, code) in self.subroutines.iter().enumerate() {
+            global.push(rewrite(code, global_masks.get(&i), local, global_shift)?);
+        }
+        if !global_shared {
+            for (i, code) in other.subroutines.iter().enumerate() {
+                let masks = other_global_masks.get(&i);
+                global.push(rewrite(
+                    code,
+                    masks,
+                    Some(other_local_shift),
+                    other_global_shift,
+                )?);
+            }
+        }
+
+        if !global_shift.is_identity() {
+            for (i, tracking) in trackings.iter().enumerate() {
+                if i == index {
+                    continue;
+                }
+                let identity = Some(Shift::identity());
+                let mut codes = Vec::with_capacity(tracking.glyphs.len());
+                for (code, masks) in self.character_strings[i].iter().zip(tracking.glyphs.iter()) {
+                    codes.push(rewrite(code, Some(masks), identity, global_shift)?);
+                }
+                self.character_strings[i] = CharacterStrings(Index::from(codes));
+                for (dictionary_id, subroutines) in locals(&mut self.records[i]) {
+                    let masks = tracking.local.get(&dictionary_id);
+                    *subroutines = relocate(subroutines, masks, Shift::identity(), global_shift)?;
+                }
+            }
+        }
+
+        let mut codes = Vec::with_capacity(count);
+        let tracking = &trackings[index];
+        for (code, masks) in self.character_strings[index]
+            .iter()
+            .zip(tracking.glyphs.iter())
+        {
+            codes.push(rewrite(code, Some(masks), Some(local_shift), global_shift)?);
+        }
+        for (dictionary_id, subroutines) in locals(&mut self.records[index]) {
+            let masks = tracking.local.get(&dictionary_id);
+            *subroutines = relocate(subroutines, masks, local_shift, global_shift)?;
+        }
+
+        let mut widths = None;
+        if let (Record::CharacterNameKeyed(ref record), Record::CharacterNameKeyed(ref other)) =
+            (&self.records[index], &other.records[other_index])
+        {
+            let old = (
+                get(&other.operations, Operator::DefaultWidthX),
+                get(&other.operations, Operator::NominalWidthX),
+            );
+            let new = (
+                get(&record.operations, Operator::DefaultWidthX),
+                get(&record.operations, Operator::NominalWidthX),
+            );
+            if old != new {
+                widths = Some((old, new));
+            }
+        }
+        let other_character_strings = &other.character_strings[other_index];
+        for &glyph_id in appended.iter() {
+            let glyph_id = glyph_id as usize;
+            let code = &other_character_strings[glyph_id];
+            let masks = &other_tracking.glyphs[glyph_id];
+            let (old, new) = match widths {
+                Some(widths) => widths,
+                _ => {
+                    let code = rewrite(
+                        code,
+                        Some(masks),
+                        Some(other_local_shift),
+                        other_global_shift,
+                    )?;
+                    codes.push(code);
+                    continue;
+                }
+            };
+            // The masks are located in the original charstring, which is thus decoded only once.
+            let mut tokens = token::decode(code, |position| masks.get(&position).copied())?;
+            rebase(&mut tokens, Some(other_local_shift), other_global_shift)?;
+            let width = other_tracking.widths[glyph_id];
+            rewidth(&mut tokens, glyph_id as GlyphID, width, old, new)?;
+            codes.push(token::encode(&tokens));
+        }
+
+        let mut strings = StringTable::from(&self.strings);
+        let mut string_ids = Vec::with_capacity(count.saturating_sub(1));
+        for glyph_id in 1..self.character_strings[index].count {
+            match self.character_sets[index].get_string_id(glyph_id) {
+                Some(string_id) => string_ids.push(string_id),
+                _ => raise!(Kind::MalformedCharacterSet),
+            }
+        }
+        for &glyph_id in appended.iter() {
+            string_ids.push(match key(other, other_index, glyph_id)? {
+                Key::Name(name) => strings.insert(name)?,
+                Key::CharacterID(character_id) => character_id,
+            });
+        }
+
+        match (&mut self.records[index], &other.records[other_index]) {
+            (Record::CharacterNameKeyed(ref mut record), Record::CharacterNameKeyed(ref inner)) => {
+                if other_local_shift.base > 0 {
+                    let masks = other_tracking.local.get(&0);
+                    let subroutines = relocate(
+                        &inner.subroutines,
+                        masks,
+                        other_local_shift,
+                        other_global_shift,
+                    )?;
+                    let mut data = std::mem::take(&mut record.subroutines.0.data);
+                    data.extend(subroutines.0.data);
+                    record.subroutines = Subroutines(Index::from(data));
+                }
+                if !record.subroutines.is_empty() {
+                    record
+                        .operations
+                        .0
+                        .entry(Operator::Subrs)
+                        .or_insert_with(|| vec![Number::Integer(0)]);
+                }
+            }
+            (Record::CharacterIDKeyed(ref mut record), Record::CharacterIDKeyed(ref inner)) => {
+                let mut dictionary_ids = Vec::with_capacity(count);
+                for glyph_id in 0..self.character_strings[index].count {
+                    match record.encoding.get(glyph_id) {
+                        Some(dictionary_id) => dictionary_ids.push(dictionary_id as u8),
+                        _ => raise!(Kind::MalformedRecord),
+                    }
+                }
+                let mut mapping = HashMap::new();
+                for &glyph_id in appended.iter() {
+                    let dictionary_id = match inner.encoding.get(glyph_id) {
+                        Some(dictionary_id) if dictionary_id < inner.records.len() => dictionary_id,
+                        Some(dictionary_id) => raise!(Kind::MissingDictionary(dictionary_id)),
+                        _ => raise!(Kind::MalformedRecord),
+                    };
+                    if let Some(&new) = mapping.get(&dictionary_id) {
+                        dictionary_ids.push(new);
+                        continue;
+                    }
+                    let mut operations = inner.operations[dictionary_id].clone();
+                    for (operator, operands) in operations.0.iter_mut() {
+                        if !operator.is_string() {
+                            continue;
+                        }
+                        for operand in operands.iter_mut() {
+                            let name = match (*operand)
+                                .try_into()
+                                .ok()
+                                .and_then(|string_id| other.strings.get(string_id))
+                            {
+                                Some(name) => name,
+                                _ => raise!(Kind::MalformedStringID),
+                            };
+                            *operand = Number::Integer(strings.insert(name)? as i32);
+                        }
+                    }
+                    let masks = other_tracking.local.get(&dictionary_id);
+                    let inner = &inner.records[dictionary_id];
+                    let subroutines = relocate(
+                        &inner.subroutines,
+                        masks,
+                        Shift::identity(),
+                        other_global_shift,
+                    )?;
+                    let found = (0..record.records.len()).find(|&i| {
+                        global_shared
+                            && strip(&record.operations[i], Operator::Private)
+                                == strip(&operations, Operator::Private)
+                            && strip(&record.records[i].operations, Operator::Subrs)
+                                == strip(&inner.operations, Operator::Subrs)
+                            && record.records[i].subroutines.data == subroutines.data
+                    });
+                    let new = match found {
+                        Some(i) => i,
+                        _ => {
+                            operations
+                                .0
+                                .insert(Operator::Private, vec![Number::Integer(0); 2]);
+                            record.operations.push(operations);
+                            record.records.push(character_id_keyed::RecordInner {
+                                operations: inner.operations.clone(),
+                                subroutines,
+                            });
+                            record.records.len() - 1
+                        }
+                    };
+                    if new > u8::MAX as usize {
+                        raise!(Kind::ExceededIndexCountLimit(u8::MAX as usize + 1));
+                    }
+                    mapping.insert(dictionary_id, new as u8);
+                    dictionary_ids.push(new as u8);
+                }
+                record.encoding = dictionary_ids.into();
+                let character_count = string_ids.iter().max().map_or(1, |&cid| cid as i32 + 1);
+                let operations = &mut self.operations[index];
+                if let Some(Number::Integer(value)) = operations.get_single(Operator::CIDCount) {
+                    if value < character_count {
+                        operations
+                            .0
+                            .insert(Operator::CIDCount, vec![Number::Integer(character_count)]);
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        self.strings = strings.into();
+        self.subroutines = Subroutines(Index::from(global));
+        self.character_strings[index] = CharacterStrings(Index::from(codes));
+        self.character_sets[index] = CharacterSet::from(string_ids);
+        Ok(glyph_ids)
+    }
+}
+
+// Rewrite the subroutine calls of a routine. The absence of a local shift means that local calls
+// cannot be rewritten. Routines that cannot be decoded are never called and are left as they are.
+fn rewrite(
+    code: &[u8],
+    masks: Option<&Masks>,
+    local: Option<Shift>,
+    global: Shift,
+) -> Result<Vec<u8>> {
+    if local.is_some_and(|local| local.is_identity()) && global.is_identity() {
+        return Ok(code.to_vec());
+    }
+    let mut tokens = match token::decode(code, |position| {
+        masks.and_then(|masks| masks.get(&position).copied())
+    }) {
+        Ok(tokens) => tokens,
+        _ if masks.is_none() => return Ok(code.to_vec()),
+        Err(error) => return Err(error),
+    };
+    rebase(&mut tokens, local, global)?;
+    Ok(token::encode(&tokens))
+}
+
+fn relocate(
+    subroutines: &Subroutines,
+    masks: Option<&HashMap<usize, Masks>>,
+    local: Shift,
+    global: Shift,
+) -> Result<Subroutines> {
+    let mut codes = Vec::with_capacity(subroutines.len());
+    for (i, code) in subroutines.iter().enumerate() {
+        let masks = masks.and_then(|masks| masks.get(&i));
+        codes.push(rewrite(code, masks, Some(local), global)?);
+    }
+    Ok(Subroutines(Index::from(codes)))
+}
+
+fn locals(record: &mut Record) -> Vec<(usize, &mut Subroutines)> {
+    match record {
+        Record::CharacterNameKeyed(ref mut record) => vec![(0, &mut record.subroutines)],
+        Record::CharacterIDKeyed(ref mut record) => record
+            .records
+            .iter_mut()
+            .map(|record| &mut record.subroutines)
+            .enumerate()
+            .collect(),
+    }
+}
+
+fn key(set: &FontSet, index: usize, glyph_id: GlyphID) -> Result<Key> {
+    let string_id = match set.character_sets[index].get_string_id(glyph_id) {
+        Some(string_id) => string_id,
+        _ => raise!(Kind::MalformedCharacterSet),
+    };
+    Ok(match set.records[index] {
+        Record::CharacterNameKeyed(_) => match set.strings.get(string_id) {
+            Some(name) => Key::Name(name),
+            _ => raise!(Kind::MalformedStringID),
+        },
+        Record::CharacterIDKeyed(_) => Key::CharacterID(string_id),
+    })
+}
+
+fn strip(operations: &Operations, operator: Operator) -> HashMap<Operator, Vec<Number>> {
+    let mut operations = operations.0.clone();
+    operations.remove(&operator);
+    operations
+}
@@ -3,33 +3,69 @@
 macro_rules! get(
     (@single $operations:expr, $operator:ident) => (
         match $operations.get_single(crate::compact1::Operator::$operator) {
-            Some(crate::compact1::Number::Integer(value)) => value,
-            Some(_) => raise!(concat!("found a malformed operation with operator ", stringify!($operator))),
-            _ => raise!(concat!("found no operation with operator ", stringify!($operator))),
+            Some(crate::compact1::Number::Integer(value)) if value >= 0 => value,
+            Some(_) => raise!(crate::error::Kind::MalformedOperation(
+                crate::compact1::Operator::$operator
+            )),
+            _ => raise!(crate::error::Kind::MissingOperation(crate::compact1::Operator::$operator)),
         }
     );
     (@try @single $operations:expr, $operator:ident) => (
         match $operations.get_single(crate::compact1::Operator::$operator) {
-            Some(crate::compact1::Number::Integer(value)) => Some(value),
-            Some(_) => raise!(concat!("found a malformed operation with operator ", stringify!($operator))),
+            Some(crate::compact1::Number::Integer(value)) if value >= 0 => Some(value),
+            Some(_) => raise!(crate::error::Kind::MalformedOperation(
+                crate::compact1::Operator::$operator
+            )),
             _ => None,
         }
     );
     (@double $operations:expr, $operator:ident) => (
         match $operations.get_double(crate::compact1::Operator::$operator) {
-            Some((crate::compact1::Number::Integer(value0), crate::compact1::Number::Integer(value1))) => (value0, value1),
-            Some(_) => raise!(concat!("found a malformed operation with operator ", stringify!($operator))),
-            _ => raise!(concat!("found no operation with operator ", stringify!($operator))),
+            Some((
+                crate::compact1::Number::Integer(value0),
+                crate::compact1::Number::Integer(value1),
+            )) if value0 >= 0 && value1 >= 0 => (value0, value1),
+            Some(_) => raise!(crate::error::Kind::MalformedOperation(
+                crate::compact1::Operator::$operator
+            )),
+            _ => raise!(crate::error::Kind::MissingOperation(crate::compact1::Operator::$operator)),
         }
     );
 );
 
+macro_rules! span(
+    ($tape:ident, $recorder:ident, $structure:ident @ $position:expr => $body:expr) => ({
+        let position: u64 = $position;
+        $recorder.open(
+            crate::compact1::font_set::span::Label::Structure(crate::error::Structure::$structure),
+            Some(position),
+        );
+        let value = locate!($structure @ position => $body);
+        $recorder.close(Some($tape.position()?));
+        value
+    });
+);
+
 pub mod character_id_keyed;
 pub mod character_name_keyed;
+pub mod span;
+
+mod common;
+mod conversion;
+mod mapping;
+mod merging;
+mod outline;
+mod subset;
+mod tracking;
+mod writing;
 
-use crate::compact1::index::{CharacterStrings, Dictionaries, Names, Strings, Subroutines};
+pub(crate) use tracking::Masks;
+
+use crate::compact1::font_set::span::{Label, Recorder};
+use crate::compact1::index::{CharacterStrings, Dictionaries, Index, Names, Strings, Subroutines};
 use crate::compact1::{CharacterSet, Encoding, Header, Operations, Operator};
-use crate::Result;
+use crate::error::Structure;
+use crate::{Limits, Result};
 
 /// A font set.
 #[derive(Clone, Debug)]
@@ -63,44 +99,100 @@ impl FontSet {
 }
 
 impl crate::value::Read for FontSet {
+    #[inline]
     fn read<T: crate::tape::Read>(tape: &mut T) -> Result<Self> {
+        tape.take_given(Limits::default())
+    }
+}
+
+impl crate::walue::Read<'static> for FontSet {
+    type Parameter = Limits;
+
+    #[inline]
+    fn read<T: crate::tape::Read>(tape: &mut T, mut limits: Limits) -> Result<Self> {
+        FontSet::read_recorded(tape, &mut limits, &mut Recorder::default())
+    }
+}
+
+impl FontSet {
+    fn read_recorded<T: crate::tape::Read>(
+        tape: &mut T,
+        limits: &mut Limits,
+        recorder: &mut Recorder,
+    ) -> Result<Self> {
         let position = tape.position()?;
-        let header = tape.take::<Header>()?;
-        let names: Names = jump_take!(@unwrap tape, position, header.header_size);
-        let operations: Vec<_> = (&tape.take::<Dictionaries>()?).try_into()?;
-        let strings = tape.take::<Strings>()?;
-        let subroutines = tape.take::<Subroutines>()?;
+        recorder.open(Label::FontSet, Some(position));
+        let header = locate!(Header @ position => tape.take::<Header>()?);
+        let header_size = header.header_size as u64;
+        recorder.mark(
+            Label::Structure(Structure::Header),
+            position..position + header_size,
+        );
+        let names = span!(tape, recorder, Names @ position + header_size => {
+            tape.jump(position + header_size)?;
+            Names(Index::read_recorded(tape, limits, recorder)?)
+        });
+        let another_position = tape.position()?;
+        let operations = span!(tape, recorder, TopDictionaries @ another_position => {
+            Vec::<Operations>::try_from(&Dictionaries::read_recorded(tape, limits, recorder)?)?
+        });
+        let strings = span!(tape, recorder, Strings @ tape.position()? => {
+            Strings(Index::read_recorded(tape, limits, recorder)?)
+        });
+        let subroutines = span!(tape, recorder, GlobalSubroutines @ tape.position()? => {
+            Subroutines(Index::read_recorded(tape, limits, recorder)?)
+        });
         let mut encodings = vec![];
         let mut character_sets = vec![];
         let mut character_strings: Vec<CharacterStrings> = vec![];
         let mut records = vec![];
         for (i, operations) in operations.iter().enumerate() {
-            character_strings.push(jump_take_given!(
-                @unwrap
-                tape,
-                position,
-                get!(@single operations, CharStrings),
-                get!(@single operations, CharStringType)
-            ));
-            character_sets.push(match get!(@single operations, CharSet) {
+            recorder.open(Label::Record(i), None);
+            let offset = locate!(TopDictionaries @ another_position => {
+                get!(@single operations, CharStrings)
+            });
+            character_strings.push(
+                span!(tape, recorder, CharacterStrings @ position + offset as u64 => {
+                    let format = get!(@single operations, CharStringType);
+                    tape.jump(position + offset as u64)?;
+                    CharacterStrings::read_recorded(tape, format, limits, recorder)?
+                }),
+            );
+            let offset =
+                locate!(TopDictionaries @ another_position => get!(@single operations, CharSet));
+            character_sets.push(match offset {
                 0 => CharacterSet::ISOAdobe,
                 1 => CharacterSet::Expert,
                 2 => CharacterSet::ExpertSubset,
-                offset => jump_take_given!(
-                    @unwrap
-                    tape,
-                    position,
-                    offset,
-                    character_strings[i].count as usize
-                ),
+                offset => span!(tape, recorder, CharacterSet @ position + offset as u64 => {
+                    jump_take_given!(
+                        @unwrap
+                        tape,
+                        position,
+                        offset,
+                        character_strings[i].count as usize
+                    )
+                }),
             });
-            encodings.push(match get!(@single operations, Encoding) {
+            let offset =
+                locate!(TopDictionaries @ another_position => get!(@single operations, Encoding));
+            encodings.push(match offset {
                 0 => Encoding::Standard,
                 1 => Encoding::Expert,
-                offset => jump_take!(@unwrap tape, position, offset),
+                offset => span!(tape, recorder, Encoding @ position + offset as u64 => {
+                    jump_take!(@unwrap tape, position, offset)
+                }),
             });
-            records.push(tape.take_given((position, operations, &character_strings[i]))?);
+            records.push(locate!(TopDictionaries @ another_position => {
+                Record::read_recorded(
+                    tape,
+                    (position, operations, &character_strings[i], limits),
+                    recorder,
+                )?
+            }));
+            recorder.close(None);
         }
+        recorder.close(None);
         Ok(Self {
             header,
             names,
@@ -116,21 +208,40 @@ impl crate::value::Read for FontSet {
 }
 
 impl<'l> crate::walue::Read<'l> for Record {
-    type Parameter = (u64, &'l Operations, &'l CharacterStrings);
+    type Parameter = (u64, &'l Operations, &'l CharacterStrings, &'l mut Limits);
 
-    fn read<T: crate::tape::Read>(
+    #[inline]
+    fn read<T: crate::tape::Read>(tape: &mut T, parameter: Self::Parameter) -> Result<Self> {
+        Record::read_recorded(tape, parameter, &mut Recorder::default())
+    }
+}
+
+impl Record {
+    fn read_recorded<T: crate::tape::Read>(
         tape: &mut T,
-        (position, operations, character_strings): Self::Parameter,
+        (position, operations, character_strings, limits): (
+            u64,
+            &Operations,
+            &CharacterStrings,
+            &mut Limits,
+        ),
+        recorder: &mut Recorder,
     ) -> Result<Self> {
         if operations.contains_key(&Operator::ROS) {
-            Ok(Record::CharacterIDKeyed(tape.take_given((
-                position,
-                operations,
-                character_strings,
-            ))?))
+            Ok(Record::CharacterIDKeyed(
+                character_id_keyed::Record::read_recorded(
+                    tape,
+                    (position, operations, character_strings, limits),
+                    recorder,
+                )?,
+            ))
         } else {
             Ok(Record::CharacterNameKeyed(
-                tape.take_given((position, operations))?,
+                character_name_keyed::Record::read_recorded(
+                    tape,
+                    (position, operations, limits),
+                    recorder,
+                )?,
             ))
         }
     }
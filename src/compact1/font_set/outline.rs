@@ -0,0 +1,175 @@
+//! The outlines of glyphs.
+
+use crate::compact1::font_set::conversion::to_matrix;
+use crate::compact1::font_set::{FontSet, Record};
+use crate::compact1::{Encoding, GlyphID, Number, Operator};
+use crate::error::Kind;
+use crate::type2::outline::{multiply, Matrix, Outline};
+use crate::type2::stroke::Stroke;
+use crate::type2::{Composite, Program};
+use crate::Result;
+
+impl FontSet {
+    /// Create a program for the charstring of a glyph.
+    pub fn program(&self, index: usize, glyph_id: GlyphID) -> Result<Program<'_>> {
+        let code = match self.character_strings[index].get(glyph_id as usize) {
+            Some(code) => code,
+            _ => raise!(Kind::MissingGlyph(glyph_id)),
+        };
+        let local = match &self.records[index] {
+            Record::CharacterNameKeyed(ref record) => &record.subroutines,
+            Record::CharacterIDKeyed(ref record) => match record.encoding.get(glyph_id) {
+                Some(dictionary_id) => match record.records.get(dictionary_id) {
+                    Some(record) => &record.subroutines,
+                    _ => raise!(Kind::MissingDictionary(dictionary_id)),
+                },
+                _ => raise!(Kind::MalformedRecord),
+            },
+        };
+        let mut program = Program::new(code, &self.subroutines, local);
+        program.set_glyph_id(glyph_id);
+        Ok(program)
+    }
+
+    /// Build the outline of a glyph in font units.
+    ///
+    /// For an accented glyph composed by `endchar`, the outline consists of the outlines of the
+    /// base and the accent, with the latter shifted by the offset given.
+    pub fn outline(&self, index: usize, glyph_id: GlyphID) -> Result<Outline> {
+        let mut program = self.program(index, glyph_id)?;
+        let mut outline = Outline::build(&mut program)?;
+        if let Some((base, accent, composite)) = self.resolve(index, program.composite())? {
+            outline = Outline::build(&mut self.program(index, base)?)?;
+            let accent = Outline::build(&mut self.program(index, accent)?)?;
+            let matrix = [1.0, 0.0, 0.0, 1.0, composite.x, composite.y];
+            outline.segments.extend(accent.transform(&matrix).segments);
+        }
+        Ok(outline)
+    }
+
+    /// Return the base and the accent of an accented glyph composed by `endchar`.
+    pub fn components(
+        &self,
+        index: usize,
+        glyph_id: GlyphID,
+    ) -> Result<Option<(GlyphID, GlyphID)>> {
+        let mut program = self.program(index, glyph_id)?;
+        while program.next()?.is_some() {}
+        Ok(self
+            .resolve(index, program.composite())?
+            .map(|(base, accent, _)| (base, accent)))
+    }
+
+    /// Return the advance width of a glyph in font units.
+    ///
+    /// The width is taken from the charstring relative to `nominalWidthX` if given there and is
+    /// `defaultWidthX` otherwise, both found in the private dictionary used by the glyph.
+    pub fn width(&self, index: usize, glyph_id: GlyphID) -> Result<f32> {
+        let mut program = self.program(index, glyph_id)?;
+        while program.next()?.is_some() {}
+        let operations = match &self.records[index] {
+            Record::CharacterNameKeyed(ref record) => &record.operations,
+            Record::CharacterIDKeyed(ref record) => match record.get(glyph_id) {
+                Some(record) => &record.operations,
+                _ => raise!(Kind::MalformedRecord),
+            },
+        };
+        let get = |operator| match operations.get_single(operator) {
+            Some(value) => value.to_f32(),
+            _ => 0.0,
+        };
+        Ok(match program.width() {
+            Some(value) => get(Operator::NominalWidthX) + value,
+            _ => get(Operator::DefaultWidthX),
+        })
+    }
+
+    /// Find the glyph of a code in the standard encoding.
+    ///
+    /// The name of the character with the code is looked up in the character set of the record,
+    /// which is to be character-name-keyed.
+    pub fn find_standard_glyph(&self, index: usize, code: u8) -> Result<GlyphID> {
+        if let Record::CharacterIDKeyed(_) = self.records[index] {
+            raise!(Kind::UnexpectedRecord);
+        }
+        let string_id = match Encoding::Standard.get(code as GlyphID) {
+            Some(string_id) if string_id > 0 => string_id,
+            _ => raise!(Kind::MissingCode(code)),
+        };
+        let character_set = &self.character_sets[index];
+        match (1..self.character_strings[index].count)
+            .find(|&glyph_id| character_set.get_string_id(glyph_id) == Some(string_id))
+        {
+            Some(glyph_id) => Ok(glyph_id),
+            _ => raise!(Kind::MissingCode(code)),
+        }
+    }
+
+    fn resolve(
+        &self,
+        index: usize,
+        composite: Option<Composite>,
+    ) -> Result<Option<(GlyphID, GlyphID, Composite)>> {
+        Ok(match composite {
+            Some(composite) => Some((
+                self.find_standard_glyph(index, composite.base)?,
+                self.find_standard_glyph(index, composite.accent)?,
+                composite,
+            )),
+            _ => None,
+        })
+    }
+
+    /// Return the parameters of stroking if the glyphs of a record are to be stroked.
+    ///
+    /// This is the case when `PaintType` is 2, and the width is then given by `StrokeWidth`.
+    pub fn stroke(&self, index: usize) -> Result<Option<Stroke>> {
+        let operations = &self.operations[index];
+        if get!(@single operations, PaintType) != 2 {
+            return Ok(None);
+        }
+        let width = match operations.get_single(Operator::StrokeWidth) {
+            Some(Number::Integer(value)) if value >= 0 => value as f32,
+            Some(Number::Real(value)) if value.significand() >= 0 => value.to_f32(),
+            _ => raise!(Kind::MalformedOperation(Operator::StrokeWidth)),
+        };
+        Ok(Some(Stroke {
+            width,
+            ..Default::default()
+        }))
+    }
+
+    /// Build the outline of a glyph in font units as it is to be filled.
+    ///
+    /// For records to be stroked, the outline is expanded by the stroke width.
+    pub fn filled_outline(&self, index: usize, glyph_id: GlyphID) -> Result<Outline> {
+        let outline = self.outline(index, glyph_id)?;
+        Ok(match self.stroke(index)? {
+            Some(stroke) => outline.stroke(&stroke),
+            _ => outline,
+        })
+    }
+
+    /// Return the font matrix of a glyph, which maps font units to ems.
+    ///
+    /// For a character-ID-keyed record, the matrix of the font dictionary used by the glyph is
+    /// combined with the one of the top dictionary.
+    pub fn matrix(&self, index: usize, glyph_id: GlyphID) -> Result<Matrix> {
+        let top = self.operations[index].0.get(&Operator::FontMatrix);
+        let other = match &self.records[index] {
+            Record::CharacterNameKeyed(_) => None,
+            Record::CharacterIDKeyed(ref record) => match record.encoding.get(glyph_id) {
+                Some(dictionary_id) => match record.operations.get(dictionary_id) {
+                    Some(operations) => operations.0.get(&Operator::FontMatrix),
+                    _ => raise!(Kind::MissingDictionary(dictionary_id)),
+                },
+                _ => raise!(Kind::MalformedRecord),
+            },
+        };
+        Ok(match (top, other) {
+            (Some(top), Some(other)) => multiply(&to_matrix(other), &to_matrix(top)),
+            (Some(matrix), _) | (_, Some(matrix)) => to_matrix(matrix),
+            _ => to_matrix(Operator::FontMatrix.default().unwrap_or(&[])),
+        })
+    }
+}
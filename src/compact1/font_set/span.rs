@@ -0,0 +1,158 @@
+//! The byte spans of structures.
+
+use std::ops::Range;
+
+use crate::compact1::font_set::FontSet;
+use crate::compact1::Operator;
+use crate::error::Structure;
+use crate::{Limits, Result};
+
+/// A range of bytes occupied by a structure together with the ranges of its parts.
+///
+/// The ranges are absolute positions in the tape. The parts of a font set and of a record are
+/// given in the order in which they are read and are not necessarily contiguous; all other parts
+/// lie within the range of their parent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    /// The label.
+    pub label: Label,
+    /// The range of bytes.
+    pub range: Range<u64>,
+    /// The spans of the parts.
+    pub children: Vec<Span>,
+}
+
+/// A label of spans.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Label {
+    /// The font set as a whole.
+    FontSet,
+    /// The structures specific to a record.
+    Record(usize),
+    /// A structure.
+    Structure(Structure),
+    /// An element of an index.
+    Element(usize),
+    /// An operation in a dictionary.
+    Operation(Operator),
+}
+
+impl Span {
+    #[inline]
+    fn new(label: Label, range: Range<u64>, children: Vec<Span>) -> Self {
+        Self {
+            label,
+            range,
+            children,
+        }
+    }
+
+    /// Return the chain of spans containing a position starting from this one and ending with
+    /// the innermost one.
+    pub fn find(&self, position: u64) -> Vec<&Span> {
+        let mut spans = vec![];
+        let mut span = self;
+        if !span.range.contains(&position) {
+            return spans;
+        }
+        loop {
+            spans.push(span);
+            match span
+                .children
+                .iter()
+                .find(|span| span.range.contains(&position))
+            {
+                Some(child) => span = child,
+                _ => return spans,
+            }
+        }
+    }
+}
+
+// A recorder of spans filled in by the readers, which records nothing unless enabled.
+#[derive(Debug, Default)]
+pub(crate) struct Recorder {
+    enabled: bool,
+    spans: Vec<Span>,
+    path: Vec<usize>,
+}
+
+impl Recorder {
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    // Start a span whose children are recorded next. In the absence of a start, the span begins
+    // with the earliest child.
+    pub fn open(&mut self, label: Label, start: Option<u64>) {
+        if !self.enabled {
+            return;
+        }
+        let spans = self.current();
+        spans.push(Span::new(label, start.unwrap_or(u64::MAX)..0, vec![]));
+        let i = spans.len() - 1;
+        self.path.push(i);
+    }
+
+    // Finish the last started span. In the absence of an end, the span ends with the latest
+    // child.
+    pub fn close(&mut self, end: Option<u64>) {
+        if !self.enabled {
+            return;
+        }
+        let i = match self.path.pop() {
+            Some(i) => i,
+            _ => return,
+        };
+        let span = &mut self.current()[i];
+        if span.range.start == u64::MAX {
+            let start = span.children.iter().map(|span| span.range.start).min();
+            span.range.start = start.unwrap_or(0);
+        }
+        let latest = span.children.iter().map(|span| span.range.end).max();
+        span.range.end = end.or(latest).unwrap_or(span.range.start);
+    }
+
+    // Record a span without children.
+    pub fn mark(&mut self, label: Label, range: Range<u64>) {
+        if self.enabled {
+            self.current().push(Span::new(label, range, vec![]));
+        }
+    }
+
+    fn current(&mut self) -> &mut Vec<Span> {
+        let mut spans = &mut self.spans;
+        for &i in self.path.iter() {
+            spans = &mut spans[i].children;
+        }
+        spans
+    }
+}
+
+impl FontSet {
+    /// Read a font set together with the spans of its structures.
+    ///
+    /// The spans cover the header, each index and its elements, each dictionary and its
+    /// operations, the character sets, the encodings, the glyph-to-dictionary encodings, and the
+    /// private dictionaries with their local subroutines.
+    pub fn read_spans<T: crate::tape::Read>(
+        tape: &mut T,
+        mut limits: Limits,
+    ) -> Result<(Self, Span)> {
+        let mut recorder = Recorder::enabled();
+        let set = FontSet::read_recorded(tape, &mut limits, &mut recorder)?;
+        let span = recorder
+            .spans
+            .pop()
+            .unwrap_or_else(|| Span::new(Label::FontSet, 0..0, vec![]));
+        Ok((set, span))
+    }
+}
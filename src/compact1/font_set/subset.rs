@@ -0,0 +1,132 @@
+//! The subsetting of records.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::compact1::encoding::{Encoding0, EncodingSupplemental};
+use crate::compact1::font_set::{FontSet, Record};
+use crate::compact1::index::{CharacterStrings, Index};
+use crate::compact1::{CharacterSet, Encoding, GlyphID, StringID};
+use crate::error::Kind;
+use crate::Result;
+
+impl FontSet {
+    /// Reduce a record to a subset of its glyphs and return the original identifiers of the
+    /// glyphs retained.
+    ///
+    /// The `.notdef` glyph and the components of accented glyphs composed by `endchar` are
+    /// always retained. The glyphs keep their relative order, and the glyph identifier of a
+    /// glyph in the result is its position in the returned vector. The character set and the
+    /// glyph-to-dictionary encoding are rebuilt. A custom encoding is rebuilt in format 0 with
+    /// code zero standing in for the glyphs that have no codes; the predefined encodings are kept
+    /// as they are. The subroutines are retained in full.
+    pub fn subset(&mut self, index: usize, glyph_ids: &[GlyphID]) -> Result<Vec<GlyphID>> {
+        if index >= self.records.len() {
+            raise!(Kind::MalformedRecord);
+        }
+        let count = self.character_strings[index].count;
+        let mut retained = BTreeSet::new();
+        retained.insert(0);
+        for &glyph_id in glyph_ids.iter() {
+            if glyph_id >= count {
+                raise!(Kind::MissingGlyph(glyph_id));
+            }
+            retained.insert(glyph_id);
+            if let Record::CharacterNameKeyed(_) = self.records[index] {
+                if let Some((base, accent)) = self.components(index, glyph_id)? {
+                    retained.insert(base);
+                    retained.insert(accent);
+                }
+            }
+        }
+        let retained = retained.into_iter().collect::<Vec<_>>();
+
+        let character_strings = &self.character_strings[index];
+        let data: Vec<_> = retained
+            .iter()
+            .map(|&glyph_id| character_strings[glyph_id as usize].clone())
+            .collect();
+        let mut string_ids = Vec::with_capacity(retained.len() - 1);
+        for &glyph_id in retained[1..].iter() {
+            match self.character_sets[index].get_string_id(glyph_id) {
+                Some(string_id) => string_ids.push(string_id),
+                _ => raise!(Kind::MalformedCharacterSet),
+            }
+        }
+        let encoding = match self.records[index] {
+            Record::CharacterNameKeyed(_) => Some(subset_encoding(
+                &self.encodings[index],
+                &retained,
+                &string_ids,
+            )),
+            _ => None,
+        };
+        if let Record::CharacterIDKeyed(ref mut record) = self.records[index] {
+            let mut dictionary_ids = Vec::with_capacity(retained.len());
+            for &glyph_id in retained.iter() {
+                match record.encoding.get(glyph_id) {
+                    Some(dictionary_id) => dictionary_ids.push(dictionary_id as u8),
+                    _ => raise!(Kind::MalformedRecord),
+                }
+            }
+            record.encoding = dictionary_ids.into();
+        }
+        self.character_strings[index] = CharacterStrings(Index::from(data));
+        self.character_sets[index] = CharacterSet::from(string_ids);
+        if let Some(encoding) = encoding {
+            self.encodings[index] = encoding;
+        }
+        Ok(retained)
+    }
+}
+
+fn subset_encoding(encoding: &Encoding, retained: &[GlyphID], string_ids: &[StringID]) -> Encoding {
+    let mut codes = HashMap::new();
+    match encoding {
+        Encoding::Standard | Encoding::Expert => return encoding.clone(),
+        Encoding::Format0(ref encoding) => {
+            for (i, &code) in encoding.codes.iter().enumerate() {
+                if code != 0 {
+                    codes.insert(i as GlyphID + 1, code);
+                }
+            }
+        }
+        Encoding::Format1(ref encoding) => {
+            let mut glyph_id = 1;
+            for range in encoding.ranges.iter() {
+                for code in range.first_code..=range.first_code.saturating_add(range.left_count) {
+                    codes.insert(glyph_id, code);
+                    glyph_id += 1;
+                }
+            }
+        }
+        Encoding::FormatSupplemental(ref encoding) => {
+            let supplements = encoding
+                .supplements
+                .iter()
+                .filter(|supplement| string_ids.contains(&supplement.glyph))
+                .copied()
+                .collect::<Vec<_>>();
+            return Encoding::FormatSupplemental(EncodingSupplemental {
+                format: encoding.format,
+                supplement_count: supplements.len() as u8,
+                supplements,
+            });
+        }
+    }
+    let mut codes = retained[1..]
+        .iter()
+        .map(|glyph_id| codes.get(glyph_id).copied())
+        .collect::<Vec<_>>();
+    while let Some(None) = codes.last() {
+        codes.pop();
+    }
+    let codes = codes
+        .into_iter()
+        .map(|code| code.unwrap_or(0))
+        .collect::<Vec<_>>();
+    Encoding::Format0(Encoding0 {
+        format: 0,
+        code_count: codes.len() as u8,
+        codes,
+    })
+}
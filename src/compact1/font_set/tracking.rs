@@ -0,0 +1,76 @@
+//! The tracking of hint masks.
+
+use std::collections::HashMap;
+
+use crate::compact1::font_set::{FontSet, Record};
+use crate::compact1::GlyphID;
+use crate::error::Kind;
+use crate::type2::{Operand, Program};
+use crate::Result;
+
+/// The sizes of hint masks by their positions in a routine.
+pub(crate) type Masks = HashMap<u64, usize>;
+
+/// The hint masks and widths found by interpreting the charstrings of a record.
+pub(crate) struct Tracking {
+    /// The masks of the charstrings.
+    pub glyphs: Vec<Masks>,
+    /// The widths of the charstrings with respect to the nominal width.
+    pub widths: Vec<Option<Operand>>,
+    /// The masks of the local subroutines by font dictionary and subroutine.
+    pub local: HashMap<usize, HashMap<usize, Masks>>,
+}
+
+impl FontSet {
+    /// Interpret the charstrings of a record to find the sizes of hint masks.
+    ///
+    /// The masks of the global subroutines are added to the given ones. Subroutines that no
+    /// charstring calls have no masks.
+    pub(crate) fn track(
+        &self,
+        index: usize,
+        global: &mut HashMap<usize, Masks>,
+    ) -> Result<Tracking> {
+        let mut tracking = Tracking {
+            glyphs: vec![],
+            widths: vec![],
+            local: HashMap::new(),
+        };
+        for (glyph_id, code) in self.character_strings[index].iter().enumerate() {
+            let (dictionary_id, subroutines) = match self.records[index] {
+                Record::CharacterNameKeyed(ref record) => (0, &record.subroutines),
+                Record::CharacterIDKeyed(ref record) => {
+                    match record.encoding.get(glyph_id as GlyphID) {
+                        Some(dictionary_id) if dictionary_id < record.records.len() => {
+                            (dictionary_id, &record.records[dictionary_id].subroutines)
+                        }
+                        Some(dictionary_id) => raise!(Kind::MissingDictionary(dictionary_id)),
+                        _ => raise!(Kind::MalformedRecord),
+                    }
+                }
+            };
+            let mut program = Program::new(code, &self.subroutines, subroutines);
+            program.set_glyph_id(glyph_id as GlyphID);
+            program.track_masks();
+            // The masks encountered before a failure are still of use.
+            while let Ok(Some(_)) = program.next() {}
+            let mut own = Masks::new();
+            for &(call, position, size) in program.masks().unwrap_or(&[]) {
+                let masks = match call {
+                    Some(call) if call.global => global.entry(call.index).or_default(),
+                    Some(call) => tracking
+                        .local
+                        .entry(dictionary_id)
+                        .or_default()
+                        .entry(call.index)
+                        .or_default(),
+                    _ => &mut own,
+                };
+                masks.insert(position, size);
+            }
+            tracking.glyphs.push(own);
+            tracking.widths.push(program.width());
+        }
+        Ok(tracking)
+    }
+}
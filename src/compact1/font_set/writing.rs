@@ -0,0 +1,182 @@
+use crate::compact1::font_set::{FontSet, Record};
+use crate::compact1::index::{Dictionaries, Index, Subroutines};
+use crate::compact1::{CharacterSet, Encoding, Header, Number, Operations, Operator};
+use crate::error::Kind;
+use crate::Result;
+
+impl crate::value::Write for FontSet {
+    /// Write the font set.
+    ///
+    /// The structures are laid out anew: the global structures come first in the order of the
+    /// specification, followed by the character sets, encodings, charstrings, dictionary
+    /// selectors, private dictionaries, local subroutines, and font dictionaries of each record.
+    /// The offsets in the top and font dictionaries are recomputed, and the header offset size is
+    /// the smallest one that accounts for the whole font set.
+    fn write<T: crate::tape::Write>(&self, tape: &mut T) -> Result<()> {
+        let count = self.records.len();
+        if self.operations.len() != count
+            || self.encodings.len() != count
+            || self.character_sets.len() != count
+            || self.character_strings.len() != count
+        {
+            raise!(Kind::MalformedRecord);
+        }
+        // The offsets are written in the five-byte form, which makes the size of the top
+        // dictionaries independent of the layout.
+        let mut operations = self
+            .operations
+            .iter()
+            .zip(self.records.iter())
+            .map(|(operations, record)| prepare(operations, record))
+            .collect::<Vec<_>>();
+        let names = bytes(&self.names)?;
+        let strings = bytes(&self.strings)?;
+        let subroutines = bytes(&self.subroutines)?;
+        let size = 4
+            + names.len()
+            + bytes(&dictionaries(&operations)?)?.len()
+            + strings.len()
+            + subroutines.len();
+
+        let mut data = vec![];
+        for (i, operations) in operations.iter_mut().enumerate() {
+            let offset = match self.character_sets[i] {
+                CharacterSet::ISOAdobe => Number::Integer(0),
+                CharacterSet::Expert => Number::Integer(1),
+                CharacterSet::ExpertSubset => Number::Integer(2),
+                ref character_set => append(&mut data, size, character_set)?,
+            };
+            operations.0.insert(Operator::CharSet, vec![offset]);
+            if let Record::CharacterNameKeyed(_) = self.records[i] {
+                let offset = match self.encodings[i] {
+                    Encoding::Standard => Number::Integer(0),
+                    Encoding::Expert => Number::Integer(1),
+                    ref encoding => append(&mut data, size, encoding)?,
+                };
+                operations.0.insert(Operator::Encoding, vec![offset]);
+            }
+            let offset = append(&mut data, size, &self.character_strings[i])?;
+            operations.0.insert(Operator::CharStrings, vec![offset]);
+            match self.records[i] {
+                Record::CharacterNameKeyed(ref record) => {
+                    let operands =
+                        private(&mut data, size, &record.operations, &record.subroutines)?;
+                    operations.0.insert(Operator::Private, operands);
+                }
+                Record::CharacterIDKeyed(ref record) => {
+                    let offset = append(&mut data, size, &record.encoding)?;
+                    operations.0.insert(Operator::FDSelect, vec![offset]);
+                    let mut inner = record.operations.clone();
+                    for (operations, record) in inner.iter_mut().zip(record.records.iter()) {
+                        let operands =
+                            private(&mut data, size, &record.operations, &record.subroutines)?;
+                        operations.0.insert(Operator::Private, operands);
+                    }
+                    let offset = append(&mut data, size, &dictionaries(&inner)?)?;
+                    operations.0.insert(Operator::FDArray, vec![offset]);
+                }
+            }
+        }
+
+        let total = size + data.len();
+        tape.give(&Header {
+            major: self.header.major,
+            minor: self.header.minor,
+            header_size: 4,
+            offset_size: match total {
+                0..=0xff => 1,
+                0x100..=0xffff => 2,
+                0x10000..=0xffffff => 3,
+                _ => 4,
+            },
+        })?;
+        tape.give_bytes(&names)?;
+        tape.give(&dictionaries(&operations)?)?;
+        tape.give_bytes(&strings)?;
+        tape.give_bytes(&subroutines)?;
+        tape.give_bytes(&data)
+    }
+}
+
+// Clone the operations of a top dictionary with placeholders for all offsets.
+fn prepare(operations: &Operations, record: &Record) -> Operations {
+    let mut operations = operations.clone();
+    let placeholder = Number::Integer(0);
+    operations.0.insert(Operator::CharSet, vec![placeholder]);
+    operations
+        .0
+        .insert(Operator::CharStrings, vec![placeholder]);
+    match record {
+        Record::CharacterNameKeyed(_) => {
+            operations.0.insert(Operator::Encoding, vec![placeholder]);
+            operations
+                .0
+                .insert(Operator::Private, vec![placeholder, placeholder]);
+            operations.0.remove(&Operator::FDArray);
+            operations.0.remove(&Operator::FDSelect);
+        }
+        Record::CharacterIDKeyed(_) => {
+            operations.0.remove(&Operator::Encoding);
+            operations.0.remove(&Operator::Private);
+            operations.0.insert(Operator::FDArray, vec![placeholder]);
+            operations.0.insert(Operator::FDSelect, vec![placeholder]);
+        }
+    }
+    operations
+}
+
+// Append a private dictionary followed by its subroutines and return the operands referring to
+// the dictionary.
+fn private(
+    data: &mut Vec<u8>,
+    size: usize,
+    operations: &Operations,
+    subroutines: &Subroutines,
+) -> Result<Vec<Number>> {
+    let mut operations = operations.clone();
+    operations.0.remove(&Operator::Subrs);
+    if subroutines.count > 0 {
+        operations
+            .0
+            .insert(Operator::Subrs, vec![Number::Integer(0)]);
+        let offset = number(bytes(&operations)?.len())?;
+        operations.0.insert(Operator::Subrs, vec![offset]);
+    }
+    let chunk = bytes(&operations)?;
+    let offset = number(size + data.len())?;
+    data.extend_from_slice(&chunk);
+    if subroutines.count > 0 {
+        append(data, size, subroutines)?;
+    }
+    Ok(vec![number(chunk.len())?, offset])
+}
+
+// Append a value and return its offset.
+fn append<V: crate::value::Write>(data: &mut Vec<u8>, size: usize, value: &V) -> Result<Number> {
+    use crate::tape::Write;
+
+    let offset = number(size + data.len())?;
+    data.give(value)?;
+    Ok(offset)
+}
+
+fn bytes<V: crate::value::Write + ?Sized>(value: &V) -> Result<Vec<u8>> {
+    use crate::tape::Write;
+
+    let mut data = vec![];
+    data.give(value)?;
+    Ok(data)
+}
+
+fn dictionaries(operations: &[Operations]) -> Result<Dictionaries> {
+    Ok(Dictionaries(Index::from(
+        operations.iter().map(bytes).collect::<Result<Vec<_>>>()?,
+    )))
+}
+
+fn number(value: usize) -> Result<Number> {
+    match i32::try_from(value) {
+        Ok(value) => Ok(Number::Integer(value)),
+        _ => raise!(Kind::MalformedOffset),
+    }
+}
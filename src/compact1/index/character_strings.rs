@@ -1,4 +1,7 @@
-use crate::Result;
+use crate::compact1::font_set::span::Recorder;
+use crate::compact1::index::Index;
+use crate::error::Kind;
+use crate::{Limits, Result};
 
 index! {
     @define
@@ -6,13 +9,25 @@ index! {
     pub CharacterStrings
 }
 
-impl crate::walue::Read<'static> for CharacterStrings {
-    type Parameter = i32;
+impl<'l> crate::walue::Read<'l> for CharacterStrings {
+    type Parameter = (i32, &'l mut Limits);
 
-    fn read<T: crate::tape::Read>(tape: &mut T, format: i32) -> Result<Self> {
+    #[inline]
+    fn read<T: crate::tape::Read>(tape: &mut T, (format, limits): Self::Parameter) -> Result<Self> {
+        CharacterStrings::read_recorded(tape, format, limits, &mut Recorder::default())
+    }
+}
+
+impl CharacterStrings {
+    pub(crate) fn read_recorded<T: crate::tape::Read>(
+        tape: &mut T,
+        format: i32,
+        limits: &mut Limits,
+        recorder: &mut Recorder,
+    ) -> Result<Self> {
         Ok(match format {
-            2 => CharacterStrings(tape.take()?),
-            format => raise!("found an unknown format of character strings ({format})"),
+            2 => CharacterStrings(Index::read_recorded(tape, limits, recorder)?),
+            format => raise!(Kind::UnknownFormat(format)),
         })
     }
 }
@@ -1,8 +1,11 @@
 use std::io::Cursor;
 
+use crate::compact1::font_set::span::{Label, Recorder};
+use crate::compact1::index::Index;
 use crate::compact1::Operations;
+use crate::error::Kind;
 use crate::tape::Read;
-use crate::{Error, Result};
+use crate::{Error, Limits, Result};
 
 index! {
     /// A dictionary index.
@@ -13,8 +16,29 @@ impl Dictionaries {
     /// Return the operations at a specific position.
     #[inline]
     pub fn get(&self, index: usize) -> Result<Operations> {
-        debug_assert!(index < self.len());
-        Cursor::new(&self[index]).take()
+        match self.0.get(index) {
+            Some(chunk) => Cursor::new(chunk).take(),
+            _ => raise!(Kind::MissingDictionary(index)),
+        }
+    }
+
+    // Read dictionaries recording the spans of the elements together with their operations.
+    pub(crate) fn read_recorded<T: crate::tape::Read>(
+        tape: &mut T,
+        limits: &mut Limits,
+        recorder: &mut Recorder,
+    ) -> Result<Self> {
+        let index = Index::read_recorded(tape, limits, &mut Recorder::default())?;
+        if recorder.is_enabled() {
+            let base = tape.position()? - index.size() as u64 - 1;
+            for (i, chunk) in index.data.iter().enumerate() {
+                let start = base + index.offsets[i].0 as u64;
+                recorder.open(Label::Element(i), Some(start));
+                Operations::read_recorded(&mut Cursor::new(chunk), start, recorder)?;
+                recorder.close(Some(start + chunk.len() as u64));
+            }
+        }
+        Ok(Dictionaries(index))
     }
 }
 
@@ -1,7 +1,9 @@
 //! The indices.
 
+use crate::compact1::font_set::span::{Label, Recorder};
 use crate::compact1::{Offset, OffsetSize};
-use crate::Result;
+use crate::error::Kind;
+use crate::{Limits, Result};
 
 table! {
     @define
@@ -16,8 +18,96 @@ table! {
 
 dereference! { Index::data => [Vec<u8>] }
 
+impl Index {
+    /// Return the total size of the data.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.data.iter().map(|chunk| chunk.len()).sum()
+    }
+}
+
+impl From<Vec<Vec<u8>>> for Index {
+    /// Create an index with offsets computed for the given data.
+    fn from(data: Vec<Vec<u8>>) -> Self {
+        if data.is_empty() {
+            return Index {
+                count: 0,
+                offset_size: 0,
+                offsets: vec![],
+                data,
+            };
+        }
+        let mut offsets = Vec::with_capacity(data.len() + 1);
+        let mut offset = 1;
+        offsets.push(Offset(offset));
+        for chunk in data.iter() {
+            offset += chunk.len() as u32;
+            offsets.push(Offset(offset));
+        }
+        let offset_size = match offset {
+            0..=0xff => 1,
+            0x100..=0xffff => 2,
+            0x10000..=0xffffff => 3,
+            _ => 4,
+        };
+        Index {
+            count: data.len() as u16,
+            offset_size,
+            offsets,
+            data,
+        }
+    }
+}
+
+impl crate::value::Write for Index {
+    /// Write the index with offsets recomputed for the data.
+    fn write<T: crate::tape::Write>(&self, tape: &mut T) -> Result<()> {
+        tape.give(&(self.data.len() as u16))?;
+        if self.data.is_empty() {
+            return Ok(());
+        }
+        let offset_size: OffsetSize = match 1 + self.size() {
+            0..=0xff => 1,
+            0x100..=0xffff => 2,
+            0x10000..=0xffffff => 3,
+            _ => 4,
+        };
+        tape.give(&offset_size)?;
+        let mut offset = 1;
+        crate::walue::Write::write(&Offset(offset), tape, offset_size)?;
+        for chunk in self.data.iter() {
+            offset += chunk.len() as u32;
+            crate::walue::Write::write(&Offset(offset), tape, offset_size)?;
+        }
+        for chunk in self.data.iter() {
+            tape.give_bytes(chunk)?;
+        }
+        Ok(())
+    }
+}
+
 impl crate::value::Read for Index {
+    #[inline]
     fn read<T: crate::tape::Read>(tape: &mut T) -> Result<Self> {
+        tape.take_given(&mut Limits::default())
+    }
+}
+
+impl<'l> crate::walue::Read<'l> for Index {
+    type Parameter = &'l mut Limits;
+
+    #[inline]
+    fn read<T: crate::tape::Read>(tape: &mut T, limits: &'l mut Limits) -> Result<Self> {
+        Index::read_recorded(tape, limits, &mut Recorder::default())
+    }
+}
+
+impl Index {
+    pub(crate) fn read_recorded<T: crate::tape::Read>(
+        tape: &mut T,
+        limits: &mut Limits,
+        recorder: &mut Recorder,
+    ) -> Result<Self> {
         let count = tape.take::<u16>()?;
         if count == 0 {
             return Ok(Index {
@@ -27,6 +117,9 @@ impl crate::value::Read for Index {
                 data: vec![],
             });
         }
+        if count as usize > limits.index_count {
+            raise!(Kind::ExceededIndexCountLimit(limits.index_count));
+        }
         let offset_size = tape.take::<OffsetSize>()?;
         let mut offsets = Vec::with_capacity(count as usize + 1);
         for _ in 0..(count as usize + 1) {
@@ -34,15 +127,22 @@ impl crate::value::Read for Index {
             offsets.push(offset);
         }
         if offsets[0] != Offset(1) {
-            raise!("found a malformed index");
+            raise!(Kind::MalformedIndex);
         }
-        let mut data = Vec::with_capacity(count as usize);
         for i in 0..(count as usize) {
             if offsets[i] > offsets[i + 1] {
-                raise!("found a malformed index");
+                raise!(Kind::MalformedIndex);
             }
+        }
+        limits.allocate((offsets[count as usize].0 - offsets[0].0) as usize)?;
+        // The offsets are relative to the byte preceding the data.
+        let base = tape.position()? - 1;
+        let mut data = Vec::with_capacity(count as usize);
+        for i in 0..(count as usize) {
             let size = (offsets[i + 1].0 - offsets[i].0) as usize;
             data.push(tape.take_given(size)?);
+            let range = base + offsets[i].0 as u64..base + offsets[i + 1].0 as u64;
+            recorder.mark(Label::Element(i), range);
         }
         Ok(Index {
             count,
@@ -63,6 +163,13 @@ macro_rules! index {
         #[derive(Clone, Debug)]
         pub struct $structure(pub $crate::compact1::index::Index);
         dereference! { $structure::0 => $crate::compact1::index::Index }
+
+        impl $crate::value::Write for $structure {
+            #[inline]
+            fn write<T: $crate::tape::Write>(&self, tape: &mut T) -> $crate::Result<()> {
+                tape.give(&self.0)
+            }
+        }
     );
     (@implement $structure:ident) => (
         impl $crate::value::Read for $structure {
@@ -71,6 +178,18 @@ macro_rules! index {
                 Ok($structure(tape.take()?))
             }
         }
+
+        impl<'l> $crate::walue::Read<'l> for $structure {
+            type Parameter = &'l mut $crate::Limits;
+
+            #[inline]
+            fn read<T: $crate::tape::Read>(
+                tape: &mut T,
+                limits: &'l mut $crate::Limits,
+            ) -> $crate::Result<Self> {
+                Ok($structure(tape.take_given(limits)?))
+            }
+        }
     );
 }
 
@@ -83,5 +202,5 @@ mod subroutines;
 pub use character_strings::CharacterStrings;
 pub use dictionaries::Dictionaries;
 pub use names::Names;
-pub use strings::Strings;
+pub use strings::{StringTable, Strings};
 pub use subroutines::Subroutines;
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+
+use crate::compact1::index::Index;
 use crate::compact1::StringID;
+use crate::error::Kind;
+use crate::Result;
 
-const NUMBER_OF_STANDARD_STRINGS: usize = 391;
+pub(crate) const NUMBER_OF_STANDARD_STRINGS: usize = 391;
 
 index! {
     /// A string index.
@@ -8,6 +13,23 @@ index! {
 }
 
 impl Strings {
+    /// Append strings and return the identifier of the first one.
+    pub fn extend<I, T>(&mut self, strings: I) -> Result<StringID>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Vec<u8>>,
+    {
+        let string_id = NUMBER_OF_STANDARD_STRINGS + self.0.len();
+        let mut data = std::mem::take(&mut self.0.data);
+        data.extend(strings.into_iter().map(Into::into));
+        if NUMBER_OF_STANDARD_STRINGS + data.len() > StringID::MAX as usize + 1 {
+            self.0.data = data;
+            raise!(Kind::ExceededIndexCountLimit(StringID::MAX as usize));
+        }
+        self.0 = Index::from(data);
+        Ok(string_id as StringID)
+    }
+
     /// Return the string corresponding to a string identifier.
     pub fn get(&self, string_id: StringID) -> Option<String> {
         match string_id as usize {
@@ -22,6 +44,112 @@ impl Strings {
     }
 }
 
+/// A builder of string indices.
+///
+/// The standard strings are never added, and other strings are added only once. The identifiers
+/// of the strings that the builder starts with are preserved.
+#[derive(Clone, Debug)]
+pub struct StringTable {
+    data: Vec<Vec<u8>>,
+    string_ids: HashMap<Vec<u8>, StringID>,
+}
+
+impl StringTable {
+    /// Create a builder without custom strings.
+    pub fn new() -> Self {
+        let mut string_ids = HashMap::with_capacity(NUMBER_OF_STANDARD_STRINGS);
+        for string_id in 0..NUMBER_OF_STANDARD_STRINGS as StringID {
+            if let Some(value) = get_standard_string(string_id) {
+                string_ids.insert(value.as_bytes().to_vec(), string_id);
+            }
+        }
+        Self {
+            data: vec![],
+            string_ids,
+        }
+    }
+
+    /// Return the identifier of a string, adding the string if it is not present.
+    pub fn insert<T: AsRef<[u8]>>(&mut self, value: T) -> Result<StringID> {
+        let value = value.as_ref();
+        if let Some(string_id) = self.find(value) {
+            return Ok(string_id);
+        }
+        let string_id = NUMBER_OF_STANDARD_STRINGS + self.data.len();
+        if string_id > StringID::MAX as usize {
+            raise!(Kind::ExceededIndexCountLimit(StringID::MAX as usize));
+        }
+        self.data.push(value.to_vec());
+        self.string_ids
+            .insert(value.to_vec(), string_id as StringID);
+        Ok(string_id as StringID)
+    }
+
+    /// Return the identifier of a string if present.
+    #[inline]
+    pub fn find<T: AsRef<[u8]>>(&self, value: T) -> Option<StringID> {
+        self.string_ids.get(value.as_ref()).copied()
+    }
+
+    /// Return the bytes of the string corresponding to a string identifier.
+    pub fn get(&self, string_id: StringID) -> Option<&[u8]> {
+        match string_id as usize {
+            i if i < NUMBER_OF_STANDARD_STRINGS => {
+                get_standard_string(string_id).map(str::as_bytes)
+            }
+            i => self
+                .data
+                .get(i - NUMBER_OF_STANDARD_STRINGS)
+                .map(Vec::as_slice),
+        }
+    }
+
+    /// Return the string corresponding to a string identifier if it is valid UTF-8.
+    #[inline]
+    pub fn get_str(&self, string_id: StringID) -> Option<&str> {
+        self.get(string_id)
+            .and_then(|value| std::str::from_utf8(value).ok())
+    }
+
+    /// Return the number of custom strings.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if there are no custom strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl Default for StringTable {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&Strings> for StringTable {
+    fn from(strings: &Strings) -> Self {
+        let mut table = Self::new();
+        for (i, value) in strings.0.iter().enumerate() {
+            let string_id = (NUMBER_OF_STANDARD_STRINGS + i) as StringID;
+            table.string_ids.entry(value.to_vec()).or_insert(string_id);
+            table.data.push(value.to_vec());
+        }
+        table
+    }
+}
+
+impl From<StringTable> for Strings {
+    #[inline]
+    fn from(table: StringTable) -> Self {
+        Strings(Index::from(table.data))
+    }
+}
+
 fn get_standard_string(string_id: StringID) -> Option<&'static str> {
     Some(match string_id {
         0 => ".notdef",
@@ -422,7 +550,8 @@ fn get_standard_string(string_id: StringID) -> Option<&'static str> {
 #[cfg(test)]
 mod tests {
     use super::get_standard_string;
-    use super::NUMBER_OF_STANDARD_STRINGS;
+    use super::{StringTable, Strings, NUMBER_OF_STANDARD_STRINGS};
+    use crate::compact1::index::Index;
     use crate::compact1::StringID;
 
     #[test]
@@ -430,4 +559,26 @@ mod tests {
         assert!(get_standard_string(NUMBER_OF_STANDARD_STRINGS as StringID - 1).is_some());
         assert!(get_standard_string(NUMBER_OF_STANDARD_STRINGS as StringID).is_none());
     }
+
+    #[test]
+    fn table() {
+        let strings = Strings(Index::from(vec![b"Hirakatana".to_vec(), b"Adobe".to_vec()]));
+        let mut table = StringTable::from(&strings);
+        assert_eq!(table.insert("space").unwrap(), 1);
+        assert_eq!(table.insert("Adobe").unwrap(), 392);
+        assert_eq!(table.insert(b"Identity").unwrap(), 393);
+        assert_eq!(table.insert("Identity").unwrap(), 393);
+        assert_eq!(table.find("Hirakatana"), Some(391));
+        assert_eq!(table.find("Regular"), Some(388));
+        assert_eq!(table.find("Bold-Italic"), None);
+        assert_eq!(table.get(34), Some(&b"A"[..]));
+        assert_eq!(table.get_str(393), Some("Identity"));
+        assert_eq!(table.get(394), None);
+        assert_eq!(table.len(), 3);
+
+        let strings = Strings::from(table);
+        assert_eq!(strings.count, 3);
+        assert_eq!(strings.get(392).as_deref(), Some("Adobe"));
+        assert_eq!(strings.get(393).as_deref(), Some("Identity"));
+    }
 }
@@ -6,6 +6,7 @@ pub mod character_set;
 pub mod encoding;
 pub mod font_set;
 pub mod index;
+pub mod validation;
 
 mod header;
 mod number;
@@ -17,12 +18,16 @@ pub use encoding::Encoding;
 pub use font_set::FontSet;
 pub use header::Header;
 pub use index::Index;
-pub use number::Number;
+pub use number::{Number, Real};
 pub use offset::{Offset, OffsetSize};
 pub use operation::{Operand, Operations, Operator};
 
+use crate::error::Kind;
 use crate::{Error, Result};
 
+/// A character identifier.
+pub type CharacterID = u16;
+
 /// A glyph identifier.
 pub type GlyphID = u16;
 
@@ -36,7 +41,7 @@ impl TryFrom<Number> for StringID {
     fn try_from(number: Number) -> Result<Self> {
         match number {
             Number::Integer(value) if value >= 0 => Ok(value as Self),
-            _ => raise!("found a malformed string ID"),
+            _ => raise!(Kind::MalformedStringID),
         }
     }
 }
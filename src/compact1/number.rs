@@ -1,6 +1,10 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::error::Kind;
 use crate::Result;
 
-macro_rules! reject(() => (raise!("found a malformed number")));
+macro_rules! reject(() => (raise!(Kind::MalformedNumber)));
 
 /// A number.
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -8,13 +12,159 @@ pub enum Number {
     /// An integer number.
     Integer(i32),
     /// A real number.
-    Real(f32),
+    Real(Real),
+}
+
+/// A real number given by a decimal significand and a decimal exponent.
+///
+/// The number is kept in the decimal form it is encoded in, so that writing it produces the same
+/// value without going through binary floating-point numbers. Trailing zeros of the significand
+/// are moved to the exponent, and zero has a zero exponent.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Real {
+    significand: i64,
+    exponent: i32,
+}
+
+// The maximum number of significant digits kept when reading.
+const DIGITS: usize = 18;
+
+impl Number {
+    /// Convert into a single-precision floating-point number.
+    #[inline]
+    pub fn to_f32(&self) -> f32 {
+        match *self {
+            Number::Integer(value) => value as f32,
+            Number::Real(value) => value.to_f32(),
+        }
+    }
+
+    /// Convert into a double-precision floating-point number.
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        match *self {
+            Number::Integer(value) => value as f64,
+            Number::Real(value) => value.to_f64(),
+        }
+    }
+}
+
+impl Real {
+    /// Create a number equal to `significand × 10^exponent`.
+    pub const fn new(mut significand: i64, mut exponent: i32) -> Self {
+        if significand == 0 {
+            exponent = 0;
+        }
+        while significand != 0 && significand % 10 == 0 {
+            significand /= 10;
+            exponent += 1;
+        }
+        Self {
+            significand,
+            exponent,
+        }
+    }
+
+    /// Return the significand, which has no trailing zeros.
+    #[inline]
+    pub fn significand(&self) -> i64 {
+        self.significand
+    }
+
+    /// Return the exponent.
+    #[inline]
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Convert into a single-precision floating-point number, which is correctly rounded.
+    #[inline]
+    pub fn to_f32(&self) -> f32 {
+        self.to_string().parse().unwrap_or(f32::NAN)
+    }
+
+    /// Convert into a double-precision floating-point number, which is correctly rounded.
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+}
+
+impl Display for Real {
+    /// Write the number in the plain decimal notation if it is short and in the scientific one
+    /// otherwise.
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.significand < 0 { "-" } else { "" };
+        let digits = self.significand.unsigned_abs().to_string();
+        let (count, exponent) = (digits.len() as i32, self.exponent);
+        if (0..=6).contains(&exponent) {
+            write!(formatter, "{sign}{digits}{}", "0".repeat(exponent as usize))
+        } else if exponent < 0 && count + exponent > 0 {
+            let (whole, fraction) = digits.split_at((count + exponent) as usize);
+            write!(formatter, "{sign}{whole}.{fraction}")
+        } else if exponent < 0 && count + exponent > -6 {
+            let zeros = "0".repeat(-(count + exponent) as usize);
+            write!(formatter, "{sign}0.{zeros}{digits}")
+        } else {
+            write!(formatter, "{sign}{digits}e{exponent}")
+        }
+    }
+}
+
+impl FromStr for Real {
+    type Err = crate::Error;
+
+    /// Parse a number written in the plain or scientific decimal notation.
+    fn from_str(value: &str) -> Result<Self> {
+        if value.is_empty() || value.contains(['i', 'I', 'n', 'N']) {
+            reject!();
+        }
+        if value.parse::<f64>().is_err() {
+            reject!();
+        }
+        Ok(from(value))
+    }
+}
+
+impl From<f32> for Real {
+    /// Create the shortest decimal number that converts back to the same value.
+    fn from(value: f32) -> Self {
+        from(&format!("{value:e}"))
+    }
+}
+
+impl From<f64> for Real {
+    /// Create the shortest decimal number that converts back to the same value.
+    fn from(value: f64) -> Self {
+        from(&format!("{value:e}"))
+    }
+}
+
+impl From<i32> for Real {
+    #[inline]
+    fn from(value: i32) -> Self {
+        Real::new(value as i64, 0)
+    }
+}
+
+impl From<Real> for f32 {
+    #[inline]
+    fn from(value: Real) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<Real> for f64 {
+    #[inline]
+    fn from(value: Real) -> Self {
+        value.to_f64()
+    }
 }
 
 impl From<f32> for Number {
     #[inline]
     fn from(value: f32) -> Self {
-        Number::Real(value)
+        Number::Real(value.into())
     }
 }
 
@@ -25,6 +175,13 @@ impl From<i32> for Number {
     }
 }
 
+impl From<Real> for Number {
+    #[inline]
+    fn from(value: Real) -> Self {
+        Number::Real(value)
+    }
+}
+
 impl crate::value::Read for Number {
     fn read<T: crate::tape::Read>(tape: &mut T) -> Result<Self> {
         let first = tape.take::<u8>()?;
@@ -44,7 +201,46 @@ impl crate::value::Read for Number {
     }
 }
 
-fn parse<T: crate::tape::Read>(tape: &mut T) -> Result<f32> {
+impl crate::value::Write for Number {
+    /// Write the number using the shortest encoding.
+    fn write<T: crate::tape::Write>(&self, tape: &mut T) -> Result<()> {
+        match *self {
+            Number::Integer(value) => match value {
+                -107..=107 => tape.give(&((value + 139) as u8)),
+                108..=1131 => {
+                    let value = value - 108;
+                    tape.give(&[(247 + (value >> 8)) as u8, value as u8][..])
+                }
+                -1131..=-108 => {
+                    let value = -value - 108;
+                    tape.give(&[(251 + (value >> 8)) as u8, value as u8][..])
+                }
+                -32768..=32767 => {
+                    tape.give(&0x1cu8)?;
+                    tape.give(&(value as i16 as u16))
+                }
+                _ => {
+                    tape.give(&0x1du8)?;
+                    tape.give(&(value as u32))
+                }
+            },
+            Number::Real(value) => {
+                let mut nibbles = encode(value);
+                nibbles.push(0x0f);
+                if nibbles.len() % 2 == 1 {
+                    nibbles.push(0x0f);
+                }
+                tape.give(&0x1eu8)?;
+                for pair in nibbles.chunks(2) {
+                    tape.give(&(pair[0] << 4 | pair[1]))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+fn parse<T: crate::tape::Read>(tape: &mut T) -> Result<Real> {
     let mut buffer = String::new();
     let mut byte = 0;
     let mut high = true;
@@ -67,17 +263,97 @@ fn parse<T: crate::tape::Read>(tape: &mut T) -> Result<f32> {
             _ => reject!(),
         }
     }
-    match buffer.parse() {
-        Ok(value) => Ok(value),
-        _ => reject!(),
+    buffer.parse()
+}
+
+// Convert a valid decimal number into a real number.
+fn from(value: &str) -> Real {
+    let (mantissa, exponent) = match value.find(['e', 'E']) {
+        Some(i) => (&value[..i], value[i + 1..].parse::<i32>().unwrap_or(0)),
+        _ => (value, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(mantissa) => (true, mantissa),
+        _ => (false, mantissa),
+    };
+    let mut significand = 0i64;
+    let mut exponent = exponent;
+    let mut count = 0;
+    let mut fraction = false;
+    for character in mantissa.chars() {
+        match character {
+            '.' => fraction = true,
+            '0'..='9' => {
+                let digit = character as i64 - '0' as i64;
+                if count < DIGITS {
+                    if significand > 0 || digit > 0 {
+                        count += 1;
+                    }
+                    significand = significand * 10 + digit;
+                    if fraction {
+                        exponent -= 1;
+                    }
+                } else if !fraction {
+                    exponent += 1;
+                }
+            }
+            _ => {}
+        }
     }
+    Real::new(if negative { -significand } else { significand }, exponent)
+}
+
+// Encode a real number as nibbles choosing the shortest notation.
+fn encode(value: Real) -> Vec<u8> {
+    let digits = value
+        .significand
+        .unsigned_abs()
+        .to_string()
+        .bytes()
+        .map(|byte| byte - b'0')
+        .collect::<Vec<_>>();
+    let (count, exponent) = (digits.len() as i32, value.exponent);
+    let mut plain = vec![];
+    if exponent >= 0 {
+        plain.extend(&digits);
+        plain.extend(std::iter::repeat_n(0, exponent as usize));
+    } else if count + exponent > 0 {
+        let (whole, fraction) = digits.split_at((count + exponent) as usize);
+        plain.extend(whole);
+        plain.push(0x0a);
+        plain.extend(fraction);
+    } else {
+        plain.push(0x0a);
+        plain.extend(std::iter::repeat_n(0, -(count + exponent) as usize));
+        plain.extend(&digits);
+    }
+    let mut scientific = digits;
+    if exponent != 0 {
+        scientific.push(if exponent < 0 { 0x0c } else { 0x0b });
+        scientific.extend(
+            exponent
+                .unsigned_abs()
+                .to_string()
+                .bytes()
+                .map(|byte| byte - b'0'),
+        );
+    }
+    let mut nibbles = if scientific.len() < plain.len() {
+        scientific
+    } else {
+        plain
+    };
+    if value.significand < 0 {
+        nibbles.insert(0, 0x0e);
+    }
+    nibbles
 }
 
 #[cfg(test)]
 mod tests {
     use std::io::Cursor;
 
-    use super::Number;
+    use super::{Number, Real};
     use crate::tape::Read;
 
     #[test]
@@ -131,9 +407,77 @@ mod tests {
         );
 
         let mut tape = Cursor::new(vec![0x1e, 0xe2, 0xa2, 0x5f, 0x0f]);
-        assert!((read!(tape) + 2.25).abs() < 1e-14);
+        let value = read!(tape);
+        assert_eq!(value, Real::new(-225, -2));
+        assert_eq!(value.to_f32(), -2.25);
 
         let mut tape = Cursor::new(vec![0x1e, 0x0a, 0x14, 0x05, 0x41, 0xc3, 0xff, 0x0f]);
-        assert!((read!(tape) - 0.140541e-3).abs() < 1e-14);
+        let value = read!(tape);
+        assert_eq!(value, Real::new(140541, -9));
+        assert_eq!(value.to_f64(), 0.140541e-3);
+    }
+
+    #[test]
+    fn write() {
+        use crate::tape::Write;
+
+        let write = |number: Number| {
+            let mut tape = vec![];
+            tape.give(&number).unwrap();
+            let mut other = Cursor::new(tape.clone());
+            assert_eq!(other.take::<Number>().unwrap(), number);
+            tape
+        };
+
+        assert_eq!(write(Number::Integer(0)), [0x8b]);
+        assert_eq!(write(Number::Integer(107)), [0xf6]);
+        assert_eq!(write(Number::Integer(-107)), [0x20]);
+        assert_eq!(write(Number::Integer(1000)), [0xfa, 0x7c]);
+        assert_eq!(write(Number::Integer(-1000)), [0xfe, 0x7c]);
+        assert_eq!(write(Number::Integer(1131)), [0xfa, 0xff]);
+        assert_eq!(write(Number::Integer(-1132)), [0x1c, 0xfb, 0x94]);
+        assert_eq!(write(Number::Integer(10000)), [0x1c, 0x27, 0x10]);
+        assert_eq!(
+            write(Number::Integer(-100000)),
+            [0x1d, 0xff, 0xfe, 0x79, 0x60]
+        );
+
+        assert_eq!(
+            write(Number::Real(Real::new(-225, -2))),
+            [0x1e, 0xe2, 0xa2, 0x5f]
+        );
+        assert_eq!(write(Number::Real(Real::new(1, -3))), [0x1e, 0x1c, 0x3f]);
+        assert_eq!(
+            write(Number::Real(Real::new(39625, -6))),
+            [0x1e, 0xa0, 0x39, 0x62, 0x5f],
+        );
+        assert_eq!(
+            write(Number::Real(Real::new(5, 10))),
+            [0x1e, 0x5b, 0x10, 0xff]
+        );
+        assert_eq!(write(Number::Real(Real::new(0, 0))), [0x1e, 0x0f]);
+
+        let mut tape = Cursor::new(vec![0x1e, 0x0a, 0x00, 0x1f]);
+        let number = tape.take::<Number>().unwrap();
+        assert_eq!(number, Number::Real(Real::new(1, -3)));
+        assert_eq!(write(number), [0x1e, 0x1c, 0x3f]);
+    }
+
+    #[test]
+    fn convert() {
+        assert_eq!(Real::from(0.001f32), Real::new(1, -3));
+        assert_eq!(Real::from(0.039625f32), Real::new(39625, -6));
+        assert_eq!(Real::from(-2.5e20f64), Real::new(-25, 19));
+        assert_eq!(Real::from(0.0f32), Real::new(0, 0));
+        assert_eq!(Real::new(1, -3).to_string(), "0.001");
+        assert_eq!(Real::new(-225, -2).to_string(), "-2.25");
+        assert_eq!(Real::new(15, 2).to_string(), "1500");
+        assert_eq!(Real::new(15, 20).to_string(), "15e20");
+        assert_eq!(Real::new(15, -20).to_string(), "15e-20");
+        assert_eq!(Real::new(1, -3).to_f32(), 0.001);
+        assert_eq!("0.0375".parse::<Real>().unwrap(), Real::new(375, -4));
+        assert_eq!("-15e-20".parse::<Real>().unwrap(), Real::new(-15, -20));
+        assert!("".parse::<Real>().is_err());
+        assert!("inf".parse::<Real>().is_err());
     }
 }
@@ -1,3 +1,4 @@
+use crate::error::Kind;
 use crate::Result;
 
 /// An offset.
@@ -19,17 +20,30 @@ impl crate::walue::Read<'static> for Offset {
                 u32::from_be_bytes([0, value[0], value[1], value[2]])
             }
             4 => tape.take::<u32>()?,
-            _ => raise!("found a malformed offset"),
+            _ => raise!(Kind::MalformedOffset),
         }))
     }
 }
 
+impl crate::walue::Write<'static> for Offset {
+    type Parameter = OffsetSize;
+
+    fn write<T: crate::tape::Write>(&self, tape: &mut T, size: OffsetSize) -> Result<()> {
+        match size {
+            1..=4 if size == 4 || self.0 >> (8 * size) == 0 => {
+                tape.give_bytes(&self.0.to_be_bytes()[4 - size as usize..])
+            }
+            _ => raise!(Kind::MalformedOffset),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::io::Cursor;
 
     use crate::compact1::Offset;
-    use crate::walue::Read;
+    use crate::walue::{Read, Write};
 
     #[test]
     fn read() {
@@ -40,4 +54,16 @@ mod tests {
         assert_eq!(Offset::read(&mut tape, 3).unwrap().0, 0x040506);
         assert_eq!(Offset::read(&mut tape, 4).unwrap().0, 0x0708090a);
     }
+
+    #[test]
+    fn write() {
+        let mut tape = vec![];
+
+        Offset(0x01).write(&mut tape, 1).unwrap();
+        Offset(0x0203).write(&mut tape, 2).unwrap();
+        Offset(0x040506).write(&mut tape, 3).unwrap();
+        Offset(0x0708090a).write(&mut tape, 4).unwrap();
+        assert_eq!(tape, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert!(Offset(0x0100).write(&mut tape, 1).is_err());
+    }
 }
@@ -2,7 +2,9 @@
 
 use std::collections::HashMap;
 
-use crate::compact1::number::Number;
+use crate::compact1::font_set::span::{Label, Recorder};
+use crate::compact1::number::{Number, Real};
+use crate::error::Kind;
 use crate::Result;
 
 /// An operand.
@@ -50,13 +52,28 @@ impl Operations {
 }
 
 impl crate::value::Read for Operations {
+    #[inline]
     fn read<T: crate::tape::Read>(tape: &mut T) -> Result<Self> {
+        Operations::read_recorded(tape, 0, &mut Recorder::default())
+    }
+}
+
+impl Operations {
+    // Read operations recording their spans given the absolute position of the tape.
+    pub(crate) fn read_recorded<T: crate::tape::Read>(
+        tape: &mut T,
+        position: u64,
+        recorder: &mut Recorder,
+    ) -> Result<Self> {
         use std::io::ErrorKind;
 
         let mut operations = HashMap::new();
         loop {
+            let start = tape.position()?;
             match tape.take() {
                 Ok(Operation(operator, operands)) => {
+                    let end = tape.position()?;
+                    recorder.mark(Label::Operation(operator), position + start..position + end);
                     operations.insert(operator, operands);
                 }
                 Err(error) => {
@@ -71,6 +88,47 @@ impl crate::value::Read for Operations {
     }
 }
 
+impl crate::value::Write for Operations {
+    /// Write the operations.
+    ///
+    /// The operations are ordered by operator with `ROS` and `SyntheticBase` coming first, as
+    /// required. The operands of operators referring to other structures are written in the
+    /// five-byte form so that the size does not depend on their values.
+    fn write<T: crate::tape::Write>(&self, tape: &mut T) -> Result<()> {
+        let mut operators = self.0.keys().copied().collect::<Vec<_>>();
+        operators.sort_by_key(|operator| match operator {
+            Operator::ROS | Operator::SyntheticBase => (0, 0),
+            operator => (1, operator.code()),
+        });
+        for operator in operators {
+            let referring = matches!(
+                operator,
+                Operator::CharSet
+                    | Operator::Encoding
+                    | Operator::CharStrings
+                    | Operator::Private
+                    | Operator::Subrs
+                    | Operator::FDArray
+                    | Operator::FDSelect
+            );
+            for operand in self.0[&operator].iter() {
+                match *operand {
+                    Number::Integer(value) if referring => {
+                        tape.give(&0x1du8)?;
+                        tape.give(&value)?;
+                    }
+                    ref operand => tape.give(operand)?,
+                }
+            }
+            match operator.code() {
+                code @ 0x0c00..=0x0cff => tape.give(&[0x0c, code as u8][..])?,
+                code => tape.give(&(code as u8))?,
+            }
+        }
+        Ok(())
+    }
+}
+
 dereference! { Operations::0 => HashMap<Operator, Vec<Operand>> }
 
 impl crate::value::Read for Operation {
@@ -101,7 +159,7 @@ macro_rules! default(
 );
 
 macro_rules! operator {
-    (pub $name:ident { $($code:pat => $variant:ident $default:tt,)+ }) => (
+    (pub $name:ident { $($code:literal => $variant:ident $default:tt,)+ }) => (
         operator! { @define pub $name { $($variant,)+ } }
         operator! { @implement pub $name { $($code => $variant $default,)+ } }
     );
@@ -110,17 +168,26 @@ macro_rules! operator {
         #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
         pub enum $name { $($variant,)* }
     );
-    (@implement pub $name:ident { $($code:pat => $variant:ident $default:tt,)* }) => (impl $name {
+    (@implement pub $name:ident { $($code:literal => $variant:ident $default:tt,)* }) => (impl $name {
         #[doc(hidden)]
         pub fn from(code: u16) -> Result<Self> {
             use self::$name::*;
 
             Ok(match code {
                 $($code => $variant,)+
-                code => raise!("found an unknown operator ({code})"),
+                code => raise!(Kind::UnknownOperator(code)),
             })
         }
 
+        /// Return the code.
+        pub fn code(&self) -> u16 {
+            use self::$name::*;
+
+            match *self {
+                $($variant => $code,)+
+            }
+        }
+
         /// Return the default operands.
         pub fn default(&self) -> Option<&'static [Operand]> {
             use self::$name::*;
@@ -177,15 +244,15 @@ operator! {
         0x0c05 => PaintType [Number::Integer(0)],
         0x0c06 => CharStringType [Number::Integer(2)],
         0x0c07 => FontMatrix [
-            Number::Real(0.001),
-            Number::Real(0.0),
-            Number::Real(0.0),
-            Number::Real(0.001),
-            Number::Real(0.0),
-            Number::Real(0.0),
+            Number::Real(Real::new(1, -3)),
+            Number::Real(Real::new(0, 0)),
+            Number::Real(Real::new(0, 0)),
+            Number::Real(Real::new(1, -3)),
+            Number::Real(Real::new(0, 0)),
+            Number::Real(Real::new(0, 0)),
         ],
         0x0c08 => StrokeWidth [Number::Integer(0)],
-        0x0c09 => BlueScale [Number::Real(0.039625)],
+        0x0c09 => BlueScale [Number::Real(Real::new(39625, -6))],
         0x0c0a => BlueShift [Number::Integer(7)],
         0x0c0b => BlueFuzz [Number::Integer(1)],
         0x0c0c => StemSnapH [],
@@ -193,7 +260,7 @@ operator! {
         0x0c0e => ForceBold [Number::Integer(false as i32)],
         // 0x0c0f..=0x0c10 => Reserved,
         0x0c11 => LanguageGroup [Number::Integer(0)],
-        0x0c12 => ExpansionFactor [Number::Real(0.06)],
+        0x0c12 => ExpansionFactor [Number::Real(Real::new(6, -2))],
         0x0c13 => InitialRandomSeed [Number::Integer(0)],
         0x0c14 => SyntheticBase [],
         0x0c15 => PostScript [],
@@ -212,3 +279,23 @@ operator! {
         // 0x0c27..=0x0cff => Reserved,
     }
 }
+
+impl Operator {
+    /// Check if the operands are string identifiers.
+    pub fn is_string(&self) -> bool {
+        use self::Operator::*;
+
+        matches!(
+            *self,
+            Version
+                | Notice
+                | Copyright
+                | FullName
+                | FamilyName
+                | Weight
+                | PostScript
+                | BaseFontName
+                | FontName
+        )
+    }
+}
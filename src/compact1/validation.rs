@@ -0,0 +1,539 @@
+//! The validation.
+//!
+//! The validation collects violations of the specification and other questionable constructs
+//! found in a font set without stopping at the first one.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Cursor;
+
+use crate::compact1::font_set::{character_id_keyed, Record};
+use crate::compact1::index::{Dictionaries, Names};
+use crate::compact1::{CharacterSet, FontSet, GlyphID, Header, Number, Operations, Operator};
+use crate::tape::Read;
+use crate::type2::Program;
+
+/// An issue found during validation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Issue {
+    /// The severity.
+    pub severity: Severity,
+    /// The rule that is violated.
+    pub rule: Rule,
+    /// The record concerned.
+    pub record: Option<usize>,
+    /// The glyph concerned.
+    pub glyph_id: Option<GlyphID>,
+    /// The description.
+    pub description: String,
+}
+
+/// A severity of issues.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Severity {
+    /// A violation of the specification.
+    Error,
+    /// A construct that is valid but likely unintended.
+    Warning,
+}
+
+/// A rule checked during validation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Rule {
+    /// The font set has to be parsable.
+    Parsing,
+    /// Offsets have to point inside the data.
+    Offset,
+    /// Operators in dictionaries have to be given the right number of operands.
+    Arity,
+    /// Alignment zones have to come in sorted pairs.
+    BlueValues,
+    /// The glyph-to-dictionary encoding has to cover all glyphs with existing dictionaries.
+    DictionarySelector,
+    /// The character set has to cover all glyphs.
+    CharacterSet,
+    /// Subroutines have to be reachable.
+    Subroutine,
+    /// Charstrings have to be interpretable and terminated.
+    CharacterString,
+    /// Glyph names have to be unique.
+    GlyphName,
+    /// Font names have to be valid PostScript names.
+    FontName,
+}
+
+/// Validate a font set.
+pub fn validate(set: &FontSet) -> Vec<Issue> {
+    let mut issues = vec![];
+    check_font_names(&set.names, &mut issues);
+    let mut global = HashSet::new();
+    for (i, record) in set.records.iter().enumerate() {
+        let glyph_count = set.character_strings[i].count as usize;
+        check_operations(&set.operations[i], i, &mut issues);
+        check_character_set(&set.character_sets[i], glyph_count, i, &mut issues);
+        match record {
+            Record::CharacterNameKeyed(ref record) => {
+                check_operations(&record.operations, i, &mut issues);
+                check_glyph_names(set, i, &mut issues);
+            }
+            Record::CharacterIDKeyed(ref record) => {
+                for operations in record.operations.iter() {
+                    check_operations(operations, i, &mut issues);
+                }
+                for record in record.records.iter() {
+                    check_operations(&record.operations, i, &mut issues);
+                }
+                check_dictionary_selector(record, glyph_count, i, &mut issues);
+                check_collection(set, i, &mut issues);
+            }
+        }
+        check_character_strings(set, i, &mut global, &mut issues);
+    }
+    report_unreachable(set.subroutines.len(), &global, true, None, &mut issues);
+    issues
+}
+
+/// Validate a font set given as raw data.
+pub fn validate_bytes(data: &[u8]) -> Vec<Issue> {
+    let mut issues = vec![];
+    check_offsets(data, &mut issues);
+    match Cursor::new(data).take::<FontSet>() {
+        Ok(set) => issues.extend(validate(&set)),
+        Err(error) => issues.push(Issue::error(Rule::Parsing, None, None, error.to_string())),
+    }
+    issues
+}
+
+impl Issue {
+    #[inline]
+    fn error(
+        rule: Rule,
+        record: Option<usize>,
+        glyph_id: Option<GlyphID>,
+        description: String,
+    ) -> Self {
+        Self {
+            severity: Severity::Error,
+            rule,
+            record,
+            glyph_id,
+            description,
+        }
+    }
+
+    #[inline]
+    fn warning(
+        rule: Rule,
+        record: Option<usize>,
+        glyph_id: Option<GlyphID>,
+        description: String,
+    ) -> Self {
+        Self {
+            severity: Severity::Warning,
+            rule,
+            record,
+            glyph_id,
+            description,
+        }
+    }
+}
+
+impl std::fmt::Display for Issue {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.severity {
+            Severity::Error => write!(formatter, "error")?,
+            Severity::Warning => write!(formatter, "warning")?,
+        }
+        if let Some(record) = self.record {
+            write!(formatter, " in record {record}")?;
+        }
+        if let Some(glyph_id) = self.glyph_id {
+            write!(formatter, " for glyph {glyph_id}")?;
+        }
+        write!(formatter, ": {}", self.description)
+    }
+}
+
+fn check_offsets(data: &[u8], issues: &mut Vec<Issue>) {
+    let mut tape = Cursor::new(data);
+    let operations: Vec<Operations> = match (|| {
+        let header = tape.take::<Header>()?;
+        tape.jump(header.header_size as u64)?;
+        tape.take::<Names>()?;
+        (&tape.take::<Dictionaries>()?).try_into()
+    })() {
+        Ok(operations) => operations,
+        _ => return,
+    };
+    let size = data.len() as i64;
+    for (i, operations) in operations.iter().enumerate() {
+        for (operator, minimum) in [
+            (Operator::CharStrings, 0),
+            (Operator::CharSet, 3),
+            (Operator::Encoding, 2),
+            (Operator::FDSelect, 0),
+            (Operator::FDArray, 0),
+        ] {
+            if let Some(Number::Integer(offset)) = operations.get_single(operator) {
+                if offset >= minimum && offset as i64 >= size {
+                    issues.push(Issue::error(
+                        Rule::Offset,
+                        Some(i),
+                        None,
+                        format!("found {operator:?} pointing at {offset} past the end ({size})"),
+                    ));
+                }
+            }
+        }
+        if let Some((Number::Integer(length), Number::Integer(offset))) =
+            operations.get_double(Operator::Private)
+        {
+            let end = offset as i64 + length as i64;
+            if end > size {
+                issues.push(Issue::error(
+                    Rule::Offset,
+                    Some(i),
+                    None,
+                    format!("found Private spanning {offset}..{end} past the end ({size})"),
+                ));
+            }
+        }
+    }
+}
+
+fn check_operations(operations: &Operations, record: usize, issues: &mut Vec<Issue>) {
+    let mut operators = operations.keys().copied().collect::<Vec<_>>();
+    operators.sort_by_key(Operator::code);
+    for operator in operators {
+        let count = operations[&operator].len();
+        let valid = match arity(operator) {
+            Some(expected) => count == expected,
+            _ => true,
+        };
+        if !valid {
+            issues.push(Issue::error(
+                Rule::Arity,
+                Some(record),
+                None,
+                format!("found {operator:?} with {count} operands"),
+            ));
+        }
+    }
+    for (operator, maximum) in [
+        (Operator::BlueValues, 14),
+        (Operator::OtherBlues, 10),
+        (Operator::FamilyBlues, 14),
+        (Operator::FamilyOtherBlues, 10),
+    ] {
+        if let Some(operands) = operations.0.get(&operator) {
+            check_zones(operator, operands, maximum, record, issues);
+        }
+    }
+}
+
+fn check_zones(
+    operator: Operator,
+    operands: &[Number],
+    maximum: usize,
+    record: usize,
+    issues: &mut Vec<Issue>,
+) {
+    if !operands.len().is_multiple_of(2) {
+        issues.push(Issue::error(
+            Rule::BlueValues,
+            Some(record),
+            None,
+            format!("found {operator:?} with an odd number of values"),
+        ));
+    }
+    if operands.len() > maximum {
+        issues.push(Issue::error(
+            Rule::BlueValues,
+            Some(record),
+            None,
+            format!("found {operator:?} with more than {maximum} values"),
+        ));
+    }
+    let mut value = 0.0;
+    let mut values = Vec::with_capacity(operands.len());
+    for operand in operands.iter() {
+        value += operand.to_f32();
+        values.push(value);
+    }
+    if values.windows(2).any(|pair| pair[0] > pair[1]) {
+        issues.push(Issue::error(
+            Rule::BlueValues,
+            Some(record),
+            None,
+            format!("found {operator:?} with unsorted zones ({values:?})"),
+        ));
+    }
+}
+
+fn check_dictionary_selector(
+    record: &character_id_keyed::Record,
+    glyph_count: usize,
+    i: usize,
+    issues: &mut Vec<Issue>,
+) {
+    use character_id_keyed::Encoding;
+
+    let count = record.records.len();
+    let mut report = |description: String| {
+        issues.push(Issue::error(
+            Rule::DictionarySelector,
+            Some(i),
+            None,
+            description,
+        ));
+    };
+    let dictionary_ids: Vec<u8> = match &record.encoding {
+        Encoding::Format0(ref encoding) => encoding.dictionary_ids.clone(),
+        Encoding::Format3(ref encoding) => {
+            let ranges = &encoding.ranges;
+            if ranges.first().map(|range| range.first_glyph_id) != Some(0) {
+                report("found no range starting at glyph 0".into());
+            }
+            if ranges
+                .windows(2)
+                .any(|pair| pair[0].first_glyph_id >= pair[1].first_glyph_id)
+            {
+                report("found ranges not in ascending order".into());
+            }
+            if encoding.glyph_count as usize != glyph_count {
+                report(format!(
+                    "found a sentinel of {} instead of {glyph_count}",
+                    encoding.glyph_count,
+                ));
+            }
+            ranges.iter().map(|range| range.dictionary_id).collect()
+        }
+    };
+    let mut seen = HashSet::new();
+    for dictionary_id in dictionary_ids {
+        if dictionary_id as usize >= count && seen.insert(dictionary_id) {
+            report(format!(
+                "found a reference to dictionary {dictionary_id} out of {count}"
+            ));
+        }
+    }
+}
+
+fn check_character_set(
+    character_set: &CharacterSet,
+    glyph_count: usize,
+    record: usize,
+    issues: &mut Vec<Issue>,
+) {
+    if glyph_count == 0 {
+        return;
+    }
+    let covered = character_set
+        .get_string_id((glyph_count - 1) as GlyphID)
+        .is_some();
+    // The predefined character sets are fixed and may cover more glyphs than there are.
+    let predefined = matches!(
+        character_set,
+        CharacterSet::ISOAdobe | CharacterSet::Expert | CharacterSet::ExpertSubset,
+    );
+    let exceeded = !predefined
+        && glyph_count <= GlyphID::MAX as usize
+        && character_set
+            .get_string_id(glyph_count as GlyphID)
+            .is_some();
+    if !covered {
+        issues.push(Issue::error(
+            Rule::CharacterSet,
+            Some(record),
+            None,
+            format!("found a character set covering fewer than {glyph_count} glyphs"),
+        ));
+    } else if exceeded {
+        issues.push(Issue::warning(
+            Rule::CharacterSet,
+            Some(record),
+            None,
+            format!("found a character set covering more than {glyph_count} glyphs"),
+        ));
+    }
+}
+
+fn check_collection(set: &FontSet, record: usize, issues: &mut Vec<Issue>) {
+    let collection = match set.collection(record) {
+        Some(collection) => collection,
+        _ => return,
+    };
+    for character_id in set.find_excess_character_ids(record).unwrap_or_default() {
+        issues.push(Issue::warning(
+            Rule::CharacterSet,
+            Some(record),
+            None,
+            format!(
+                "found CID {character_id} beyond supplement {} of {}-{}",
+                collection.supplement, collection.registry, collection.ordering,
+            ),
+        ));
+    }
+}
+
+fn check_glyph_names(set: &FontSet, record: usize, issues: &mut Vec<Issue>) {
+    let glyph_count = set.character_strings[record].count as usize;
+    let mut names: HashMap<String, GlyphID> = HashMap::new();
+    for glyph_id in 0..glyph_count {
+        let glyph_id = glyph_id as GlyphID;
+        let name = match set.character_sets[record].get_string_id(glyph_id) {
+            Some(string_id) => match set.strings.get(string_id) {
+                Some(name) => name,
+                _ => {
+                    issues.push(Issue::error(
+                        Rule::GlyphName,
+                        Some(record),
+                        Some(glyph_id),
+                        format!("found a name referring to no string ({string_id})"),
+                    ));
+                    continue;
+                }
+            },
+            _ => continue,
+        };
+        if let Some(other) = names.get(&name) {
+            issues.push(Issue::error(
+                Rule::GlyphName,
+                Some(record),
+                Some(glyph_id),
+                format!("found name {name:?} already given to glyph {other}"),
+            ));
+        } else {
+            names.insert(name, glyph_id);
+        }
+    }
+}
+
+fn check_font_names(names: &Names, issues: &mut Vec<Issue>) {
+    for (i, name) in names.iter().enumerate() {
+        // A deleted entry starts with a zero byte.
+        if name.first() == Some(&0) {
+            continue;
+        }
+        let printable = String::from_utf8_lossy(name);
+        if name.len() > 127 {
+            issues.push(Issue::error(
+                Rule::FontName,
+                Some(i),
+                None,
+                format!("found name {printable:?} longer than 127 characters"),
+            ));
+        } else if name.len() > 63 {
+            issues.push(Issue::warning(
+                Rule::FontName,
+                Some(i),
+                None,
+                format!("found name {printable:?} longer than 63 characters"),
+            ));
+        }
+        if name.is_empty()
+            || name
+                .iter()
+                .any(|&byte| !(33..=126).contains(&byte) || b"[](){}<>/%".contains(&byte))
+        {
+            issues.push(Issue::error(
+                Rule::FontName,
+                Some(i),
+                None,
+                format!("found name {printable:?} with invalid characters"),
+            ));
+        }
+    }
+}
+
+fn check_character_strings(
+    set: &FontSet,
+    record: usize,
+    global: &mut HashSet<usize>,
+    issues: &mut Vec<Issue>,
+) {
+    let subroutines: Vec<&[Vec<u8>]> = match &set.records[record] {
+        Record::CharacterNameKeyed(ref record) => vec![&record.subroutines],
+        Record::CharacterIDKeyed(ref record) => record
+            .records
+            .iter()
+            .map(|record| &record.subroutines[..])
+            .collect(),
+    };
+    let mut locals = vec![HashSet::new(); subroutines.len()];
+    for (glyph_id, code) in set.character_strings[record].iter().enumerate() {
+        let glyph_id = glyph_id as GlyphID;
+        let i = match &set.records[record] {
+            Record::CharacterNameKeyed(_) => 0,
+            Record::CharacterIDKeyed(ref record) => match record.encoding.get(glyph_id) {
+                Some(i) if i < subroutines.len() => i,
+                _ => continue,
+            },
+        };
+        let mut program = Program::new(code, &set.subroutines, subroutines[i]);
+        program.set_glyph_id(glyph_id);
+        program.track_calls();
+        let result = loop {
+            match program.next() {
+                Ok(Some(_)) => {}
+                Ok(None) => break None,
+                Err(error) => break Some(error),
+            }
+        };
+        for call in program.calls().unwrap_or(&[]) {
+            match call.global {
+                true => global.insert(call.index),
+                _ => locals[i].insert(call.index),
+            };
+        }
+        if let Some(error) = result {
+            issues.push(Issue::error(
+                Rule::CharacterString,
+                Some(record),
+                Some(glyph_id),
+                error.to_string(),
+            ));
+        } else if !program.terminated() {
+            issues.push(Issue::error(
+                Rule::CharacterString,
+                Some(record),
+                Some(glyph_id),
+                "found no endchar operator".into(),
+            ));
+        }
+    }
+    for (local, subroutines) in locals.iter().zip(subroutines) {
+        report_unreachable(subroutines.len(), local, false, Some(record), issues);
+    }
+}
+
+fn report_unreachable(
+    count: usize,
+    reached: &HashSet<usize>,
+    global: bool,
+    record: Option<usize>,
+    issues: &mut Vec<Issue>,
+) {
+    let kind = if global { "global" } else { "local" };
+    for index in (0..count).filter(|index| !reached.contains(index)) {
+        issues.push(Issue::warning(
+            Rule::Subroutine,
+            record,
+            None,
+            format!("found unreachable {kind} subroutine {index}"),
+        ));
+    }
+}
+
+fn arity(operator: Operator) -> Option<usize> {
+    use Operator::*;
+
+    match operator {
+        Private => Some(2),
+        ROS => Some(3),
+        FontBBox => Some(4),
+        FontMatrix => Some(6),
+        XUID | BlueValues | OtherBlues | FamilyBlues | FamilyOtherBlues | StemSnapH | StemSnapV
+        | BaseFontBlend => None,
+        _ => Some(1),
+    }
+}
@@ -0,0 +1,354 @@
+//! The errors.
+//!
+//! Failures are reported via [`crate::Error`], which wraps an [`Error`] describing what went
+//! wrong and where. The structured error can be recovered using [`Error::find`].
+
+use crate::compact1::GlyphID;
+
+macro_rules! raise(
+    ($kind:expr) => (return Err($crate::error::Error::from($kind).into()));
+);
+
+macro_rules! locate(
+    ($structure:ident @ $position:expr => $body:expr) => ({
+        let position: u64 = $position;
+        #[allow(clippy::redundant_closure_call)]
+        let result: $crate::Result<_> = (|| Ok($body))();
+        $crate::error::locate(result, $crate::error::Structure::$structure, position)?
+    });
+);
+
+/// An error with location context.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Error {
+    /// The kind.
+    pub kind: Kind,
+    /// The structure being parsed.
+    pub structure: Option<Structure>,
+    /// The position in bytes.
+    ///
+    /// For compact fonts, the position is absolute. For charstrings, the position is relative to
+    /// the beginning of the innermost routine.
+    pub position: Option<u64>,
+    /// The glyph being interpreted.
+    pub glyph_id: Option<GlyphID>,
+    /// The chain of subroutine calls starting from the charstring.
+    pub calls: Vec<Call>,
+}
+
+/// A kind of errors.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Kind {
+    /// A character identifier is assigned to several glyphs.
+    DuplicateCharacterID(crate::compact1::CharacterID),
+    /// The allocation limit is exceeded.
+    ExceededAllocationLimit(usize),
+    /// The index count limit is exceeded.
+    ExceededIndexCountLimit(usize),
+    /// The operation count limit is exceeded.
+    ExceededOperationCountLimit(usize),
+    /// The stack depth limit is exceeded.
+    ExceededStackDepthLimit(usize),
+    /// The subroutine depth limit is exceeded.
+    ExceededSubroutineDepthLimit(usize),
+    /// The dictionaries cannot be combined.
+    IncompatibleDictionaries,
+    /// A character set is malformed.
+    MalformedCharacterSet,
+    /// A document is malformed.
+    MalformedDocument(String),
+    /// An encoding is malformed.
+    MalformedEncoding,
+    /// An index is malformed.
+    MalformedIndex,
+    /// A character mapping is malformed.
+    MalformedMapping,
+    /// A number is malformed.
+    MalformedNumber,
+    /// An offset is malformed.
+    MalformedOffset,
+    /// The operands of an operator are malformed.
+    MalformedOperands,
+    /// An operation in a dictionary is malformed.
+    MalformedOperation(crate::compact1::Operator),
+    /// A record is malformed.
+    MalformedRecord,
+    /// A string identifier is malformed.
+    MalformedStringID,
+    /// A glyph has no character identifier.
+    MissingCharacterID(GlyphID),
+    /// A code has no glyph in the standard encoding.
+    MissingCode(u8),
+    /// A dictionary is missing.
+    MissingDictionary(usize),
+    /// A glyph is missing.
+    MissingGlyph(GlyphID),
+    /// A hint mask is missing.
+    MissingMask,
+    /// A required operation in a dictionary is missing.
+    MissingOperation(crate::compact1::Operator),
+    /// A subroutine is missing.
+    MissingSubroutine(i32),
+    /// The argument stack has fewer operands than needed.
+    StackUnderflow,
+    /// There are data after the end of a charstring.
+    TrailingData,
+    /// The data end unexpectedly.
+    UnexpectedEnd,
+    /// An operand is of an unexpected type.
+    UnexpectedOperand,
+    /// A record is of an unexpected kind.
+    UnexpectedRecord,
+    /// A return occurs outside of a subroutine.
+    UnexpectedReturn,
+    /// A format is unknown.
+    UnknownFormat(i32),
+    /// An operator is unknown.
+    UnknownOperator(u16),
+    /// An operator is not supported.
+    UnsupportedOperator(crate::type2::Operator),
+    /// A subroutine call is not supported.
+    UnsupportedSubroutineCall,
+    /// A width is not given at the beginning of a charstring.
+    UnsupportedWidth(GlyphID),
+    /// Another error.
+    Other(String),
+}
+
+/// A structure of a font.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Structure {
+    /// The header.
+    Header,
+    /// The name index.
+    Names,
+    /// The top dictionary index.
+    TopDictionaries,
+    /// The string index.
+    Strings,
+    /// The global subroutine index.
+    GlobalSubroutines,
+    /// The charstring index.
+    CharacterStrings,
+    /// A character set.
+    CharacterSet,
+    /// An encoding.
+    Encoding,
+    /// The font dictionary index.
+    FontDictionaries,
+    /// The font dictionary selector.
+    DictionarySelector,
+    /// A private dictionary.
+    PrivateDictionary,
+    /// A local subroutine index.
+    LocalSubroutines,
+    /// A charstring.
+    CharacterString,
+}
+
+/// A subroutine call.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Call {
+    /// The kind of the subroutine.
+    pub global: bool,
+    /// The index of the subroutine after applying the bias.
+    pub index: usize,
+}
+
+impl Error {
+    /// Find the structured error behind an error.
+    #[inline]
+    pub fn find(error: &crate::Error) -> Option<&Error> {
+        error.get_ref().and_then(|error| error.downcast_ref())
+    }
+
+    #[inline]
+    fn find_mut(error: &mut crate::Error) -> Option<&mut Error> {
+        error.get_mut().and_then(|error| error.downcast_mut())
+    }
+}
+
+impl From<Kind> for Error {
+    #[inline]
+    fn from(kind: Kind) -> Self {
+        Self {
+            kind,
+            structure: None,
+            position: None,
+            glyph_id: None,
+            calls: vec![],
+        }
+    }
+}
+
+impl From<Error> for crate::Error {
+    #[inline]
+    fn from(error: Error) -> Self {
+        crate::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
+
+impl From<crate::Error> for Error {
+    fn from(error: crate::Error) -> Self {
+        if let Some(error) = Error::find(&error) {
+            return error.clone();
+        }
+        match error.kind() {
+            std::io::ErrorKind::UnexpectedEof => Kind::UnexpectedEnd.into(),
+            _ => Kind::Other(error.to_string()).into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(formatter, "{}", self.kind)?;
+        if let Some(structure) = self.structure {
+            write!(formatter, " in {structure}")?;
+        }
+        if let Some(glyph_id) = self.glyph_id {
+            write!(formatter, " of glyph {glyph_id}")?;
+        }
+        for call in self.calls.iter() {
+            match call.global {
+                true => write!(formatter, " via global subroutine {}", call.index)?,
+                _ => write!(formatter, " via local subroutine {}", call.index)?,
+            }
+        }
+        if let Some(position) = self.position {
+            write!(formatter, " at position {position}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Kind::DuplicateCharacterID(value) => {
+                write!(formatter, "found a duplicate CID ({value})")
+            }
+            Kind::ExceededAllocationLimit(value) => {
+                write!(formatter, "exceeded the allocation limit ({value})")
+            }
+            Kind::ExceededIndexCountLimit(value) => {
+                write!(formatter, "exceeded the index count limit ({value})")
+            }
+            Kind::ExceededOperationCountLimit(value) => {
+                write!(formatter, "exceeded the operation count limit ({value})")
+            }
+            Kind::ExceededStackDepthLimit(value) => {
+                write!(formatter, "exceeded the stack depth limit ({value})")
+            }
+            Kind::ExceededSubroutineDepthLimit(value) => {
+                write!(formatter, "exceeded the subroutine depth limit ({value})")
+            }
+            Kind::IncompatibleDictionaries => {
+                write!(formatter, "found dictionaries that cannot be merged")
+            }
+            Kind::MalformedCharacterSet => write!(formatter, "found a malformed character set"),
+            Kind::MalformedDocument(description) => {
+                write!(formatter, "found a malformed document ({description})")
+            }
+            Kind::MalformedEncoding => write!(formatter, "found a malformed encoding"),
+            Kind::MalformedIndex => write!(formatter, "found a malformed index"),
+            Kind::MalformedMapping => write!(formatter, "found a malformed mapping"),
+            Kind::MalformedNumber => write!(formatter, "found a malformed number"),
+            Kind::MalformedOffset => write!(formatter, "found a malformed offset"),
+            Kind::MalformedOperands => write!(formatter, "found malformed operands"),
+            Kind::MalformedOperation(operator) => {
+                write!(
+                    formatter,
+                    "found a malformed operation with operator {operator:?}"
+                )
+            }
+            Kind::MalformedRecord => write!(formatter, "found a malformed record"),
+            Kind::MalformedStringID => write!(formatter, "found a malformed string ID"),
+            Kind::MissingCharacterID(glyph_id) => {
+                write!(formatter, "found no CID for glyph {glyph_id}")
+            }
+            Kind::MissingDictionary(index) => {
+                write!(formatter, "found no dictionary at position {index}")
+            }
+            Kind::MissingCode(code) => {
+                write!(
+                    formatter,
+                    "found no glyph for code {code} in the standard encoding"
+                )
+            }
+            Kind::MissingGlyph(glyph_id) => {
+                write!(formatter, "found no glyph {glyph_id}")
+            }
+            Kind::MissingMask => write!(formatter, "found a hint mask of unknown size"),
+            Kind::MissingOperation(operator) => {
+                write!(formatter, "found no operation with operator {operator:?}")
+            }
+            Kind::MissingSubroutine(address) => {
+                write!(formatter, "found no subroutine with address {address}")
+            }
+            Kind::StackUnderflow => write!(formatter, "expected more operands"),
+            Kind::TrailingData => write!(formatter, "found trailing data after the end operator"),
+            Kind::UnexpectedEnd => write!(formatter, "found an unexpected end of data"),
+            Kind::UnexpectedOperand => write!(formatter, "found an operand of an unexpected type"),
+            Kind::UnexpectedRecord => write!(formatter, "found a record of an unexpected kind"),
+            Kind::UnexpectedReturn => {
+                write!(formatter, "found a return operator without a caller")
+            }
+            Kind::UnknownFormat(format) => write!(formatter, "found an unknown format ({format})"),
+            Kind::UnknownOperator(code) => write!(formatter, "found an unknown operator ({code})"),
+            Kind::UnsupportedOperator(operator) => {
+                write!(formatter, "found an unsupported operator ({operator:?})")
+            }
+            Kind::UnsupportedSubroutineCall => {
+                write!(formatter, "found a subroutine call with a computed address")
+            }
+            Kind::UnsupportedWidth(glyph_id) => write!(
+                formatter,
+                "found a width of glyph {glyph_id} not given at the beginning of the charstring"
+            ),
+            Kind::Other(description) => write!(formatter, "{description}"),
+        }
+    }
+}
+
+impl std::fmt::Display for Structure {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Structure::Header => "the header",
+            Structure::Names => "the name index",
+            Structure::TopDictionaries => "the top dictionary index",
+            Structure::Strings => "the string index",
+            Structure::GlobalSubroutines => "the global subroutine index",
+            Structure::CharacterStrings => "the character-string index",
+            Structure::CharacterSet => "the character set",
+            Structure::Encoding => "the encoding",
+            Structure::FontDictionaries => "the font dictionary index",
+            Structure::DictionarySelector => "the glyph-to-dictionary encoding",
+            Structure::PrivateDictionary => "the private dictionary",
+            Structure::LocalSubroutines => "the local subroutine index",
+            Structure::CharacterString => "the charstring",
+        };
+        formatter.write_str(name)
+    }
+}
+
+/// Attach a structure and a position to an error unless already present.
+pub(crate) fn locate<T>(
+    result: crate::Result<T>,
+    structure: Structure,
+    position: u64,
+) -> crate::Result<T> {
+    result.map_err(|error| {
+        let mut error = match Error::find(&error) {
+            Some(_) => error,
+            _ => Error::from(error).into(),
+        };
+        if let Some(error) = Error::find_mut(&mut error) {
+            error.structure.get_or_insert(structure);
+            error.position.get_or_insert(position);
+        }
+        error
+    })
+}
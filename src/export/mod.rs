@@ -0,0 +1,251 @@
+//! The export of outlines as vector drawings.
+//!
+//! Outlines are written in the units they are given in, which are font units for outlines built
+//! by [`FontSet::outline`]. They can be scaled beforehand using [`Outline::transform`].
+
+use std::fmt::Write;
+
+use crate::compact1::FontSet;
+use crate::type2::outline::{Outline, Point, Segment};
+use crate::Result;
+
+/// A format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Scalable Vector Graphics.
+    Svg,
+    /// Encapsulated PostScript.
+    Eps,
+    /// The path operators of PDF content streams.
+    Pdf,
+}
+
+// The number of columns when exporting a whole font.
+const COLUMNS: usize = 16;
+
+// The distance from the bottom of a cell to the baseline in ems when exporting a whole font.
+const DESCENT: f32 = 0.25;
+
+/// Write the path of an outline.
+///
+/// For SVG, the path is the value of the `d` attribute in the coordinate system of the outline,
+/// where the y axis points upward. For EPS and PDF, each operator is written on its own line.
+pub fn path(outline: &Outline, format: Format) -> String {
+    let mut value = String::new();
+    for segment in outline.segments.iter() {
+        let (first, operator, rest) = match (*segment, format) {
+            (Segment::Move(a), Format::Svg) => (a, "M", None),
+            (Segment::Move(a), Format::Eps) => (a, "moveto", None),
+            (Segment::Move(a), Format::Pdf) => (a, "m", None),
+            (Segment::Line(a), Format::Svg) => (a, "L", None),
+            (Segment::Line(a), Format::Eps) => (a, "lineto", None),
+            (Segment::Line(a), Format::Pdf) => (a, "l", None),
+            (Segment::Curve(a, b, c), Format::Svg) => (a, "C", Some((b, c))),
+            (Segment::Curve(a, b, c), Format::Eps) => (a, "curveto", Some((b, c))),
+            (Segment::Curve(a, b, c), Format::Pdf) => (a, "c", Some((b, c))),
+            (Segment::Close, format) => {
+                let operator = match format {
+                    Format::Svg => "Z",
+                    Format::Eps => "closepath",
+                    Format::Pdf => "h",
+                };
+                push(&mut value, operator, format);
+                continue;
+            }
+        };
+        let mut points = vec![first];
+        points.extend(rest.map(|(b, c)| [b, c]).into_iter().flatten());
+        let points = points
+            .into_iter()
+            .map(|(x, y)| format!("{} {}", number(x), number(y)));
+        let points = points.collect::<Vec<_>>().join(" ");
+        match format {
+            Format::Svg => push(&mut value, &format!("{operator} {points}"), format),
+            _ => push(&mut value, &format!("{points} {operator}"), format),
+        }
+    }
+    value
+}
+
+/// Write an outline as a document.
+///
+/// For SVG, the document contains a path with a transformation flipping the y axis and a view
+/// box fitting the outline. For EPS, the document fills the path and declares the bounding box of
+/// the outline. For PDF, the result is a content stream filling the path using the non-zero
+/// winding rule.
+pub fn glyph(outline: &Outline, format: Format) -> String {
+    let bounds = outline.bounds().unwrap_or_default();
+    document(&[(outline, (0.0, 0.0))], bounds, format)
+}
+
+/// Write all glyphs of a record as a document arranged in a grid.
+///
+/// Each cell is one em wide and high, which is rounded to whole font units, and glyphs are placed
+/// from left to right and from top to bottom in the order of their glyph IDs.
+pub fn font(set: &FontSet, index: usize, format: Format) -> Result<String> {
+    let count = set.character_strings[index].count;
+    let mut outlines = Vec::with_capacity(count as usize);
+    for glyph_id in 0..count {
+        outlines.push(set.outline(index, glyph_id)?);
+    }
+    let size = match set.matrix(index, 0)?[3] {
+        scale if scale > 0.0 => (1.0 / scale).round().max(1.0),
+        _ => 1000.0,
+    };
+    let rows = (count as usize).div_ceil(COLUMNS);
+    let placements = outlines
+        .iter()
+        .enumerate()
+        .map(|(i, outline)| {
+            let (column, row) = (i % COLUMNS, i / COLUMNS);
+            let x = column as f32 * size;
+            let y = (rows - row - 1) as f32 * size + DESCENT * size;
+            (outline, (x, y))
+        })
+        .collect::<Vec<_>>();
+    let bounds = (
+        (0.0, 0.0),
+        (
+            COLUMNS.min(count as usize) as f32 * size,
+            rows as f32 * size,
+        ),
+    );
+    Ok(document(&placements, bounds, format))
+}
+
+fn document(placements: &[(&Outline, Point)], bounds: (Point, Point), format: Format) -> String {
+    let ((x0, y0), (x1, y1)) = bounds;
+    let mut value = String::new();
+    match format {
+        Format::Svg => {
+            let _ = writeln!(
+                value,
+                r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+                number(x0),
+                number(-y1),
+                number(x1 - x0),
+                number(y1 - y0),
+            );
+            for (outline, (x, y)) in placements.iter() {
+                let _ = writeln!(
+                    value,
+                    r#"<path transform="matrix(1 0 0 -1 {} {})" d="{}"/>"#,
+                    number(*x),
+                    number(-y),
+                    path(outline, format),
+                );
+            }
+            value.push_str("</svg>\n");
+        }
+        Format::Eps => {
+            value.push_str("%!PS-Adobe-3.0 EPSF-3.0\n");
+            let _ = writeln!(
+                value,
+                "%%BoundingBox: {} {} {} {}",
+                number(x0.floor()),
+                number(y0.floor()),
+                number(x1.ceil()),
+                number(y1.ceil()),
+            );
+            let _ = writeln!(
+                value,
+                "%%HiResBoundingBox: {} {} {} {}",
+                number(x0),
+                number(y0),
+                number(x1),
+                number(y1),
+            );
+            value.push_str("%%EndComments\n");
+            for (outline, (x, y)) in placements.iter() {
+                let translated = *x != 0.0 || *y != 0.0;
+                if translated {
+                    let _ = writeln!(value, "gsave {} {} translate", number(*x), number(*y));
+                }
+                let _ = writeln!(value, "newpath\n{}\nfill", path(outline, format));
+                if translated {
+                    value.push_str("grestore\n");
+                }
+            }
+            value.push_str("%%EOF\n");
+        }
+        Format::Pdf => {
+            for (outline, (x, y)) in placements.iter() {
+                let translated = *x != 0.0 || *y != 0.0;
+                if translated {
+                    let _ = writeln!(value, "q 1 0 0 1 {} {} cm", number(*x), number(*y));
+                }
+                let _ = writeln!(value, "{}\nf", path(outline, format));
+                if translated {
+                    value.push_str("Q\n");
+                }
+            }
+        }
+    }
+    value
+}
+
+fn number(value: f32) -> String {
+    if value == 0.0 {
+        return "0".into();
+    }
+    let value = format!("{value:.3}");
+    match value.trim_end_matches('0').trim_end_matches('.') {
+        "-0" => "0".into(),
+        value => value.into(),
+    }
+}
+
+fn push(value: &mut String, item: &str, format: Format) {
+    if !value.is_empty() {
+        value.push(if format == Format::Svg { ' ' } else { '\n' });
+    }
+    value.push_str(item);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{glyph, path, Format};
+    use crate::type2::outline::{Outline, Segment};
+
+    fn outline() -> Outline {
+        Outline {
+            segments: vec![
+                Segment::Move((10.0, -5.0)),
+                Segment::Line((110.5, -5.0)),
+                Segment::Curve((110.5, 50.0), (60.0, 100.0), (10.0, 100.0)),
+                Segment::Close,
+            ],
+        }
+    }
+
+    #[test]
+    fn paths() {
+        let outline = outline();
+        assert_eq!(
+            path(&outline, Format::Svg),
+            "M 10 -5 L 110.5 -5 C 110.5 50 60 100 10 100 Z",
+        );
+        assert_eq!(
+            path(&outline, Format::Eps),
+            "10 -5 moveto\n110.5 -5 lineto\n110.5 50 60 100 10 100 curveto\nclosepath",
+        );
+        assert_eq!(
+            path(&outline, Format::Pdf),
+            "10 -5 m\n110.5 -5 l\n110.5 50 60 100 10 100 c\nh",
+        );
+    }
+
+    #[test]
+    fn documents() {
+        let outline = outline();
+        let value = glyph(&outline, Format::Svg);
+        assert!(value.contains(r#"viewBox="10 -100 100.5 105""#));
+        assert!(value.contains(r#"<path transform="matrix(1 0 0 -1 0 0)" d="M 10 -5"#));
+        let value = glyph(&outline, Format::Eps);
+        assert!(value.starts_with("%!PS-Adobe-3.0 EPSF-3.0\n%%BoundingBox: 10 -5 111 100\n"));
+        assert!(value.contains("newpath\n10 -5 moveto\n"));
+        assert!(value.ends_with("closepath\nfill\n%%EOF\n"));
+        let value = glyph(&outline, Format::Pdf);
+        assert!(value.ends_with("h\nf\n"));
+    }
+}
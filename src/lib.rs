@@ -1,10 +1,22 @@
 //! Parser of PostScript fonts.
 
-#[macro_use(dereference, jump_take, jump_take_given, raise, table)]
+#[macro_use(dereference, jump_take, jump_take_given, table)]
 extern crate typeface;
 
+#[macro_use]
+pub mod error;
+
+pub mod cmap;
 pub mod compact1;
+pub mod export;
+pub mod raster;
+pub mod ttx;
 pub mod type1;
 pub mod type2;
+pub mod unicode;
+
+mod limits;
+mod slice;
 
+pub use limits::Limits;
 pub use typeface::{tape, value, walue, Error, Result};
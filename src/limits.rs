@@ -0,0 +1,43 @@
+//! The resource limits.
+
+use crate::error::Kind;
+use crate::Result;
+
+/// Resource limits enforced while parsing fonts and interpreting charstrings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// The maximum number of nested subroutine calls, which is capped at 10.
+    pub subroutine_depth: usize,
+    /// The maximum number of operands on the argument stack, which is capped at 48.
+    pub stack_depth: usize,
+    /// The maximum number of operations executed per glyph.
+    pub operation_count: usize,
+    /// The maximum number of bytes allocated for the data of a font set.
+    pub allocation_size: usize,
+    /// The maximum number of elements in an index.
+    pub index_count: usize,
+}
+
+impl Limits {
+    #[doc(hidden)]
+    pub fn allocate(&mut self, size: usize) -> Result<()> {
+        if size > self.allocation_size {
+            raise!(Kind::ExceededAllocationLimit(self.allocation_size));
+        }
+        self.allocation_size -= size;
+        Ok(())
+    }
+}
+
+impl Default for Limits {
+    /// Create limits following the Type 2 Charstring Format.
+    fn default() -> Self {
+        Self {
+            subroutine_depth: 10,
+            stack_depth: 48,
+            operation_count: 1 << 16,
+            allocation_size: 1 << 28,
+            index_count: u16::MAX as usize,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! The rasterization of outlines.
+//!
+//! Outlines are rendered into coverage bitmaps using the non-zero winding rule. Each row of pixels
+//! is sampled along several scanlines, and the coverage along each scanline is computed exactly.
+
+use crate::type2::outline::{multiply, Matrix, Outline, Point};
+
+/// A bitmap of coverage values.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bitmap {
+    /// The number of columns.
+    pub width: usize,
+    /// The number of rows.
+    pub height: usize,
+    /// The horizontal distance from the origin to the left edge in pixels.
+    pub left: i32,
+    /// The vertical distance from the origin to the top edge in pixels, positive upward.
+    pub top: i32,
+    /// The coverage values from zero to 255 in rows from top to bottom.
+    pub data: Vec<u8>,
+}
+
+// The number of scanlines per row of pixels.
+const SAMPLES: usize = 16;
+
+// The tolerance of curve flattening in pixels.
+const TOLERANCE: f32 = 0.05;
+
+impl Bitmap {
+    /// Return the coverage of a pixel.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        if x < self.width {
+            self.data.get(y * self.width + x).copied()
+        } else {
+            None
+        }
+    }
+}
+
+/// Render an outline at a size given in pixels per em.
+///
+/// The outline is mapped to ems by the font matrix, scaled by the size, and then mapped by the
+/// transformation if one is given, which operates in pixels with the y axis pointing upward.
+pub fn render(outline: &Outline, matrix: &Matrix, size: f32, transform: Option<&Matrix>) -> Bitmap {
+    let mut matrix = multiply(matrix, &[size, 0.0, 0.0, size, 0.0, 0.0]);
+    if let Some(transform) = transform {
+        matrix = multiply(&matrix, transform);
+    }
+    // Flip the y axis so that rows go downward.
+    let matrix = multiply(&matrix, &[1.0, 0.0, 0.0, -1.0, 0.0, 0.0]);
+    let contours = outline.transform(&matrix).flatten(TOLERANCE);
+    let points = contours.iter().flat_map(|contour| contour.points.iter());
+    let (mut x0, mut y0) = (f32::INFINITY, f32::INFINITY);
+    let (mut x1, mut y1) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+    for &(x, y) in points {
+        x0 = x0.min(x);
+        y0 = y0.min(y);
+        x1 = x1.max(x);
+        y1 = y1.max(y);
+    }
+    if !(x0 < x1 && y0 < y1) {
+        return Bitmap::default();
+    }
+    let (left, top) = (x0.floor(), y0.floor());
+    let width = (x1.ceil() - left) as usize;
+    let height = (y1.ceil() - top) as usize;
+    let edges = contours
+        .iter()
+        .flat_map(|contour| contour.points.windows(2))
+        .filter_map(|pair| {
+            let shift = |(x, y): Point| (x - left, y - top);
+            Edge::new(shift(pair[0]), shift(pair[1]))
+        })
+        .collect::<Vec<_>>();
+    Bitmap {
+        width,
+        height,
+        left: left as i32,
+        top: -top as i32,
+        data: fill(&edges, width, height),
+    }
+}
+
+struct Edge {
+    x: f32,
+    y0: f32,
+    y1: f32,
+    slope: f32,
+    direction: i32,
+}
+
+impl Edge {
+    fn new(a: Point, b: Point) -> Option<Self> {
+        if a.1 == b.1 {
+            return None;
+        }
+        let (a, b, direction) = if a.1 < b.1 { (a, b, 1) } else { (b, a, -1) };
+        Some(Self {
+            x: a.0,
+            y0: a.1,
+            y1: b.1,
+            slope: (b.0 - a.0) / (b.1 - a.1),
+            direction,
+        })
+    }
+}
+
+fn fill(edges: &[Edge], width: usize, height: usize) -> Vec<u8> {
+    let mut data = vec![0; width * height];
+    let mut row = vec![0.0f32; width + 1];
+    let mut crossings: Vec<(f32, i32)> = vec![];
+    for y in 0..height {
+        row.iter_mut().for_each(|value| *value = 0.0);
+        for sample in 0..SAMPLES {
+            let scanline = y as f32 + (sample as f32 + 0.5) / SAMPLES as f32;
+            crossings.clear();
+            for edge in edges.iter() {
+                if edge.y0 <= scanline && scanline < edge.y1 {
+                    let x = edge.x + (scanline - edge.y0) * edge.slope;
+                    crossings.push((x, edge.direction));
+                }
+            }
+            crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let mut winding = 0;
+            let mut start = 0.0;
+            for &(x, direction) in crossings.iter() {
+                let previous = winding;
+                winding += direction;
+                if previous == 0 && winding != 0 {
+                    start = x;
+                } else if previous != 0 && winding == 0 {
+                    accumulate(&mut row, start, x);
+                }
+            }
+        }
+        for (value, &coverage) in data[y * width..(y + 1) * width].iter_mut().zip(row.iter()) {
+            *value = (coverage / SAMPLES as f32 * 255.0).round().min(255.0) as u8;
+        }
+    }
+    data
+}
+
+fn accumulate(row: &mut [f32], start: f32, end: f32) {
+    let limit = (row.len() - 1) as f32;
+    let (start, end) = (start.clamp(0.0, limit), end.clamp(0.0, limit));
+    if start >= end {
+        return;
+    }
+    let (first, last) = (start.floor() as usize, end.floor() as usize);
+    if first == last {
+        row[first] += end - start;
+        return;
+    }
+    row[first] += (first + 1) as f32 - start;
+    for value in row[first + 1..last].iter_mut() {
+        *value += 1.0;
+    }
+    row[last] += end - last as f32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use crate::type2::outline::{Outline, Segment, IDENTITY};
+
+    fn rectangle(x0: f32, y0: f32, x1: f32, y1: f32, clockwise: bool) -> Vec<Segment> {
+        let mut points = vec![(x1, y0), (x1, y1), (x0, y1)];
+        if clockwise {
+            points.reverse();
+        }
+        let mut segments = vec![Segment::Move((x0, y0))];
+        segments.extend(points.into_iter().map(Segment::Line));
+        segments.push(Segment::Close);
+        segments
+    }
+
+    #[test]
+    fn coverage() {
+        let outline = Outline {
+            segments: rectangle(0.5, 0.0, 2.0, 2.0, false),
+        };
+        let bitmap = render(&outline, &IDENTITY, 1.0, None);
+        assert_eq!((bitmap.width, bitmap.height), (2, 2));
+        assert_eq!((bitmap.left, bitmap.top), (0, 2));
+        assert_eq!(bitmap.data, [128, 255, 128, 255]);
+    }
+
+    #[test]
+    fn winding() {
+        let mut segments = rectangle(0.0, 0.0, 4.0, 4.0, false);
+        segments.extend(rectangle(1.0, 1.0, 3.0, 3.0, false));
+        let bitmap = render(&Outline { segments }, &IDENTITY, 1.0, None);
+        assert_eq!(bitmap.get(1, 1), Some(255));
+
+        let mut segments = rectangle(0.0, 0.0, 4.0, 4.0, false);
+        segments.extend(rectangle(1.0, 1.0, 3.0, 3.0, true));
+        let bitmap = render(&Outline { segments }, &IDENTITY, 1.0, None);
+        assert_eq!(bitmap.get(1, 1), Some(0));
+        assert_eq!(bitmap.get(0, 1), Some(255));
+    }
+
+    #[test]
+    fn transform() {
+        let outline = Outline {
+            segments: rectangle(0.0, 0.0, 1.0, 1.0, false),
+        };
+        let bitmap = render(
+            &outline,
+            &[0.5, 0.0, 0.0, 0.5, 0.0, 0.0],
+            8.0,
+            Some(&[1.0, 0.0, 0.0, 1.0, -2.0, 1.0]),
+        );
+        assert_eq!((bitmap.width, bitmap.height), (4, 4));
+        assert_eq!((bitmap.left, bitmap.top), (-2, 5));
+        assert!(bitmap.data.iter().all(|&value| value == 255));
+        assert!(render(&Outline::default(), &IDENTITY, 1.0, None)
+            .data
+            .is_empty());
+    }
+}
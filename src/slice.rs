@@ -0,0 +1,97 @@
+//! The reading of byte slices.
+
+use crate::error::Kind;
+use crate::Result;
+
+/// A reader of big-endian values from a byte slice.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Slice<'l> {
+    data: &'l [u8],
+    position: usize,
+}
+
+impl<'l> Slice<'l> {
+    /// Create a reader positioned at the beginning of the data.
+    #[inline]
+    pub fn new(data: &'l [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Return the position of the next byte.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Check if all the data have been read.
+    #[inline]
+    pub fn done(&self) -> bool {
+        self.position == self.data.len()
+    }
+
+    /// Return the next byte without advancing.
+    #[inline]
+    pub fn peek(&self) -> Result<u8> {
+        match self.data.get(self.position) {
+            Some(&value) => Ok(value),
+            _ => raise!(Kind::UnexpectedEnd),
+        }
+    }
+
+    /// Read a byte.
+    #[inline]
+    pub fn take(&mut self) -> Result<u8> {
+        let value = self.peek()?;
+        self.position += 1;
+        Ok(value)
+    }
+
+    /// Read two bytes as an unsigned integer.
+    #[inline]
+    pub fn take_u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.take_array()?))
+    }
+
+    /// Read four bytes as an unsigned integer.
+    #[inline]
+    pub fn take_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.take_array()?))
+    }
+
+    /// Advance by a number of bytes.
+    pub fn skip(&mut self, count: usize) -> Result<()> {
+        match self.position.checked_add(count) {
+            Some(position) if position <= self.data.len() => {
+                self.position = position;
+                Ok(())
+            }
+            _ => raise!(Kind::UnexpectedEnd),
+        }
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let position = self.position;
+        self.skip(N)?;
+        let mut value = [0; N];
+        value.copy_from_slice(&self.data[position..position + N]);
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slice;
+
+    #[test]
+    fn take() {
+        let mut slice = Slice::new(&[0x0c, 0x12, 0x34, 0x00, 0x01, 0x04, 0x5a]);
+        assert_eq!(slice.peek().unwrap(), 0x0c);
+        assert_eq!(slice.take().unwrap(), 0x0c);
+        assert_eq!(slice.take_u16().unwrap(), 0x1234);
+        assert_eq!(slice.take_u32().unwrap(), 0x0001045a);
+        assert!(slice.done());
+        assert!(slice.take().is_err());
+        assert!(slice.skip(1).is_err());
+        assert_eq!(slice.position(), 7);
+    }
+}
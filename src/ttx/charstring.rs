@@ -0,0 +1,189 @@
+//! The assembly of charstrings.
+
+use crate::error::Kind;
+use crate::type2::token::Token;
+use crate::type2::Operator;
+use crate::Result;
+
+const OPERATORS: &[(Operator, &str)] = &[
+    (Operator::HStem, "hstem"),
+    (Operator::VStem, "vstem"),
+    (Operator::VMoveTo, "vmoveto"),
+    (Operator::RLineTo, "rlineto"),
+    (Operator::HLineTo, "hlineto"),
+    (Operator::VLineTo, "vlineto"),
+    (Operator::RRCurveTo, "rrcurveto"),
+    (Operator::CallSubr, "callsubr"),
+    (Operator::Return, "return"),
+    (Operator::EndChar, "endchar"),
+    (Operator::HStemHM, "hstemhm"),
+    (Operator::HintMask, "hintmask"),
+    (Operator::CntrMask, "cntrmask"),
+    (Operator::RMoveTo, "rmoveto"),
+    (Operator::HMoveTo, "hmoveto"),
+    (Operator::VStemHM, "vstemhm"),
+    (Operator::RCurveLine, "rcurveline"),
+    (Operator::RLineCurve, "rlinecurve"),
+    (Operator::VVCurveTo, "vvcurveto"),
+    (Operator::HHCurveTo, "hhcurveto"),
+    (Operator::CallGSubr, "callgsubr"),
+    (Operator::VHCurveTo, "vhcurveto"),
+    (Operator::HVCurveTo, "hvcurveto"),
+    (Operator::DotSection, "dotsection"),
+    (Operator::And, "and"),
+    (Operator::Or, "or"),
+    (Operator::Not, "not"),
+    (Operator::Abs, "abs"),
+    (Operator::Add, "add"),
+    (Operator::Sub, "sub"),
+    (Operator::Div, "div"),
+    (Operator::Neg, "neg"),
+    (Operator::Eq, "eq"),
+    (Operator::Drop, "drop"),
+    (Operator::Put, "put"),
+    (Operator::Get, "get"),
+    (Operator::IfElse, "ifelse"),
+    (Operator::Random, "random"),
+    (Operator::Mul, "mul"),
+    (Operator::Sqrt, "sqrt"),
+    (Operator::Dup, "dup"),
+    (Operator::Exch, "exch"),
+    (Operator::Index, "index"),
+    (Operator::Roll, "roll"),
+    (Operator::HFlex, "hflex"),
+    (Operator::Flex, "flex"),
+    (Operator::HFlex1, "hflex1"),
+    (Operator::Flex1, "flex1"),
+];
+
+/// Write tokens with each operator ending a line.
+///
+/// Fixed-point numbers are written using the shortest decimal notation with a decimal point that
+/// reads back to the same value, and hint masks are written as bits following their operators.
+pub fn write(tokens: &[Token]) -> String {
+    let mut lines = vec![];
+    let mut words = vec![];
+    for token in tokens.iter() {
+        match *token {
+            Token::Integer(value) => words.push(value.to_string()),
+            Token::Fixed(value) => words.push(fixed(value)),
+            Token::Operator(operator) => {
+                words.push(name(operator).to_string());
+                if !matches!(operator, Operator::HintMask | Operator::CntrMask) {
+                    lines.push(words.join(" "));
+                    words.clear();
+                }
+            }
+            Token::Mask(ref mask) => {
+                if !mask.is_empty() {
+                    words.push(mask.iter().map(|byte| format!("{byte:08b}")).collect());
+                }
+                lines.push(words.join(" "));
+                words.clear();
+            }
+        }
+    }
+    if !words.is_empty() {
+        lines.push(words.join(" "));
+    }
+    lines.join("\n")
+}
+
+/// Read tokens written by `write`.
+pub fn read(text: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut words = text.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        if let Some(&(operator, _)) = OPERATORS.iter().find(|(_, name)| *name == word) {
+            tokens.push(Token::Operator(operator));
+            if matches!(operator, Operator::HintMask | Operator::CntrMask) {
+                let mut mask = vec![];
+                if let Some(bits) = words.next_if(|word| is_mask(word)) {
+                    for chunk in bits.as_bytes().chunks(8) {
+                        let byte = chunk.iter().fold(0, |byte, bit| byte << 1 | (bit - b'0'));
+                        mask.push(byte);
+                    }
+                }
+                tokens.push(Token::Mask(mask));
+            }
+        } else if word.contains(['.', 'e', 'E']) {
+            match word.parse::<f64>() {
+                Ok(value) if value.is_finite() => {
+                    let value = (value * 65536.0).round();
+                    tokens.push(Token::Fixed(
+                        value.clamp(i32::MIN as f64, i32::MAX as f64) as i32
+                    ));
+                }
+                _ => raise!(Kind::MalformedNumber),
+            }
+        } else if let Ok(value) = word.parse::<i32>() {
+            tokens.push(Token::Integer(value));
+        } else {
+            raise!(Kind::MalformedDocument(format!(
+                "found an unknown operator {word:?} in a charstring"
+            )));
+        }
+    }
+    Ok(tokens)
+}
+
+fn name(operator: Operator) -> &'static str {
+    match OPERATORS.iter().find(|(other, _)| *other == operator) {
+        Some((_, name)) => name,
+        _ => unreachable!(),
+    }
+}
+
+fn fixed(value: i32) -> String {
+    let number = value as f64 / 65536.0;
+    // Five decimal places are finer than the resolution of the format.
+    for precision in 1..5 {
+        let text = format!("{number:.precision$}");
+        if text.parse::<f64>().map(|other| (other * 65536.0).round()) == Ok(value as f64) {
+            return text;
+        }
+    }
+    format!("{number:.5}")
+}
+
+fn is_mask(word: &str) -> bool {
+    !word.is_empty()
+        && word.len().is_multiple_of(8)
+        && word.bytes().all(|byte| byte == b'0' || byte == b'1')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read, write};
+    use crate::type2::token::Token;
+    use crate::type2::Operator;
+
+    #[test]
+    fn round_trip() {
+        let tokens = vec![
+            Token::Integer(-15),
+            Token::Fixed(0x0001_8000),
+            Token::Fixed(0x5555),
+            Token::Fixed(0x0002_0000),
+            Token::Operator(Operator::HStemHM),
+            Token::Operator(Operator::HintMask),
+            Token::Mask(vec![0b1010_0000, 0b0000_0001]),
+            Token::Integer(100),
+            Token::Operator(Operator::HMoveTo),
+            Token::Operator(Operator::CntrMask),
+            Token::Mask(vec![]),
+            Token::Operator(Operator::EndChar),
+        ];
+        let text = write(&tokens);
+        assert_eq!(
+            text,
+            "-15 1.5 0.33333 2.0 hstemhm\n\
+             hintmask 1010000000000001\n\
+             100 hmoveto\n\
+             cntrmask\n\
+             endchar",
+        );
+        assert_eq!(read(&text).unwrap(), tokens);
+        assert!(read("1 foo").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! The [TTX format][1] of font sets.
+//!
+//! A font set is written as a `ttFont` document with a `CFF` table, which is how fontTools
+//! represents the `CFF ` table of an OpenType font. The dictionaries are spelled out using the
+//! names of fontTools with string identifiers resolved and delta-encoded arrays written as absolute
+//! values, and the charstrings and subroutines are written in the Type 2 assembly, one operator
+//! per line. Offsets are not part of the format; hence, the font sets created by reading a document
+//! carry placeholder offsets, as the ones produced by the conversions of records do.
+//!
+//! [1]: https://fonttools.readthedocs.io/en/latest/ttx.html
+
+mod charstring;
+mod reading;
+mod writing;
+mod xml;
+
+pub use reading::read;
+pub use writing::write;
+
+use crate::compact1::{Number, Operator, Real};
+
+/// A kind of operands of a dictionary entry.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Value {
+    /// Numbers written as they are.
+    Numbers,
+    /// Numbers written as absolute values but encoded as differences to the previous ones.
+    Delta,
+    /// A string identifier written as the string.
+    String,
+}
+
+// The entries of top, font, and private dictionaries in the order fontTools writes them, except
+// for the ones referring to other structures, which are handled separately.
+const ENTRIES: &[(Operator, &str, Value)] = &[
+    (Operator::Version, "version", Value::String),
+    (Operator::Notice, "Notice", Value::String),
+    (Operator::Copyright, "Copyright", Value::String),
+    (Operator::FullName, "FullName", Value::String),
+    (Operator::FamilyName, "FamilyName", Value::String),
+    (Operator::Weight, "Weight", Value::String),
+    (Operator::IsFixedPitch, "isFixedPitch", Value::Numbers),
+    (Operator::ItalicAngle, "ItalicAngle", Value::Numbers),
+    (
+        Operator::UnderlinePosition,
+        "UnderlinePosition",
+        Value::Numbers,
+    ),
+    (
+        Operator::UnderlineThickness,
+        "UnderlineThickness",
+        Value::Numbers,
+    ),
+    (Operator::PaintType, "PaintType", Value::Numbers),
+    (Operator::CharStringType, "CharstringType", Value::Numbers),
+    (Operator::FontMatrix, "FontMatrix", Value::Numbers),
+    (Operator::UniqueID, "UniqueID", Value::Numbers),
+    (Operator::FontBBox, "FontBBox", Value::Numbers),
+    (Operator::StrokeWidth, "StrokeWidth", Value::Numbers),
+    (Operator::XUID, "XUID", Value::Numbers),
+    (Operator::PostScript, "PostScript", Value::String),
+    (Operator::BaseFontName, "BaseFontName", Value::String),
+    (Operator::BaseFontBlend, "BaseFontBlend", Value::Delta),
+    (Operator::CIDFontVersion, "CIDFontVersion", Value::Numbers),
+    (Operator::CIDFontRevision, "CIDFontRevision", Value::Numbers),
+    (Operator::CIDFontType, "CIDFontType", Value::Numbers),
+    (Operator::CIDCount, "CIDCount", Value::Numbers),
+    (Operator::UIDBase, "UIDBase", Value::Numbers),
+    (Operator::FontName, "FontName", Value::String),
+    (Operator::SyntheticBase, "SyntheticBase", Value::Numbers),
+    (Operator::BlueValues, "BlueValues", Value::Delta),
+    (Operator::OtherBlues, "OtherBlues", Value::Delta),
+    (Operator::FamilyBlues, "FamilyBlues", Value::Delta),
+    (Operator::FamilyOtherBlues, "FamilyOtherBlues", Value::Delta),
+    (Operator::BlueScale, "BlueScale", Value::Numbers),
+    (Operator::BlueShift, "BlueShift", Value::Numbers),
+    (Operator::BlueFuzz, "BlueFuzz", Value::Numbers),
+    (Operator::StdHW, "StdHW", Value::Numbers),
+    (Operator::StdVW, "StdVW", Value::Numbers),
+    (Operator::StemSnapH, "StemSnapH", Value::Delta),
+    (Operator::StemSnapV, "StemSnapV", Value::Delta),
+    (Operator::ForceBold, "ForceBold", Value::Numbers),
+    (Operator::LanguageGroup, "LanguageGroup", Value::Numbers),
+    (Operator::ExpansionFactor, "ExpansionFactor", Value::Numbers),
+    (
+        Operator::InitialRandomSeed,
+        "initialRandomSeed",
+        Value::Numbers,
+    ),
+    (Operator::DefaultWidthX, "defaultWidthX", Value::Numbers),
+    (Operator::NominalWidthX, "nominalWidthX", Value::Numbers),
+];
+
+// Add two numbers exactly unless the result does not fit.
+fn add(one: Number, other: Number) -> Number {
+    if let (Number::Integer(one), Number::Integer(other)) = (one, other) {
+        if let Some(value) = one.checked_add(other) {
+            return Number::Integer(value);
+        }
+    }
+    let (one, other) = (real(one), real(other));
+    let exponent = one.exponent().min(other.exponent());
+    let scale = |value: Real| {
+        10i64
+            .checked_pow((value.exponent() - exponent) as u32)
+            .and_then(|factor| value.significand().checked_mul(factor))
+    };
+    match (scale(one), scale(other)) {
+        (Some(one), Some(other)) => match one.checked_add(other) {
+            Some(value) => Number::Real(Real::new(value, exponent)),
+            _ => Number::Real(Real::from(one as f64 + other as f64)),
+        },
+        _ => Number::Real(Real::from(one.to_f64() + other.to_f64())),
+    }
+}
+
+fn negate(value: Number) -> Number {
+    match value {
+        Number::Integer(value) if value != i32::MIN => Number::Integer(-value),
+        value => {
+            let value = real(value);
+            Number::Real(Real::new(-value.significand(), value.exponent()))
+        }
+    }
+}
+
+#[inline]
+fn real(value: Number) -> Real {
+    match value {
+        Number::Integer(value) => Real::from(value),
+        Number::Real(value) => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add, negate};
+    use crate::compact1::{Number, Real};
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(
+            add(Number::Integer(-15), Number::Integer(15)),
+            Number::Integer(0)
+        );
+        assert_eq!(
+            add(Number::Real(Real::new(5, -1)), Number::Integer(-3)),
+            Number::Real(Real::new(-25, -1)),
+        );
+        assert_eq!(
+            add(Number::Integer(i32::MAX), Number::Integer(1)),
+            Number::Real(Real::new(2147483648, 0)),
+        );
+        assert_eq!(
+            negate(Number::Real(Real::new(39625, -6))),
+            Number::Real(Real::new(-39625, -6)),
+        );
+    }
+}
@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+
+use crate::compact1::encoding::Encoding0;
+use crate::compact1::font_set::character_id_keyed::{self, Encoding3, Range3, RecordInner};
+use crate::compact1::font_set::{character_name_keyed, FontSet, Record};
+use crate::compact1::index::{CharacterStrings, Index, Names, StringTable, Subroutines};
+use crate::compact1::{
+    CharacterSet, Encoding, GlyphID, Header, Number, Operations, Operator, StringID,
+};
+use crate::error::Kind;
+use crate::ttx::xml::{self, Element};
+use crate::ttx::{add, charstring, negate, Value, ENTRIES};
+use crate::type2::token;
+use crate::Result;
+
+macro_rules! reject(
+    ($($argument:tt)*) => (raise!(Kind::MalformedDocument(format!($($argument)*))));
+);
+
+/// Read a font set from a TTX document.
+///
+/// The document is either a `ttFont` document with a `CFF` table or the table alone. The glyph
+/// order is taken from `GlyphOrder` when present and there is a single font and from the order of
+/// the charstrings otherwise. Strings are added to the string index in the order they appear.
+pub fn read(text: &str) -> Result<FontSet> {
+    let root = xml::parse(text)?;
+    let table = match root.name.as_str() {
+        "CFF" => &root,
+        _ => match root.find("CFF") {
+            Some(table) => table,
+            _ => reject!("found no CFF table"),
+        },
+    };
+    let fonts = table
+        .children
+        .iter()
+        .filter(|child| child.name == "CFFFont")
+        .collect::<Vec<_>>();
+    let order = match (root.find("GlyphOrder"), fonts.len()) {
+        (Some(element), 1) => Some(
+            element
+                .children
+                .iter()
+                .map(|child| child.require("name"))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        _ => None,
+    };
+    let version = |name: &str, default: u8| -> Result<u8> {
+        match table.find(name) {
+            Some(element) => match element.require("value")?.parse() {
+                Ok(value) => Ok(value),
+                _ => raise!(Kind::MalformedNumber),
+            },
+            _ => Ok(default),
+        }
+    };
+    let header = Header {
+        major: version("major", 1)?,
+        minor: version("minor", 0)?,
+        header_size: 4,
+        offset_size: 4,
+    };
+
+    let mut strings = StringTable::new();
+    let mut names = Vec::with_capacity(fonts.len());
+    let mut operations = Vec::with_capacity(fonts.len());
+    let mut encodings = Vec::with_capacity(fonts.len());
+    let mut character_strings = Vec::with_capacity(fonts.len());
+    let mut character_sets = Vec::with_capacity(fonts.len());
+    let mut records = Vec::with_capacity(fonts.len());
+    for element in fonts {
+        names.push(element.require("name")?.as_bytes().to_vec());
+        let font = font(element, order.as_deref(), &mut strings)?;
+        operations.push(font.operations);
+        encodings.push(font.encoding);
+        character_strings.push(CharacterStrings(Index::from(font.codes)));
+        character_sets.push(font.character_set);
+        records.push(font.record);
+    }
+    let subroutines = match table.find("GlobalSubrs") {
+        Some(element) => routines(element)?,
+        _ => vec![],
+    };
+    Ok(FontSet {
+        header,
+        names: Names(Index::from(names)),
+        operations,
+        strings: strings.into(),
+        subroutines: Subroutines(Index::from(subroutines)),
+        encodings,
+        character_strings,
+        character_sets,
+        records,
+    })
+}
+
+struct Font {
+    operations: Operations,
+    encoding: Encoding,
+    codes: Vec<Vec<u8>>,
+    character_set: CharacterSet,
+    record: Record,
+}
+
+fn font(element: &Element, order: Option<&[&str]>, strings: &mut StringTable) -> Result<Font> {
+    let mut operations = Operations::default();
+    let mut collection = None;
+    let mut encoding = None;
+    let mut private = None;
+    let mut array = None;
+    let mut selector = None;
+    let mut glyphs = None;
+    for child in element.children.iter() {
+        match child.name.as_str() {
+            "ROS" => {
+                let registry = strings.insert(child.require("Registry")?)?;
+                let ordering = strings.insert(child.require("Order")?)?;
+                let supplement = number(child.require("Supplement")?)?;
+                operations.0.insert(
+                    Operator::ROS,
+                    vec![
+                        Number::Integer(registry as i32),
+                        Number::Integer(ordering as i32),
+                        supplement,
+                    ],
+                );
+                collection = Some((registry, ordering, supplement));
+            }
+            "Encoding" => encoding = Some(child),
+            "Private" => private = Some(child),
+            "FDArray" => array = Some(child),
+            "FDSelect" => selector = Some(child),
+            "CharStrings" => glyphs = Some(child),
+            _ => entry(child, &mut operations, strings)?,
+        }
+    }
+    let glyphs = match glyphs {
+        Some(glyphs) => glyphs,
+        _ => reject!("found no charstrings"),
+    };
+    let mut codes = HashMap::new();
+    let mut dictionary_ids = HashMap::new();
+    let mut default_order = vec![];
+    for child in glyphs.children.iter() {
+        let name = child.require("name")?;
+        if codes.insert(name, code(child)?).is_some() {
+            reject!("found a duplicate glyph {name:?}");
+        }
+        if let Some(value) = child.get("fdSelectIndex") {
+            match value.parse::<usize>() {
+                Ok(value) => dictionary_ids.insert(name, value),
+                _ => raise!(Kind::MalformedNumber),
+            };
+        }
+        default_order.push(name);
+    }
+    let order = order.unwrap_or(&default_order);
+    if order.len() != codes.len() || order.len() > GlyphID::MAX as usize + 1 {
+        reject!("found a glyph order inconsistent with the charstrings");
+    }
+    let mut glyph_ids = HashMap::with_capacity(order.len());
+    for (glyph_id, &name) in order.iter().enumerate() {
+        glyph_ids.insert(name, glyph_id as GlyphID);
+    }
+    let codes = order
+        .iter()
+        .map(|name| match codes.remove(name) {
+            Some(code) => Ok(code),
+            _ => reject!("found no charstring for glyph {name:?}"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    operations
+        .0
+        .insert(Operator::CharStrings, vec![Number::Integer(0)]);
+
+    match (collection, array) {
+        (Some((registry, ordering, supplement)), Some(array)) => {
+            let mut string_ids = Vec::with_capacity(order.len().saturating_sub(1));
+            for name in order[1.min(order.len())..].iter() {
+                match name.strip_prefix("cid").map(str::parse::<StringID>) {
+                    Some(Ok(cid)) => string_ids.push(cid),
+                    _ => reject!("found a glyph {name:?} not named after its CID"),
+                }
+            }
+            let mut font_operations = vec![];
+            let mut records = vec![];
+            for child in array
+                .children
+                .iter()
+                .filter(|child| child.name == "FontDict")
+            {
+                let mut operations = Operations::default();
+                let mut inner = None;
+                for child in child.children.iter() {
+                    match child.name.as_str() {
+                        "Private" => inner = Some(dictionary(child, strings)?),
+                        _ => entry(child, &mut operations, strings)?,
+                    }
+                }
+                let (private_operations, subroutines) = inner.unwrap_or_default();
+                operations.0.insert(
+                    Operator::Private,
+                    vec![Number::Integer(0), Number::Integer(0)],
+                );
+                font_operations.push(operations);
+                records.push(RecordInner {
+                    operations: private_operations,
+                    subroutines: Subroutines(Index::from(subroutines)),
+                });
+            }
+            let mut selection = Vec::with_capacity(order.len());
+            for name in order.iter() {
+                match dictionary_ids.get(name).copied().unwrap_or(0) {
+                    dictionary_id if dictionary_id < records.len().min(256) => {
+                        selection.push(dictionary_id as u8)
+                    }
+                    dictionary_id => raise!(Kind::MissingDictionary(dictionary_id)),
+                }
+            }
+            let format = selector.and_then(|selector| selector.get("format"));
+            let encoding = match format {
+                Some("0") => character_id_keyed::Encoding::Format0(character_id_keyed::Encoding0 {
+                    format: 0,
+                    dictionary_ids: selection,
+                }),
+                _ => {
+                    let mut ranges: Vec<Range3> = vec![];
+                    for (glyph_id, &dictionary_id) in selection.iter().enumerate() {
+                        if ranges.last().map(|range| range.dictionary_id) != Some(dictionary_id) {
+                            ranges.push(Range3 {
+                                first_glyph_id: glyph_id as GlyphID,
+                                dictionary_id,
+                            });
+                        }
+                    }
+                    character_id_keyed::Encoding::Format3(Encoding3 {
+                        format: 3,
+                        range_count: ranges.len() as u16,
+                        ranges,
+                        glyph_count: selection.len() as GlyphID,
+                    })
+                }
+            };
+            for operator in [Operator::FDArray, Operator::FDSelect] {
+                operations.0.insert(operator, vec![Number::Integer(0)]);
+            }
+            Ok(Font {
+                operations,
+                encoding: Encoding::Standard,
+                codes,
+                character_set: CharacterSet::from(string_ids),
+                record: Record::CharacterIDKeyed(character_id_keyed::Record {
+                    registry,
+                    ordering,
+                    supplement,
+                    encoding,
+                    operations: font_operations,
+                    records,
+                }),
+            })
+        }
+        (None, None) => {
+            let mut string_ids = Vec::with_capacity(order.len().saturating_sub(1));
+            for name in order[1.min(order.len())..].iter() {
+                string_ids.push(strings.insert(name)?);
+            }
+            let encoding = match encoding {
+                Some(element) => match element.get("name") {
+                    Some("StandardEncoding") => Encoding::Standard,
+                    Some("ExpertEncoding") => {
+                        operations
+                            .0
+                            .insert(Operator::Encoding, vec![Number::Integer(1)]);
+                        Encoding::Expert
+                    }
+                    Some(name) => reject!("found an unknown encoding {name:?}"),
+                    _ => custom(element, &glyph_ids)?,
+                },
+                _ => Encoding::Standard,
+            };
+            let (private_operations, subroutines) = match private {
+                Some(element) => dictionary(element, strings)?,
+                _ => Default::default(),
+            };
+            operations.0.insert(
+                Operator::Private,
+                vec![Number::Integer(0), Number::Integer(0)],
+            );
+            Ok(Font {
+                operations,
+                encoding,
+                codes,
+                character_set: CharacterSet::from(string_ids),
+                record: Record::CharacterNameKeyed(character_name_keyed::Record {
+                    operations: private_operations,
+                    subroutines: Subroutines(Index::from(subroutines)),
+                }),
+            })
+        }
+        _ => raise!(Kind::MalformedRecord),
+    }
+}
+
+// Read a private dictionary together with its local subroutines.
+fn dictionary(element: &Element, strings: &mut StringTable) -> Result<(Operations, Vec<Vec<u8>>)> {
+    let mut operations = Operations::default();
+    let mut subroutines = vec![];
+    for child in element.children.iter() {
+        match child.name.as_str() {
+            "Subrs" => subroutines = routines(child)?,
+            _ => entry(child, &mut operations, strings)?,
+        }
+    }
+    if !subroutines.is_empty() {
+        operations
+            .0
+            .insert(Operator::Subrs, vec![Number::Integer(0)]);
+    }
+    Ok((operations, subroutines))
+}
+
+fn entry(element: &Element, operations: &mut Operations, strings: &mut StringTable) -> Result<()> {
+    let (operator, value) = match ENTRIES.iter().find(|(_, name, _)| *name == element.name) {
+        Some(&(operator, _, value)) => (operator, value),
+        _ => reject!("found an unknown element {:?}", element.name),
+    };
+    let text = element.require("value")?;
+    let operands = match value {
+        Value::Numbers => text.split_whitespace().map(number).collect::<Result<_>>()?,
+        Value::Delta => {
+            let mut previous = Number::Integer(0);
+            let mut operands = vec![];
+            for value in text.split_whitespace() {
+                let value = number(value)?;
+                operands.push(add(value, negate(previous)));
+                previous = value;
+            }
+            operands
+        }
+        Value::String => vec![Number::Integer(strings.insert(text)? as i32)],
+    };
+    operations.0.insert(operator, operands);
+    Ok(())
+}
+
+fn custom(element: &Element, glyph_ids: &HashMap<&str, GlyphID>) -> Result<Encoding> {
+    let mut codes = vec![None; glyph_ids.len()];
+    for child in element.children.iter().filter(|child| child.name == "map") {
+        let name = child.require("name")?;
+        if name == ".notdef" {
+            continue;
+        }
+        let code = child.require("code")?;
+        let code = match code.strip_prefix("0x") {
+            Some(code) => u8::from_str_radix(code, 16).ok(),
+            _ => code.parse().ok(),
+        };
+        match (code, glyph_ids.get(name)) {
+            (Some(code), Some(&glyph_id)) if codes[glyph_id as usize].is_none() => {
+                codes[glyph_id as usize] = Some(code);
+            }
+            // Glyphs with several codes or without a charstring are not supported.
+            _ => raise!(Kind::MalformedEncoding),
+        }
+    }
+    let count = codes
+        .iter()
+        .skip(1)
+        .take_while(|code| code.is_some())
+        .count();
+    if count > u8::MAX as usize || codes.iter().skip(1 + count).any(Option::is_some) {
+        raise!(Kind::MalformedEncoding);
+    }
+    Ok(Encoding::Format0(Encoding0 {
+        format: 0,
+        code_count: count as u8,
+        codes: codes.into_iter().skip(1).flatten().collect(),
+    }))
+}
+
+fn routines(element: &Element) -> Result<Vec<Vec<u8>>> {
+    element
+        .children
+        .iter()
+        .filter(|child| child.name == "CharString")
+        .map(code)
+        .collect()
+}
+
+fn code(element: &Element) -> Result<Vec<u8>> {
+    Ok(token::encode(&charstring::read(&element.text)?))
+}
+
+fn number(value: &str) -> Result<Number> {
+    if value.contains(['.', 'e', 'E']) {
+        Ok(Number::Real(value.parse()?))
+    } else {
+        match value.parse() {
+            Ok(value) => Ok(Number::Integer(value)),
+            _ => raise!(Kind::MalformedNumber),
+        }
+    }
+}
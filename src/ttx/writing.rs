@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+
+use crate::compact1::font_set::{character_id_keyed, FontSet, Masks, Record};
+use crate::compact1::{Encoding, GlyphID, Number, Operations, StringID};
+use crate::error::Kind;
+use crate::ttx::xml::Element;
+use crate::ttx::{add, charstring, Value, ENTRIES};
+use crate::type2::token::{self, Token};
+use crate::Result;
+
+/// Write a font set as a TTX document.
+///
+/// The glyph order is included when there is a single record, and the charstrings are written in
+/// the glyph order. The sizes of hint masks are found by interpreting the charstrings; for
+/// subroutines that no charstring calls, they are guessed.
+pub fn write(set: &FontSet) -> Result<String> {
+    let mut root = Element::new("ttFont").with("sfntVersion", "OTTO");
+    if set.records.len() == 1 {
+        let mut element = Element::new("GlyphOrder");
+        for (glyph_id, name) in names(set, 0)?.into_iter().enumerate() {
+            let child = Element::new("GlyphID").with("id", glyph_id.to_string());
+            element.children.push(child.with("name", name));
+        }
+        root.children.push(element);
+    }
+    let mut table = Element::new("CFF");
+    table
+        .children
+        .push(Element::new("major").with("value", set.header.major.to_string()));
+    table
+        .children
+        .push(Element::new("minor").with("value", set.header.minor.to_string()));
+    let mut global = HashMap::new();
+    for index in 0..set.records.len() {
+        table.children.push(font(set, index, &mut global)?);
+    }
+    let mut element = Element::new("GlobalSubrs");
+    for (i, code) in set.subroutines.iter().enumerate() {
+        element
+            .children
+            .push(routine(code, global.get(&i), "index", &i.to_string())?);
+    }
+    table.children.push(element);
+    root.children.push(table);
+    let mut value = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    root.write(&mut value, 0);
+    Ok(value)
+}
+
+fn font(set: &FontSet, index: usize, global: &mut HashMap<usize, Masks>) -> Result<Element> {
+    let name = match set.names.get(index) {
+        Some(name) => String::from_utf8_lossy(name).into_owned(),
+        _ => raise!(Kind::MalformedRecord),
+    };
+    let mut element = Element::new("CFFFont").with("name", name);
+    let top_operations = &set.operations[index];
+    if let Record::CharacterIDKeyed(ref record) = set.records[index] {
+        let string = |string_id| -> Result<String> {
+            match set.strings.get(string_id) {
+                Some(value) => Ok(value),
+                _ => raise!(Kind::MalformedStringID),
+            }
+        };
+        element.children.push(
+            Element::new("ROS")
+                .with("Registry", string(record.registry)?)
+                .with("Order", string(record.ordering)?)
+                .with("Supplement", number(record.supplement)),
+        );
+    }
+    entries(set, top_operations, &mut element)?;
+
+    let tracking = set.track(index, global)?;
+    let (local, glyphs) = (tracking.local, tracking.glyphs);
+    match set.records[index] {
+        Record::CharacterNameKeyed(ref record) => {
+            element.children.push(encoding(set, index)?);
+            element.children.push(private(
+                set,
+                &record.operations,
+                &record.subroutines,
+                local.get(&0),
+            )?);
+        }
+        Record::CharacterIDKeyed(ref record) => {
+            let mut array = Element::new("FDArray");
+            for (i, (operations, inner)) in record
+                .operations
+                .iter()
+                .zip(record.records.iter())
+                .enumerate()
+            {
+                let mut child = Element::new("FontDict").with("index", i.to_string());
+                entries(set, operations, &mut child)?;
+                child.children.push(private(
+                    set,
+                    &inner.operations,
+                    &inner.subroutines,
+                    local.get(&i),
+                )?);
+                array.children.push(child);
+            }
+            element.children.push(array);
+            let format = match record.encoding {
+                character_id_keyed::Encoding::Format0(_) => "0",
+                character_id_keyed::Encoding::Format3(_) => "3",
+            };
+            element
+                .children
+                .push(Element::new("FDSelect").with("format", format));
+        }
+    }
+
+    let mut strings = Element::new("CharStrings");
+    for (glyph_id, (name, code)) in names(set, index)?
+        .into_iter()
+        .zip(set.character_strings[index].iter())
+        .enumerate()
+    {
+        let mut child = routine(code, Some(&glyphs[glyph_id]), "name", &name)?;
+        if let Record::CharacterIDKeyed(ref record) = set.records[index] {
+            if let Some(dictionary_id) = record.encoding.get(glyph_id as GlyphID) {
+                child = child.with("fdSelectIndex", dictionary_id.to_string());
+            }
+        }
+        strings.children.push(child);
+    }
+    element.children.push(strings);
+    Ok(element)
+}
+
+fn entries(set: &FontSet, operations: &Operations, element: &mut Element) -> Result<()> {
+    for &(operator, name, value) in ENTRIES.iter() {
+        let operands = match operations.0.get(&operator) {
+            Some(operands) => operands,
+            _ => continue,
+        };
+        let text = match value {
+            Value::Numbers => numbers(operands.iter().copied()),
+            Value::Delta => {
+                let mut total = Number::Integer(0);
+                numbers(operands.iter().map(|&operand| {
+                    total = add(total, operand);
+                    total
+                }))
+            }
+            Value::String => {
+                match operands
+                    .first()
+                    .and_then(|&operand| StringID::try_from(operand).ok())
+                    .and_then(|string_id| set.strings.get(string_id))
+                {
+                    Some(value) => value,
+                    _ => raise!(Kind::MalformedOperation(operator)),
+                }
+            }
+        };
+        element
+            .children
+            .push(Element::new(name).with("value", text));
+    }
+    Ok(())
+}
+
+fn private(
+    set: &FontSet,
+    operations: &Operations,
+    subroutines: &[Vec<u8>],
+    masks: Option<&HashMap<usize, Masks>>,
+) -> Result<Element> {
+    let mut element = Element::new("Private");
+    entries(set, operations, &mut element)?;
+    if !subroutines.is_empty() {
+        let mut child = Element::new("Subrs");
+        for (i, code) in subroutines.iter().enumerate() {
+            let masks = masks.and_then(|masks| masks.get(&i));
+            child
+                .children
+                .push(routine(code, masks, "index", &i.to_string())?);
+        }
+        element.children.push(child);
+    }
+    Ok(element)
+}
+
+fn encoding(set: &FontSet, index: usize) -> Result<Element> {
+    let element = Element::new("Encoding");
+    let mut codes = vec![];
+    match set.encodings[index] {
+        Encoding::Standard => return Ok(element.with("name", "StandardEncoding")),
+        Encoding::Expert => return Ok(element.with("name", "ExpertEncoding")),
+        Encoding::Format0(ref encoding) => {
+            codes.extend(
+                encoding
+                    .codes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &code)| (code, i + 1)),
+            );
+        }
+        Encoding::Format1(ref encoding) => {
+            let mut glyph_id = 1;
+            for range in encoding.ranges.iter() {
+                for code in range.first_code as usize
+                    ..=range.first_code as usize + range.left_count as usize
+                {
+                    codes.push((code as u8, glyph_id));
+                    glyph_id += 1;
+                }
+            }
+        }
+        Encoding::FormatSupplemental(_) => {}
+    }
+    let names = names(set, index)?;
+    let mut element = element;
+    codes.sort();
+    for (code, glyph_id) in codes {
+        let name = match names.get(glyph_id) {
+            Some(name) => name.clone(),
+            _ => raise!(Kind::MalformedEncoding),
+        };
+        let child = Element::new("map").with("code", format!("0x{code:02x}"));
+        element.children.push(child.with("name", name));
+    }
+    if let Encoding::FormatSupplemental(ref encoding) = set.encodings[index] {
+        for supplement in encoding.supplements.iter() {
+            let name = match set.strings.get(supplement.glyph) {
+                Some(name) => name,
+                _ => raise!(Kind::MalformedStringID),
+            };
+            let child = Element::new("map").with("code", format!("0x{:02x}", supplement.code));
+            element.children.push(child.with("name", name));
+        }
+    }
+    Ok(element)
+}
+
+fn routine(code: &[u8], masks: Option<&Masks>, key: &str, value: &str) -> Result<Element> {
+    let mut element = Element::new("CharString").with(key, value);
+    element.text = charstring::write(&decode(code, masks)?);
+    Ok(element)
+}
+
+fn decode(code: &[u8], masks: Option<&Masks>) -> Result<Vec<Token>> {
+    let known = |position| masks.and_then(|masks| masks.get(&position).copied());
+    let error = match token::decode(code, known) {
+        Ok(tokens) => return Ok(tokens),
+        Err(error) => error,
+    };
+    // The hint masks of routines that are not reached are of unknown size, in which case the
+    // smallest size that accounts for all the data is taken.
+    for size in 1..=12 {
+        if let Ok(tokens) = token::decode(code, |position| known(position).or(Some(size))) {
+            if token::encode(&tokens).len() == code.len() {
+                return Ok(tokens);
+            }
+        }
+    }
+    Err(error)
+}
+
+fn names(set: &FontSet, index: usize) -> Result<Vec<String>> {
+    let count = set.character_strings[index].count as usize;
+    let mut names = Vec::with_capacity(count);
+    names.push(".notdef".to_string());
+    let character_set = &set.character_sets[index];
+    for glyph_id in 1..count {
+        let string_id = match character_set.get_string_id(glyph_id as GlyphID) {
+            Some(string_id) => string_id,
+            _ => raise!(Kind::MalformedCharacterSet),
+        };
+        names.push(match set.records[index] {
+            Record::CharacterIDKeyed(_) => format!("cid{string_id:05}"),
+            Record::CharacterNameKeyed(_) => match set.strings.get(string_id) {
+                Some(name) => name,
+                _ => raise!(Kind::MalformedStringID),
+            },
+        });
+    }
+    Ok(names)
+}
+
+fn numbers<I: Iterator<Item = Number>>(values: I) -> String {
+    values.map(number).collect::<Vec<_>>().join(" ")
+}
+
+// Write a number so that reading it back gives the same kind of number.
+fn number(value: Number) -> String {
+    match value {
+        Number::Integer(value) => value.to_string(),
+        Number::Real(value) => {
+            let value = value.to_string();
+            if value.contains(['.', 'e']) {
+                value
+            } else {
+                format!("{value}.0")
+            }
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! A minimal reader and writer of XML documents.
+
+use std::fmt::Write;
+
+use crate::error::Kind;
+use crate::Result;
+
+/// An element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+    pub text: String,
+}
+
+impl Element {
+    /// Create an element without content.
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add an attribute.
+    pub fn with<T: Into<String>, U: Into<String>>(mut self, name: T, value: U) -> Self {
+        self.attributes.push((name.into(), value.into()));
+        self
+    }
+
+    /// Return the value of an attribute.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Return the value of an attribute that is required to be present.
+    pub fn require(&self, name: &str) -> Result<&str> {
+        match self.get(name) {
+            Some(value) => Ok(value),
+            _ => raise!(Kind::MalformedDocument(format!(
+                "found no attribute {name} in element {}",
+                self.name,
+            ))),
+        }
+    }
+
+    /// Return the first child with a name.
+    pub fn find(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Write the element with an indentation given in levels of two spaces.
+    ///
+    /// The text of an element is written on separate lines, one per line of the text.
+    pub fn write(&self, value: &mut String, level: usize) {
+        let indentation = "  ".repeat(level);
+        let _ = write!(value, "{indentation}<{}", self.name);
+        for (name, attribute) in self.attributes.iter() {
+            let _ = write!(value, " {name}=\"{}\"", escape(attribute));
+        }
+        if self.children.is_empty() && self.text.is_empty() {
+            value.push_str("/>\n");
+            return;
+        }
+        value.push_str(">\n");
+        for line in self.text.lines() {
+            let _ = writeln!(value, "{indentation}  {}", escape(line));
+        }
+        for child in self.children.iter() {
+            child.write(value, level + 1);
+        }
+        let _ = writeln!(value, "{indentation}</{}>", self.name);
+    }
+}
+
+/// Parse a document and return its root element.
+pub fn parse(text: &str) -> Result<Element> {
+    let mut parser = Parser { text, position: 0 };
+    parser.skip_miscellaneous()?;
+    let element = parser.element()?;
+    parser.skip_miscellaneous()?;
+    if parser.position < text.len() {
+        parser.fail("found trailing content")?;
+    }
+    Ok(element)
+}
+
+struct Parser<'l> {
+    text: &'l str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn element(&mut self) -> Result<Element> {
+        self.expect("<")?;
+        let mut element = Element::new(self.name()?);
+        loop {
+            self.skip_whitespace();
+            if self.consume("/>") {
+                return Ok(element);
+            }
+            if self.consume(">") {
+                break;
+            }
+            let name = self.name()?;
+            self.skip_whitespace();
+            self.expect("=")?;
+            self.skip_whitespace();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return self.fail("found an unquoted attribute"),
+            };
+            self.position += 1;
+            let text = self.text;
+            let value = match text[self.position..].find(quote) {
+                Some(end) => &text[self.position..self.position + end],
+                _ => return self.fail("found an unterminated attribute"),
+            };
+            self.position += value.len() + 1;
+            element.attributes.push((name, unescape(value)?));
+        }
+        loop {
+            let end = self.rest().find('<').unwrap_or(self.rest().len());
+            element.text.push_str(&unescape(&self.rest()[..end])?);
+            self.position += end;
+            if self.consume("</") {
+                let name = self.name()?;
+                if name != element.name {
+                    return self.fail("found a mismatched closing tag");
+                }
+                self.skip_whitespace();
+                self.expect(">")?;
+                return Ok(element);
+            } else if self.rest().starts_with("<!--") {
+                self.skip_comment()?;
+            } else if self.consume("<![CDATA[") {
+                match self.rest().find("]]>") {
+                    Some(end) => {
+                        element.text.push_str(&self.rest()[..end]);
+                        self.position += end + 3;
+                    }
+                    _ => return self.fail("found an unterminated section"),
+                }
+            } else if self.rest().starts_with('<') {
+                element.children.push(self.element()?);
+            } else {
+                return self.fail("found an unterminated element");
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String> {
+        let end = self
+            .rest()
+            .find(|character: char| {
+                character.is_whitespace() || matches!(character, '=' | '/' | '>' | '<')
+            })
+            .unwrap_or(self.rest().len());
+        if end == 0 {
+            return self.fail("found no name");
+        }
+        let name = self.rest()[..end].to_string();
+        self.position += end;
+        Ok(name)
+    }
+
+    fn skip_miscellaneous(&mut self) -> Result<()> {
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("<!--") {
+                self.skip_comment()?;
+            } else if self.rest().starts_with("<?") || self.rest().starts_with("<!") {
+                match self.rest().find('>') {
+                    Some(end) => self.position += end + 1,
+                    _ => return self.fail("found an unterminated declaration"),
+                }
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) -> Result<()> {
+        match self.rest().find("-->") {
+            Some(end) => {
+                self.position += end + 3;
+                Ok(())
+            }
+            _ => self.fail("found an unterminated comment"),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn consume(&mut self, value: &str) -> bool {
+        if self.rest().starts_with(value) {
+            self.position += value.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, value: &str) -> Result<()> {
+        if self.consume(value) {
+            Ok(())
+        } else {
+            self.fail(&format!("expected {value:?}"))
+        }
+    }
+
+    #[inline]
+    fn rest(&self) -> &str {
+        &self.text[self.position..]
+    }
+
+    fn fail<T>(&self, message: &str) -> Result<T> {
+        raise!(Kind::MalformedDocument(format!(
+            "{message} at position {}",
+            self.position,
+        )))
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            character => result.push(character),
+        }
+    }
+    result
+}
+
+fn unescape(value: &str) -> Result<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        let end = match rest.find(';') {
+            Some(end) => end,
+            _ => raise!(Kind::MalformedDocument(
+                "found an unterminated entity".into()
+            )),
+        };
+        let character = match &rest[..end] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            entity => match (entity.strip_prefix("#x"), entity.strip_prefix('#')) {
+                (Some(code), _) => u32::from_str_radix(code, 16).ok(),
+                (_, Some(code)) => code.parse().ok(),
+                _ => None,
+            }
+            .and_then(char::from_u32),
+        };
+        match character {
+            Some(character) => result.push(character),
+            _ => raise!(Kind::MalformedDocument(format!(
+                "found an unknown entity {:?}",
+                &rest[..end],
+            ))),
+        }
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Element};
+
+    #[test]
+    fn round_trip() {
+        let mut element = Element::new("a").with("b", "1 < 2 & \"3\"");
+        element.children.push(Element::new("c"));
+        let mut child = Element::new("d");
+        child.text = "e f\ng".into();
+        element.children.push(child);
+        let mut value = String::new();
+        element.write(&mut value, 0);
+        assert_eq!(
+            value,
+            "<a b=\"1 &lt; 2 &amp; &quot;3&quot;\">\n  <c/>\n  <d>\n    e f\n    g\n  </d>\n</a>\n",
+        );
+        let other = parse(&format!("<?xml version=\"1.0\"?>\n<!-- x -->\n{value}")).unwrap();
+        assert_eq!(other.get("b"), Some("1 < 2 & \"3\""));
+        assert_eq!(other.children.len(), 2);
+        assert_eq!(other.find("d").unwrap().text.split_whitespace().count(), 3);
+        assert!(parse("<a><b></a>").is_err());
+        assert!(parse("<a b='&#x41;&#66;'/>").unwrap().get("b") == Some("AB"));
+    }
+}
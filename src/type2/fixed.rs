@@ -0,0 +1,158 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A fixed-point number in the 16.16 format.
+///
+/// The arithmetic follows the one of the reference rasterizers: addition and subtraction wrap
+/// around, and multiplication and division round to the nearest value with ties away from zero
+/// and saturate on overflow.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fixed(pub i32);
+
+const ONE: i64 = 1 << 16;
+
+impl Fixed {
+    /// Return the absolute value.
+    #[inline]
+    pub fn abs(self) -> Self {
+        Fixed(self.0.wrapping_abs())
+    }
+
+    /// Return the square root, which is zero for negative values.
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Fixed(0);
+        }
+        let value = (self.0 as u64) << 16;
+        let mut root = (value as f64).sqrt() as u64;
+        while root * root > value {
+            root -= 1;
+        }
+        while (root + 1) * (root + 1) <= value {
+            root += 1;
+        }
+        // Round to the nearest value.
+        if value - root * root > root {
+            root += 1;
+        }
+        Fixed(root as i32)
+    }
+
+    /// Convert into a single-precision floating-point number.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / ONE as f32
+    }
+
+    /// Convert into a double-precision floating-point number, which is exact.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / ONE as f64
+    }
+
+    /// Convert from a double-precision floating-point number rounding to the nearest value.
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Fixed(saturate((value * ONE as f64).round() as i64))
+    }
+}
+
+impl From<i32> for Fixed {
+    /// Create a number equal to an integer, which wraps around outside the 16-bit range.
+    #[inline]
+    fn from(value: i32) -> Self {
+        Fixed(value.wrapping_shl(16))
+    }
+}
+
+impl From<Fixed> for f32 {
+    #[inline]
+    fn from(value: Fixed) -> Self {
+        value.to_f32()
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Fixed(self.0.wrapping_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Fixed(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let product = (self.0 as i64).abs() * (other.0 as i64).abs();
+        let value = (product + ONE / 2) >> 16;
+        Fixed(saturate(sign(self, other) * value))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let (left, right) = ((self.0 as i64).abs(), (other.0 as i64).abs());
+        if right == 0 {
+            return Fixed(saturate(sign(self, other) * i32::MAX as i64));
+        }
+        let value = ((left << 16) + right / 2) / right;
+        Fixed(saturate(sign(self, other) * value))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Fixed(self.0.wrapping_neg())
+    }
+}
+
+#[inline]
+fn sign(left: Fixed, right: Fixed) -> i64 {
+    if (left.0 < 0) != (right.0 < 0) {
+        -1
+    } else {
+        1
+    }
+}
+
+#[inline]
+fn saturate(value: i64) -> i32 {
+    value.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fixed;
+
+    #[test]
+    fn arithmetic() {
+        let (one, two, three) = (Fixed::from(1), Fixed::from(2), Fixed::from(3));
+        assert_eq!(one + two, three);
+        assert_eq!(one - two, -one);
+        assert_eq!(two * three, Fixed::from(6));
+        assert_eq!(one / three, Fixed(0x5555));
+        assert_eq!(two / three, Fixed(0xaaab));
+        assert_eq!(-two / three, Fixed(-0xaaab));
+        assert_eq!(one / Fixed(0), Fixed(i32::MAX));
+        assert_eq!(Fixed(0x8000) * Fixed(1), Fixed(1));
+        assert_eq!(Fixed::from(4).sqrt(), two);
+        assert_eq!(two.sqrt(), Fixed(0x16a0a));
+        assert_eq!(Fixed::from_f64(1.017), Fixed(0x1045a));
+        assert_eq!(Fixed(0x1045a).to_f64(), 66650.0 / 65536.0);
+    }
+}
@@ -2,9 +2,17 @@
 //!
 //! [1]: https://adobe-type-tools.github.io/font-tech-notes/pdfs/5177.Type2.pdf
 
+mod fixed;
 mod number;
 mod operation;
 mod program;
 
+pub mod outline;
+pub mod stroke;
+pub mod token;
+
+pub use fixed::Fixed;
 pub use operation::{Operand, Operation, Operations, Operator};
-pub use program::Program;
+pub use program::{Composite, Mask, Program};
+
+pub(crate) use program::bias;
@@ -1,26 +1,27 @@
-use typeface::q32;
-
+use crate::error::Kind;
+use crate::slice::Slice;
 use crate::Result;
 
-pub fn read<T: crate::tape::Read>(tape: &mut T) -> Result<f32> {
-    let first = tape.take::<u8>()?;
+// Read a number, which is exact given that all numbers are multiples of 2^-16 within 32 bits.
+pub fn read(tape: &mut Slice<'_>) -> Result<f64> {
+    let first = tape.take()?;
     Ok(match first {
-        0x20..=0xf6 => (first as i32 - 139) as f32,
-        0xf7..=0xfa => ((first as i32 - 247) * 256 + tape.take::<u8>()? as i32 + 108) as f32,
-        0xfb..=0xfe => (-(first as i32 - 251) * 256 - tape.take::<u8>()? as i32 - 108) as f32,
-        0x1c => tape.take::<u16>()? as i16 as i32 as f32,
-        0xff => tape.take::<q32>()?.into(),
-        _ => raise!("found a malformed number"),
+        0x20..=0xf6 => (first as i32 - 139) as f64,
+        0xf7..=0xfa => ((first as i32 - 247) * 256 + tape.take()? as i32 + 108) as f64,
+        0xfb..=0xfe => (-(first as i32 - 251) * 256 - tape.take()? as i32 - 108) as f64,
+        0x1c => tape.take_u16()? as i16 as f64,
+        0xff => tape.take_u32()? as i32 as f64 / 65536.0,
+        _ => raise!(Kind::MalformedNumber),
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::Cursor;
+    use crate::slice::Slice;
 
     #[test]
     fn real() {
-        let mut tape = Cursor::new(vec![0xff, 0x00, 0x01, 0x04, 0x5a]);
+        let mut tape = Slice::new(&[0xff, 0x00, 0x01, 0x04, 0x5a]);
         assert!(format!("{:.3}", super::read(&mut tape).unwrap()) == "1.017");
     }
 }
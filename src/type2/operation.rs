@@ -1,5 +1,6 @@
 //! The operations.
 
+use crate::error::Kind;
 use crate::Result;
 
 /// An operand.
@@ -12,7 +13,7 @@ pub type Operation = (Operator, Vec<Operand>);
 pub type Operations = Vec<Operation>;
 
 macro_rules! operator {
-    (pub $name:ident { $($code:pat => $variant:ident,)+ }) => (
+    (pub $name:ident { $($code:literal => $variant:ident,)+ }) => (
         operator! { @define pub $name { $($variant,)+ } }
         operator! { @implement pub $name { $($code => $variant,)+ } }
     );
@@ -21,15 +22,23 @@ macro_rules! operator {
         #[derive(Clone, Copy, Debug, Eq, PartialEq)]
         pub enum $name { $($variant,)* }
     );
-    (@implement pub $name:ident { $($code:pat => $variant:ident,)* }) => (impl $name {
+    (@implement pub $name:ident { $($code:literal => $variant:ident,)* }) => (impl $name {
         #[doc(hidden)]
         pub fn from(code: u16) -> Result<Self> {
             use self::$name::*;
             Ok(match code {
                 $($code => $variant,)+
-                code => raise!("found an unknown operator ({code})"),
+                code => raise!(Kind::UnknownOperator(code)),
             })
         }
+
+        /// Return the code.
+        pub fn code(&self) -> u16 {
+            use self::$name::*;
+            match *self {
+                $($variant => $code,)+
+            }
+        }
     });
 }
 
@@ -70,7 +79,7 @@ operator! {
         // 0x20..=0xf6 => <numbers>,
         // 0xf7..=0xfe => <numbers>,
         // 0xff => <number>,
-        // 0x0c00 => Reserved,
+        0x0c00 => DotSection,
         // 0x0c01 => Reserved,
         // 0x0c02 => Reserved,
         0x0c03 => And,
@@ -0,0 +1,533 @@
+//! The outlines.
+
+use crate::error::Kind;
+use std::ops::{Add, Neg, Sub};
+
+use crate::type2::{Fixed, Operand, Operator, Program};
+use crate::Result;
+
+/// A point.
+pub type Point = (f32, f32);
+
+/// An affine transformation given as `[a, b, c, d, e, f]`, which maps `(x, y)` to
+/// `(a * x + c * y + e, b * x + d * y + f)`.
+pub type Matrix = [f32; 6];
+
+/// The identity transformation.
+pub const IDENTITY: Matrix = [1.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+
+/// An outline.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Outline {
+    /// The segments in absolute coordinates.
+    pub segments: Vec<Segment>,
+}
+
+/// A segment of an outline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Segment {
+    /// The start of a contour.
+    Move(Point),
+    /// A straight line to a point.
+    Line(Point),
+    /// A cubic Bézier curve given by two control points and an end point.
+    Curve(Point, Point, Point),
+    /// The end of a contour, which is closed by a straight line to its start if needed.
+    Close,
+}
+
+impl Outline {
+    /// Build an outline by running a program until it terminates.
+    ///
+    /// Following the Type 2 Charstring Format, each contour is closed when the next one starts or
+    /// when the program terminates. If the program is in the fixed-point mode, the points are
+    /// accumulated in the 16.16 format and only then converted.
+    pub fn build(program: &mut Program) -> Result<Self> {
+        if program.fixed_point() {
+            build(|| program.next_fixed())
+        } else {
+            build(|| program.next())
+        }
+    }
+
+    /// Compute the bounding box as the lower-left and upper-right corners.
+    ///
+    /// The extrema of curves are taken into account exactly rather than via their control points.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut bounds: Option<(Point, Point)> = None;
+        let mut include = |(x, y): Point| {
+            let ((x0, y0), (x1, y1)) = bounds.get_or_insert(((x, y), (x, y)));
+            *x0 = x0.min(x);
+            *y0 = y0.min(y);
+            *x1 = x1.max(x);
+            *y1 = y1.max(y);
+        };
+        let mut current = (0.0, 0.0);
+        for segment in self.segments.iter() {
+            match *segment {
+                Segment::Move(point) | Segment::Line(point) => {
+                    include(point);
+                    current = point;
+                }
+                Segment::Curve(a, b, c) => {
+                    include(c);
+                    for t in extrema(current.0, a.0, b.0, c.0)
+                        .into_iter()
+                        .chain(extrema(current.1, a.1, b.1, c.1))
+                        .flatten()
+                    {
+                        include(evaluate(current, a, b, c, t));
+                    }
+                    current = c;
+                }
+                Segment::Close => {}
+            }
+        }
+        bounds
+    }
+
+    /// Apply an affine transformation.
+    pub fn transform(&self, matrix: &Matrix) -> Self {
+        let map = |point| apply(matrix, point);
+        Self {
+            segments: self
+                .segments
+                .iter()
+                .map(|segment| match *segment {
+                    Segment::Move(a) => Segment::Move(map(a)),
+                    Segment::Line(a) => Segment::Line(map(a)),
+                    Segment::Curve(a, b, c) => Segment::Curve(map(a), map(b), map(c)),
+                    Segment::Close => Segment::Close,
+                })
+                .collect(),
+        }
+    }
+
+    /// Flatten the outline into closed polylines.
+    ///
+    /// Curves are subdivided adaptively until the control points of each piece lie within the
+    /// tolerance from its chord, which bounds the distance between the curve and the polyline.
+    /// The tolerance is given in the units of the outline. Each contour ends with its first
+    /// point, and contours with fewer than three distinct points are omitted.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Contour> {
+        let tolerance = tolerance.max(1e-6);
+        let mut contours = vec![];
+        let mut points: Vec<Point> = vec![];
+        for segment in self.segments.iter() {
+            match *segment {
+                Segment::Move(point) => {
+                    contours.extend(Contour::new(std::mem::take(&mut points)));
+                    points.push(point);
+                }
+                Segment::Line(point) => push(&mut points, point),
+                Segment::Curve(a, b, c) => {
+                    let start = points.last().copied().unwrap_or_default();
+                    subdivide(&mut points, [start, a, b, c], tolerance, 0);
+                }
+                Segment::Close => contours.extend(Contour::new(std::mem::take(&mut points))),
+            }
+        }
+        contours.extend(Contour::new(points));
+        contours
+    }
+}
+
+/// A closed polyline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Contour {
+    /// The points, with the first one repeated at the end.
+    pub points: Vec<Point>,
+    /// The signed area, which is positive for counterclockwise contours when the y axis points
+    /// upward.
+    pub area: f32,
+}
+
+/// A winding direction.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Clockwise,
+    Counterclockwise,
+}
+
+impl Contour {
+    fn new(mut points: Vec<Point>) -> Option<Self> {
+        if let Some(&first) = points.first() {
+            push(&mut points, first);
+        }
+        if points.len() < 4 {
+            return None;
+        }
+        let area = points
+            .windows(2)
+            .map(|pair| pair[0].0 * pair[1].1 - pair[1].0 * pair[0].1)
+            .sum::<f32>()
+            / 2.0;
+        Some(Self { points, area })
+    }
+
+    /// Return the winding direction assuming that the y axis points upward.
+    ///
+    /// Type 2 charstrings conventionally draw outer contours counterclockwise.
+    #[inline]
+    pub fn direction(&self) -> Direction {
+        if self.area < 0.0 {
+            Direction::Clockwise
+        } else {
+            Direction::Counterclockwise
+        }
+    }
+}
+
+/// Apply an affine transformation to a point.
+#[inline]
+pub fn apply(matrix: &Matrix, (x, y): Point) -> Point {
+    let [a, b, c, d, e, f] = *matrix;
+    (a * x + c * y + e, b * x + d * y + f)
+}
+
+/// Compose two affine transformations so that the first one is applied first.
+pub fn multiply(first: &Matrix, second: &Matrix) -> Matrix {
+    let [a, b, c, d, e, f] = *first;
+    let [g, h, i, j, k, l] = *second;
+    [
+        a * g + b * i,
+        a * h + b * j,
+        c * g + d * i,
+        c * h + d * j,
+        e * g + f * i + k,
+        e * h + f * j + l,
+    ]
+}
+
+#[allow(unused_assignments)]
+fn build<T, F>(mut next: F) -> Result<Outline>
+where
+    T: Coordinate,
+    F: FnMut() -> Result<Option<(Operator, Vec<T>)>>,
+{
+    use crate::type2::Operator::*;
+
+    let zero = T::default();
+    let mut builder = Builder::default();
+    while let Some((operator, operands)) = next()? {
+        let mut arguments = &operands[..];
+        macro_rules! take(
+            ($count:expr) => ({
+                if arguments.len() < $count {
+                    raise!(Kind::MalformedOperands);
+                }
+                let (head, tail) = arguments.split_at($count);
+                arguments = tail;
+                head
+            });
+        );
+        match operator {
+            RMoveTo => {
+                let a = take!(2);
+                builder.move_by(a[0], a[1]);
+            }
+            HMoveTo => builder.move_by(take!(1)[0], zero),
+            VMoveTo => builder.move_by(zero, take!(1)[0]),
+            RLineTo => {
+                while !arguments.is_empty() {
+                    let a = take!(2);
+                    builder.line_by(a[0], a[1]);
+                }
+            }
+            HLineTo | VLineTo => {
+                let mut horizontal = operator == HLineTo;
+                while !arguments.is_empty() {
+                    let a = take!(1)[0];
+                    match horizontal {
+                        true => builder.line_by(a, zero),
+                        _ => builder.line_by(zero, a),
+                    }
+                    horizontal = !horizontal;
+                }
+            }
+            RRCurveTo => {
+                while !arguments.is_empty() {
+                    let a = take!(6);
+                    builder.curve_by(a[0], a[1], a[2], a[3], a[4], a[5]);
+                }
+            }
+            HHCurveTo => {
+                let mut dy = match arguments.len() % 2 {
+                    1 => take!(1)[0],
+                    _ => zero,
+                };
+                while !arguments.is_empty() {
+                    let a = take!(4);
+                    builder.curve_by(a[0], dy, a[1], a[2], a[3], zero);
+                    dy = zero;
+                }
+            }
+            VVCurveTo => {
+                let mut dx = match arguments.len() % 2 {
+                    1 => take!(1)[0],
+                    _ => zero,
+                };
+                while !arguments.is_empty() {
+                    let a = take!(4);
+                    builder.curve_by(dx, a[0], a[1], a[2], zero, a[3]);
+                    dx = zero;
+                }
+            }
+            HVCurveTo | VHCurveTo => {
+                let mut horizontal = operator == HVCurveTo;
+                while !arguments.is_empty() {
+                    let a = take!(4);
+                    let last = if arguments.len() == 1 {
+                        take!(1)[0]
+                    } else {
+                        zero
+                    };
+                    match horizontal {
+                        true => builder.curve_by(a[0], zero, a[1], a[2], last, a[3]),
+                        _ => builder.curve_by(zero, a[0], a[1], a[2], a[3], last),
+                    }
+                    horizontal = !horizontal;
+                }
+            }
+            RCurveLine => {
+                while arguments.len() > 2 {
+                    let a = take!(6);
+                    builder.curve_by(a[0], a[1], a[2], a[3], a[4], a[5]);
+                }
+                let a = take!(2);
+                builder.line_by(a[0], a[1]);
+            }
+            RLineCurve => {
+                while arguments.len() > 6 {
+                    let a = take!(2);
+                    builder.line_by(a[0], a[1]);
+                }
+                let a = take!(6);
+                builder.curve_by(a[0], a[1], a[2], a[3], a[4], a[5]);
+            }
+            Flex => {
+                let a = take!(13);
+                builder.curve_by(a[0], a[1], a[2], a[3], a[4], a[5]);
+                builder.curve_by(a[6], a[7], a[8], a[9], a[10], a[11]);
+            }
+            Flex1 => {
+                let a = take!(11);
+                let (dx, dy) = (
+                    a[0] + a[2] + a[4] + a[6] + a[8],
+                    a[1] + a[3] + a[5] + a[7] + a[9],
+                );
+                let (dx6, dy6) = if dx.abs() > dy.abs() {
+                    (a[10], -dy)
+                } else {
+                    (-dx, a[10])
+                };
+                builder.curve_by(a[0], a[1], a[2], a[3], a[4], a[5]);
+                builder.curve_by(a[6], a[7], a[8], a[9], dx6, dy6);
+            }
+            HFlex => {
+                let a = take!(7);
+                builder.curve_by(a[0], zero, a[1], a[2], a[3], zero);
+                builder.curve_by(a[4], zero, a[5], -a[2], a[6], zero);
+            }
+            HFlex1 => {
+                let a = take!(9);
+                builder.curve_by(a[0], a[1], a[2], a[3], a[4], zero);
+                let dy = a[1] + a[3] + a[7];
+                builder.curve_by(a[5], zero, a[6], a[7], a[8], -dy);
+            }
+            _ => {}
+        }
+    }
+    builder.close();
+    Ok(Outline {
+        segments: builder.segments,
+    })
+}
+
+// A number in which the points of an outline are accumulated.
+trait Coordinate:
+    Copy + Default + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+    fn to_f32(self) -> f32;
+}
+
+impl Coordinate for Operand {
+    #[inline]
+    fn abs(self) -> Self {
+        Operand::abs(self)
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        self
+    }
+}
+
+impl Coordinate for Fixed {
+    #[inline]
+    fn abs(self) -> Self {
+        Fixed::abs(self)
+    }
+
+    #[inline]
+    fn to_f32(self) -> f32 {
+        Fixed::to_f32(self)
+    }
+}
+
+#[derive(Default)]
+struct Builder<T> {
+    segments: Vec<Segment>,
+    current: (T, T),
+    open: bool,
+}
+
+impl<T: Coordinate> Builder<T> {
+    fn move_by(&mut self, dx: T, dy: T) {
+        self.close();
+        self.current = (self.current.0 + dx, self.current.1 + dy);
+        self.segments.push(Segment::Move(point(self.current)));
+        self.open = true;
+    }
+
+    fn line_by(&mut self, dx: T, dy: T) {
+        self.start();
+        self.current = (self.current.0 + dx, self.current.1 + dy);
+        self.segments.push(Segment::Line(point(self.current)));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn curve_by(&mut self, dxa: T, dya: T, dxb: T, dyb: T, dxc: T, dyc: T) {
+        self.start();
+        let a = (self.current.0 + dxa, self.current.1 + dya);
+        let b = (a.0 + dxb, a.1 + dyb);
+        let c = (b.0 + dxc, b.1 + dyc);
+        self.segments
+            .push(Segment::Curve(point(a), point(b), point(c)));
+        self.current = c;
+    }
+
+    fn start(&mut self) {
+        if !self.open {
+            self.segments.push(Segment::Move(point(self.current)));
+            self.open = true;
+        }
+    }
+
+    fn close(&mut self) {
+        if self.open {
+            self.segments.push(Segment::Close);
+            self.open = false;
+        }
+    }
+}
+
+#[inline]
+fn point<T: Coordinate>((x, y): (T, T)) -> Point {
+    (x.to_f32(), y.to_f32())
+}
+
+fn evaluate(a: Point, b: Point, c: Point, d: Point, t: f32) -> Point {
+    let s = 1.0 - t;
+    let (p, q, r, u) = (s * s * s, 3.0 * s * s * t, 3.0 * s * t * t, t * t * t);
+    (
+        p * a.0 + q * b.0 + r * c.0 + u * d.0,
+        p * a.1 + q * b.1 + r * c.1 + u * d.1,
+    )
+}
+
+fn extrema(a: f32, b: f32, c: f32, d: f32) -> [Option<f32>; 2] {
+    // The derivative divided by three is alpha * t^2 + beta * t + gamma.
+    let alpha = -a + 3.0 * b - 3.0 * c + d;
+    let beta = 2.0 * (a - 2.0 * b + c);
+    let gamma = b - a;
+    let valid = |t: f32| Some(t).filter(|t| *t > 0.0 && *t < 1.0);
+    if alpha.abs() < 1e-6 {
+        if beta.abs() < 1e-6 {
+            return [None, None];
+        }
+        return [valid(-gamma / beta), None];
+    }
+    let discriminant = beta * beta - 4.0 * alpha * gamma;
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    [
+        valid((-beta + root) / (2.0 * alpha)),
+        valid((-beta - root) / (2.0 * alpha)),
+    ]
+}
+
+pub(super) fn subdivide(points: &mut Vec<Point>, curve: [Point; 4], tolerance: f32, depth: usize) {
+    let [a, b, c, d] = curve;
+    if depth >= 16 || (deviation(a, d, b) <= tolerance && deviation(a, d, c) <= tolerance) {
+        push(points, d);
+        return;
+    }
+    let middle = |p: Point, q: Point| ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0);
+    let (ab, bc, cd) = (middle(a, b), middle(b, c), middle(c, d));
+    let (abc, bcd) = (middle(ab, bc), middle(bc, cd));
+    let abcd = middle(abc, bcd);
+    subdivide(points, [a, ab, abc, abcd], tolerance, depth + 1);
+    subdivide(points, [abcd, bcd, cd, d], tolerance, depth + 1);
+}
+
+// Compute the distance from a point to the segment between two other points.
+fn deviation(a: Point, b: Point, point: Point) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let (px, py) = (point.0 - a.0, point.1 - a.1);
+    let length = dx * dx + dy * dy;
+    let t = if length > 0.0 {
+        ((px * dx + py * dy) / length).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (ex, ey) = (px - t * dx, py - t * dy);
+    (ex * ex + ey * ey).sqrt()
+}
+
+#[inline]
+fn push(points: &mut Vec<Point>, point: Point) {
+    if points.last() != Some(&point) {
+        points.push(point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate, Direction, Outline, Segment};
+
+    #[test]
+    fn flatten() {
+        let (a, b, c, d) = ((0.0, 0.0), (0.0, 55.0), (45.0, 100.0), (100.0, 100.0));
+        let outline = Outline {
+            segments: vec![
+                Segment::Move(a),
+                Segment::Curve(b, c, d),
+                Segment::Line((100.0, 0.0)),
+                Segment::Close,
+                Segment::Move((0.0, 0.0)),
+                Segment::Line((1.0, 1.0)),
+                Segment::Close,
+            ],
+        };
+        for tolerance in [0.01, 0.5, 5.0] {
+            let contours = outline.flatten(tolerance);
+            assert_eq!(contours.len(), 1);
+            let contour = &contours[0];
+            assert_eq!(contour.direction(), Direction::Clockwise);
+            assert_eq!(contour.points.first(), contour.points.last());
+            for i in 0..=100 {
+                let (x, y) = evaluate(a, b, c, d, i as f32 / 100.0);
+                let distance = contour
+                    .points
+                    .windows(2)
+                    .map(|pair| super::deviation(pair[0], pair[1], (x, y)))
+                    .fold(f32::INFINITY, f32::min);
+                assert!(distance <= tolerance + 1e-3);
+            }
+        }
+    }
+}
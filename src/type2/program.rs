@@ -1,298 +1,558 @@
-use std::io::Cursor;
+use crate::compact1::GlyphID;
+use crate::error::{Call, Kind};
+use crate::slice::Slice;
+use crate::type2::{number, Fixed, Operand, Operation, Operator};
+use crate::{Limits, Result};
 
-use crate::tape::Read;
-use crate::type2::{number, Operand, Operation, Operator};
-use crate::Result;
+// The maximum number of nested subroutine calls and the maximum number of operands in the Type 2
+// Charstring Format, which are the capacities of the call and argument stacks. Limits exceeding
+// them are capped.
+const CALL_DEPTH: usize = 10;
+const STACK_DEPTH: usize = 48;
 
 /// A program.
 pub struct Program<'l> {
     routine: Routine<'l>,
+    callers: [Routine<'l>; CALL_DEPTH],
     global: &'l [Vec<u8>],
     local: &'l [Vec<u8>],
-    stack: Vec<Operand>,
+    limits: Limits,
+    stack: Stack,
     stems: usize,
-    width: Option<Operand>,
+    width: Option<Value>,
+    depth: usize,
+    count: usize,
+    glyph_id: Option<GlyphID>,
+    calls: Option<Vec<Call>>,
+    masks: Option<Vec<Mask>>,
+    composite: Option<Composite>,
+    fixed_point: bool,
+    terminated: bool,
 }
 
+// A value on the argument stack, which is exactly representable as an operand or as a fixed-point
+// number depending on the mode.
+type Value = f64;
+
+/// A hint mask given by the routine containing it, the position of its first byte in the routine,
+/// and its size in bytes.
+pub type Mask = (Option<Call>, u64, usize);
+
+/// An accented glyph composed of a base and an accent, which is requested by passing four
+/// arguments to `endchar`.
+///
+/// The components are given by their codes in the standard encoding, and the accent is placed
+/// relative to the origin of the base.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Composite {
+    /// The horizontal offset of the accent.
+    pub x: Operand,
+    /// The vertical offset of the accent.
+    pub y: Operand,
+    /// The code of the base.
+    pub base: u8,
+    /// The code of the accent.
+    pub accent: u8,
+}
+
+#[derive(Clone, Copy)]
 struct Routine<'l> {
-    tape: Cursor<&'l [u8]>,
-    size: usize,
-    caller: Option<Box<Routine<'l>>>,
+    tape: Slice<'l>,
+    call: Option<Call>,
+}
+
+struct Stack {
+    values: [Value; STACK_DEPTH],
+    length: usize,
 }
 
 impl<'l> Program<'l> {
     /// Create a program.
     #[inline]
     pub fn new(code: &'l [u8], global: &'l [Vec<u8>], local: &'l [Vec<u8>]) -> Self {
+        Program::with_limits(code, global, local, Limits::default())
+    }
+
+    /// Create a program with specific resource limits.
+    pub fn with_limits(
+        code: &'l [u8],
+        global: &'l [Vec<u8>],
+        local: &'l [Vec<u8>],
+        limits: Limits,
+    ) -> Self {
         Program {
             routine: Routine::new(code),
+            callers: [Routine::new(&[]); CALL_DEPTH],
             global,
             local,
-            stack: vec![],
+            limits,
+            stack: Stack::new(),
             stems: 0,
             width: None,
+            depth: 0,
+            count: 0,
+            glyph_id: None,
+            calls: None,
+            masks: None,
+            composite: None,
+            fixed_point: false,
+            terminated: false,
         }
     }
 
+    /// Set the glyph being interpreted, which is used for error reporting.
+    #[inline]
+    pub fn set_glyph_id(&mut self, glyph_id: GlyphID) {
+        self.glyph_id = Some(glyph_id);
+    }
+
+    /// Start recording subroutine calls, which can then be inspected via `calls`.
+    #[inline]
+    pub fn track_calls(&mut self) {
+        self.calls.get_or_insert_with(Vec::new);
+    }
+
+    /// Return the recorded subroutine calls if tracking is enabled.
+    #[inline]
+    pub fn calls(&self) -> Option<&[Call]> {
+        self.calls.as_deref()
+    }
+
+    /// Start recording hint masks, which can then be inspected via `masks`.
+    #[inline]
+    pub fn track_masks(&mut self) {
+        self.masks.get_or_insert_with(Vec::new);
+    }
+
+    /// Return the recorded hint masks if tracking is enabled.
+    #[inline]
+    pub fn masks(&self) -> Option<&[Mask]> {
+        self.masks.as_deref()
+    }
+
+    /// Switch to the fixed-point mode, in which operands and the results of arithmetic operators
+    /// are kept exactly in the 16.16 format, which is what the reference rasterizers use.
+    ///
+    /// The mode is to be chosen before the first operation is requested. Exact operands can then
+    /// be obtained via `next_fixed`, and `next` rounds them to the nearest operands.
+    #[inline]
+    pub fn use_fixed_point(&mut self) {
+        self.fixed_point = true;
+    }
+
+    /// Check if the fixed-point mode is used.
+    #[inline]
+    pub fn fixed_point(&self) -> bool {
+        self.fixed_point
+    }
+
+    /// Return the composition requested by the terminal operator if any.
+    #[inline]
+    pub fn composite(&self) -> Option<Composite> {
+        self.composite
+    }
+
+    /// Check if the terminal operator has been encountered.
+    #[inline]
+    pub fn terminated(&self) -> bool {
+        self.terminated
+    }
+
     /// Return the next operation.
     #[allow(clippy::should_implement_trait)]
-    #[allow(unused_comparisons, unused_must_use)]
     pub fn next(&mut self) -> Result<Option<Operation>> {
-        use crate::type2::Operator::*;
+        let operation = self.interpret()?;
+        Ok(operation.map(|(operator, operands)| {
+            let operands = operands.into_iter().map(|value| value as Operand);
+            (operator, operands.collect())
+        }))
+    }
 
-        if self.routine.done()? {
-            return Ok(None);
-        }
+    /// Return the next operation with operands in the 16.16 format.
+    ///
+    /// In the floating-point mode, the operands are rounded to the nearest fixed-point numbers.
+    pub fn next_fixed(&mut self) -> Result<Option<(Operator, Vec<Fixed>)>> {
+        let operation = self.interpret()?;
+        Ok(operation.map(|(operator, operands)| {
+            let operands = operands.into_iter().map(Fixed::from_f64);
+            (operator, operands.collect())
+        }))
+    }
 
+    fn interpret(&mut self) -> Result<Option<(Operator, Vec<Value>)>> {
+        self.step().map_err(|error| {
+            let mut error = crate::error::Error::from(error);
+            error.structure = Some(crate::error::Structure::CharacterString);
+            error.position = Some(self.routine.position() as u64);
+            error.glyph_id = self.glyph_id;
+            let callers = self.callers[..self.depth].iter();
+            error
+                .calls
+                .extend(callers.filter_map(|routine| routine.call));
+            error.calls.extend(self.routine.call);
+            error.into()
+        })
+    }
+
+    fn step(&mut self) -> Result<Option<(Operator, Vec<Value>)>> {
+        use crate::type2::Operator::*;
+
+        let stack_depth = self.limits.stack_depth.min(STACK_DEPTH);
+        let subroutine_depth = self.limits.subroutine_depth.min(CALL_DEPTH);
         macro_rules! pop(
             () => (match self.stack.pop() {
                 Some(value) => value,
-                _ => raise!("expected an operand"),
+                _ => raise!(Kind::StackUnderflow),
             });
             (bool) => (match self.stack.pop() {
                 Some(value) => value != 0.0,
-                _ => raise!("expected an operand"),
+                _ => raise!(Kind::StackUnderflow),
             });
             (i32) => (match self.stack.pop() {
-                Some(value) if value as i32 as Operand == value => value as i32,
-                _ => raise!("expected an operand of a different type"),
+                Some(value) if value as i32 as Value == value => value as i32,
+                Some(_) => raise!(Kind::UnexpectedOperand),
+                _ => raise!(Kind::StackUnderflow),
             });
         );
         macro_rules! push(
             ($operand:expr, bool) => ({
                 let operand = $operand;
-                self.stack.push(if operand { 1.0 } else { 0.0 });
+                push!(if operand { 1.0 } else { 0.0 });
             });
             ($operand:expr) => ({
                 let operand = $operand;
+                if self.stack.len() >= stack_depth {
+                    raise!(Kind::ExceededStackDepthLimit(stack_depth));
+                }
                 self.stack.push(operand);
             });
         );
         macro_rules! read(($index:expr) => ({
             let length = self.stack.len();
             if $index >= length {
-                raise!("expected more operands");
+                raise!(Kind::StackUnderflow);
             }
             self.stack[length - 1 - $index]
         }));
 
-        let mut code;
+        // Operators that do not produce operations are executed in a loop until one that does.
         loop {
-            code = self.routine.peek::<u8>()?;
-            match code {
-                0x1c | 0x20..=0xff => push!(self.routine.take_operand()?),
-                _ => break,
+            if self.routine.done() {
+                return Ok(None);
             }
-        }
-        let operator = if code == 0x0c {
-            Operator::from(self.routine.take::<u16>()?)?
-        } else {
-            Operator::from(self.routine.take::<u8>()? as u16)?
-        };
 
-        macro_rules! clear(
-            (@reduce [$min:expr, $left:expr] []) => ({
-                if $min > $left {
-                    $min = $left;
+            let mut code;
+            loop {
+                code = self.routine.peek()?;
+                match code {
+                    0x1c | 0x20..=0xff => {
+                        let value = number::read(&mut self.routine.tape)?;
+                        push!(if self.fixed_point {
+                            value
+                        } else {
+                            value as Operand as Value
+                        });
+                    }
+                    _ => break,
                 }
-            });
-            (@reduce [$min:expr, $left:expr] [equal($count:expr), $($tail:tt)*]) => ({
-                if $left >= $count {
-                    let left = $left - $count;
-                    clear!(@reduce [$min, left] [$($tail)*]);
+            }
+            let operator = if code == 0x0c {
+                Operator::from(self.routine.take_u16()?)?
+            } else {
+                Operator::from(self.routine.take()? as u16)?
+            };
+            self.count += 1;
+            if self.count > self.limits.operation_count {
+                raise!(Kind::ExceededOperationCountLimit(
+                    self.limits.operation_count
+                ));
+            }
+
+            macro_rules! clear(
+                (@reduce [$min:expr, $left:expr] []) => ({
+                    if $min > $left {
+                        $min = $left;
+                    }
+                });
+                (@reduce [$min:expr, $left:expr] [equal($count:expr), $($tail:tt)*]) => ({
+                    if $left >= $count {
+                        let left = $left - $count;
+                        clear!(@reduce [$min, left] [$($tail)*]);
+                    }
+                });
+                (@reduce [$min:expr, $left:expr] [maybe_equal($count:expr), $($tail:tt)*]) => ({
+                    clear!(@reduce [$min, $left] [$($tail)*]);
+                    if $left >= $count {
+                        clear!(@reduce [$min, $left - $count] [$($tail)*]);
+                    }
+                });
+                (@reduce [$min:expr, $left:expr] [modulo($count:expr), $($tail:tt)*]) => ({
+                    for i in 1..($left / $count + 1) {
+                        let left = $left - i * $count;
+                        clear!(@reduce [$min, left] [$($tail)*]);
+                    }
+                });
+                (@reduce [$min:expr, $left:expr] [maybe_modulo($count:expr), $($tail:tt)*]) => ({
+                    for i in 0..($left / $count + 1) {
+                        let left = $left - i * $count;
+                        clear!(@reduce [$min, left] [$($tail)*]);
+                    }
+                });
+                ($([$($predicate:ident($count:expr)),*]),+) => ({
+                    let length = self.stack.len();
+                    let mut min = !0;
+                    $(clear!(@reduce [min, length] [$($predicate($count),)*]);)+
+                    if min == !0 {
+                        raise!(Kind::MalformedOperands);
+                    }
+                    let operands = self.stack[min..].to_vec();
+                    if min > 0 && self.width.is_none() {
+                        self.width = Some(self.stack[min - 1]);
+                    }
+                    self.stack.clear();
+                    return Ok(Some((operator, operands)));
+                });
+            );
+
+            match operator {
+                // Path-construction operators
+                RMoveTo => clear!([equal(2)]),
+                HMoveTo | VMoveTo => clear!([equal(1)]),
+                RLineTo => clear!([modulo(2)]),
+                HLineTo | VLineTo => clear!([equal(1), maybe_modulo(2)], [modulo(2)]),
+                RRCurveTo => clear!([modulo(6)]),
+                HHCurveTo | VVCurveTo => clear!([maybe_equal(1), modulo(4)]),
+                HVCurveTo | VHCurveTo => clear!(
+                    [equal(4), maybe_modulo(8), maybe_equal(1)],
+                    [modulo(8), maybe_equal(1)]
+                ),
+                RCurveLine => clear!([modulo(6), equal(2)]),
+                RLineCurve => clear!([modulo(2), equal(6)]),
+                Flex => clear!([equal(13)]),
+                Flex1 => clear!([equal(11)]),
+                HFlex => clear!([equal(7)]),
+                HFlex1 => clear!([equal(9)]),
+
+                // Terminal operator
+                EndChar => {
+                    while self.depth > 0 {
+                        if !self.routine.done() {
+                            raise!(Kind::TrailingData);
+                        }
+                        self.depth -= 1;
+                        self.routine = self.callers[self.depth];
+                    }
+                    let mut length = self.stack.len();
+                    // The operands are those of seac, possibly preceded by the width.
+                    match length {
+                        0 | 1 => {}
+                        4 | 5 => {
+                            let operands = &self.stack[length - 4..];
+                            let mut codes = [0; 2];
+                            for (code, &value) in codes.iter_mut().zip(operands[2..].iter()) {
+                                if !(0.0..=255.0).contains(&value) || value.fract() != 0.0 {
+                                    raise!(Kind::UnexpectedOperand);
+                                }
+                                *code = value as u8;
+                            }
+                            self.composite = Some(Composite {
+                                x: operands[0] as Operand,
+                                y: operands[1] as Operand,
+                                base: codes[0],
+                                accent: codes[1],
+                            });
+                            length -= 4;
+                        }
+                        _ => raise!(Kind::MalformedOperands),
+                    }
+                    if length > 0 && self.width.is_none() {
+                        self.width = Some(self.stack[length - 1]);
+                    }
+                    self.terminated = true;
+                    return Ok(None);
                 }
-            });
-            (@reduce [$min:expr, $left:expr] [maybe_equal($count:expr), $($tail:tt)*]) => ({
-                clear!(@reduce [$min, $left] [$($tail)*]);
-                if $left >= $count {
-                    clear!(@reduce [$min, $left - $count] [$($tail)*]);
+
+                // Hint operators
+                DotSection => self.stack.clear(),
+                HStem | VStem | HStemHM | VStemHM => {
+                    self.stems += self.stack.len() >> 1;
+                    clear!([equal(2), maybe_modulo(2)]);
                 }
-            });
-            (@reduce [$min:expr, $left:expr] [modulo($count:expr), $($tail:tt)*]) => ({
-                for i in 1..($left / $count + 1) {
-                    let left = $left - i * $count;
-                    clear!(@reduce [$min, left] [$($tail)*]);
+                HintMask | CntrMask => {
+                    self.stems += self.stack.len() >> 1;
+                    let size = (self.stems + 7) >> 3;
+                    if let Some(masks) = self.masks.as_mut() {
+                        let position = self.routine.position() as u64;
+                        masks.push((self.routine.call, position, size));
+                    }
+                    self.routine.skip(size)?;
+                    // The operands are implicit vertical stems, possibly preceded by the width.
+                    if self.stack.len() % 2 == 1 && self.width.is_none() {
+                        self.width = Some(self.stack[0]);
+                    }
+                    self.stack.clear();
+                    return Ok(Some((operator, vec![])));
                 }
-            });
-            (@reduce [$min:expr, $left:expr] [maybe_modulo($count:expr), $($tail:tt)*]) => ({
-                for i in 0..($left / $count + 1) {
-                    let left = $left - i * $count;
-                    clear!(@reduce [$min, left] [$($tail)*]);
+
+                // Arithmetic operators
+                Abs => {
+                    let value = pop!();
+                    push!(self.unary(value, Operand::abs, Fixed::abs));
                 }
-            });
-            ($([$($predicate:ident($count:expr)),*]),+) => ({
-                let length = self.stack.len();
-                let mut min = !0;
-                $(clear!(@reduce [min, length] [$($predicate($count),)*]);)+
-                if min == !0 {
-                    raise!("found malformed operands");
+                Add => {
+                    let (right, left) = (pop!(), pop!());
+                    push!(self.binary(left, right, |a, b| a + b, |a, b| a + b));
                 }
-                let mut stack = std::mem::take(&mut self.stack);
-                let operands = stack.drain(min..).collect();
-                if min > 0 && self.width.is_none() {
-                    self.width = Some(stack[min - 1]);
+                Sub => {
+                    let (right, left) = (pop!(), pop!());
+                    push!(self.binary(left, right, |a, b| a - b, |a, b| a - b));
                 }
-                return Ok(Some((operator, operands)));
-            });
-        );
-
-        match operator {
-            // Path-construction operators
-            RMoveTo => clear!([equal(2)]),
-            HMoveTo | VMoveTo => clear!([equal(1)]),
-            RLineTo => clear!([modulo(2)]),
-            HLineTo | VLineTo => clear!([equal(1), maybe_modulo(2)], [modulo(2)]),
-            RRCurveTo => clear!([modulo(6)]),
-            HHCurveTo | VVCurveTo => clear!([maybe_equal(1), modulo(4)]),
-            HVCurveTo | VHCurveTo => clear!(
-                [equal(4), maybe_modulo(8), maybe_equal(1)],
-                [modulo(8), maybe_equal(1)]
-            ),
-            RCurveLine => clear!([modulo(6), equal(2)]),
-            RLineCurve => clear!([modulo(2), equal(6)]),
-            Flex => clear!([equal(13)]),
-            Flex1 => clear!([equal(11)]),
-            HFlex => clear!([equal(7)]),
-            HFlex1 => clear!([equal(9)]),
-
-            // Terminal operator
-            EndChar => {
-                while let Some(caller) = self.routine.caller.take() {
-                    if !self.routine.done()? {
-                        raise!("found trailing data after the end operator");
-                    }
-                    std::mem::replace(&mut self.routine, *caller);
+                Div => {
+                    let (right, left) = (pop!(), pop!());
+                    push!(self.binary(left, right, |a, b| a / b, |a, b| a / b));
                 }
-                let length = self.stack.len();
-                if length > 0 && self.width.is_none() {
-                    self.width = Some(self.stack[length - 1]);
+                Neg => {
+                    let value = pop!();
+                    push!(self.unary(value, |a| -a, |a| -a));
                 }
-                return Ok(None);
-            }
-
-            // Hint operators
-            HStem | VStem | HStemHM | VStemHM => {
-                self.stems += self.stack.len() >> 1;
-                clear!([equal(2), maybe_modulo(2)]);
-            }
-            HintMask | CntrMask => {
-                self.stems += self.stack.len() >> 1;
-                let _ = self.routine.take_given::<Vec<u8>>((self.stems + 7) >> 3)?;
-                clear!([equal(0)]);
-            }
-
-            // Arithmetic operators
-            Abs => push!(pop!().abs()),
-            Add => push!(pop!() + pop!()),
-            Sub => {
-                let (right, left) = (pop!(), pop!());
-                push!(left - right);
-            }
-            Div => {
-                let (right, left) = (pop!(), pop!());
-                push!(left / right);
-            }
-            Neg => push!(-pop!()),
-            // Random =>
-            Mul => push!(pop!() * pop!()),
-            Sqrt => push!(pop!().sqrt()),
-            #[allow(dropping_copy_types)]
-            Drop => std::mem::drop(pop!()),
-            Exch => {
-                let (right, left) = (pop!(), pop!());
-                push!(right);
-                push!(left);
-            }
-            Index => {
-                let i = pop!(i32);
-                push!(read!(if i >= 0 { i as usize } else { 0 }));
-            }
-            Roll => {
-                let (shift, span) = (pop!(i32), pop!(i32));
-                let length = self.stack.len();
-                if span < 0 {
-                    raise!("found an invalid operand");
-                } else if span as usize > length {
-                    raise!("expected more operands");
-                } else if span > 0 {
-                    let position = length - span as usize;
-                    match shift.cmp(&0) {
-                        std::cmp::Ordering::Greater => {
-                            for _ in 0..shift {
-                                let operand = pop!();
-                                self.stack.insert(position, operand);
-                            }
-                        }
-                        std::cmp::Ordering::Less => {
-                            for _ in 0..(-shift) {
-                                push!(self.stack.remove(position));
-                            }
-                        }
-                        _ => {}
+                // Random =>
+                Mul => {
+                    let (right, left) = (pop!(), pop!());
+                    push!(self.binary(left, right, |a, b| a * b, |a, b| a * b));
+                }
+                Sqrt => {
+                    let value = pop!();
+                    push!(self.unary(value, Operand::sqrt, Fixed::sqrt));
+                }
+                #[allow(dropping_copy_types)]
+                Drop => std::mem::drop(pop!()),
+                Exch => {
+                    let (right, left) = (pop!(), pop!());
+                    push!(right);
+                    push!(left);
+                }
+                Index => {
+                    let i = pop!(i32);
+                    push!(read!(if i >= 0 { i as usize } else { 0 }));
+                }
+                Roll => {
+                    let (shift, span) = (pop!(i32), pop!(i32));
+                    let length = self.stack.len();
+                    if span < 0 {
+                        raise!(Kind::UnexpectedOperand);
+                    } else if span as usize > length {
+                        raise!(Kind::StackUnderflow);
+                    } else if span > 0 {
+                        let position = length - span as usize;
+                        let shift = shift.rem_euclid(span) as usize;
+                        self.stack[position..].rotate_right(shift);
                     }
                 }
-            }
-            Dup => push!(read!(0)),
+                Dup => push!(read!(0)),
 
-            // Storage operators
-            // Put =>
-            // Get =>
+                // Storage operators
+                // Put =>
+                // Get =>
 
-            // Conditional operators
-            And => {
-                let (right, left) = (pop!(bool), pop!(bool));
-                push!(left && right, bool);
-            }
-            Or => {
-                let (right, left) = (pop!(bool), pop!(bool));
-                push!(left || right, bool);
-            }
-            Not => push!(!pop!(bool), bool),
-            Eq => {
-                let (right, left) = (pop!(), pop!());
-                push!(left == right, bool);
-            }
-            IfElse => {
-                let (right, left, no, yes) = (pop!(), pop!(), pop!(), pop!());
-                push!(if left <= right { yes } else { no });
-            }
+                // Conditional operators
+                And => {
+                    let (right, left) = (pop!(bool), pop!(bool));
+                    push!(left && right, bool);
+                }
+                Or => {
+                    let (right, left) = (pop!(bool), pop!(bool));
+                    push!(left || right, bool);
+                }
+                Not => push!(!pop!(bool), bool),
+                Eq => {
+                    let (right, left) = (pop!(), pop!());
+                    push!(left == right, bool);
+                }
+                IfElse => {
+                    let (right, left, no, yes) = (pop!(), pop!(), pop!(), pop!());
+                    push!(if left <= right { yes } else { no });
+                }
 
-            // Subroutine operators
-            CallSubr | CallGSubr => {
-                let address = pop!(i32);
-                let mut routine = {
-                    let subroutines = if operator == CallSubr {
-                        &self.local
-                    } else {
-                        &self.global
+                // Subroutine operators
+                CallSubr | CallGSubr => {
+                    if self.depth >= subroutine_depth {
+                        raise!(Kind::ExceededSubroutineDepthLimit(subroutine_depth));
+                    }
+                    let address = pop!(i32);
+                    let routine = {
+                        let subroutines = if operator == CallSubr {
+                            &self.local
+                        } else {
+                            &self.global
+                        };
+                        let count = subroutines.len();
+                        match address
+                            .checked_add(bias(count))
+                            .and_then(|i| usize::try_from(i).ok())
+                            .and_then(|i| subroutines.get(i).map(|code| (i, code)))
+                        {
+                            Some((index, code)) => {
+                                let call = Call {
+                                    global: operator == CallGSubr,
+                                    index,
+                                };
+                                if let Some(calls) = self.calls.as_mut() {
+                                    calls.push(call);
+                                }
+                                let mut routine = Routine::new(code);
+                                routine.call = Some(call);
+                                routine
+                            }
+                            _ => raise!(Kind::MissingSubroutine(address)),
+                        }
                     };
-                    let count = subroutines.len();
-                    let i = address + bias(count);
-                    if i < 0 || i as usize >= count {
-                        raise!("found no subroutine");
+                    self.callers[self.depth] = std::mem::replace(&mut self.routine, routine);
+                    self.depth += 1;
+                }
+                Return => {
+                    if self.depth == 0 {
+                        raise!(Kind::UnexpectedReturn);
                     }
-                    Routine::new(&subroutines[i as usize])
-                };
-                std::mem::swap(&mut self.routine, &mut routine);
-                self.routine.caller = Some(Box::new(routine));
-            }
-            Return => {
-                let caller = match self.routine.caller.take() {
-                    Some(caller) => caller,
-                    _ => raise!("found a return operator without a caller"),
-                };
-                std::mem::replace(&mut self.routine, *caller);
-            }
+                    self.depth -= 1;
+                    self.routine = self.callers[self.depth];
+                }
 
-            operator => raise!("found an unknown operator ({operator:?})"),
-        };
-        self.next()
+                operator => raise!(Kind::UnsupportedOperator(operator)),
+            };
+        }
     }
 
     /// Return the width difference with respect to the nominal width.
     #[inline]
     pub fn width(&self) -> Option<Operand> {
-        self.width
+        self.width.map(|value| value as Operand)
+    }
+
+    fn unary(
+        &self,
+        value: Value,
+        float: fn(Operand) -> Operand,
+        fixed: fn(Fixed) -> Fixed,
+    ) -> Value {
+        if self.fixed_point {
+            fixed(Fixed::from_f64(value)).to_f64()
+        } else {
+            float(value as Operand) as Value
+        }
+    }
+
+    fn binary(
+        &self,
+        left: Value,
+        right: Value,
+        float: fn(Operand, Operand) -> Operand,
+        fixed: fn(Fixed, Fixed) -> Fixed,
+    ) -> Value {
+        if self.fixed_point {
+            fixed(Fixed::from_f64(left), Fixed::from_f64(right)).to_f64()
+        } else {
+            float(left as Operand, right as Operand) as Value
+        }
     }
 }
 
@@ -300,27 +560,62 @@ impl<'l> Routine<'l> {
     #[inline]
     fn new(code: &'l [u8]) -> Routine<'l> {
         Routine {
-            tape: Cursor::new(code),
-            size: code.len(),
-            caller: None,
+            tape: Slice::new(code),
+            call: None,
+        }
+    }
+}
+
+dereference! { Routine<'l>::tape => Slice<'l> }
+
+impl Stack {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            values: [0.0; STACK_DEPTH],
+            length: 0,
         }
     }
 
     #[inline]
-    fn done(&mut self) -> Result<bool> {
-        Ok(Read::position(&mut self.tape)? == self.size as u64)
+    fn push(&mut self, value: Value) {
+        self.values[self.length] = value;
+        self.length += 1;
+    }
+
+    #[inline]
+    fn pop(&mut self) -> Option<Value> {
+        if self.length == 0 {
+            return None;
+        }
+        self.length -= 1;
+        Some(self.values[self.length])
     }
 
     #[inline]
-    fn take_operand(&mut self) -> Result<Operand> {
-        number::read(&mut self.tape)
+    fn clear(&mut self) {
+        self.length = 0;
     }
 }
 
-dereference! { Routine<'l>::tape => Cursor<&'l [u8]> }
+impl std::ops::Deref for Stack {
+    type Target = [Value];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.values[..self.length]
+    }
+}
+
+impl std::ops::DerefMut for Stack {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.values[..self.length]
+    }
+}
 
 #[inline]
-fn bias(count: usize) -> i32 {
+pub(crate) fn bias(count: usize) -> i32 {
     if count < 1240 {
         107
     } else if count < 33900 {
@@ -0,0 +1,363 @@
+//! The stroking of outlines.
+
+use std::f32::consts::PI;
+
+use crate::type2::outline::{subdivide, Outline, Point, Segment};
+
+/// Parameters of stroking.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stroke {
+    /// The width of the stroke.
+    pub width: f32,
+    /// The shape at the corners.
+    pub join: Join,
+    /// The shape at the ends of open contours.
+    pub cap: Cap,
+    /// The maximum ratio of the length of a miter to the width beyond which a bevel is used.
+    pub miter_limit: f32,
+    /// Whether contours are treated as open, in which case they are not joined back to their
+    /// starts but capped.
+    pub open: bool,
+    /// The tolerance of approximating curves and round shapes by lines.
+    pub tolerance: f32,
+}
+
+/// A shape at a corner.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Join {
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// A shape at an end.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cap {
+    Butt,
+    Round,
+    Square,
+}
+
+impl Default for Stroke {
+    /// Create parameters following the defaults of PostScript with a width of one unit.
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            join: Join::Miter,
+            cap: Cap::Butt,
+            miter_limit: 10.0,
+            open: false,
+            tolerance: 0.1,
+        }
+    }
+}
+
+impl Outline {
+    /// Expand the contours into a filled outline covering the area painted by stroking them.
+    ///
+    /// The result consists of straight lines and is to be filled using the non-zero winding rule.
+    pub fn stroke(&self, stroke: &Stroke) -> Outline {
+        let radius = stroke.width / 2.0;
+        let mut segments = vec![];
+        if radius <= 0.0 {
+            return Outline { segments };
+        }
+        let mut append = |points: Vec<Point>| {
+            if points.len() > 2 {
+                segments.push(Segment::Move(points[0]));
+                segments.extend(points[1..].iter().map(|&point| Segment::Line(point)));
+                segments.push(Segment::Close);
+            }
+        };
+        for mut points in polylines(self, stroke.tolerance) {
+            let closed = !stroke.open && points.len() > 2 && points.first() == points.last();
+            if closed {
+                points.pop();
+                let reversed = points.iter().rev().copied().collect::<Vec<_>>();
+                append(offset(&points, true, radius, stroke));
+                append(offset(&reversed, true, radius, stroke));
+            } else if points.len() > 1 {
+                let mut side = offset(&points, false, radius, stroke);
+                let count = points.len();
+                cap(
+                    &mut side,
+                    points[count - 2],
+                    points[count - 1],
+                    radius,
+                    stroke,
+                );
+                points.reverse();
+                side.extend(offset(&points, false, radius, stroke));
+                cap(
+                    &mut side,
+                    points[count - 2],
+                    points[count - 1],
+                    radius,
+                    stroke,
+                );
+                append(side);
+            } else if let (Some(&center), Cap::Round) = (points.first(), stroke.cap) {
+                append(arc(center, radius, 0.0, 2.0 * PI, stroke.tolerance));
+            }
+        }
+        Outline { segments }
+    }
+}
+
+fn polylines(outline: &Outline, tolerance: f32) -> Vec<Vec<Point>> {
+    let mut polylines = vec![];
+    let mut points: Vec<Point> = vec![];
+    let mut start = (0.0, 0.0);
+    for segment in outline.segments.iter() {
+        match *segment {
+            Segment::Move(point) => {
+                if !points.is_empty() {
+                    polylines.push(std::mem::take(&mut points));
+                }
+                points.push(point);
+                start = point;
+            }
+            Segment::Line(point) => push(&mut points, point),
+            Segment::Curve(a, b, c) => {
+                let start = points.last().copied().unwrap_or(start);
+                subdivide(&mut points, [start, a, b, c], tolerance, 0);
+            }
+            Segment::Close => {
+                push(&mut points, start);
+                polylines.push(std::mem::take(&mut points));
+            }
+        }
+    }
+    if !points.is_empty() {
+        polylines.push(points);
+    }
+    polylines
+}
+
+// Compute one side of a polyline, which is the left side given that the y axis points upward.
+fn offset(points: &[Point], closed: bool, radius: f32, stroke: &Stroke) -> Vec<Point> {
+    let count = points.len();
+    let mut side = vec![];
+    if count < 2 {
+        return side;
+    }
+    let normal = |i: usize| {
+        let (a, b) = (points[i % count], points[(i + 1) % count]);
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let length = (dx * dx + dy * dy).sqrt();
+        (-dy / length * radius, dx / length * radius)
+    };
+    let shift = |point: Point, normal: Point| (point.0 + normal.0, point.1 + normal.1);
+    let segments = if closed { count } else { count - 1 };
+    for (i, &point) in points[..segments].iter().enumerate() {
+        let current = normal(i);
+        if closed || i > 0 {
+            let previous = normal(i + count - 1);
+            join(&mut side, point, previous, current, radius, stroke);
+        } else {
+            side.push(shift(point, current));
+        }
+    }
+    if !closed {
+        side.push(shift(points[count - 1], normal(count - 2)));
+    }
+    side
+}
+
+fn join(
+    side: &mut Vec<Point>,
+    point: Point,
+    previous: Point,
+    current: Point,
+    radius: f32,
+    stroke: &Stroke,
+) {
+    let shift = |normal: Point| (point.0 + normal.0, point.1 + normal.1);
+    let cross = previous.0 * current.1 - previous.1 * current.0;
+    let dot = previous.0 * current.0 + previous.1 * current.1;
+    if cross.abs() <= 1e-6 * radius * radius && dot > 0.0 {
+        side.push(shift(current));
+        return;
+    }
+    if cross > 0.0 {
+        // The side is on the inside of the turn, and going through the point keeps the winding
+        // consistent.
+        side.extend([shift(previous), point, shift(current)]);
+        return;
+    }
+    match stroke.join {
+        Join::Miter => {
+            // The miter length relative to the width is 1 / cos(theta / 2) for the turn angle
+            // theta between the normals.
+            let cosine = dot / (radius * radius);
+            let ratio = (2.0 / (1.0 + cosine)).sqrt();
+            if ratio <= stroke.miter_limit {
+                let (x, y) = (previous.0 + current.0, previous.1 + current.1);
+                let scale = 1.0 / (1.0 + cosine);
+                side.push((point.0 + x * scale, point.1 + y * scale));
+            } else {
+                side.extend([shift(previous), shift(current)]);
+            }
+        }
+        Join::Round => {
+            let start = previous.1.atan2(previous.0);
+            let mut end = current.1.atan2(current.0);
+            // The outside of the turn is traversed clockwise.
+            if end > start {
+                end -= 2.0 * PI;
+            }
+            side.extend(arc(point, radius, start, end, stroke.tolerance));
+        }
+        Join::Bevel => side.extend([shift(previous), shift(current)]),
+    }
+}
+
+// Append the cap at the end of a segment going from one point to another.
+fn cap(side: &mut Vec<Point>, from: Point, to: Point, radius: f32, stroke: &Stroke) {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let length = (dx * dx + dy * dy).sqrt();
+    let (ux, uy) = (dx / length * radius, dy / length * radius);
+    match stroke.cap {
+        Cap::Butt => {}
+        Cap::Round => {
+            let start = ux.atan2(-uy);
+            let points = arc(to, radius, start, start - PI, stroke.tolerance);
+            side.extend(points[1..points.len() - 1].iter().copied());
+        }
+        Cap::Square => side.extend([
+            (to.0 - uy + ux, to.1 + ux + uy),
+            (to.0 + uy + ux, to.1 - ux + uy),
+        ]),
+    }
+}
+
+fn arc(center: Point, radius: f32, start: f32, end: f32, tolerance: f32) -> Vec<Point> {
+    let step = 2.0 * (1.0 - tolerance.min(radius) / radius).acos();
+    let count = ((end - start).abs() / step.max(1e-3))
+        .ceil()
+        .clamp(1.0, 1024.0) as usize;
+    (0..=count)
+        .map(|i| {
+            let angle = start + (end - start) * i as f32 / count as f32;
+            (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        })
+        .collect()
+}
+
+#[inline]
+fn push(points: &mut Vec<Point>, point: Point) {
+    if points.last() != Some(&point) {
+        points.push(point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cap, Join, Stroke};
+    use crate::raster::render;
+    use crate::type2::outline::{Outline, Segment, IDENTITY};
+
+    fn square() -> Outline {
+        Outline {
+            segments: vec![
+                Segment::Move((0.0, 0.0)),
+                Segment::Line((10.0, 0.0)),
+                Segment::Line((10.0, 10.0)),
+                Segment::Line((0.0, 10.0)),
+                Segment::Close,
+            ],
+        }
+    }
+
+    #[test]
+    fn closed() {
+        let stroke = Stroke {
+            width: 2.0,
+            ..Default::default()
+        };
+        let bitmap = render(&square().stroke(&stroke), &IDENTITY, 1.0, None);
+        assert_eq!((bitmap.width, bitmap.height), (12, 12));
+        assert_eq!((bitmap.left, bitmap.top), (-1, 11));
+        for y in 0..12 {
+            for x in 0..12 {
+                let inside = (2..10).contains(&x) && (2..10).contains(&y);
+                assert_eq!(bitmap.get(x, y), Some(if inside { 0 } else { 255 }));
+            }
+        }
+
+        let outline = Outline {
+            segments: vec![
+                Segment::Move((0.0, 0.0)),
+                Segment::Line((0.0, 10.0)),
+                Segment::Line((10.0, 10.0)),
+                Segment::Line((10.0, 0.0)),
+                Segment::Close,
+            ],
+        };
+        let bitmap = render(&outline.stroke(&stroke), &IDENTITY, 1.0, None);
+        assert_eq!(bitmap.get(5, 5), Some(0));
+        assert_eq!(bitmap.get(0, 5), Some(255));
+
+        let bevel = Stroke {
+            join: Join::Bevel,
+            ..stroke
+        };
+        let bitmap = render(&square().stroke(&bevel), &IDENTITY, 1.0, None);
+        assert_eq!(bitmap.get(0, 0), Some(128));
+        assert_eq!(bitmap.get(1, 1), Some(255));
+        let round = Stroke {
+            join: Join::Round,
+            ..stroke
+        };
+        let bitmap = render(&square().stroke(&round), &IDENTITY, 1.0, None);
+        let value = bitmap.get(0, 0).unwrap();
+        assert!(128 < value && value < 255);
+    }
+
+    #[test]
+    fn open() {
+        let outline = Outline {
+            segments: vec![Segment::Move((0.0, 0.0)), Segment::Line((10.0, 0.0))],
+        };
+        let stroke = Stroke {
+            width: 2.0,
+            ..Default::default()
+        };
+        let bitmap = render(&outline.stroke(&stroke), &IDENTITY, 1.0, None);
+        assert_eq!((bitmap.width, bitmap.height), (10, 2));
+        assert!(bitmap.data.iter().all(|&value| value == 255));
+        let square = Stroke {
+            cap: Cap::Square,
+            ..stroke
+        };
+        let bitmap = render(&outline.stroke(&square), &IDENTITY, 1.0, None);
+        assert_eq!((bitmap.width, bitmap.height), (12, 2));
+        assert!(bitmap.data.iter().all(|&value| value == 255));
+        let round = Stroke {
+            cap: Cap::Round,
+            ..stroke
+        };
+        let bitmap = render(&outline.stroke(&round), &IDENTITY, 1.0, None);
+        assert_eq!((bitmap.width, bitmap.height), (12, 2));
+        assert!(bitmap.get(0, 0).unwrap() < 255);
+        assert_eq!(bitmap.get(1, 0), Some(255));
+
+        let outline = Outline {
+            segments: vec![
+                Segment::Move((0.0, 0.0)),
+                Segment::Line((10.0, 0.0)),
+                Segment::Line((10.0, 10.0)),
+            ],
+        };
+        let bitmap = render(&outline.stroke(&stroke), &IDENTITY, 1.0, None);
+        assert_eq!((bitmap.width, bitmap.height), (11, 11));
+        assert_eq!(
+            bitmap.data.iter().filter(|&&value| value == 255).count(),
+            40
+        );
+        assert!(bitmap.data.iter().all(|&value| value == 0 || value == 255));
+    }
+}
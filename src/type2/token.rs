@@ -0,0 +1,174 @@
+//! The tokens.
+//!
+//! Unlike [`Program`](crate::type2::Program), which interprets a charstring, tokens reflect the
+//! encoding of a charstring and can be used for rewriting it.
+
+use crate::error::Kind;
+use crate::type2::{Operand, Operator};
+use crate::Result;
+
+/// A token.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Token {
+    /// An integer number.
+    Integer(i32),
+    /// A fixed-point number in the 16.16 format.
+    Fixed(i32),
+    /// An operator.
+    Operator(Operator),
+    /// The data of a hint mask following a hint or counter mask operator.
+    Mask(Vec<u8>),
+}
+
+impl Token {
+    /// Return the value if the token is a number.
+    pub fn value(&self) -> Option<Operand> {
+        match self {
+            Token::Integer(value) => Some(*value as Operand),
+            Token::Fixed(value) => Some(*value as Operand / 65536.0),
+            _ => None,
+        }
+    }
+}
+
+impl From<Operand> for Token {
+    /// Create an integer token if the value is integral and a fixed-point one otherwise.
+    fn from(value: Operand) -> Self {
+        if value.fract() == 0.0 && value.abs() <= i16::MAX as Operand {
+            Token::Integer(value as i32)
+        } else {
+            Token::Fixed((value as f64 * 65536.0).round() as i32)
+        }
+    }
+}
+
+impl From<Operator> for Token {
+    #[inline]
+    fn from(operator: Operator) -> Self {
+        Token::Operator(operator)
+    }
+}
+
+/// Decode a charstring.
+///
+/// The size of a hint mask depends on the hints declared so far, which might reside in other
+/// routines. For this reason, the size is requested from the given function, which receives the
+/// position of the mask in the charstring. The masks recorded by a program can be used for this
+/// purpose. Decoding stops after a terminal or return operator.
+pub fn decode<F>(code: &[u8], mut size: F) -> Result<Vec<Token>>
+where
+    F: FnMut(u64) -> Option<usize>,
+{
+    let mut tokens = vec![];
+    let mut i = 0;
+    macro_rules! take(() => ({
+        match code.get(i) {
+            Some(&byte) => {
+                i += 1;
+                byte
+            }
+            _ => raise!(Kind::UnexpectedEnd),
+        }
+    }));
+    while i < code.len() {
+        let first = take!();
+        let token = match first {
+            0x1c => Token::Integer(i16::from_be_bytes([take!(), take!()]) as i32),
+            0x20..=0xf6 => Token::Integer(first as i32 - 139),
+            0xf7..=0xfa => Token::Integer((first as i32 - 247) * 256 + take!() as i32 + 108),
+            0xfb..=0xfe => Token::Integer(-(first as i32 - 251) * 256 - take!() as i32 - 108),
+            0xff => Token::Fixed(i32::from_be_bytes([take!(), take!(), take!(), take!()])),
+            0x0c => Token::Operator(Operator::from(0x0c00 | take!() as u16)?),
+            code => Token::Operator(Operator::from(code as u16)?),
+        };
+        let operator = match token {
+            Token::Operator(operator) => Some(operator),
+            _ => None,
+        };
+        tokens.push(token);
+        match operator {
+            Some(Operator::HintMask) | Some(Operator::CntrMask) => {
+                let count = match size(i as u64) {
+                    Some(count) => count,
+                    _ => raise!(Kind::MissingMask),
+                };
+                let mut mask = Vec::with_capacity(count);
+                for _ in 0..count {
+                    mask.push(take!());
+                }
+                tokens.push(Token::Mask(mask));
+            }
+            Some(Operator::EndChar) | Some(Operator::Return) => break,
+            _ => {}
+        }
+    }
+    Ok(tokens)
+}
+
+/// Encode a charstring.
+///
+/// Integers are written using the shortest encoding. Those that do not fit into 16 bits are
+/// written as fixed-point numbers, saturating at the boundaries of the format.
+pub fn encode(tokens: &[Token]) -> Vec<u8> {
+    let mut code = Vec::with_capacity(tokens.len() * 2);
+    for token in tokens.iter() {
+        match *token {
+            Token::Integer(value @ -107..=107) => code.push((value + 139) as u8),
+            Token::Integer(value @ 108..=1131) => {
+                let value = value - 108;
+                code.extend([(value >> 8) as u8 + 247, value as u8]);
+            }
+            Token::Integer(value @ -1131..=-108) => {
+                let value = -value - 108;
+                code.extend([(value >> 8) as u8 + 251, value as u8]);
+            }
+            Token::Integer(value) if value >= i16::MIN as i32 && value <= i16::MAX as i32 => {
+                code.push(0x1c);
+                code.extend((value as i16).to_be_bytes());
+            }
+            Token::Integer(value) => {
+                let value = value.clamp(i16::MIN as i32, i16::MAX as i32) << 16;
+                code.push(0xff);
+                code.extend(value.to_be_bytes());
+            }
+            Token::Fixed(value) => {
+                code.push(0xff);
+                code.extend(value.to_be_bytes());
+            }
+            Token::Operator(operator) => match operator.code() {
+                value @ 0x0c00..=0x0cff => code.extend([0x0c, value as u8]),
+                value => code.push(value as u8),
+            },
+            Token::Mask(ref mask) => code.extend(mask),
+        }
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, Token};
+    use crate::type2::Operator;
+
+    #[test]
+    fn round_trip() {
+        let tokens = vec![
+            Token::Integer(0),
+            Token::Integer(-107),
+            Token::Integer(108),
+            Token::Integer(-1131),
+            Token::Integer(1132),
+            Token::Integer(-32768),
+            Token::Fixed(0x0001_8000),
+            Token::Operator(Operator::HStemHM),
+            Token::Operator(Operator::HintMask),
+            Token::Mask(vec![0b1010_0000]),
+            Token::Operator(Operator::Flex),
+            Token::Operator(Operator::EndChar),
+        ];
+        let code = encode(&tokens);
+        assert_eq!(code.len(), 1 + 1 + 2 + 2 + 3 + 3 + 5 + 1 + 1 + 1 + 2 + 1);
+        assert_eq!(decode(&code, |_| Some(1)).unwrap(), tokens);
+        assert!(decode(&code, |_| None).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+//! The character collections.
+//!
+//! The built-in data cover the proportional Latin characters at the beginning of the Adobe-Japan1,
+//! Adobe-GB1, Adobe-CNS1, and Adobe-Korea1 collections and the characters of JIS X 0208 in
+//! Adobe-Japan1, all of which are defined by the first supplement. Complete mappings can be loaded
+//! from the `cid2code.txt` files published by Adobe in the [character collection
+//! repositories][1]. Adobe-Identity assigns no meaning to character IDs, and its built-in mapping
+//! is empty.
+//!
+//! [1]: https://github.com/adobe-type-tools/Adobe-Japan1
+
+use std::collections::BTreeMap;
+
+use crate::compact1::font_set::character_id_keyed::Collection;
+use crate::compact1::CharacterID;
+use crate::error::Kind;
+use crate::unicode::collection_list::JAPAN1;
+use crate::Result;
+
+/// A mapping between character IDs and characters.
+#[derive(Clone, Debug, Default)]
+pub struct Mapping {
+    forward: BTreeMap<CharacterID, char>,
+    backward: BTreeMap<char, CharacterID>,
+}
+
+type Range = (CharacterID, u32, u16);
+
+// The number of character IDs defined by each supplement.
+static SUPPLEMENTS: &[(&str, &[usize])] = &[
+    (
+        "CNS1",
+        &[14099, 17408, 17601, 18846, 18965, 19088, 19156, 19179],
+    ),
+    ("GB1", &[7717, 9897, 22127, 22353, 29064, 30284]),
+    (
+        "Japan1",
+        &[8284, 8359, 8720, 9354, 15444, 20317, 23058, 23060],
+    ),
+    ("Korea1", &[9333, 18155, 18352]),
+];
+
+// The first character ID, the first code point, and the length of each range. In Adobe-Japan1,
+// the JIS-Roman character set places the yen sign and the overline in place of the backslash and
+// the tilde; the ranges are given in a separate file. In Adobe-Korea1, the character in place of
+// the backslash is left out.
+static RANGES: &[(&str, &[Range])] = &[
+    ("CNS1", &[(1, 0x20, 95)]),
+    ("GB1", &[(1, 0x20, 95)]),
+    ("Identity", &[]),
+    ("Japan1", JAPAN1),
+    ("Korea1", &[(1, 0x20, 60), (62, 0x5d, 34)]),
+];
+
+impl Mapping {
+    /// Create the built-in mapping of a collection if the collection is known.
+    ///
+    /// Character IDs beyond the ones defined by the supplement are left out. The built-in mapping
+    /// is partial: it covers the proportional Latin characters of Adobe-GB1, Adobe-CNS1, and
+    /// Adobe-Korea1 and, in addition, the characters of JIS X 0208 in Adobe-Japan1, so
+    /// [`Mapping::get`] returns `None` for most other character IDs. A complete mapping is
+    /// obtained with [`Mapping::parse`] given the `cid2code.txt` file of the collection.
+    pub fn new(collection: &Collection) -> Option<Self> {
+        if collection.registry != "Adobe" {
+            return None;
+        }
+        let i = RANGES
+            .binary_search_by_key(&collection.ordering.as_str(), |&(ordering, _)| ordering)
+            .ok()?;
+        let limit = count(collection).unwrap_or(usize::MAX);
+        let mut mapping = Self::default();
+        for &(character_id, code, length) in RANGES[i].1 {
+            for j in 0..length {
+                if (character_id + j) as usize >= limit {
+                    break;
+                }
+                if let Some(character) = char::from_u32(code + j as u32) {
+                    mapping.insert(character_id + j, character);
+                }
+            }
+        }
+        Some(mapping)
+    }
+
+    /// Parse a mapping in the format of `cid2code.txt`.
+    ///
+    /// The first column whose name contains `UTF32` is used. Where several code points are listed
+    /// for a character ID, the first one is taken, and the suffix denoting vertical forms is
+    /// ignored.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut lines = text
+            .lines()
+            .filter(|line| !line.starts_with('#') && !line.trim().is_empty());
+        let column = match lines.next() {
+            Some(line) if line.starts_with("CID\t") => {
+                match line.split('\t').position(|name| name.contains("UTF32")) {
+                    Some(column) => column,
+                    _ => raise!(Kind::MalformedMapping),
+                }
+            }
+            _ => raise!(Kind::MalformedMapping),
+        };
+        let mut mapping = Self::default();
+        for line in lines {
+            let mut fields = line.split('\t');
+            let character_id = match fields.next().map(str::parse::<CharacterID>) {
+                Some(Ok(character_id)) => character_id,
+                _ => raise!(Kind::MalformedMapping),
+            };
+            let value = match fields.nth(column - 1) {
+                Some(value) => value,
+                _ => raise!(Kind::MalformedMapping),
+            };
+            if value == "*" {
+                continue;
+            }
+            let value = value.split(',').next().unwrap_or_default();
+            let value = value.strip_suffix('v').unwrap_or(value);
+            match u32::from_str_radix(value, 16).ok().and_then(char::from_u32) {
+                Some(character) => mapping.insert(character_id, character),
+                _ => raise!(Kind::MalformedMapping),
+            }
+        }
+        Ok(mapping)
+    }
+
+    /// Return the character of a character ID.
+    #[inline]
+    pub fn get(&self, character_id: CharacterID) -> Option<char> {
+        self.forward.get(&character_id).copied()
+    }
+
+    /// Return the character ID of a character.
+    ///
+    /// If several character IDs represent the same character, the smallest one is returned.
+    #[inline]
+    pub fn find(&self, character: char) -> Option<CharacterID> {
+        self.backward.get(&character).copied()
+    }
+
+    /// Iterate over the pairs of character IDs and characters.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (CharacterID, char)> + '_ {
+        self.forward.iter().map(|(&key, &value)| (key, value))
+    }
+
+    /// Return the number of character IDs.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.forward.len()
+    }
+
+    /// Check if the mapping is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.forward.is_empty()
+    }
+
+    fn insert(&mut self, character_id: CharacterID, character: char) {
+        self.forward.insert(character_id, character);
+        self.backward.entry(character).or_insert(character_id);
+    }
+}
+
+/// Return the number of character IDs defined by a collection.
+///
+/// Character IDs from zero up to but not including the number belong to the collection. `None`
+/// is returned if the collection or the supplement is unknown and for Adobe-Identity, which
+/// places no bound on character IDs.
+pub fn count(collection: &Collection) -> Option<usize> {
+    if collection.registry != "Adobe" {
+        return None;
+    }
+    let i = SUPPLEMENTS
+        .binary_search_by_key(&collection.ordering.as_str(), |&(ordering, _)| ordering)
+        .ok()?;
+    let supplement = usize::try_from(collection.supplement).ok()?;
+    SUPPLEMENTS[i].1.get(supplement).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count, Collection, Mapping};
+
+    fn collection(ordering: &str, supplement: i32) -> Collection {
+        Collection {
+            registry: "Adobe".into(),
+            ordering: ordering.into(),
+            supplement,
+        }
+    }
+
+    #[test]
+    fn new() {
+        let mapping = Mapping::new(&collection("Japan1", 7)).unwrap();
+        assert_eq!(mapping.len(), 95 + 6845);
+        assert_eq!(mapping.get(34), Some('A'));
+        assert_eq!(mapping.get(61), Some('¥'));
+        assert_eq!(mapping.get(96), None);
+        assert_eq!(mapping.get(633), Some('\u{3000}'));
+        assert_eq!(mapping.get(843), Some('あ'));
+        assert_eq!(mapping.get(1125), Some('亜'));
+        assert_eq!(mapping.get(7477), Some('瑤'));
+        assert_eq!(mapping.get(7478), None);
+        assert_eq!(mapping.find('~'), None);
+        assert_eq!(mapping.find('}'), Some(94));
+        assert_eq!(mapping.find('漢'), Some(1533));
+        let mapping = Mapping::new(&collection("GB1", 5)).unwrap();
+        assert_eq!(mapping.get(61), Some('\\'));
+        assert_eq!(mapping.get(95), Some('~'));
+        assert!(Mapping::new(&collection("Identity", 0)).unwrap().is_empty());
+        assert!(Mapping::new(&collection("Unknown", 0)).is_none());
+    }
+
+    #[test]
+    fn parse() {
+        let text = "# A comment\n\
+                    CID\tJIS\tUniJIS-UCS2-H\tUniJIS-UTF32-H\n\
+                    0\t*\t*\t*\n\
+                    34\t2341\t0041\t0041\n\
+                    1200\t3021\t4e9c\t4e9c\n\
+                    7887\t*\t3001v\t3001v\n\
+                    7888\t*\t*\t20b9f,20b9e\n";
+        let mapping = Mapping::parse(text).unwrap();
+        assert_eq!(mapping.len(), 4);
+        assert_eq!(mapping.get(0), None);
+        assert_eq!(mapping.get(1200), Some('亜'));
+        assert_eq!(mapping.get(7887), Some('、'));
+        assert_eq!(mapping.get(7888), Some('\u{20b9f}'));
+        assert_eq!(mapping.find('A'), Some(34));
+        assert!(Mapping::parse("CID\tJIS\n").is_err());
+        assert!(Mapping::parse("CID\tUniGB-UTF32-H\n1\tzz\n").is_err());
+    }
+
+    #[test]
+    fn counts() {
+        assert_eq!(count(&collection("Japan1", 0)), Some(8284));
+        assert_eq!(count(&collection("Japan1", 7)), Some(23060));
+        assert_eq!(count(&collection("Japan1", 8)), None);
+        assert_eq!(count(&collection("Korea1", 2)), Some(18352));
+        assert_eq!(count(&collection("Identity", 0)), None);
+        assert_eq!(count(&Collection::default()), None);
+    }
+}
@@ -0,0 +1,6324 @@
+// The mapping from character IDs to code points of Adobe-Japan1.
+//
+// The proportional Latin characters take character IDs from 1 to 95. The characters of JIS X 0208
+// except for the box-drawing characters of row 8 take character IDs from 633 to 7477 in the order
+// of their codes. The first character ID, the first code point, and the length of each range are
+// given.
+
+use crate::compact1::CharacterID;
+
+pub static JAPAN1: &[(CharacterID, u32, u16)] = &[
+    (1, 0x0020, 60),
+    (61, 0x00a5, 1),
+    (62, 0x005d, 33),
+    (95, 0x203e, 1),
+    (633, 0x3000, 3),
+    (636, 0xff0c, 1),
+    (637, 0xff0e, 1),
+    (638, 0x30fb, 1),
+    (639, 0xff1a, 2),
+    (641, 0xff1f, 1),
+    (642, 0xff01, 1),
+    (643, 0x309b, 2),
+    (645, 0x00b4, 1),
+    (646, 0xff40, 1),
+    (647, 0x00a8, 1),
+    (648, 0xff3e, 1),
+    (649, 0xffe3, 1),
+    (650, 0xff3f, 1),
+    (651, 0x30fd, 2),
+    (653, 0x309d, 2),
+    (655, 0x3003, 1),
+    (656, 0x4edd, 1),
+    (657, 0x3005, 3),
+    (660, 0x30fc, 1),
+    (661, 0x2015, 1),
+    (662, 0x2010, 1),
+    (663, 0xff0f, 1),
+    (664, 0xff3c, 1),
+    (665, 0x301c, 1),
+    (666, 0x2016, 1),
+    (667, 0xff5c, 1),
+    (668, 0x2026, 1),
+    (669, 0x2025, 1),
+    (670, 0x2018, 2),
+    (672, 0x201c, 2),
+    (674, 0xff08, 2),
+    (676, 0x3014, 2),
+    (678, 0xff3b, 1),
+    (679, 0xff3d, 1),
+    (680, 0xff5b, 1),
+    (681, 0xff5d, 1),
+    (682, 0x3008, 10),
+    (692, 0xff0b, 1),
+    (693, 0x2212, 1),
+    (694, 0x00b1, 1),
+    (695, 0x00d7, 1),
+    (696, 0x00f7, 1),
+    (697, 0xff1d, 1),
+    (698, 0x2260, 1),
+    (699, 0xff1c, 1),
+    (700, 0xff1e, 1),
+    (701, 0x2266, 2),
+    (703, 0x221e, 1),
+    (704, 0x2234, 1),
+    (705, 0x2642, 1),
+    (706, 0x2640, 1),
+    (707, 0x00b0, 1),
+    (708, 0x2032, 2),
+    (710, 0x2103, 1),
+    (711, 0xffe5, 1),
+    (712, 0xff04, 1),
+    (713, 0x00a2, 2),
+    (715, 0xff05, 1),
+    (716, 0xff03, 1),
+    (717, 0xff06, 1),
+    (718, 0xff0a, 1),
+    (719, 0xff20, 1),
+    (720, 0x00a7, 1),
+    (721, 0x2606, 1),
+    (722, 0x2605, 1),
+    (723, 0x25cb, 1),
+    (724, 0x25cf, 1),
+    (725, 0x25ce, 1),
+    (726, 0x25c7, 1),
+    (727, 0x25c6, 1),
+    (728, 0x25a1, 1),
+    (729, 0x25a0, 1),
+    (730, 0x25b3, 1),
+    (731, 0x25b2, 1),
+    (732, 0x25bd, 1),
+    (733, 0x25bc, 1),
+    (734, 0x203b, 1),
+    (735, 0x3012, 1),
+    (736, 0x2192, 1),
+    (737, 0x2190, 2),
+    (739, 0x2193, 1),
+    (740, 0x3013, 1),
+    (741, 0x2208, 1),
+    (742, 0x220b, 1),
+    (743, 0x2286, 2),
+    (745, 0x2282, 2),
+    (747, 0x222a, 1),
+    (748, 0x2229, 1),
+    (749, 0x2227, 2),
+    (751, 0x00ac, 1),
+    (752, 0x21d2, 1),
+    (753, 0x21d4, 1),
+    (754, 0x2200, 1),
+    (755, 0x2203, 1),
+    (756, 0x2220, 1),
+    (757, 0x22a5, 1),
+    (758, 0x2312, 1),
+    (759, 0x2202, 1),
+    (760, 0x2207, 1),
+    (761, 0x2261, 1),
+    (762, 0x2252, 1),
+    (763, 0x226a, 2),
+    (765, 0x221a, 1),
+    (766, 0x223d, 1),
+    (767, 0x221d, 1),
+    (768, 0x2235, 1),
+    (769, 0x222b, 2),
+    (771, 0x212b, 1),
+    (772, 0x2030, 1),
+    (773, 0x266f, 1),
+    (774, 0x266d, 1),
+    (775, 0x266a, 1),
+    (776, 0x2020, 2),
+    (778, 0x00b6, 1),
+    (779, 0x25ef, 1),
+    (780, 0xff10, 10),
+    (790, 0xff21, 26),
+    (816, 0xff41, 26),
+    (842, 0x3041, 83),
+    (925, 0x30a1, 86),
+    (1011, 0x0391, 17),
+    (1028, 0x03a3, 7),
+    (1035, 0x03b1, 17),
+    (1052, 0x03c3, 7),
+    (1059, 0x0410, 6),
+    (1065, 0x0401, 1),
+    (1066, 0x0416, 32),
+    (1098, 0x0451, 1),
+    (1099, 0x0436, 26),
+    (1125, 0x4e9c, 1),
+    (1126, 0x5516, 1),
+    (1127, 0x5a03, 1),
+    (1128, 0x963f, 1),
+    (1129, 0x54c0, 1),
+    (1130, 0x611b, 1),
+    (1131, 0x6328, 1),
+    (1132, 0x59f6, 1),
+    (1133, 0x9022, 1),
+    (1134, 0x8475, 1),
+    (1135, 0x831c, 1),
+    (1136, 0x7a50, 1),
+    (1137, 0x60aa, 1),
+    (1138, 0x63e1, 1),
+    (1139, 0x6e25, 1),
+    (1140, 0x65ed, 1),
+    (1141, 0x8466, 1),
+    (1142, 0x82a6, 1),
+    (1143, 0x9bf5, 1),
+    (1144, 0x6893, 1),
+    (1145, 0x5727, 1),
+    (1146, 0x65a1, 1),
+    (1147, 0x6271, 1),
+    (1148, 0x5b9b, 1),
+    (1149, 0x59d0, 1),
+    (1150, 0x867b, 1),
+    (1151, 0x98f4, 1),
+    (1152, 0x7d62, 1),
+    (1153, 0x7dbe, 1),
+    (1154, 0x9b8e, 1),
+    (1155, 0x6216, 1),
+    (1156, 0x7c9f, 1),
+    (1157, 0x88b7, 1),
+    (1158, 0x5b89, 1),
+    (1159, 0x5eb5, 1),
+    (1160, 0x6309, 1),
+    (1161, 0x6697, 1),
+    (1162, 0x6848, 1),
+    (1163, 0x95c7, 1),
+    (1164, 0x978d, 1),
+    (1165, 0x674f, 1),
+    (1166, 0x4ee5, 1),
+    (1167, 0x4f0a, 1),
+    (1168, 0x4f4d, 1),
+    (1169, 0x4f9d, 1),
+    (1170, 0x5049, 1),
+    (1171, 0x56f2, 1),
+    (1172, 0x5937, 1),
+    (1173, 0x59d4, 1),
+    (1174, 0x5a01, 1),
+    (1175, 0x5c09, 1),
+    (1176, 0x60df, 1),
+    (1177, 0x610f, 1),
+    (1178, 0x6170, 1),
+    (1179, 0x6613, 1),
+    (1180, 0x6905, 1),
+    (1181, 0x70ba, 1),
+    (1182, 0x754f, 1),
+    (1183, 0x7570, 1),
+    (1184, 0x79fb, 1),
+    (1185, 0x7dad, 1),
+    (1186, 0x7def, 1),
+    (1187, 0x80c3, 1),
+    (1188, 0x840e, 1),
+    (1189, 0x8863, 1),
+    (1190, 0x8b02, 1),
+    (1191, 0x9055, 1),
+    (1192, 0x907a, 1),
+    (1193, 0x533b, 1),
+    (1194, 0x4e95, 1),
+    (1195, 0x4ea5, 1),
+    (1196, 0x57df, 1),
+    (1197, 0x80b2, 1),
+    (1198, 0x90c1, 1),
+    (1199, 0x78ef, 1),
+    (1200, 0x4e00, 1),
+    (1201, 0x58f1, 1),
+    (1202, 0x6ea2, 1),
+    (1203, 0x9038, 1),
+    (1204, 0x7a32, 1),
+    (1205, 0x8328, 1),
+    (1206, 0x828b, 1),
+    (1207, 0x9c2f, 1),
+    (1208, 0x5141, 1),
+    (1209, 0x5370, 1),
+    (1210, 0x54bd, 1),
+    (1211, 0x54e1, 1),
+    (1212, 0x56e0, 1),
+    (1213, 0x59fb, 1),
+    (1214, 0x5f15, 1),
+    (1215, 0x98f2, 1),
+    (1216, 0x6deb, 1),
+    (1217, 0x80e4, 1),
+    (1218, 0x852d, 1),
+    (1219, 0x9662, 1),
+    (1220, 0x9670, 1),
+    (1221, 0x96a0, 1),
+    (1222, 0x97fb, 1),
+    (1223, 0x540b, 1),
+    (1224, 0x53f3, 1),
+    (1225, 0x5b87, 1),
+    (1226, 0x70cf, 1),
+    (1227, 0x7fbd, 1),
+    (1228, 0x8fc2, 1),
+    (1229, 0x96e8, 1),
+    (1230, 0x536f, 1),
+    (1231, 0x9d5c, 1),
+    (1232, 0x7aba, 1),
+    (1233, 0x4e11, 1),
+    (1234, 0x7893, 1),
+    (1235, 0x81fc, 1),
+    (1236, 0x6e26, 1),
+    (1237, 0x5618, 1),
+    (1238, 0x5504, 1),
+    (1239, 0x6b1d, 1),
+    (1240, 0x851a, 1),
+    (1241, 0x9c3b, 1),
+    (1242, 0x59e5, 1),
+    (1243, 0x53a9, 1),
+    (1244, 0x6d66, 1),
+    (1245, 0x74dc, 1),
+    (1246, 0x958f, 1),
+    (1247, 0x5642, 1),
+    (1248, 0x4e91, 1),
+    (1249, 0x904b, 1),
+    (1250, 0x96f2, 1),
+    (1251, 0x834f, 1),
+    (1252, 0x990c, 1),
+    (1253, 0x53e1, 1),
+    (1254, 0x55b6, 1),
+    (1255, 0x5b30, 1),
+    (1256, 0x5f71, 1),
+    (1257, 0x6620, 1),
+    (1258, 0x66f3, 1),
+    (1259, 0x6804, 1),
+    (1260, 0x6c38, 1),
+    (1261, 0x6cf3, 1),
+    (1262, 0x6d29, 1),
+    (1263, 0x745b, 1),
+    (1264, 0x76c8, 1),
+    (1265, 0x7a4e, 1),
+    (1266, 0x9834, 1),
+    (1267, 0x82f1, 1),
+    (1268, 0x885b, 1),
+    (1269, 0x8a60, 1),
+    (1270, 0x92ed, 1),
+    (1271, 0x6db2, 1),
+    (1272, 0x75ab, 1),
+    (1273, 0x76ca, 1),
+    (1274, 0x99c5, 1),
+    (1275, 0x60a6, 1),
+    (1276, 0x8b01, 1),
+    (1277, 0x8d8a, 1),
+    (1278, 0x95b2, 1),
+    (1279, 0x698e, 1),
+    (1280, 0x53ad, 1),
+    (1281, 0x5186, 1),
+    (1282, 0x5712, 1),
+    (1283, 0x5830, 1),
+    (1284, 0x5944, 1),
+    (1285, 0x5bb4, 1),
+    (1286, 0x5ef6, 1),
+    (1287, 0x6028, 1),
+    (1288, 0x63a9, 1),
+    (1289, 0x63f4, 1),
+    (1290, 0x6cbf, 1),
+    (1291, 0x6f14, 1),
+    (1292, 0x708e, 1),
+    (1293, 0x7114, 1),
+    (1294, 0x7159, 1),
+    (1295, 0x71d5, 1),
+    (1296, 0x733f, 1),
+    (1297, 0x7e01, 1),
+    (1298, 0x8276, 1),
+    (1299, 0x82d1, 1),
+    (1300, 0x8597, 1),
+    (1301, 0x9060, 1),
+    (1302, 0x925b, 1),
+    (1303, 0x9d1b, 1),
+    (1304, 0x5869, 1),
+    (1305, 0x65bc, 1),
+    (1306, 0x6c5a, 1),
+    (1307, 0x7525, 1),
+    (1308, 0x51f9, 1),
+    (1309, 0x592e, 1),
+    (1310, 0x5965, 1),
+    (1311, 0x5f80, 1),
+    (1312, 0x5fdc, 1),
+    (1313, 0x62bc, 1),
+    (1314, 0x65fa, 1),
+    (1315, 0x6a2a, 1),
+    (1316, 0x6b27, 1),
+    (1317, 0x6bb4, 1),
+    (1318, 0x738b, 1),
+    (1319, 0x7fc1, 1),
+    (1320, 0x8956, 1),
+    (1321, 0x9d2c, 1),
+    (1322, 0x9d0e, 1),
+    (1323, 0x9ec4, 1),
+    (1324, 0x5ca1, 1),
+    (1325, 0x6c96, 1),
+    (1326, 0x837b, 1),
+    (1327, 0x5104, 1),
+    (1328, 0x5c4b, 1),
+    (1329, 0x61b6, 1),
+    (1330, 0x81c6, 1),
+    (1331, 0x6876, 1),
+    (1332, 0x7261, 1),
+    (1333, 0x4e59, 1),
+    (1334, 0x4ffa, 1),
+    (1335, 0x5378, 1),
+    (1336, 0x6069, 1),
+    (1337, 0x6e29, 1),
+    (1338, 0x7a4f, 1),
+    (1339, 0x97f3, 1),
+    (1340, 0x4e0b, 1),
+    (1341, 0x5316, 1),
+    (1342, 0x4eee, 1),
+    (1343, 0x4f55, 1),
+    (1344, 0x4f3d, 1),
+    (1345, 0x4fa1, 1),
+    (1346, 0x4f73, 1),
+    (1347, 0x52a0, 1),
+    (1348, 0x53ef, 1),
+    (1349, 0x5609, 1),
+    (1350, 0x590f, 1),
+    (1351, 0x5ac1, 1),
+    (1352, 0x5bb6, 1),
+    (1353, 0x5be1, 1),
+    (1354, 0x79d1, 1),
+    (1355, 0x6687, 1),
+    (1356, 0x679c, 1),
+    (1357, 0x67b6, 1),
+    (1358, 0x6b4c, 1),
+    (1359, 0x6cb3, 1),
+    (1360, 0x706b, 1),
+    (1361, 0x73c2, 1),
+    (1362, 0x798d, 1),
+    (1363, 0x79be, 1),
+    (1364, 0x7a3c, 1),
+    (1365, 0x7b87, 1),
+    (1366, 0x82b1, 1),
+    (1367, 0x82db, 1),
+    (1368, 0x8304, 1),
+    (1369, 0x8377, 1),
+    (1370, 0x83ef, 1),
+    (1371, 0x83d3, 1),
+    (1372, 0x8766, 1),
+    (1373, 0x8ab2, 1),
+    (1374, 0x5629, 1),
+    (1375, 0x8ca8, 1),
+    (1376, 0x8fe6, 1),
+    (1377, 0x904e, 1),
+    (1378, 0x971e, 1),
+    (1379, 0x868a, 1),
+    (1380, 0x4fc4, 1),
+    (1381, 0x5ce8, 1),
+    (1382, 0x6211, 1),
+    (1383, 0x7259, 1),
+    (1384, 0x753b, 1),
+    (1385, 0x81e5, 1),
+    (1386, 0x82bd, 1),
+    (1387, 0x86fe, 1),
+    (1388, 0x8cc0, 1),
+    (1389, 0x96c5, 1),
+    (1390, 0x9913, 1),
+    (1391, 0x99d5, 1),
+    (1392, 0x4ecb, 1),
+    (1393, 0x4f1a, 1),
+    (1394, 0x89e3, 1),
+    (1395, 0x56de, 1),
+    (1396, 0x584a, 1),
+    (1397, 0x58ca, 1),
+    (1398, 0x5efb, 1),
+    (1399, 0x5feb, 1),
+    (1400, 0x602a, 1),
+    (1401, 0x6094, 1),
+    (1402, 0x6062, 1),
+    (1403, 0x61d0, 1),
+    (1404, 0x6212, 1),
+    (1405, 0x62d0, 1),
+    (1406, 0x6539, 1),
+    (1407, 0x9b41, 1),
+    (1408, 0x6666, 1),
+    (1409, 0x68b0, 1),
+    (1410, 0x6d77, 1),
+    (1411, 0x7070, 1),
+    (1412, 0x754c, 1),
+    (1413, 0x7686, 1),
+    (1414, 0x7d75, 1),
+    (1415, 0x82a5, 1),
+    (1416, 0x87f9, 1),
+    (1417, 0x958b, 1),
+    (1418, 0x968e, 1),
+    (1419, 0x8c9d, 1),
+    (1420, 0x51f1, 1),
+    (1421, 0x52be, 1),
+    (1422, 0x5916, 1),
+    (1423, 0x54b3, 1),
+    (1424, 0x5bb3, 1),
+    (1425, 0x5d16, 1),
+    (1426, 0x6168, 1),
+    (1427, 0x6982, 1),
+    (1428, 0x6daf, 1),
+    (1429, 0x788d, 1),
+    (1430, 0x84cb, 1),
+    (1431, 0x8857, 1),
+    (1432, 0x8a72, 1),
+    (1433, 0x93a7, 1),
+    (1434, 0x9ab8, 1),
+    (1435, 0x6d6c, 1),
+    (1436, 0x99a8, 1),
+    (1437, 0x86d9, 1),
+    (1438, 0x57a3, 1),
+    (1439, 0x67ff, 1),
+    (1440, 0x86ce, 1),
+    (1441, 0x920e, 1),
+    (1442, 0x5283, 1),
+    (1443, 0x5687, 1),
+    (1444, 0x5404, 1),
+    (1445, 0x5ed3, 1),
+    (1446, 0x62e1, 1),
+    (1447, 0x64b9, 1),
+    (1448, 0x683c, 1),
+    (1449, 0x6838, 1),
+    (1450, 0x6bbb, 1),
+    (1451, 0x7372, 1),
+    (1452, 0x78ba, 1),
+    (1453, 0x7a6b, 1),
+    (1454, 0x899a, 1),
+    (1455, 0x89d2, 1),
+    (1456, 0x8d6b, 1),
+    (1457, 0x8f03, 1),
+    (1458, 0x90ed, 1),
+    (1459, 0x95a3, 1),
+    (1460, 0x9694, 1),
+    (1461, 0x9769, 1),
+    (1462, 0x5b66, 1),
+    (1463, 0x5cb3, 1),
+    (1464, 0x697d, 1),
+    (1465, 0x984d, 2),
+    (1467, 0x639b, 1),
+    (1468, 0x7b20, 1),
+    (1469, 0x6a2b, 1),
+    (1470, 0x6a7f, 1),
+    (1471, 0x68b6, 1),
+    (1472, 0x9c0d, 1),
+    (1473, 0x6f5f, 1),
+    (1474, 0x5272, 1),
+    (1475, 0x559d, 1),
+    (1476, 0x6070, 1),
+    (1477, 0x62ec, 1),
+    (1478, 0x6d3b, 1),
+    (1479, 0x6e07, 1),
+    (1480, 0x6ed1, 1),
+    (1481, 0x845b, 1),
+    (1482, 0x8910, 1),
+    (1483, 0x8f44, 1),
+    (1484, 0x4e14, 1),
+    (1485, 0x9c39, 1),
+    (1486, 0x53f6, 1),
+    (1487, 0x691b, 1),
+    (1488, 0x6a3a, 1),
+    (1489, 0x9784, 1),
+    (1490, 0x682a, 1),
+    (1491, 0x515c, 1),
+    (1492, 0x7ac3, 1),
+    (1493, 0x84b2, 1),
+    (1494, 0x91dc, 1),
+    (1495, 0x938c, 1),
+    (1496, 0x565b, 1),
+    (1497, 0x9d28, 1),
+    (1498, 0x6822, 1),
+    (1499, 0x8305, 1),
+    (1500, 0x8431, 1),
+    (1501, 0x7ca5, 1),
+    (1502, 0x5208, 1),
+    (1503, 0x82c5, 1),
+    (1504, 0x74e6, 1),
+    (1505, 0x4e7e, 1),
+    (1506, 0x4f83, 1),
+    (1507, 0x51a0, 1),
+    (1508, 0x5bd2, 1),
+    (1509, 0x520a, 1),
+    (1510, 0x52d8, 1),
+    (1511, 0x52e7, 1),
+    (1512, 0x5dfb, 1),
+    (1513, 0x559a, 1),
+    (1514, 0x582a, 1),
+    (1515, 0x59e6, 1),
+    (1516, 0x5b8c, 1),
+    (1517, 0x5b98, 1),
+    (1518, 0x5bdb, 1),
+    (1519, 0x5e72, 1),
+    (1520, 0x5e79, 1),
+    (1521, 0x60a3, 1),
+    (1522, 0x611f, 1),
+    (1523, 0x6163, 1),
+    (1524, 0x61be, 1),
+    (1525, 0x63db, 1),
+    (1526, 0x6562, 1),
+    (1527, 0x67d1, 1),
+    (1528, 0x6853, 1),
+    (1529, 0x68fa, 1),
+    (1530, 0x6b3e, 1),
+    (1531, 0x6b53, 1),
+    (1532, 0x6c57, 1),
+    (1533, 0x6f22, 1),
+    (1534, 0x6f97, 1),
+    (1535, 0x6f45, 1),
+    (1536, 0x74b0, 1),
+    (1537, 0x7518, 1),
+    (1538, 0x76e3, 1),
+    (1539, 0x770b, 1),
+    (1540, 0x7aff, 1),
+    (1541, 0x7ba1, 1),
+    (1542, 0x7c21, 1),
+    (1543, 0x7de9, 1),
+    (1544, 0x7f36, 1),
+    (1545, 0x7ff0, 1),
+    (1546, 0x809d, 1),
+    (1547, 0x8266, 1),
+    (1548, 0x839e, 1),
+    (1549, 0x89b3, 1),
+    (1550, 0x8acc, 1),
+    (1551, 0x8cab, 1),
+    (1552, 0x9084, 1),
+    (1553, 0x9451, 1),
+    (1554, 0x9593, 1),
+    (1555, 0x9591, 1),
+    (1556, 0x95a2, 1),
+    (1557, 0x9665, 1),
+    (1558, 0x97d3, 1),
+    (1559, 0x9928, 1),
+    (1560, 0x8218, 1),
+    (1561, 0x4e38, 1),
+    (1562, 0x542b, 1),
+    (1563, 0x5cb8, 1),
+    (1564, 0x5dcc, 1),
+    (1565, 0x73a9, 1),
+    (1566, 0x764c, 1),
+    (1567, 0x773c, 1),
+    (1568, 0x5ca9, 1),
+    (1569, 0x7feb, 1),
+    (1570, 0x8d0b, 1),
+    (1571, 0x96c1, 1),
+    (1572, 0x9811, 1),
+    (1573, 0x9854, 1),
+    (1574, 0x9858, 1),
+    (1575, 0x4f01, 1),
+    (1576, 0x4f0e, 1),
+    (1577, 0x5371, 1),
+    (1578, 0x559c, 1),
+    (1579, 0x5668, 1),
+    (1580, 0x57fa, 1),
+    (1581, 0x5947, 1),
+    (1582, 0x5b09, 1),
+    (1583, 0x5bc4, 1),
+    (1584, 0x5c90, 1),
+    (1585, 0x5e0c, 1),
+    (1586, 0x5e7e, 1),
+    (1587, 0x5fcc, 1),
+    (1588, 0x63ee, 1),
+    (1589, 0x673a, 1),
+    (1590, 0x65d7, 1),
+    (1591, 0x65e2, 1),
+    (1592, 0x671f, 1),
+    (1593, 0x68cb, 1),
+    (1594, 0x68c4, 1),
+    (1595, 0x6a5f, 1),
+    (1596, 0x5e30, 1),
+    (1597, 0x6bc5, 1),
+    (1598, 0x6c17, 1),
+    (1599, 0x6c7d, 1),
+    (1600, 0x757f, 1),
+    (1601, 0x7948, 1),
+    (1602, 0x5b63, 1),
+    (1603, 0x7a00, 1),
+    (1604, 0x7d00, 1),
+    (1605, 0x5fbd, 1),
+    (1606, 0x898f, 1),
+    (1607, 0x8a18, 1),
+    (1608, 0x8cb4, 1),
+    (1609, 0x8d77, 1),
+    (1610, 0x8ecc, 1),
+    (1611, 0x8f1d, 1),
+    (1612, 0x98e2, 1),
+    (1613, 0x9a0e, 1),
+    (1614, 0x9b3c, 1),
+    (1615, 0x4e80, 1),
+    (1616, 0x507d, 1),
+    (1617, 0x5100, 1),
+    (1618, 0x5993, 1),
+    (1619, 0x5b9c, 1),
+    (1620, 0x622f, 1),
+    (1621, 0x6280, 1),
+    (1622, 0x64ec, 1),
+    (1623, 0x6b3a, 1),
+    (1624, 0x72a0, 1),
+    (1625, 0x7591, 1),
+    (1626, 0x7947, 1),
+    (1627, 0x7fa9, 1),
+    (1628, 0x87fb, 1),
+    (1629, 0x8abc, 1),
+    (1630, 0x8b70, 1),
+    (1631, 0x63ac, 1),
+    (1632, 0x83ca, 1),
+    (1633, 0x97a0, 1),
+    (1634, 0x5409, 1),
+    (1635, 0x5403, 1),
+    (1636, 0x55ab, 1),
+    (1637, 0x6854, 1),
+    (1638, 0x6a58, 1),
+    (1639, 0x8a70, 1),
+    (1640, 0x7827, 1),
+    (1641, 0x6775, 1),
+    (1642, 0x9ecd, 1),
+    (1643, 0x5374, 1),
+    (1644, 0x5ba2, 1),
+    (1645, 0x811a, 1),
+    (1646, 0x8650, 1),
+    (1647, 0x9006, 1),
+    (1648, 0x4e18, 1),
+    (1649, 0x4e45, 1),
+    (1650, 0x4ec7, 1),
+    (1651, 0x4f11, 1),
+    (1652, 0x53ca, 1),
+    (1653, 0x5438, 1),
+    (1654, 0x5bae, 1),
+    (1655, 0x5f13, 1),
+    (1656, 0x6025, 1),
+    (1657, 0x6551, 1),
+    (1658, 0x673d, 1),
+    (1659, 0x6c42, 1),
+    (1660, 0x6c72, 1),
+    (1661, 0x6ce3, 1),
+    (1662, 0x7078, 1),
+    (1663, 0x7403, 1),
+    (1664, 0x7a76, 1),
+    (1665, 0x7aae, 1),
+    (1666, 0x7b08, 1),
+    (1667, 0x7d1a, 1),
+    (1668, 0x7cfe, 1),
+    (1669, 0x7d66, 1),
+    (1670, 0x65e7, 1),
+    (1671, 0x725b, 1),
+    (1672, 0x53bb, 1),
+    (1673, 0x5c45, 1),
+    (1674, 0x5de8, 1),
+    (1675, 0x62d2, 1),
+    (1676, 0x62e0, 1),
+    (1677, 0x6319, 1),
+    (1678, 0x6e20, 1),
+    (1679, 0x865a, 1),
+    (1680, 0x8a31, 1),
+    (1681, 0x8ddd, 1),
+    (1682, 0x92f8, 1),
+    (1683, 0x6f01, 1),
+    (1684, 0x79a6, 1),
+    (1685, 0x9b5a, 1),
+    (1686, 0x4ea8, 1),
+    (1687, 0x4eab, 2),
+    (1689, 0x4f9b, 1),
+    (1690, 0x4fa0, 1),
+    (1691, 0x50d1, 1),
+    (1692, 0x5147, 1),
+    (1693, 0x7af6, 1),
+    (1694, 0x5171, 1),
+    (1695, 0x51f6, 1),
+    (1696, 0x5354, 1),
+    (1697, 0x5321, 1),
+    (1698, 0x537f, 1),
+    (1699, 0x53eb, 1),
+    (1700, 0x55ac, 1),
+    (1701, 0x5883, 1),
+    (1702, 0x5ce1, 1),
+    (1703, 0x5f37, 1),
+    (1704, 0x5f4a, 1),
+    (1705, 0x602f, 1),
+    (1706, 0x6050, 1),
+    (1707, 0x606d, 1),
+    (1708, 0x631f, 1),
+    (1709, 0x6559, 1),
+    (1710, 0x6a4b, 1),
+    (1711, 0x6cc1, 1),
+    (1712, 0x72c2, 1),
+    (1713, 0x72ed, 1),
+    (1714, 0x77ef, 1),
+    (1715, 0x80f8, 1),
+    (1716, 0x8105, 1),
+    (1717, 0x8208, 1),
+    (1718, 0x854e, 1),
+    (1719, 0x90f7, 1),
+    (1720, 0x93e1, 1),
+    (1721, 0x97ff, 1),
+    (1722, 0x9957, 1),
+    (1723, 0x9a5a, 1),
+    (1724, 0x4ef0, 1),
+    (1725, 0x51dd, 1),
+    (1726, 0x5c2d, 1),
+    (1727, 0x6681, 1),
+    (1728, 0x696d, 1),
+    (1729, 0x5c40, 1),
+    (1730, 0x66f2, 1),
+    (1731, 0x6975, 1),
+    (1732, 0x7389, 1),
+    (1733, 0x6850, 1),
+    (1734, 0x7c81, 1),
+    (1735, 0x50c5, 1),
+    (1736, 0x52e4, 1),
+    (1737, 0x5747, 1),
+    (1738, 0x5dfe, 1),
+    (1739, 0x9326, 1),
+    (1740, 0x65a4, 1),
+    (1741, 0x6b23, 1),
+    (1742, 0x6b3d, 1),
+    (1743, 0x7434, 1),
+    (1744, 0x7981, 1),
+    (1745, 0x79bd, 1),
+    (1746, 0x7b4b, 1),
+    (1747, 0x7dca, 1),
+    (1748, 0x82b9, 1),
+    (1749, 0x83cc, 1),
+    (1750, 0x887f, 1),
+    (1751, 0x895f, 1),
+    (1752, 0x8b39, 1),
+    (1753, 0x8fd1, 1),
+    (1754, 0x91d1, 1),
+    (1755, 0x541f, 1),
+    (1756, 0x9280, 1),
+    (1757, 0x4e5d, 1),
+    (1758, 0x5036, 1),
+    (1759, 0x53e5, 1),
+    (1760, 0x533a, 1),
+    (1761, 0x72d7, 1),
+    (1762, 0x7396, 1),
+    (1763, 0x77e9, 1),
+    (1764, 0x82e6, 1),
+    (1765, 0x8eaf, 1),
+    (1766, 0x99c6, 1),
+    (1767, 0x99c8, 1),
+    (1768, 0x99d2, 1),
+    (1769, 0x5177, 1),
+    (1770, 0x611a, 1),
+    (1771, 0x865e, 1),
+    (1772, 0x55b0, 1),
+    (1773, 0x7a7a, 1),
+    (1774, 0x5076, 1),
+    (1775, 0x5bd3, 1),
+    (1776, 0x9047, 1),
+    (1777, 0x9685, 1),
+    (1778, 0x4e32, 1),
+    (1779, 0x6adb, 1),
+    (1780, 0x91e7, 1),
+    (1781, 0x5c51, 1),
+    (1782, 0x5c48, 1),
+    (1783, 0x6398, 1),
+    (1784, 0x7a9f, 1),
+    (1785, 0x6c93, 1),
+    (1786, 0x9774, 1),
+    (1787, 0x8f61, 1),
+    (1788, 0x7aaa, 1),
+    (1789, 0x718a, 1),
+    (1790, 0x9688, 1),
+    (1791, 0x7c82, 1),
+    (1792, 0x6817, 1),
+    (1793, 0x7e70, 1),
+    (1794, 0x6851, 1),
+    (1795, 0x936c, 1),
+    (1796, 0x52f2, 1),
+    (1797, 0x541b, 1),
+    (1798, 0x85ab, 1),
+    (1799, 0x8a13, 1),
+    (1800, 0x7fa4, 1),
+    (1801, 0x8ecd, 1),
+    (1802, 0x90e1, 1),
+    (1803, 0x5366, 1),
+    (1804, 0x8888, 1),
+    (1805, 0x7941, 1),
+    (1806, 0x4fc2, 1),
+    (1807, 0x50be, 1),
+    (1808, 0x5211, 1),
+    (1809, 0x5144, 1),
+    (1810, 0x5553, 1),
+    (1811, 0x572d, 1),
+    (1812, 0x73ea, 1),
+    (1813, 0x578b, 1),
+    (1814, 0x5951, 1),
+    (1815, 0x5f62, 1),
+    (1816, 0x5f84, 1),
+    (1817, 0x6075, 1),
+    (1818, 0x6176, 1),
+    (1819, 0x6167, 1),
+    (1820, 0x61a9, 1),
+    (1821, 0x63b2, 1),
+    (1822, 0x643a, 1),
+    (1823, 0x656c, 1),
+    (1824, 0x666f, 1),
+    (1825, 0x6842, 1),
+    (1826, 0x6e13, 1),
+    (1827, 0x7566, 1),
+    (1828, 0x7a3d, 1),
+    (1829, 0x7cfb, 1),
+    (1830, 0x7d4c, 1),
+    (1831, 0x7d99, 1),
+    (1832, 0x7e4b, 1),
+    (1833, 0x7f6b, 1),
+    (1834, 0x830e, 1),
+    (1835, 0x834a, 1),
+    (1836, 0x86cd, 1),
+    (1837, 0x8a08, 1),
+    (1838, 0x8a63, 1),
+    (1839, 0x8b66, 1),
+    (1840, 0x8efd, 1),
+    (1841, 0x981a, 1),
+    (1842, 0x9d8f, 1),
+    (1843, 0x82b8, 1),
+    (1844, 0x8fce, 1),
+    (1845, 0x9be8, 1),
+    (1846, 0x5287, 1),
+    (1847, 0x621f, 1),
+    (1848, 0x6483, 1),
+    (1849, 0x6fc0, 1),
+    (1850, 0x9699, 1),
+    (1851, 0x6841, 1),
+    (1852, 0x5091, 1),
+    (1853, 0x6b20, 1),
+    (1854, 0x6c7a, 1),
+    (1855, 0x6f54, 1),
+    (1856, 0x7a74, 1),
+    (1857, 0x7d50, 1),
+    (1858, 0x8840, 1),
+    (1859, 0x8a23, 1),
+    (1860, 0x6708, 1),
+    (1861, 0x4ef6, 1),
+    (1862, 0x5039, 1),
+    (1863, 0x5026, 1),
+    (1864, 0x5065, 1),
+    (1865, 0x517c, 1),
+    (1866, 0x5238, 1),
+    (1867, 0x5263, 1),
+    (1868, 0x55a7, 1),
+    (1869, 0x570f, 1),
+    (1870, 0x5805, 1),
+    (1871, 0x5acc, 1),
+    (1872, 0x5efa, 1),
+    (1873, 0x61b2, 1),
+    (1874, 0x61f8, 1),
+    (1875, 0x62f3, 1),
+    (1876, 0x6372, 1),
+    (1877, 0x691c, 1),
+    (1878, 0x6a29, 1),
+    (1879, 0x727d, 1),
+    (1880, 0x72ac, 1),
+    (1881, 0x732e, 1),
+    (1882, 0x7814, 1),
+    (1883, 0x786f, 1),
+    (1884, 0x7d79, 1),
+    (1885, 0x770c, 1),
+    (1886, 0x80a9, 1),
+    (1887, 0x898b, 1),
+    (1888, 0x8b19, 1),
+    (1889, 0x8ce2, 1),
+    (1890, 0x8ed2, 1),
+    (1891, 0x9063, 1),
+    (1892, 0x9375, 1),
+    (1893, 0x967a, 1),
+    (1894, 0x9855, 1),
+    (1895, 0x9a13, 1),
+    (1896, 0x9e78, 1),
+    (1897, 0x5143, 1),
+    (1898, 0x539f, 1),
+    (1899, 0x53b3, 1),
+    (1900, 0x5e7b, 1),
+    (1901, 0x5f26, 1),
+    (1902, 0x6e1b, 1),
+    (1903, 0x6e90, 1),
+    (1904, 0x7384, 1),
+    (1905, 0x73fe, 1),
+    (1906, 0x7d43, 1),
+    (1907, 0x8237, 1),
+    (1908, 0x8a00, 1),
+    (1909, 0x8afa, 1),
+    (1910, 0x9650, 1),
+    (1911, 0x4e4e, 1),
+    (1912, 0x500b, 1),
+    (1913, 0x53e4, 1),
+    (1914, 0x547c, 1),
+    (1915, 0x56fa, 1),
+    (1916, 0x59d1, 1),
+    (1917, 0x5b64, 1),
+    (1918, 0x5df1, 1),
+    (1919, 0x5eab, 1),
+    (1920, 0x5f27, 1),
+    (1921, 0x6238, 1),
+    (1922, 0x6545, 1),
+    (1923, 0x67af, 1),
+    (1924, 0x6e56, 1),
+    (1925, 0x72d0, 1),
+    (1926, 0x7cca, 1),
+    (1927, 0x88b4, 1),
+    (1928, 0x80a1, 1),
+    (1929, 0x80e1, 1),
+    (1930, 0x83f0, 1),
+    (1931, 0x864e, 1),
+    (1932, 0x8a87, 1),
+    (1933, 0x8de8, 1),
+    (1934, 0x9237, 1),
+    (1935, 0x96c7, 1),
+    (1936, 0x9867, 1),
+    (1937, 0x9f13, 1),
+    (1938, 0x4e94, 1),
+    (1939, 0x4e92, 1),
+    (1940, 0x4f0d, 1),
+    (1941, 0x5348, 1),
+    (1942, 0x5449, 1),
+    (1943, 0x543e, 1),
+    (1944, 0x5a2f, 1),
+    (1945, 0x5f8c, 1),
+    (1946, 0x5fa1, 1),
+    (1947, 0x609f, 1),
+    (1948, 0x68a7, 1),
+    (1949, 0x6a8e, 1),
+    (1950, 0x745a, 1),
+    (1951, 0x7881, 1),
+    (1952, 0x8a9e, 1),
+    (1953, 0x8aa4, 1),
+    (1954, 0x8b77, 1),
+    (1955, 0x9190, 1),
+    (1956, 0x4e5e, 1),
+    (1957, 0x9bc9, 1),
+    (1958, 0x4ea4, 1),
+    (1959, 0x4f7c, 1),
+    (1960, 0x4faf, 1),
+    (1961, 0x5019, 1),
+    (1962, 0x5016, 1),
+    (1963, 0x5149, 1),
+    (1964, 0x516c, 1),
+    (1965, 0x529f, 1),
+    (1966, 0x52b9, 1),
+    (1967, 0x52fe, 1),
+    (1968, 0x539a, 1),
+    (1969, 0x53e3, 1),
+    (1970, 0x5411, 1),
+    (1971, 0x540e, 1),
+    (1972, 0x5589, 1),
+    (1973, 0x5751, 1),
+    (1974, 0x57a2, 1),
+    (1975, 0x597d, 1),
+    (1976, 0x5b54, 1),
+    (1977, 0x5b5d, 1),
+    (1978, 0x5b8f, 1),
+    (1979, 0x5de5, 1),
+    (1980, 0x5de7, 1),
+    (1981, 0x5df7, 1),
+    (1982, 0x5e78, 1),
+    (1983, 0x5e83, 1),
+    (1984, 0x5e9a, 1),
+    (1985, 0x5eb7, 1),
+    (1986, 0x5f18, 1),
+    (1987, 0x6052, 1),
+    (1988, 0x614c, 1),
+    (1989, 0x6297, 1),
+    (1990, 0x62d8, 1),
+    (1991, 0x63a7, 1),
+    (1992, 0x653b, 1),
+    (1993, 0x6602, 1),
+    (1994, 0x6643, 1),
+    (1995, 0x66f4, 1),
+    (1996, 0x676d, 1),
+    (1997, 0x6821, 1),
+    (1998, 0x6897, 1),
+    (1999, 0x69cb, 1),
+    (2000, 0x6c5f, 1),
+    (2001, 0x6d2a, 1),
+    (2002, 0x6d69, 1),
+    (2003, 0x6e2f, 1),
+    (2004, 0x6e9d, 1),
+    (2005, 0x7532, 1),
+    (2006, 0x7687, 1),
+    (2007, 0x786c, 1),
+    (2008, 0x7a3f, 1),
+    (2009, 0x7ce0, 1),
+    (2010, 0x7d05, 1),
+    (2011, 0x7d18, 1),
+    (2012, 0x7d5e, 1),
+    (2013, 0x7db1, 1),
+    (2014, 0x8015, 1),
+    (2015, 0x8003, 1),
+    (2016, 0x80af, 1),
+    (2017, 0x80b1, 1),
+    (2018, 0x8154, 1),
+    (2019, 0x818f, 1),
+    (2020, 0x822a, 1),
+    (2021, 0x8352, 1),
+    (2022, 0x884c, 1),
+    (2023, 0x8861, 1),
+    (2024, 0x8b1b, 1),
+    (2025, 0x8ca2, 1),
+    (2026, 0x8cfc, 1),
+    (2027, 0x90ca, 1),
+    (2028, 0x9175, 1),
+    (2029, 0x9271, 1),
+    (2030, 0x783f, 1),
+    (2031, 0x92fc, 1),
+    (2032, 0x95a4, 1),
+    (2033, 0x964d, 1),
+    (2034, 0x9805, 1),
+    (2035, 0x9999, 1),
+    (2036, 0x9ad8, 1),
+    (2037, 0x9d3b, 1),
+    (2038, 0x525b, 1),
+    (2039, 0x52ab, 1),
+    (2040, 0x53f7, 1),
+    (2041, 0x5408, 1),
+    (2042, 0x58d5, 1),
+    (2043, 0x62f7, 1),
+    (2044, 0x6fe0, 1),
+    (2045, 0x8c6a, 1),
+    (2046, 0x8f5f, 1),
+    (2047, 0x9eb9, 1),
+    (2048, 0x514b, 1),
+    (2049, 0x523b, 1),
+    (2050, 0x544a, 1),
+    (2051, 0x56fd, 1),
+    (2052, 0x7a40, 1),
+    (2053, 0x9177, 1),
+    (2054, 0x9d60, 1),
+    (2055, 0x9ed2, 1),
+    (2056, 0x7344, 1),
+    (2057, 0x6f09, 1),
+    (2058, 0x8170, 1),
+    (2059, 0x7511, 1),
+    (2060, 0x5ffd, 1),
+    (2061, 0x60da, 1),
+    (2062, 0x9aa8, 1),
+    (2063, 0x72db, 1),
+    (2064, 0x8fbc, 1),
+    (2065, 0x6b64, 1),
+    (2066, 0x9803, 1),
+    (2067, 0x4eca, 1),
+    (2068, 0x56f0, 1),
+    (2069, 0x5764, 1),
+    (2070, 0x58be, 1),
+    (2071, 0x5a5a, 1),
+    (2072, 0x6068, 1),
+    (2073, 0x61c7, 1),
+    (2074, 0x660f, 1),
+    (2075, 0x6606, 1),
+    (2076, 0x6839, 1),
+    (2077, 0x68b1, 1),
+    (2078, 0x6df7, 1),
+    (2079, 0x75d5, 1),
+    (2080, 0x7d3a, 1),
+    (2081, 0x826e, 1),
+    (2082, 0x9b42, 1),
+    (2083, 0x4e9b, 1),
+    (2084, 0x4f50, 1),
+    (2085, 0x53c9, 1),
+    (2086, 0x5506, 1),
+    (2087, 0x5d6f, 1),
+    (2088, 0x5de6, 1),
+    (2089, 0x5dee, 1),
+    (2090, 0x67fb, 1),
+    (2091, 0x6c99, 1),
+    (2092, 0x7473, 1),
+    (2093, 0x7802, 1),
+    (2094, 0x8a50, 1),
+    (2095, 0x9396, 1),
+    (2096, 0x88df, 1),
+    (2097, 0x5750, 1),
+    (2098, 0x5ea7, 1),
+    (2099, 0x632b, 1),
+    (2100, 0x50b5, 1),
+    (2101, 0x50ac, 1),
+    (2102, 0x518d, 1),
+    (2103, 0x6700, 1),
+    (2104, 0x54c9, 1),
+    (2105, 0x585e, 1),
+    (2106, 0x59bb, 1),
+    (2107, 0x5bb0, 1),
+    (2108, 0x5f69, 1),
+    (2109, 0x624d, 1),
+    (2110, 0x63a1, 1),
+    (2111, 0x683d, 1),
+    (2112, 0x6b73, 1),
+    (2113, 0x6e08, 1),
+    (2114, 0x707d, 1),
+    (2115, 0x91c7, 1),
+    (2116, 0x7280, 1),
+    (2117, 0x7815, 1),
+    (2118, 0x7826, 1),
+    (2119, 0x796d, 1),
+    (2120, 0x658e, 1),
+    (2121, 0x7d30, 1),
+    (2122, 0x83dc, 1),
+    (2123, 0x88c1, 1),
+    (2124, 0x8f09, 1),
+    (2125, 0x969b, 1),
+    (2126, 0x5264, 1),
+    (2127, 0x5728, 1),
+    (2128, 0x6750, 1),
+    (2129, 0x7f6a, 1),
+    (2130, 0x8ca1, 1),
+    (2131, 0x51b4, 1),
+    (2132, 0x5742, 1),
+    (2133, 0x962a, 1),
+    (2134, 0x583a, 1),
+    (2135, 0x698a, 1),
+    (2136, 0x80b4, 1),
+    (2137, 0x54b2, 1),
+    (2138, 0x5d0e, 1),
+    (2139, 0x57fc, 1),
+    (2140, 0x7895, 1),
+    (2141, 0x9dfa, 1),
+    (2142, 0x4f5c, 1),
+    (2143, 0x524a, 1),
+    (2144, 0x548b, 1),
+    (2145, 0x643e, 1),
+    (2146, 0x6628, 1),
+    (2147, 0x6714, 1),
+    (2148, 0x67f5, 1),
+    (2149, 0x7a84, 1),
+    (2150, 0x7b56, 1),
+    (2151, 0x7d22, 1),
+    (2152, 0x932f, 1),
+    (2153, 0x685c, 1),
+    (2154, 0x9bad, 1),
+    (2155, 0x7b39, 1),
+    (2156, 0x5319, 1),
+    (2157, 0x518a, 1),
+    (2158, 0x5237, 1),
+    (2159, 0x5bdf, 1),
+    (2160, 0x62f6, 1),
+    (2161, 0x64ae, 1),
+    (2162, 0x64e6, 1),
+    (2163, 0x672d, 1),
+    (2164, 0x6bba, 1),
+    (2165, 0x85a9, 1),
+    (2166, 0x96d1, 1),
+    (2167, 0x7690, 1),
+    (2168, 0x9bd6, 1),
+    (2169, 0x634c, 1),
+    (2170, 0x9306, 1),
+    (2171, 0x9bab, 1),
+    (2172, 0x76bf, 1),
+    (2173, 0x6652, 1),
+    (2174, 0x4e09, 1),
+    (2175, 0x5098, 1),
+    (2176, 0x53c2, 1),
+    (2177, 0x5c71, 1),
+    (2178, 0x60e8, 1),
+    (2179, 0x6492, 1),
+    (2180, 0x6563, 1),
+    (2181, 0x685f, 1),
+    (2182, 0x71e6, 1),
+    (2183, 0x73ca, 1),
+    (2184, 0x7523, 1),
+    (2185, 0x7b97, 1),
+    (2186, 0x7e82, 1),
+    (2187, 0x8695, 1),
+    (2188, 0x8b83, 1),
+    (2189, 0x8cdb, 1),
+    (2190, 0x9178, 1),
+    (2191, 0x9910, 1),
+    (2192, 0x65ac, 1),
+    (2193, 0x66ab, 1),
+    (2194, 0x6b8b, 1),
+    (2195, 0x4ed5, 1),
+    (2196, 0x4ed4, 1),
+    (2197, 0x4f3a, 1),
+    (2198, 0x4f7f, 1),
+    (2199, 0x523a, 1),
+    (2200, 0x53f8, 1),
+    (2201, 0x53f2, 1),
+    (2202, 0x55e3, 1),
+    (2203, 0x56db, 1),
+    (2204, 0x58eb, 1),
+    (2205, 0x59cb, 1),
+    (2206, 0x59c9, 1),
+    (2207, 0x59ff, 1),
+    (2208, 0x5b50, 1),
+    (2209, 0x5c4d, 1),
+    (2210, 0x5e02, 1),
+    (2211, 0x5e2b, 1),
+    (2212, 0x5fd7, 1),
+    (2213, 0x601d, 1),
+    (2214, 0x6307, 1),
+    (2215, 0x652f, 1),
+    (2216, 0x5b5c, 1),
+    (2217, 0x65af, 1),
+    (2218, 0x65bd, 1),
+    (2219, 0x65e8, 1),
+    (2220, 0x679d, 1),
+    (2221, 0x6b62, 1),
+    (2222, 0x6b7b, 1),
+    (2223, 0x6c0f, 1),
+    (2224, 0x7345, 1),
+    (2225, 0x7949, 1),
+    (2226, 0x79c1, 1),
+    (2227, 0x7cf8, 1),
+    (2228, 0x7d19, 1),
+    (2229, 0x7d2b, 1),
+    (2230, 0x80a2, 1),
+    (2231, 0x8102, 1),
+    (2232, 0x81f3, 1),
+    (2233, 0x8996, 1),
+    (2234, 0x8a5e, 1),
+    (2235, 0x8a69, 1),
+    (2236, 0x8a66, 1),
+    (2237, 0x8a8c, 1),
+    (2238, 0x8aee, 1),
+    (2239, 0x8cc7, 1),
+    (2240, 0x8cdc, 1),
+    (2241, 0x96cc, 1),
+    (2242, 0x98fc, 1),
+    (2243, 0x6b6f, 1),
+    (2244, 0x4e8b, 1),
+    (2245, 0x4f3c, 1),
+    (2246, 0x4f8d, 1),
+    (2247, 0x5150, 1),
+    (2248, 0x5b57, 1),
+    (2249, 0x5bfa, 1),
+    (2250, 0x6148, 1),
+    (2251, 0x6301, 1),
+    (2252, 0x6642, 1),
+    (2253, 0x6b21, 1),
+    (2254, 0x6ecb, 1),
+    (2255, 0x6cbb, 1),
+    (2256, 0x723e, 1),
+    (2257, 0x74bd, 1),
+    (2258, 0x75d4, 1),
+    (2259, 0x78c1, 1),
+    (2260, 0x793a, 1),
+    (2261, 0x800c, 1),
+    (2262, 0x8033, 1),
+    (2263, 0x81ea, 1),
+    (2264, 0x8494, 1),
+    (2265, 0x8f9e, 1),
+    (2266, 0x6c50, 1),
+    (2267, 0x9e7f, 1),
+    (2268, 0x5f0f, 1),
+    (2269, 0x8b58, 1),
+    (2270, 0x9d2b, 1),
+    (2271, 0x7afa, 1),
+    (2272, 0x8ef8, 1),
+    (2273, 0x5b8d, 1),
+    (2274, 0x96eb, 1),
+    (2275, 0x4e03, 1),
+    (2276, 0x53f1, 1),
+    (2277, 0x57f7, 1),
+    (2278, 0x5931, 1),
+    (2279, 0x5ac9, 1),
+    (2280, 0x5ba4, 1),
+    (2281, 0x6089, 1),
+    (2282, 0x6e7f, 1),
+    (2283, 0x6f06, 1),
+    (2284, 0x75be, 1),
+    (2285, 0x8cea, 1),
+    (2286, 0x5b9f, 1),
+    (2287, 0x8500, 1),
+    (2288, 0x7be0, 1),
+    (2289, 0x5072, 1),
+    (2290, 0x67f4, 1),
+    (2291, 0x829d, 1),
+    (2292, 0x5c61, 1),
+    (2293, 0x854a, 1),
+    (2294, 0x7e1e, 1),
+    (2295, 0x820e, 1),
+    (2296, 0x5199, 1),
+    (2297, 0x5c04, 1),
+    (2298, 0x6368, 1),
+    (2299, 0x8d66, 1),
+    (2300, 0x659c, 1),
+    (2301, 0x716e, 1),
+    (2302, 0x793e, 1),
+    (2303, 0x7d17, 1),
+    (2304, 0x8005, 1),
+    (2305, 0x8b1d, 1),
+    (2306, 0x8eca, 1),
+    (2307, 0x906e, 1),
+    (2308, 0x86c7, 1),
+    (2309, 0x90aa, 1),
+    (2310, 0x501f, 1),
+    (2311, 0x52fa, 1),
+    (2312, 0x5c3a, 1),
+    (2313, 0x6753, 1),
+    (2314, 0x707c, 1),
+    (2315, 0x7235, 1),
+    (2316, 0x914c, 1),
+    (2317, 0x91c8, 1),
+    (2318, 0x932b, 1),
+    (2319, 0x82e5, 1),
+    (2320, 0x5bc2, 1),
+    (2321, 0x5f31, 1),
+    (2322, 0x60f9, 1),
+    (2323, 0x4e3b, 1),
+    (2324, 0x53d6, 1),
+    (2325, 0x5b88, 1),
+    (2326, 0x624b, 1),
+    (2327, 0x6731, 1),
+    (2328, 0x6b8a, 1),
+    (2329, 0x72e9, 1),
+    (2330, 0x73e0, 1),
+    (2331, 0x7a2e, 1),
+    (2332, 0x816b, 1),
+    (2333, 0x8da3, 1),
+    (2334, 0x9152, 1),
+    (2335, 0x9996, 1),
+    (2336, 0x5112, 1),
+    (2337, 0x53d7, 1),
+    (2338, 0x546a, 1),
+    (2339, 0x5bff, 1),
+    (2340, 0x6388, 1),
+    (2341, 0x6a39, 1),
+    (2342, 0x7dac, 1),
+    (2343, 0x9700, 1),
+    (2344, 0x56da, 1),
+    (2345, 0x53ce, 1),
+    (2346, 0x5468, 1),
+    (2347, 0x5b97, 1),
+    (2348, 0x5c31, 1),
+    (2349, 0x5dde, 1),
+    (2350, 0x4fee, 1),
+    (2351, 0x6101, 1),
+    (2352, 0x62fe, 1),
+    (2353, 0x6d32, 1),
+    (2354, 0x79c0, 1),
+    (2355, 0x79cb, 1),
+    (2356, 0x7d42, 1),
+    (2357, 0x7e4d, 1),
+    (2358, 0x7fd2, 1),
+    (2359, 0x81ed, 1),
+    (2360, 0x821f, 1),
+    (2361, 0x8490, 1),
+    (2362, 0x8846, 1),
+    (2363, 0x8972, 1),
+    (2364, 0x8b90, 1),
+    (2365, 0x8e74, 1),
+    (2366, 0x8f2f, 1),
+    (2367, 0x9031, 1),
+    (2368, 0x914b, 1),
+    (2369, 0x916c, 1),
+    (2370, 0x96c6, 1),
+    (2371, 0x919c, 1),
+    (2372, 0x4ec0, 1),
+    (2373, 0x4f4f, 1),
+    (2374, 0x5145, 1),
+    (2375, 0x5341, 1),
+    (2376, 0x5f93, 1),
+    (2377, 0x620e, 1),
+    (2378, 0x67d4, 1),
+    (2379, 0x6c41, 1),
+    (2380, 0x6e0b, 1),
+    (2381, 0x7363, 1),
+    (2382, 0x7e26, 1),
+    (2383, 0x91cd, 1),
+    (2384, 0x9283, 1),
+    (2385, 0x53d4, 1),
+    (2386, 0x5919, 1),
+    (2387, 0x5bbf, 1),
+    (2388, 0x6dd1, 1),
+    (2389, 0x795d, 1),
+    (2390, 0x7e2e, 1),
+    (2391, 0x7c9b, 1),
+    (2392, 0x587e, 1),
+    (2393, 0x719f, 1),
+    (2394, 0x51fa, 1),
+    (2395, 0x8853, 1),
+    (2396, 0x8ff0, 1),
+    (2397, 0x4fca, 1),
+    (2398, 0x5cfb, 1),
+    (2399, 0x6625, 1),
+    (2400, 0x77ac, 1),
+    (2401, 0x7ae3, 1),
+    (2402, 0x821c, 1),
+    (2403, 0x99ff, 1),
+    (2404, 0x51c6, 1),
+    (2405, 0x5faa, 1),
+    (2406, 0x65ec, 1),
+    (2407, 0x696f, 1),
+    (2408, 0x6b89, 1),
+    (2409, 0x6df3, 1),
+    (2410, 0x6e96, 1),
+    (2411, 0x6f64, 1),
+    (2412, 0x76fe, 1),
+    (2413, 0x7d14, 1),
+    (2414, 0x5de1, 1),
+    (2415, 0x9075, 1),
+    (2416, 0x9187, 1),
+    (2417, 0x9806, 1),
+    (2418, 0x51e6, 1),
+    (2419, 0x521d, 1),
+    (2420, 0x6240, 1),
+    (2421, 0x6691, 1),
+    (2422, 0x66d9, 1),
+    (2423, 0x6e1a, 1),
+    (2424, 0x5eb6, 1),
+    (2425, 0x7dd2, 1),
+    (2426, 0x7f72, 1),
+    (2427, 0x66f8, 1),
+    (2428, 0x85af, 1),
+    (2429, 0x85f7, 1),
+    (2430, 0x8af8, 1),
+    (2431, 0x52a9, 1),
+    (2432, 0x53d9, 1),
+    (2433, 0x5973, 1),
+    (2434, 0x5e8f, 1),
+    (2435, 0x5f90, 1),
+    (2436, 0x6055, 1),
+    (2437, 0x92e4, 1),
+    (2438, 0x9664, 1),
+    (2439, 0x50b7, 1),
+    (2440, 0x511f, 1),
+    (2441, 0x52dd, 1),
+    (2442, 0x5320, 1),
+    (2443, 0x5347, 1),
+    (2444, 0x53ec, 1),
+    (2445, 0x54e8, 1),
+    (2446, 0x5546, 1),
+    (2447, 0x5531, 1),
+    (2448, 0x5617, 1),
+    (2449, 0x5968, 1),
+    (2450, 0x59be, 1),
+    (2451, 0x5a3c, 1),
+    (2452, 0x5bb5, 1),
+    (2453, 0x5c06, 1),
+    (2454, 0x5c0f, 1),
+    (2455, 0x5c11, 1),
+    (2456, 0x5c1a, 1),
+    (2457, 0x5e84, 1),
+    (2458, 0x5e8a, 1),
+    (2459, 0x5ee0, 1),
+    (2460, 0x5f70, 1),
+    (2461, 0x627f, 1),
+    (2462, 0x6284, 1),
+    (2463, 0x62db, 1),
+    (2464, 0x638c, 1),
+    (2465, 0x6377, 1),
+    (2466, 0x6607, 1),
+    (2467, 0x660c, 1),
+    (2468, 0x662d, 1),
+    (2469, 0x6676, 1),
+    (2470, 0x677e, 1),
+    (2471, 0x68a2, 1),
+    (2472, 0x6a1f, 1),
+    (2473, 0x6a35, 1),
+    (2474, 0x6cbc, 1),
+    (2475, 0x6d88, 1),
+    (2476, 0x6e09, 1),
+    (2477, 0x6e58, 1),
+    (2478, 0x713c, 1),
+    (2479, 0x7126, 1),
+    (2480, 0x7167, 1),
+    (2481, 0x75c7, 1),
+    (2482, 0x7701, 1),
+    (2483, 0x785d, 1),
+    (2484, 0x7901, 1),
+    (2485, 0x7965, 1),
+    (2486, 0x79f0, 1),
+    (2487, 0x7ae0, 1),
+    (2488, 0x7b11, 1),
+    (2489, 0x7ca7, 1),
+    (2490, 0x7d39, 1),
+    (2491, 0x8096, 1),
+    (2492, 0x83d6, 1),
+    (2493, 0x848b, 1),
+    (2494, 0x8549, 1),
+    (2495, 0x885d, 1),
+    (2496, 0x88f3, 1),
+    (2497, 0x8a1f, 1),
+    (2498, 0x8a3c, 1),
+    (2499, 0x8a54, 1),
+    (2500, 0x8a73, 1),
+    (2501, 0x8c61, 1),
+    (2502, 0x8cde, 1),
+    (2503, 0x91a4, 1),
+    (2504, 0x9266, 1),
+    (2505, 0x937e, 1),
+    (2506, 0x9418, 1),
+    (2507, 0x969c, 1),
+    (2508, 0x9798, 1),
+    (2509, 0x4e0a, 1),
+    (2510, 0x4e08, 1),
+    (2511, 0x4e1e, 1),
+    (2512, 0x4e57, 1),
+    (2513, 0x5197, 1),
+    (2514, 0x5270, 1),
+    (2515, 0x57ce, 1),
+    (2516, 0x5834, 1),
+    (2517, 0x58cc, 1),
+    (2518, 0x5b22, 1),
+    (2519, 0x5e38, 1),
+    (2520, 0x60c5, 1),
+    (2521, 0x64fe, 1),
+    (2522, 0x6761, 1),
+    (2523, 0x6756, 1),
+    (2524, 0x6d44, 1),
+    (2525, 0x72b6, 1),
+    (2526, 0x7573, 1),
+    (2527, 0x7a63, 1),
+    (2528, 0x84b8, 1),
+    (2529, 0x8b72, 1),
+    (2530, 0x91b8, 1),
+    (2531, 0x9320, 1),
+    (2532, 0x5631, 1),
+    (2533, 0x57f4, 1),
+    (2534, 0x98fe, 1),
+    (2535, 0x62ed, 1),
+    (2536, 0x690d, 1),
+    (2537, 0x6b96, 1),
+    (2538, 0x71ed, 1),
+    (2539, 0x7e54, 1),
+    (2540, 0x8077, 1),
+    (2541, 0x8272, 1),
+    (2542, 0x89e6, 1),
+    (2543, 0x98df, 1),
+    (2544, 0x8755, 1),
+    (2545, 0x8fb1, 1),
+    (2546, 0x5c3b, 1),
+    (2547, 0x4f38, 1),
+    (2548, 0x4fe1, 1),
+    (2549, 0x4fb5, 1),
+    (2550, 0x5507, 1),
+    (2551, 0x5a20, 1),
+    (2552, 0x5bdd, 1),
+    (2553, 0x5be9, 1),
+    (2554, 0x5fc3, 1),
+    (2555, 0x614e, 1),
+    (2556, 0x632f, 1),
+    (2557, 0x65b0, 1),
+    (2558, 0x664b, 1),
+    (2559, 0x68ee, 1),
+    (2560, 0x699b, 1),
+    (2561, 0x6d78, 1),
+    (2562, 0x6df1, 1),
+    (2563, 0x7533, 1),
+    (2564, 0x75b9, 1),
+    (2565, 0x771f, 1),
+    (2566, 0x795e, 1),
+    (2567, 0x79e6, 1),
+    (2568, 0x7d33, 1),
+    (2569, 0x81e3, 1),
+    (2570, 0x82af, 1),
+    (2571, 0x85aa, 1),
+    (2572, 0x89aa, 1),
+    (2573, 0x8a3a, 1),
+    (2574, 0x8eab, 1),
+    (2575, 0x8f9b, 1),
+    (2576, 0x9032, 1),
+    (2577, 0x91dd, 1),
+    (2578, 0x9707, 1),
+    (2579, 0x4eba, 1),
+    (2580, 0x4ec1, 1),
+    (2581, 0x5203, 1),
+    (2582, 0x5875, 1),
+    (2583, 0x58ec, 1),
+    (2584, 0x5c0b, 1),
+    (2585, 0x751a, 1),
+    (2586, 0x5c3d, 1),
+    (2587, 0x814e, 1),
+    (2588, 0x8a0a, 1),
+    (2589, 0x8fc5, 1),
+    (2590, 0x9663, 1),
+    (2591, 0x976d, 1),
+    (2592, 0x7b25, 1),
+    (2593, 0x8acf, 1),
+    (2594, 0x9808, 1),
+    (2595, 0x9162, 1),
+    (2596, 0x56f3, 1),
+    (2597, 0x53a8, 1),
+    (2598, 0x9017, 1),
+    (2599, 0x5439, 1),
+    (2600, 0x5782, 1),
+    (2601, 0x5e25, 1),
+    (2602, 0x63a8, 1),
+    (2603, 0x6c34, 1),
+    (2604, 0x708a, 1),
+    (2605, 0x7761, 1),
+    (2606, 0x7c8b, 1),
+    (2607, 0x7fe0, 1),
+    (2608, 0x8870, 1),
+    (2609, 0x9042, 1),
+    (2610, 0x9154, 1),
+    (2611, 0x9310, 1),
+    (2612, 0x9318, 1),
+    (2613, 0x968f, 1),
+    (2614, 0x745e, 1),
+    (2615, 0x9ac4, 1),
+    (2616, 0x5d07, 1),
+    (2617, 0x5d69, 1),
+    (2618, 0x6570, 1),
+    (2619, 0x67a2, 1),
+    (2620, 0x8da8, 1),
+    (2621, 0x96db, 1),
+    (2622, 0x636e, 1),
+    (2623, 0x6749, 1),
+    (2624, 0x6919, 1),
+    (2625, 0x83c5, 1),
+    (2626, 0x9817, 1),
+    (2627, 0x96c0, 1),
+    (2628, 0x88fe, 1),
+    (2629, 0x6f84, 1),
+    (2630, 0x647a, 1),
+    (2631, 0x5bf8, 1),
+    (2632, 0x4e16, 1),
+    (2633, 0x702c, 1),
+    (2634, 0x755d, 1),
+    (2635, 0x662f, 1),
+    (2636, 0x51c4, 1),
+    (2637, 0x5236, 1),
+    (2638, 0x52e2, 1),
+    (2639, 0x59d3, 1),
+    (2640, 0x5f81, 1),
+    (2641, 0x6027, 1),
+    (2642, 0x6210, 1),
+    (2643, 0x653f, 1),
+    (2644, 0x6574, 1),
+    (2645, 0x661f, 1),
+    (2646, 0x6674, 1),
+    (2647, 0x68f2, 1),
+    (2648, 0x6816, 1),
+    (2649, 0x6b63, 1),
+    (2650, 0x6e05, 1),
+    (2651, 0x7272, 1),
+    (2652, 0x751f, 1),
+    (2653, 0x76db, 1),
+    (2654, 0x7cbe, 1),
+    (2655, 0x8056, 1),
+    (2656, 0x58f0, 1),
+    (2657, 0x88fd, 1),
+    (2658, 0x897f, 1),
+    (2659, 0x8aa0, 1),
+    (2660, 0x8a93, 1),
+    (2661, 0x8acb, 1),
+    (2662, 0x901d, 1),
+    (2663, 0x9192, 1),
+    (2664, 0x9752, 1),
+    (2665, 0x9759, 1),
+    (2666, 0x6589, 1),
+    (2667, 0x7a0e, 1),
+    (2668, 0x8106, 1),
+    (2669, 0x96bb, 1),
+    (2670, 0x5e2d, 1),
+    (2671, 0x60dc, 1),
+    (2672, 0x621a, 1),
+    (2673, 0x65a5, 1),
+    (2674, 0x6614, 1),
+    (2675, 0x6790, 1),
+    (2676, 0x77f3, 1),
+    (2677, 0x7a4d, 1),
+    (2678, 0x7c4d, 1),
+    (2679, 0x7e3e, 1),
+    (2680, 0x810a, 1),
+    (2681, 0x8cac, 1),
+    (2682, 0x8d64, 1),
+    (2683, 0x8de1, 1),
+    (2684, 0x8e5f, 1),
+    (2685, 0x78a9, 1),
+    (2686, 0x5207, 1),
+    (2687, 0x62d9, 1),
+    (2688, 0x63a5, 1),
+    (2689, 0x6442, 1),
+    (2690, 0x6298, 1),
+    (2691, 0x8a2d, 1),
+    (2692, 0x7a83, 1),
+    (2693, 0x7bc0, 1),
+    (2694, 0x8aac, 1),
+    (2695, 0x96ea, 1),
+    (2696, 0x7d76, 1),
+    (2697, 0x820c, 1),
+    (2698, 0x8749, 1),
+    (2699, 0x4ed9, 1),
+    (2700, 0x5148, 1),
+    (2701, 0x5343, 1),
+    (2702, 0x5360, 1),
+    (2703, 0x5ba3, 1),
+    (2704, 0x5c02, 1),
+    (2705, 0x5c16, 1),
+    (2706, 0x5ddd, 1),
+    (2707, 0x6226, 1),
+    (2708, 0x6247, 1),
+    (2709, 0x64b0, 1),
+    (2710, 0x6813, 1),
+    (2711, 0x6834, 1),
+    (2712, 0x6cc9, 1),
+    (2713, 0x6d45, 1),
+    (2714, 0x6d17, 1),
+    (2715, 0x67d3, 1),
+    (2716, 0x6f5c, 1),
+    (2717, 0x714e, 1),
+    (2718, 0x717d, 1),
+    (2719, 0x65cb, 1),
+    (2720, 0x7a7f, 1),
+    (2721, 0x7bad, 1),
+    (2722, 0x7dda, 1),
+    (2723, 0x7e4a, 1),
+    (2724, 0x7fa8, 1),
+    (2725, 0x817a, 1),
+    (2726, 0x821b, 1),
+    (2727, 0x8239, 1),
+    (2728, 0x85a6, 1),
+    (2729, 0x8a6e, 1),
+    (2730, 0x8cce, 1),
+    (2731, 0x8df5, 1),
+    (2732, 0x9078, 1),
+    (2733, 0x9077, 1),
+    (2734, 0x92ad, 1),
+    (2735, 0x9291, 1),
+    (2736, 0x9583, 1),
+    (2737, 0x9bae, 1),
+    (2738, 0x524d, 1),
+    (2739, 0x5584, 1),
+    (2740, 0x6f38, 1),
+    (2741, 0x7136, 1),
+    (2742, 0x5168, 1),
+    (2743, 0x7985, 1),
+    (2744, 0x7e55, 1),
+    (2745, 0x81b3, 1),
+    (2746, 0x7cce, 1),
+    (2747, 0x564c, 1),
+    (2748, 0x5851, 1),
+    (2749, 0x5ca8, 1),
+    (2750, 0x63aa, 1),
+    (2751, 0x66fe, 1),
+    (2752, 0x66fd, 1),
+    (2753, 0x695a, 1),
+    (2754, 0x72d9, 1),
+    (2755, 0x758f, 1),
+    (2756, 0x758e, 1),
+    (2757, 0x790e, 1),
+    (2758, 0x7956, 1),
+    (2759, 0x79df, 1),
+    (2760, 0x7c97, 1),
+    (2761, 0x7d20, 1),
+    (2762, 0x7d44, 1),
+    (2763, 0x8607, 1),
+    (2764, 0x8a34, 1),
+    (2765, 0x963b, 1),
+    (2766, 0x9061, 1),
+    (2767, 0x9f20, 1),
+    (2768, 0x50e7, 1),
+    (2769, 0x5275, 1),
+    (2770, 0x53cc, 1),
+    (2771, 0x53e2, 1),
+    (2772, 0x5009, 1),
+    (2773, 0x55aa, 1),
+    (2774, 0x58ee, 1),
+    (2775, 0x594f, 1),
+    (2776, 0x723d, 1),
+    (2777, 0x5b8b, 1),
+    (2778, 0x5c64, 1),
+    (2779, 0x531d, 1),
+    (2780, 0x60e3, 1),
+    (2781, 0x60f3, 1),
+    (2782, 0x635c, 1),
+    (2783, 0x6383, 1),
+    (2784, 0x633f, 1),
+    (2785, 0x63bb, 1),
+    (2786, 0x64cd, 1),
+    (2787, 0x65e9, 1),
+    (2788, 0x66f9, 1),
+    (2789, 0x5de3, 1),
+    (2790, 0x69cd, 1),
+    (2791, 0x69fd, 1),
+    (2792, 0x6f15, 1),
+    (2793, 0x71e5, 1),
+    (2794, 0x4e89, 1),
+    (2795, 0x75e9, 1),
+    (2796, 0x76f8, 1),
+    (2797, 0x7a93, 1),
+    (2798, 0x7cdf, 1),
+    (2799, 0x7dcf, 1),
+    (2800, 0x7d9c, 1),
+    (2801, 0x8061, 1),
+    (2802, 0x8349, 1),
+    (2803, 0x8358, 1),
+    (2804, 0x846c, 1),
+    (2805, 0x84bc, 1),
+    (2806, 0x85fb, 1),
+    (2807, 0x88c5, 1),
+    (2808, 0x8d70, 1),
+    (2809, 0x9001, 1),
+    (2810, 0x906d, 1),
+    (2811, 0x9397, 1),
+    (2812, 0x971c, 1),
+    (2813, 0x9a12, 1),
+    (2814, 0x50cf, 1),
+    (2815, 0x5897, 1),
+    (2816, 0x618e, 1),
+    (2817, 0x81d3, 1),
+    (2818, 0x8535, 1),
+    (2819, 0x8d08, 1),
+    (2820, 0x9020, 1),
+    (2821, 0x4fc3, 1),
+    (2822, 0x5074, 1),
+    (2823, 0x5247, 1),
+    (2824, 0x5373, 1),
+    (2825, 0x606f, 1),
+    (2826, 0x6349, 1),
+    (2827, 0x675f, 1),
+    (2828, 0x6e2c, 1),
+    (2829, 0x8db3, 1),
+    (2830, 0x901f, 1),
+    (2831, 0x4fd7, 1),
+    (2832, 0x5c5e, 1),
+    (2833, 0x8cca, 1),
+    (2834, 0x65cf, 1),
+    (2835, 0x7d9a, 1),
+    (2836, 0x5352, 1),
+    (2837, 0x8896, 1),
+    (2838, 0x5176, 1),
+    (2839, 0x63c3, 1),
+    (2840, 0x5b58, 1),
+    (2841, 0x5b6b, 1),
+    (2842, 0x5c0a, 1),
+    (2843, 0x640d, 1),
+    (2844, 0x6751, 1),
+    (2845, 0x905c, 1),
+    (2846, 0x4ed6, 1),
+    (2847, 0x591a, 1),
+    (2848, 0x592a, 1),
+    (2849, 0x6c70, 1),
+    (2850, 0x8a51, 1),
+    (2851, 0x553e, 1),
+    (2852, 0x5815, 1),
+    (2853, 0x59a5, 1),
+    (2854, 0x60f0, 1),
+    (2855, 0x6253, 1),
+    (2856, 0x67c1, 1),
+    (2857, 0x8235, 1),
+    (2858, 0x6955, 1),
+    (2859, 0x9640, 1),
+    (2860, 0x99c4, 1),
+    (2861, 0x9a28, 1),
+    (2862, 0x4f53, 1),
+    (2863, 0x5806, 1),
+    (2864, 0x5bfe, 1),
+    (2865, 0x8010, 1),
+    (2866, 0x5cb1, 1),
+    (2867, 0x5e2f, 1),
+    (2868, 0x5f85, 1),
+    (2869, 0x6020, 1),
+    (2870, 0x614b, 1),
+    (2871, 0x6234, 1),
+    (2872, 0x66ff, 1),
+    (2873, 0x6cf0, 1),
+    (2874, 0x6ede, 1),
+    (2875, 0x80ce, 1),
+    (2876, 0x817f, 1),
+    (2877, 0x82d4, 1),
+    (2878, 0x888b, 1),
+    (2879, 0x8cb8, 1),
+    (2880, 0x9000, 1),
+    (2881, 0x902e, 1),
+    (2882, 0x968a, 1),
+    (2883, 0x9edb, 1),
+    (2884, 0x9bdb, 1),
+    (2885, 0x4ee3, 1),
+    (2886, 0x53f0, 1),
+    (2887, 0x5927, 1),
+    (2888, 0x7b2c, 1),
+    (2889, 0x918d, 1),
+    (2890, 0x984c, 1),
+    (2891, 0x9df9, 1),
+    (2892, 0x6edd, 1),
+    (2893, 0x7027, 1),
+    (2894, 0x5353, 1),
+    (2895, 0x5544, 1),
+    (2896, 0x5b85, 1),
+    (2897, 0x6258, 1),
+    (2898, 0x629e, 1),
+    (2899, 0x62d3, 1),
+    (2900, 0x6ca2, 1),
+    (2901, 0x6fef, 1),
+    (2902, 0x7422, 1),
+    (2903, 0x8a17, 1),
+    (2904, 0x9438, 1),
+    (2905, 0x6fc1, 1),
+    (2906, 0x8afe, 1),
+    (2907, 0x8338, 1),
+    (2908, 0x51e7, 1),
+    (2909, 0x86f8, 1),
+    (2910, 0x53ea, 1),
+    (2911, 0x53e9, 1),
+    (2912, 0x4f46, 1),
+    (2913, 0x9054, 1),
+    (2914, 0x8fb0, 1),
+    (2915, 0x596a, 1),
+    (2916, 0x8131, 1),
+    (2917, 0x5dfd, 1),
+    (2918, 0x7aea, 1),
+    (2919, 0x8fbf, 1),
+    (2920, 0x68da, 1),
+    (2921, 0x8c37, 1),
+    (2922, 0x72f8, 1),
+    (2923, 0x9c48, 1),
+    (2924, 0x6a3d, 1),
+    (2925, 0x8ab0, 1),
+    (2926, 0x4e39, 1),
+    (2927, 0x5358, 1),
+    (2928, 0x5606, 1),
+    (2929, 0x5766, 1),
+    (2930, 0x62c5, 1),
+    (2931, 0x63a2, 1),
+    (2932, 0x65e6, 1),
+    (2933, 0x6b4e, 1),
+    (2934, 0x6de1, 1),
+    (2935, 0x6e5b, 1),
+    (2936, 0x70ad, 1),
+    (2937, 0x77ed, 1),
+    (2938, 0x7aef, 1),
+    (2939, 0x7baa, 1),
+    (2940, 0x7dbb, 1),
+    (2941, 0x803d, 1),
+    (2942, 0x80c6, 1),
+    (2943, 0x86cb, 1),
+    (2944, 0x8a95, 1),
+    (2945, 0x935b, 1),
+    (2946, 0x56e3, 1),
+    (2947, 0x58c7, 1),
+    (2948, 0x5f3e, 1),
+    (2949, 0x65ad, 1),
+    (2950, 0x6696, 1),
+    (2951, 0x6a80, 1),
+    (2952, 0x6bb5, 1),
+    (2953, 0x7537, 1),
+    (2954, 0x8ac7, 1),
+    (2955, 0x5024, 1),
+    (2956, 0x77e5, 1),
+    (2957, 0x5730, 1),
+    (2958, 0x5f1b, 1),
+    (2959, 0x6065, 1),
+    (2960, 0x667a, 1),
+    (2961, 0x6c60, 1),
+    (2962, 0x75f4, 1),
+    (2963, 0x7a1a, 1),
+    (2964, 0x7f6e, 1),
+    (2965, 0x81f4, 1),
+    (2966, 0x8718, 1),
+    (2967, 0x9045, 1),
+    (2968, 0x99b3, 1),
+    (2969, 0x7bc9, 1),
+    (2970, 0x755c, 1),
+    (2971, 0x7af9, 1),
+    (2972, 0x7b51, 1),
+    (2973, 0x84c4, 1),
+    (2974, 0x9010, 1),
+    (2975, 0x79e9, 1),
+    (2976, 0x7a92, 1),
+    (2977, 0x8336, 1),
+    (2978, 0x5ae1, 1),
+    (2979, 0x7740, 1),
+    (2980, 0x4e2d, 1),
+    (2981, 0x4ef2, 1),
+    (2982, 0x5b99, 1),
+    (2983, 0x5fe0, 1),
+    (2984, 0x62bd, 1),
+    (2985, 0x663c, 1),
+    (2986, 0x67f1, 1),
+    (2987, 0x6ce8, 1),
+    (2988, 0x866b, 1),
+    (2989, 0x8877, 1),
+    (2990, 0x8a3b, 1),
+    (2991, 0x914e, 1),
+    (2992, 0x92f3, 1),
+    (2993, 0x99d0, 1),
+    (2994, 0x6a17, 1),
+    (2995, 0x7026, 1),
+    (2996, 0x732a, 1),
+    (2997, 0x82e7, 1),
+    (2998, 0x8457, 1),
+    (2999, 0x8caf, 1),
+    (3000, 0x4e01, 1),
+    (3001, 0x5146, 1),
+    (3002, 0x51cb, 1),
+    (3003, 0x558b, 1),
+    (3004, 0x5bf5, 1),
+    (3005, 0x5e16, 1),
+    (3006, 0x5e33, 1),
+    (3007, 0x5e81, 1),
+    (3008, 0x5f14, 1),
+    (3009, 0x5f35, 1),
+    (3010, 0x5f6b, 1),
+    (3011, 0x5fb4, 1),
+    (3012, 0x61f2, 1),
+    (3013, 0x6311, 1),
+    (3014, 0x66a2, 1),
+    (3015, 0x671d, 1),
+    (3016, 0x6f6e, 1),
+    (3017, 0x7252, 1),
+    (3018, 0x753a, 1),
+    (3019, 0x773a, 1),
+    (3020, 0x8074, 1),
+    (3021, 0x8139, 1),
+    (3022, 0x8178, 1),
+    (3023, 0x8776, 1),
+    (3024, 0x8abf, 1),
+    (3025, 0x8adc, 1),
+    (3026, 0x8d85, 1),
+    (3027, 0x8df3, 1),
+    (3028, 0x929a, 1),
+    (3029, 0x9577, 1),
+    (3030, 0x9802, 1),
+    (3031, 0x9ce5, 1),
+    (3032, 0x52c5, 1),
+    (3033, 0x6357, 1),
+    (3034, 0x76f4, 1),
+    (3035, 0x6715, 1),
+    (3036, 0x6c88, 1),
+    (3037, 0x73cd, 1),
+    (3038, 0x8cc3, 1),
+    (3039, 0x93ae, 1),
+    (3040, 0x9673, 1),
+    (3041, 0x6d25, 1),
+    (3042, 0x589c, 1),
+    (3043, 0x690e, 1),
+    (3044, 0x69cc, 1),
+    (3045, 0x8ffd, 1),
+    (3046, 0x939a, 1),
+    (3047, 0x75db, 1),
+    (3048, 0x901a, 1),
+    (3049, 0x585a, 1),
+    (3050, 0x6802, 1),
+    (3051, 0x63b4, 1),
+    (3052, 0x69fb, 1),
+    (3053, 0x4f43, 1),
+    (3054, 0x6f2c, 1),
+    (3055, 0x67d8, 1),
+    (3056, 0x8fbb, 1),
+    (3057, 0x8526, 1),
+    (3058, 0x7db4, 1),
+    (3059, 0x9354, 1),
+    (3060, 0x693f, 1),
+    (3061, 0x6f70, 1),
+    (3062, 0x576a, 1),
+    (3063, 0x58f7, 1),
+    (3064, 0x5b2c, 1),
+    (3065, 0x7d2c, 1),
+    (3066, 0x722a, 1),
+    (3067, 0x540a, 1),
+    (3068, 0x91e3, 1),
+    (3069, 0x9db4, 1),
+    (3070, 0x4ead, 1),
+    (3071, 0x4f4e, 1),
+    (3072, 0x505c, 1),
+    (3073, 0x5075, 1),
+    (3074, 0x5243, 1),
+    (3075, 0x8c9e, 1),
+    (3076, 0x5448, 1),
+    (3077, 0x5824, 1),
+    (3078, 0x5b9a, 1),
+    (3079, 0x5e1d, 1),
+    (3080, 0x5e95, 1),
+    (3081, 0x5ead, 1),
+    (3082, 0x5ef7, 1),
+    (3083, 0x5f1f, 1),
+    (3084, 0x608c, 1),
+    (3085, 0x62b5, 1),
+    (3086, 0x633a, 1),
+    (3087, 0x63d0, 1),
+    (3088, 0x68af, 1),
+    (3089, 0x6c40, 1),
+    (3090, 0x7887, 1),
+    (3091, 0x798e, 1),
+    (3092, 0x7a0b, 1),
+    (3093, 0x7de0, 1),
+    (3094, 0x8247, 1),
+    (3095, 0x8a02, 1),
+    (3096, 0x8ae6, 1),
+    (3097, 0x8e44, 1),
+    (3098, 0x9013, 1),
+    (3099, 0x90b8, 1),
+    (3100, 0x912d, 1),
+    (3101, 0x91d8, 1),
+    (3102, 0x9f0e, 1),
+    (3103, 0x6ce5, 1),
+    (3104, 0x6458, 1),
+    (3105, 0x64e2, 1),
+    (3106, 0x6575, 1),
+    (3107, 0x6ef4, 1),
+    (3108, 0x7684, 1),
+    (3109, 0x7b1b, 1),
+    (3110, 0x9069, 1),
+    (3111, 0x93d1, 1),
+    (3112, 0x6eba, 1),
+    (3113, 0x54f2, 1),
+    (3114, 0x5fb9, 1),
+    (3115, 0x64a4, 1),
+    (3116, 0x8f4d, 1),
+    (3117, 0x8fed, 1),
+    (3118, 0x9244, 1),
+    (3119, 0x5178, 1),
+    (3120, 0x586b, 1),
+    (3121, 0x5929, 1),
+    (3122, 0x5c55, 1),
+    (3123, 0x5e97, 1),
+    (3124, 0x6dfb, 1),
+    (3125, 0x7e8f, 1),
+    (3126, 0x751c, 1),
+    (3127, 0x8cbc, 1),
+    (3128, 0x8ee2, 1),
+    (3129, 0x985b, 1),
+    (3130, 0x70b9, 1),
+    (3131, 0x4f1d, 1),
+    (3132, 0x6bbf, 1),
+    (3133, 0x6fb1, 1),
+    (3134, 0x7530, 1),
+    (3135, 0x96fb, 1),
+    (3136, 0x514e, 1),
+    (3137, 0x5410, 1),
+    (3138, 0x5835, 1),
+    (3139, 0x5857, 1),
+    (3140, 0x59ac, 1),
+    (3141, 0x5c60, 1),
+    (3142, 0x5f92, 1),
+    (3143, 0x6597, 1),
+    (3144, 0x675c, 1),
+    (3145, 0x6e21, 1),
+    (3146, 0x767b, 1),
+    (3147, 0x83df, 1),
+    (3148, 0x8ced, 1),
+    (3149, 0x9014, 1),
+    (3150, 0x90fd, 1),
+    (3151, 0x934d, 1),
+    (3152, 0x7825, 1),
+    (3153, 0x783a, 1),
+    (3154, 0x52aa, 1),
+    (3155, 0x5ea6, 1),
+    (3156, 0x571f, 1),
+    (3157, 0x5974, 1),
+    (3158, 0x6012, 1),
+    (3159, 0x5012, 1),
+    (3160, 0x515a, 1),
+    (3161, 0x51ac, 1),
+    (3162, 0x51cd, 1),
+    (3163, 0x5200, 1),
+    (3164, 0x5510, 1),
+    (3165, 0x5854, 1),
+    (3166, 0x5858, 1),
+    (3167, 0x5957, 1),
+    (3168, 0x5b95, 1),
+    (3169, 0x5cf6, 1),
+    (3170, 0x5d8b, 1),
+    (3171, 0x60bc, 1),
+    (3172, 0x6295, 1),
+    (3173, 0x642d, 1),
+    (3174, 0x6771, 1),
+    (3175, 0x6843, 1),
+    (3176, 0x68bc, 1),
+    (3177, 0x68df, 1),
+    (3178, 0x76d7, 1),
+    (3179, 0x6dd8, 1),
+    (3180, 0x6e6f, 1),
+    (3181, 0x6d9b, 1),
+    (3182, 0x706f, 1),
+    (3183, 0x71c8, 1),
+    (3184, 0x5f53, 1),
+    (3185, 0x75d8, 1),
+    (3186, 0x7977, 1),
+    (3187, 0x7b49, 1),
+    (3188, 0x7b54, 1),
+    (3189, 0x7b52, 1),
+    (3190, 0x7cd6, 1),
+    (3191, 0x7d71, 1),
+    (3192, 0x5230, 1),
+    (3193, 0x8463, 1),
+    (3194, 0x8569, 1),
+    (3195, 0x85e4, 1),
+    (3196, 0x8a0e, 1),
+    (3197, 0x8b04, 1),
+    (3198, 0x8c46, 1),
+    (3199, 0x8e0f, 1),
+    (3200, 0x9003, 1),
+    (3201, 0x900f, 1),
+    (3202, 0x9419, 1),
+    (3203, 0x9676, 1),
+    (3204, 0x982d, 1),
+    (3205, 0x9a30, 1),
+    (3206, 0x95d8, 1),
+    (3207, 0x50cd, 1),
+    (3208, 0x52d5, 1),
+    (3209, 0x540c, 1),
+    (3210, 0x5802, 1),
+    (3211, 0x5c0e, 1),
+    (3212, 0x61a7, 1),
+    (3213, 0x649e, 1),
+    (3214, 0x6d1e, 1),
+    (3215, 0x77b3, 1),
+    (3216, 0x7ae5, 1),
+    (3217, 0x80f4, 1),
+    (3218, 0x8404, 1),
+    (3219, 0x9053, 1),
+    (3220, 0x9285, 1),
+    (3221, 0x5ce0, 1),
+    (3222, 0x9d07, 1),
+    (3223, 0x533f, 1),
+    (3224, 0x5f97, 1),
+    (3225, 0x5fb3, 1),
+    (3226, 0x6d9c, 1),
+    (3227, 0x7279, 1),
+    (3228, 0x7763, 1),
+    (3229, 0x79bf, 1),
+    (3230, 0x7be4, 1),
+    (3231, 0x6bd2, 1),
+    (3232, 0x72ec, 1),
+    (3233, 0x8aad, 1),
+    (3234, 0x6803, 1),
+    (3235, 0x6a61, 1),
+    (3236, 0x51f8, 1),
+    (3237, 0x7a81, 1),
+    (3238, 0x6934, 1),
+    (3239, 0x5c4a, 1),
+    (3240, 0x9cf6, 1),
+    (3241, 0x82eb, 1),
+    (3242, 0x5bc5, 1),
+    (3243, 0x9149, 1),
+    (3244, 0x701e, 1),
+    (3245, 0x5678, 1),
+    (3246, 0x5c6f, 1),
+    (3247, 0x60c7, 1),
+    (3248, 0x6566, 1),
+    (3249, 0x6c8c, 1),
+    (3250, 0x8c5a, 1),
+    (3251, 0x9041, 1),
+    (3252, 0x9813, 1),
+    (3253, 0x5451, 1),
+    (3254, 0x66c7, 1),
+    (3255, 0x920d, 1),
+    (3256, 0x5948, 1),
+    (3257, 0x90a3, 1),
+    (3258, 0x5185, 1),
+    (3259, 0x4e4d, 1),
+    (3260, 0x51ea, 1),
+    (3261, 0x8599, 1),
+    (3262, 0x8b0e, 1),
+    (3263, 0x7058, 1),
+    (3264, 0x637a, 1),
+    (3265, 0x934b, 1),
+    (3266, 0x6962, 1),
+    (3267, 0x99b4, 1),
+    (3268, 0x7e04, 1),
+    (3269, 0x7577, 1),
+    (3270, 0x5357, 1),
+    (3271, 0x6960, 1),
+    (3272, 0x8edf, 1),
+    (3273, 0x96e3, 1),
+    (3274, 0x6c5d, 1),
+    (3275, 0x4e8c, 1),
+    (3276, 0x5c3c, 1),
+    (3277, 0x5f10, 1),
+    (3278, 0x8fe9, 1),
+    (3279, 0x5302, 1),
+    (3280, 0x8cd1, 1),
+    (3281, 0x8089, 1),
+    (3282, 0x8679, 1),
+    (3283, 0x5eff, 1),
+    (3284, 0x65e5, 1),
+    (3285, 0x4e73, 1),
+    (3286, 0x5165, 1),
+    (3287, 0x5982, 1),
+    (3288, 0x5c3f, 1),
+    (3289, 0x97ee, 1),
+    (3290, 0x4efb, 1),
+    (3291, 0x598a, 1),
+    (3292, 0x5fcd, 1),
+    (3293, 0x8a8d, 1),
+    (3294, 0x6fe1, 1),
+    (3295, 0x79b0, 1),
+    (3296, 0x7962, 1),
+    (3297, 0x5be7, 1),
+    (3298, 0x8471, 1),
+    (3299, 0x732b, 1),
+    (3300, 0x71b1, 1),
+    (3301, 0x5e74, 1),
+    (3302, 0x5ff5, 1),
+    (3303, 0x637b, 1),
+    (3304, 0x649a, 1),
+    (3305, 0x71c3, 1),
+    (3306, 0x7c98, 1),
+    (3307, 0x4e43, 1),
+    (3308, 0x5efc, 1),
+    (3309, 0x4e4b, 1),
+    (3310, 0x57dc, 1),
+    (3311, 0x56a2, 1),
+    (3312, 0x60a9, 1),
+    (3313, 0x6fc3, 1),
+    (3314, 0x7d0d, 1),
+    (3315, 0x80fd, 1),
+    (3316, 0x8133, 1),
+    (3317, 0x81bf, 1),
+    (3318, 0x8fb2, 1),
+    (3319, 0x8997, 1),
+    (3320, 0x86a4, 1),
+    (3321, 0x5df4, 1),
+    (3322, 0x628a, 1),
+    (3323, 0x64ad, 1),
+    (3324, 0x8987, 1),
+    (3325, 0x6777, 1),
+    (3326, 0x6ce2, 1),
+    (3327, 0x6d3e, 1),
+    (3328, 0x7436, 1),
+    (3329, 0x7834, 1),
+    (3330, 0x5a46, 1),
+    (3331, 0x7f75, 1),
+    (3332, 0x82ad, 1),
+    (3333, 0x99ac, 1),
+    (3334, 0x4ff3, 1),
+    (3335, 0x5ec3, 1),
+    (3336, 0x62dd, 1),
+    (3337, 0x6392, 1),
+    (3338, 0x6557, 1),
+    (3339, 0x676f, 1),
+    (3340, 0x76c3, 1),
+    (3341, 0x724c, 1),
+    (3342, 0x80cc, 1),
+    (3343, 0x80ba, 1),
+    (3344, 0x8f29, 1),
+    (3345, 0x914d, 1),
+    (3346, 0x500d, 1),
+    (3347, 0x57f9, 1),
+    (3348, 0x5a92, 1),
+    (3349, 0x6885, 1),
+    (3350, 0x6973, 1),
+    (3351, 0x7164, 1),
+    (3352, 0x72fd, 1),
+    (3353, 0x8cb7, 1),
+    (3354, 0x58f2, 1),
+    (3355, 0x8ce0, 1),
+    (3356, 0x966a, 1),
+    (3357, 0x9019, 1),
+    (3358, 0x877f, 1),
+    (3359, 0x79e4, 1),
+    (3360, 0x77e7, 1),
+    (3361, 0x8429, 1),
+    (3362, 0x4f2f, 1),
+    (3363, 0x5265, 1),
+    (3364, 0x535a, 1),
+    (3365, 0x62cd, 1),
+    (3366, 0x67cf, 1),
+    (3367, 0x6cca, 1),
+    (3368, 0x767d, 1),
+    (3369, 0x7b94, 1),
+    (3370, 0x7c95, 1),
+    (3371, 0x8236, 1),
+    (3372, 0x8584, 1),
+    (3373, 0x8feb, 1),
+    (3374, 0x66dd, 1),
+    (3375, 0x6f20, 1),
+    (3376, 0x7206, 1),
+    (3377, 0x7e1b, 1),
+    (3378, 0x83ab, 1),
+    (3379, 0x99c1, 1),
+    (3380, 0x9ea6, 1),
+    (3381, 0x51fd, 1),
+    (3382, 0x7bb1, 1),
+    (3383, 0x7872, 1),
+    (3384, 0x7bb8, 1),
+    (3385, 0x8087, 1),
+    (3386, 0x7b48, 1),
+    (3387, 0x6ae8, 1),
+    (3388, 0x5e61, 1),
+    (3389, 0x808c, 1),
+    (3390, 0x7551, 1),
+    (3391, 0x7560, 1),
+    (3392, 0x516b, 1),
+    (3393, 0x9262, 1),
+    (3394, 0x6e8c, 1),
+    (3395, 0x767a, 1),
+    (3396, 0x9197, 1),
+    (3397, 0x9aea, 1),
+    (3398, 0x4f10, 1),
+    (3399, 0x7f70, 1),
+    (3400, 0x629c, 1),
+    (3401, 0x7b4f, 1),
+    (3402, 0x95a5, 1),
+    (3403, 0x9ce9, 1),
+    (3404, 0x567a, 1),
+    (3405, 0x5859, 1),
+    (3406, 0x86e4, 1),
+    (3407, 0x96bc, 1),
+    (3408, 0x4f34, 1),
+    (3409, 0x5224, 1),
+    (3410, 0x534a, 1),
+    (3411, 0x53cd, 1),
+    (3412, 0x53db, 1),
+    (3413, 0x5e06, 1),
+    (3414, 0x642c, 1),
+    (3415, 0x6591, 1),
+    (3416, 0x677f, 1),
+    (3417, 0x6c3e, 1),
+    (3418, 0x6c4e, 1),
+    (3419, 0x7248, 1),
+    (3420, 0x72af, 1),
+    (3421, 0x73ed, 1),
+    (3422, 0x7554, 1),
+    (3423, 0x7e41, 1),
+    (3424, 0x822c, 1),
+    (3425, 0x85e9, 1),
+    (3426, 0x8ca9, 1),
+    (3427, 0x7bc4, 1),
+    (3428, 0x91c6, 1),
+    (3429, 0x7169, 1),
+    (3430, 0x9812, 1),
+    (3431, 0x98ef, 1),
+    (3432, 0x633d, 1),
+    (3433, 0x6669, 1),
+    (3434, 0x756a, 1),
+    (3435, 0x76e4, 1),
+    (3436, 0x78d0, 1),
+    (3437, 0x8543, 1),
+    (3438, 0x86ee, 1),
+    (3439, 0x532a, 1),
+    (3440, 0x5351, 1),
+    (3441, 0x5426, 1),
+    (3442, 0x5983, 1),
+    (3443, 0x5e87, 1),
+    (3444, 0x5f7c, 1),
+    (3445, 0x60b2, 1),
+    (3446, 0x6249, 1),
+    (3447, 0x6279, 1),
+    (3448, 0x62ab, 1),
+    (3449, 0x6590, 1),
+    (3450, 0x6bd4, 1),
+    (3451, 0x6ccc, 1),
+    (3452, 0x75b2, 1),
+    (3453, 0x76ae, 1),
+    (3454, 0x7891, 1),
+    (3455, 0x79d8, 1),
+    (3456, 0x7dcb, 1),
+    (3457, 0x7f77, 1),
+    (3458, 0x80a5, 1),
+    (3459, 0x88ab, 1),
+    (3460, 0x8ab9, 1),
+    (3461, 0x8cbb, 1),
+    (3462, 0x907f, 1),
+    (3463, 0x975e, 1),
+    (3464, 0x98db, 1),
+    (3465, 0x6a0b, 1),
+    (3466, 0x7c38, 1),
+    (3467, 0x5099, 1),
+    (3468, 0x5c3e, 1),
+    (3469, 0x5fae, 1),
+    (3470, 0x6787, 1),
+    (3471, 0x6bd8, 1),
+    (3472, 0x7435, 1),
+    (3473, 0x7709, 1),
+    (3474, 0x7f8e, 1),
+    (3475, 0x9f3b, 1),
+    (3476, 0x67ca, 1),
+    (3477, 0x7a17, 1),
+    (3478, 0x5339, 1),
+    (3479, 0x758b, 1),
+    (3480, 0x9aed, 1),
+    (3481, 0x5f66, 1),
+    (3482, 0x819d, 1),
+    (3483, 0x83f1, 1),
+    (3484, 0x8098, 1),
+    (3485, 0x5f3c, 1),
+    (3486, 0x5fc5, 1),
+    (3487, 0x7562, 1),
+    (3488, 0x7b46, 1),
+    (3489, 0x903c, 1),
+    (3490, 0x6867, 1),
+    (3491, 0x59eb, 1),
+    (3492, 0x5a9b, 1),
+    (3493, 0x7d10, 1),
+    (3494, 0x767e, 1),
+    (3495, 0x8b2c, 1),
+    (3496, 0x4ff5, 1),
+    (3497, 0x5f6a, 1),
+    (3498, 0x6a19, 1),
+    (3499, 0x6c37, 1),
+    (3500, 0x6f02, 1),
+    (3501, 0x74e2, 1),
+    (3502, 0x7968, 1),
+    (3503, 0x8868, 1),
+    (3504, 0x8a55, 1),
+    (3505, 0x8c79, 1),
+    (3506, 0x5edf, 1),
+    (3507, 0x63cf, 1),
+    (3508, 0x75c5, 1),
+    (3509, 0x79d2, 1),
+    (3510, 0x82d7, 1),
+    (3511, 0x9328, 1),
+    (3512, 0x92f2, 1),
+    (3513, 0x849c, 1),
+    (3514, 0x86ed, 1),
+    (3515, 0x9c2d, 1),
+    (3516, 0x54c1, 1),
+    (3517, 0x5f6c, 1),
+    (3518, 0x658c, 1),
+    (3519, 0x6d5c, 1),
+    (3520, 0x7015, 1),
+    (3521, 0x8ca7, 1),
+    (3522, 0x8cd3, 1),
+    (3523, 0x983b, 1),
+    (3524, 0x654f, 1),
+    (3525, 0x74f6, 1),
+    (3526, 0x4e0d, 1),
+    (3527, 0x4ed8, 1),
+    (3528, 0x57e0, 1),
+    (3529, 0x592b, 1),
+    (3530, 0x5a66, 1),
+    (3531, 0x5bcc, 1),
+    (3532, 0x51a8, 1),
+    (3533, 0x5e03, 1),
+    (3534, 0x5e9c, 1),
+    (3535, 0x6016, 1),
+    (3536, 0x6276, 1),
+    (3537, 0x6577, 1),
+    (3538, 0x65a7, 1),
+    (3539, 0x666e, 1),
+    (3540, 0x6d6e, 1),
+    (3541, 0x7236, 1),
+    (3542, 0x7b26, 1),
+    (3543, 0x8150, 1),
+    (3544, 0x819a, 1),
+    (3545, 0x8299, 1),
+    (3546, 0x8b5c, 1),
+    (3547, 0x8ca0, 1),
+    (3548, 0x8ce6, 1),
+    (3549, 0x8d74, 1),
+    (3550, 0x961c, 1),
+    (3551, 0x9644, 1),
+    (3552, 0x4fae, 1),
+    (3553, 0x64ab, 1),
+    (3554, 0x6b66, 1),
+    (3555, 0x821e, 1),
+    (3556, 0x8461, 1),
+    (3557, 0x856a, 1),
+    (3558, 0x90e8, 1),
+    (3559, 0x5c01, 1),
+    (3560, 0x6953, 1),
+    (3561, 0x98a8, 1),
+    (3562, 0x847a, 1),
+    (3563, 0x8557, 1),
+    (3564, 0x4f0f, 1),
+    (3565, 0x526f, 1),
+    (3566, 0x5fa9, 1),
+    (3567, 0x5e45, 1),
+    (3568, 0x670d, 1),
+    (3569, 0x798f, 1),
+    (3570, 0x8179, 1),
+    (3571, 0x8907, 1),
+    (3572, 0x8986, 1),
+    (3573, 0x6df5, 1),
+    (3574, 0x5f17, 1),
+    (3575, 0x6255, 1),
+    (3576, 0x6cb8, 1),
+    (3577, 0x4ecf, 1),
+    (3578, 0x7269, 1),
+    (3579, 0x9b92, 1),
+    (3580, 0x5206, 1),
+    (3581, 0x543b, 1),
+    (3582, 0x5674, 1),
+    (3583, 0x58b3, 1),
+    (3584, 0x61a4, 1),
+    (3585, 0x626e, 1),
+    (3586, 0x711a, 1),
+    (3587, 0x596e, 1),
+    (3588, 0x7c89, 1),
+    (3589, 0x7cde, 1),
+    (3590, 0x7d1b, 1),
+    (3591, 0x96f0, 1),
+    (3592, 0x6587, 1),
+    (3593, 0x805e, 1),
+    (3594, 0x4e19, 1),
+    (3595, 0x4f75, 1),
+    (3596, 0x5175, 1),
+    (3597, 0x5840, 1),
+    (3598, 0x5e63, 1),
+    (3599, 0x5e73, 1),
+    (3600, 0x5f0a, 1),
+    (3601, 0x67c4, 1),
+    (3602, 0x4e26, 1),
+    (3603, 0x853d, 1),
+    (3604, 0x9589, 1),
+    (3605, 0x965b, 1),
+    (3606, 0x7c73, 1),
+    (3607, 0x9801, 1),
+    (3608, 0x50fb, 1),
+    (3609, 0x58c1, 1),
+    (3610, 0x7656, 1),
+    (3611, 0x78a7, 1),
+    (3612, 0x5225, 1),
+    (3613, 0x77a5, 1),
+    (3614, 0x8511, 1),
+    (3615, 0x7b86, 1),
+    (3616, 0x504f, 1),
+    (3617, 0x5909, 1),
+    (3618, 0x7247, 1),
+    (3619, 0x7bc7, 1),
+    (3620, 0x7de8, 1),
+    (3621, 0x8fba, 1),
+    (3622, 0x8fd4, 1),
+    (3623, 0x904d, 1),
+    (3624, 0x4fbf, 1),
+    (3625, 0x52c9, 1),
+    (3626, 0x5a29, 1),
+    (3627, 0x5f01, 1),
+    (3628, 0x97ad, 1),
+    (3629, 0x4fdd, 1),
+    (3630, 0x8217, 1),
+    (3631, 0x92ea, 1),
+    (3632, 0x5703, 1),
+    (3633, 0x6355, 1),
+    (3634, 0x6b69, 1),
+    (3635, 0x752b, 1),
+    (3636, 0x88dc, 1),
+    (3637, 0x8f14, 1),
+    (3638, 0x7a42, 1),
+    (3639, 0x52df, 1),
+    (3640, 0x5893, 1),
+    (3641, 0x6155, 1),
+    (3642, 0x620a, 1),
+    (3643, 0x66ae, 1),
+    (3644, 0x6bcd, 1),
+    (3645, 0x7c3f, 1),
+    (3646, 0x83e9, 1),
+    (3647, 0x5023, 1),
+    (3648, 0x4ff8, 1),
+    (3649, 0x5305, 1),
+    (3650, 0x5446, 1),
+    (3651, 0x5831, 1),
+    (3652, 0x5949, 1),
+    (3653, 0x5b9d, 1),
+    (3654, 0x5cf0, 1),
+    (3655, 0x5cef, 1),
+    (3656, 0x5d29, 1),
+    (3657, 0x5e96, 1),
+    (3658, 0x62b1, 1),
+    (3659, 0x6367, 1),
+    (3660, 0x653e, 1),
+    (3661, 0x65b9, 1),
+    (3662, 0x670b, 1),
+    (3663, 0x6cd5, 1),
+    (3664, 0x6ce1, 1),
+    (3665, 0x70f9, 1),
+    (3666, 0x7832, 1),
+    (3667, 0x7e2b, 1),
+    (3668, 0x80de, 1),
+    (3669, 0x82b3, 1),
+    (3670, 0x840c, 1),
+    (3671, 0x84ec, 1),
+    (3672, 0x8702, 1),
+    (3673, 0x8912, 1),
+    (3674, 0x8a2a, 1),
+    (3675, 0x8c4a, 1),
+    (3676, 0x90a6, 1),
+    (3677, 0x92d2, 1),
+    (3678, 0x98fd, 1),
+    (3679, 0x9cf3, 1),
+    (3680, 0x9d6c, 1),
+    (3681, 0x4e4f, 1),
+    (3682, 0x4ea1, 1),
+    (3683, 0x508d, 1),
+    (3684, 0x5256, 1),
+    (3685, 0x574a, 1),
+    (3686, 0x59a8, 1),
+    (3687, 0x5e3d, 1),
+    (3688, 0x5fd8, 2),
+    (3690, 0x623f, 1),
+    (3691, 0x66b4, 1),
+    (3692, 0x671b, 1),
+    (3693, 0x67d0, 1),
+    (3694, 0x68d2, 1),
+    (3695, 0x5192, 1),
+    (3696, 0x7d21, 1),
+    (3697, 0x80aa, 1),
+    (3698, 0x81a8, 1),
+    (3699, 0x8b00, 1),
+    (3700, 0x8c8c, 1),
+    (3701, 0x8cbf, 1),
+    (3702, 0x927e, 1),
+    (3703, 0x9632, 1),
+    (3704, 0x5420, 1),
+    (3705, 0x982c, 1),
+    (3706, 0x5317, 1),
+    (3707, 0x50d5, 1),
+    (3708, 0x535c, 1),
+    (3709, 0x58a8, 1),
+    (3710, 0x64b2, 1),
+    (3711, 0x6734, 1),
+    (3712, 0x7267, 1),
+    (3713, 0x7766, 1),
+    (3714, 0x7a46, 1),
+    (3715, 0x91e6, 1),
+    (3716, 0x52c3, 1),
+    (3717, 0x6ca1, 1),
+    (3718, 0x6b86, 1),
+    (3719, 0x5800, 1),
+    (3720, 0x5e4c, 1),
+    (3721, 0x5954, 1),
+    (3722, 0x672c, 1),
+    (3723, 0x7ffb, 1),
+    (3724, 0x51e1, 1),
+    (3725, 0x76c6, 1),
+    (3726, 0x6469, 1),
+    (3727, 0x78e8, 1),
+    (3728, 0x9b54, 1),
+    (3729, 0x9ebb, 1),
+    (3730, 0x57cb, 1),
+    (3731, 0x59b9, 1),
+    (3732, 0x6627, 1),
+    (3733, 0x679a, 1),
+    (3734, 0x6bce, 1),
+    (3735, 0x54e9, 1),
+    (3736, 0x69d9, 1),
+    (3737, 0x5e55, 1),
+    (3738, 0x819c, 1),
+    (3739, 0x6795, 1),
+    (3740, 0x9baa, 1),
+    (3741, 0x67fe, 1),
+    (3742, 0x9c52, 1),
+    (3743, 0x685d, 1),
+    (3744, 0x4ea6, 1),
+    (3745, 0x4fe3, 1),
+    (3746, 0x53c8, 1),
+    (3747, 0x62b9, 1),
+    (3748, 0x672b, 1),
+    (3749, 0x6cab, 1),
+    (3750, 0x8fc4, 1),
+    (3751, 0x4fad, 1),
+    (3752, 0x7e6d, 1),
+    (3753, 0x9ebf, 1),
+    (3754, 0x4e07, 1),
+    (3755, 0x6162, 1),
+    (3756, 0x6e80, 1),
+    (3757, 0x6f2b, 1),
+    (3758, 0x8513, 1),
+    (3759, 0x5473, 1),
+    (3760, 0x672a, 1),
+    (3761, 0x9b45, 1),
+    (3762, 0x5df3, 1),
+    (3763, 0x7b95, 1),
+    (3764, 0x5cac, 1),
+    (3765, 0x5bc6, 1),
+    (3766, 0x871c, 1),
+    (3767, 0x6e4a, 1),
+    (3768, 0x84d1, 1),
+    (3769, 0x7a14, 1),
+    (3770, 0x8108, 1),
+    (3771, 0x5999, 1),
+    (3772, 0x7c8d, 1),
+    (3773, 0x6c11, 1),
+    (3774, 0x7720, 1),
+    (3775, 0x52d9, 1),
+    (3776, 0x5922, 1),
+    (3777, 0x7121, 1),
+    (3778, 0x725f, 1),
+    (3779, 0x77db, 1),
+    (3780, 0x9727, 1),
+    (3781, 0x9d61, 1),
+    (3782, 0x690b, 1),
+    (3783, 0x5a7f, 1),
+    (3784, 0x5a18, 1),
+    (3785, 0x51a5, 1),
+    (3786, 0x540d, 1),
+    (3787, 0x547d, 1),
+    (3788, 0x660e, 1),
+    (3789, 0x76df, 1),
+    (3790, 0x8ff7, 1),
+    (3791, 0x9298, 1),
+    (3792, 0x9cf4, 1),
+    (3793, 0x59ea, 1),
+    (3794, 0x725d, 1),
+    (3795, 0x6ec5, 1),
+    (3796, 0x514d, 1),
+    (3797, 0x68c9, 1),
+    (3798, 0x7dbf, 1),
+    (3799, 0x7dec, 1),
+    (3800, 0x9762, 1),
+    (3801, 0x9eba, 1),
+    (3802, 0x6478, 1),
+    (3803, 0x6a21, 1),
+    (3804, 0x8302, 1),
+    (3805, 0x5984, 1),
+    (3806, 0x5b5f, 1),
+    (3807, 0x6bdb, 1),
+    (3808, 0x731b, 1),
+    (3809, 0x76f2, 1),
+    (3810, 0x7db2, 1),
+    (3811, 0x8017, 1),
+    (3812, 0x8499, 1),
+    (3813, 0x5132, 1),
+    (3814, 0x6728, 1),
+    (3815, 0x9ed9, 1),
+    (3816, 0x76ee, 1),
+    (3817, 0x6762, 1),
+    (3818, 0x52ff, 1),
+    (3819, 0x9905, 1),
+    (3820, 0x5c24, 1),
+    (3821, 0x623b, 1),
+    (3822, 0x7c7e, 1),
+    (3823, 0x8cb0, 1),
+    (3824, 0x554f, 1),
+    (3825, 0x60b6, 1),
+    (3826, 0x7d0b, 1),
+    (3827, 0x9580, 1),
+    (3828, 0x5301, 1),
+    (3829, 0x4e5f, 1),
+    (3830, 0x51b6, 1),
+    (3831, 0x591c, 1),
+    (3832, 0x723a, 1),
+    (3833, 0x8036, 1),
+    (3834, 0x91ce, 1),
+    (3835, 0x5f25, 1),
+    (3836, 0x77e2, 1),
+    (3837, 0x5384, 1),
+    (3838, 0x5f79, 1),
+    (3839, 0x7d04, 1),
+    (3840, 0x85ac, 1),
+    (3841, 0x8a33, 1),
+    (3842, 0x8e8d, 1),
+    (3843, 0x9756, 1),
+    (3844, 0x67f3, 1),
+    (3845, 0x85ae, 1),
+    (3846, 0x9453, 1),
+    (3847, 0x6109, 1),
+    (3848, 0x6108, 1),
+    (3849, 0x6cb9, 1),
+    (3850, 0x7652, 1),
+    (3851, 0x8aed, 1),
+    (3852, 0x8f38, 1),
+    (3853, 0x552f, 1),
+    (3854, 0x4f51, 1),
+    (3855, 0x512a, 1),
+    (3856, 0x52c7, 1),
+    (3857, 0x53cb, 1),
+    (3858, 0x5ba5, 1),
+    (3859, 0x5e7d, 1),
+    (3860, 0x60a0, 1),
+    (3861, 0x6182, 1),
+    (3862, 0x63d6, 1),
+    (3863, 0x6709, 1),
+    (3864, 0x67da, 1),
+    (3865, 0x6e67, 1),
+    (3866, 0x6d8c, 1),
+    (3867, 0x7336, 2),
+    (3869, 0x7531, 1),
+    (3870, 0x7950, 1),
+    (3871, 0x88d5, 1),
+    (3872, 0x8a98, 1),
+    (3873, 0x904a, 1),
+    (3874, 0x9091, 1),
+    (3875, 0x90f5, 1),
+    (3876, 0x96c4, 1),
+    (3877, 0x878d, 1),
+    (3878, 0x5915, 1),
+    (3879, 0x4e88, 1),
+    (3880, 0x4f59, 1),
+    (3881, 0x4e0e, 1),
+    (3882, 0x8a89, 1),
+    (3883, 0x8f3f, 1),
+    (3884, 0x9810, 1),
+    (3885, 0x50ad, 1),
+    (3886, 0x5e7c, 1),
+    (3887, 0x5996, 1),
+    (3888, 0x5bb9, 1),
+    (3889, 0x5eb8, 1),
+    (3890, 0x63da, 1),
+    (3891, 0x63fa, 1),
+    (3892, 0x64c1, 1),
+    (3893, 0x66dc, 1),
+    (3894, 0x694a, 1),
+    (3895, 0x69d8, 1),
+    (3896, 0x6d0b, 1),
+    (3897, 0x6eb6, 1),
+    (3898, 0x7194, 1),
+    (3899, 0x7528, 1),
+    (3900, 0x7aaf, 1),
+    (3901, 0x7f8a, 1),
+    (3902, 0x8000, 1),
+    (3903, 0x8449, 1),
+    (3904, 0x84c9, 1),
+    (3905, 0x8981, 1),
+    (3906, 0x8b21, 1),
+    (3907, 0x8e0a, 1),
+    (3908, 0x9065, 1),
+    (3909, 0x967d, 1),
+    (3910, 0x990a, 1),
+    (3911, 0x617e, 1),
+    (3912, 0x6291, 1),
+    (3913, 0x6b32, 1),
+    (3914, 0x6c83, 1),
+    (3915, 0x6d74, 1),
+    (3916, 0x7fcc, 1),
+    (3917, 0x7ffc, 1),
+    (3918, 0x6dc0, 1),
+    (3919, 0x7f85, 1),
+    (3920, 0x87ba, 1),
+    (3921, 0x88f8, 1),
+    (3922, 0x6765, 1),
+    (3923, 0x83b1, 1),
+    (3924, 0x983c, 1),
+    (3925, 0x96f7, 1),
+    (3926, 0x6d1b, 1),
+    (3927, 0x7d61, 1),
+    (3928, 0x843d, 1),
+    (3929, 0x916a, 1),
+    (3930, 0x4e71, 1),
+    (3931, 0x5375, 1),
+    (3932, 0x5d50, 1),
+    (3933, 0x6b04, 1),
+    (3934, 0x6feb, 1),
+    (3935, 0x85cd, 1),
+    (3936, 0x862d, 1),
+    (3937, 0x89a7, 1),
+    (3938, 0x5229, 1),
+    (3939, 0x540f, 1),
+    (3940, 0x5c65, 1),
+    (3941, 0x674e, 1),
+    (3942, 0x68a8, 1),
+    (3943, 0x7406, 1),
+    (3944, 0x7483, 1),
+    (3945, 0x75e2, 1),
+    (3946, 0x88cf, 1),
+    (3947, 0x88e1, 1),
+    (3948, 0x91cc, 1),
+    (3949, 0x96e2, 1),
+    (3950, 0x9678, 1),
+    (3951, 0x5f8b, 1),
+    (3952, 0x7387, 1),
+    (3953, 0x7acb, 1),
+    (3954, 0x844e, 1),
+    (3955, 0x63a0, 1),
+    (3956, 0x7565, 1),
+    (3957, 0x5289, 1),
+    (3958, 0x6d41, 1),
+    (3959, 0x6e9c, 1),
+    (3960, 0x7409, 1),
+    (3961, 0x7559, 1),
+    (3962, 0x786b, 1),
+    (3963, 0x7c92, 1),
+    (3964, 0x9686, 1),
+    (3965, 0x7adc, 1),
+    (3966, 0x9f8d, 1),
+    (3967, 0x4fb6, 1),
+    (3968, 0x616e, 1),
+    (3969, 0x65c5, 1),
+    (3970, 0x865c, 1),
+    (3971, 0x4e86, 1),
+    (3972, 0x4eae, 1),
+    (3973, 0x50da, 1),
+    (3974, 0x4e21, 1),
+    (3975, 0x51cc, 1),
+    (3976, 0x5bee, 1),
+    (3977, 0x6599, 1),
+    (3978, 0x6881, 1),
+    (3979, 0x6dbc, 1),
+    (3980, 0x731f, 1),
+    (3981, 0x7642, 1),
+    (3982, 0x77ad, 1),
+    (3983, 0x7a1c, 1),
+    (3984, 0x7ce7, 1),
+    (3985, 0x826f, 1),
+    (3986, 0x8ad2, 1),
+    (3987, 0x907c, 1),
+    (3988, 0x91cf, 1),
+    (3989, 0x9675, 1),
+    (3990, 0x9818, 1),
+    (3991, 0x529b, 1),
+    (3992, 0x7dd1, 1),
+    (3993, 0x502b, 1),
+    (3994, 0x5398, 1),
+    (3995, 0x6797, 1),
+    (3996, 0x6dcb, 1),
+    (3997, 0x71d0, 1),
+    (3998, 0x7433, 1),
+    (3999, 0x81e8, 1),
+    (4000, 0x8f2a, 1),
+    (4001, 0x96a3, 1),
+    (4002, 0x9c57, 1),
+    (4003, 0x9e9f, 1),
+    (4004, 0x7460, 1),
+    (4005, 0x5841, 1),
+    (4006, 0x6d99, 1),
+    (4007, 0x7d2f, 1),
+    (4008, 0x985e, 1),
+    (4009, 0x4ee4, 1),
+    (4010, 0x4f36, 1),
+    (4011, 0x4f8b, 1),
+    (4012, 0x51b7, 1),
+    (4013, 0x52b1, 1),
+    (4014, 0x5dba, 1),
+    (4015, 0x601c, 1),
+    (4016, 0x73b2, 1),
+    (4017, 0x793c, 1),
+    (4018, 0x82d3, 1),
+    (4019, 0x9234, 1),
+    (4020, 0x96b7, 1),
+    (4021, 0x96f6, 1),
+    (4022, 0x970a, 1),
+    (4023, 0x9e97, 1),
+    (4024, 0x9f62, 1),
+    (4025, 0x66a6, 1),
+    (4026, 0x6b74, 1),
+    (4027, 0x5217, 1),
+    (4028, 0x52a3, 1),
+    (4029, 0x70c8, 1),
+    (4030, 0x88c2, 1),
+    (4031, 0x5ec9, 1),
+    (4032, 0x604b, 1),
+    (4033, 0x6190, 1),
+    (4034, 0x6f23, 1),
+    (4035, 0x7149, 1),
+    (4036, 0x7c3e, 1),
+    (4037, 0x7df4, 1),
+    (4038, 0x806f, 1),
+    (4039, 0x84ee, 1),
+    (4040, 0x9023, 1),
+    (4041, 0x932c, 1),
+    (4042, 0x5442, 1),
+    (4043, 0x9b6f, 1),
+    (4044, 0x6ad3, 1),
+    (4045, 0x7089, 1),
+    (4046, 0x8cc2, 1),
+    (4047, 0x8def, 1),
+    (4048, 0x9732, 1),
+    (4049, 0x52b4, 1),
+    (4050, 0x5a41, 1),
+    (4051, 0x5eca, 1),
+    (4052, 0x5f04, 1),
+    (4053, 0x6717, 1),
+    (4054, 0x697c, 1),
+    (4055, 0x6994, 1),
+    (4056, 0x6d6a, 1),
+    (4057, 0x6f0f, 1),
+    (4058, 0x7262, 1),
+    (4059, 0x72fc, 1),
+    (4060, 0x7bed, 1),
+    (4061, 0x8001, 1),
+    (4062, 0x807e, 1),
+    (4063, 0x874b, 1),
+    (4064, 0x90ce, 1),
+    (4065, 0x516d, 1),
+    (4066, 0x9e93, 1),
+    (4067, 0x7984, 1),
+    (4068, 0x808b, 1),
+    (4069, 0x9332, 1),
+    (4070, 0x8ad6, 1),
+    (4071, 0x502d, 1),
+    (4072, 0x548c, 1),
+    (4073, 0x8a71, 1),
+    (4074, 0x6b6a, 1),
+    (4075, 0x8cc4, 1),
+    (4076, 0x8107, 1),
+    (4077, 0x60d1, 1),
+    (4078, 0x67a0, 1),
+    (4079, 0x9df2, 1),
+    (4080, 0x4e99, 1),
+    (4081, 0x4e98, 1),
+    (4082, 0x9c10, 1),
+    (4083, 0x8a6b, 1),
+    (4084, 0x85c1, 1),
+    (4085, 0x8568, 1),
+    (4086, 0x6900, 1),
+    (4087, 0x6e7e, 1),
+    (4088, 0x7897, 1),
+    (4089, 0x8155, 1),
+    (4090, 0x5f0c, 1),
+    (4091, 0x4e10, 1),
+    (4092, 0x4e15, 1),
+    (4093, 0x4e2a, 1),
+    (4094, 0x4e31, 1),
+    (4095, 0x4e36, 1),
+    (4096, 0x4e3c, 1),
+    (4097, 0x4e3f, 1),
+    (4098, 0x4e42, 1),
+    (4099, 0x4e56, 1),
+    (4100, 0x4e58, 1),
+    (4101, 0x4e82, 1),
+    (4102, 0x4e85, 1),
+    (4103, 0x8c6b, 1),
+    (4104, 0x4e8a, 1),
+    (4105, 0x8212, 1),
+    (4106, 0x5f0d, 1),
+    (4107, 0x4e8e, 1),
+    (4108, 0x4e9e, 3),
+    (4111, 0x4ea2, 1),
+    (4112, 0x4eb0, 1),
+    (4113, 0x4eb3, 1),
+    (4114, 0x4eb6, 1),
+    (4115, 0x4ece, 1),
+    (4116, 0x4ecd, 1),
+    (4117, 0x4ec4, 1),
+    (4118, 0x4ec6, 1),
+    (4119, 0x4ec2, 1),
+    (4120, 0x4ed7, 1),
+    (4121, 0x4ede, 1),
+    (4122, 0x4eed, 1),
+    (4123, 0x4edf, 1),
+    (4124, 0x4ef7, 1),
+    (4125, 0x4f09, 1),
+    (4126, 0x4f5a, 1),
+    (4127, 0x4f30, 1),
+    (4128, 0x4f5b, 1),
+    (4129, 0x4f5d, 1),
+    (4130, 0x4f57, 1),
+    (4131, 0x4f47, 1),
+    (4132, 0x4f76, 1),
+    (4133, 0x4f88, 1),
+    (4134, 0x4f8f, 1),
+    (4135, 0x4f98, 1),
+    (4136, 0x4f7b, 1),
+    (4137, 0x4f69, 1),
+    (4138, 0x4f70, 1),
+    (4139, 0x4f91, 1),
+    (4140, 0x4f6f, 1),
+    (4141, 0x4f86, 1),
+    (4142, 0x4f96, 1),
+    (4143, 0x5118, 1),
+    (4144, 0x4fd4, 1),
+    (4145, 0x4fdf, 1),
+    (4146, 0x4fce, 1),
+    (4147, 0x4fd8, 1),
+    (4148, 0x4fdb, 1),
+    (4149, 0x4fd1, 1),
+    (4150, 0x4fda, 1),
+    (4151, 0x4fd0, 1),
+    (4152, 0x4fe4, 2),
+    (4154, 0x501a, 1),
+    (4155, 0x5028, 1),
+    (4156, 0x5014, 1),
+    (4157, 0x502a, 1),
+    (4158, 0x5025, 1),
+    (4159, 0x5005, 1),
+    (4160, 0x4f1c, 1),
+    (4161, 0x4ff6, 1),
+    (4162, 0x5021, 1),
+    (4163, 0x5029, 1),
+    (4164, 0x502c, 1),
+    (4165, 0x4ffe, 1),
+    (4166, 0x4fef, 1),
+    (4167, 0x5011, 1),
+    (4168, 0x5006, 1),
+    (4169, 0x5043, 1),
+    (4170, 0x5047, 1),
+    (4171, 0x6703, 1),
+    (4172, 0x5055, 1),
+    (4173, 0x5050, 1),
+    (4174, 0x5048, 1),
+    (4175, 0x505a, 1),
+    (4176, 0x5056, 1),
+    (4177, 0x506c, 1),
+    (4178, 0x5078, 1),
+    (4179, 0x5080, 1),
+    (4180, 0x509a, 1),
+    (4181, 0x5085, 1),
+    (4182, 0x50b4, 1),
+    (4183, 0x50b2, 1),
+    (4184, 0x50c9, 2),
+    (4186, 0x50b3, 1),
+    (4187, 0x50c2, 1),
+    (4188, 0x50d6, 1),
+    (4189, 0x50de, 1),
+    (4190, 0x50e5, 1),
+    (4191, 0x50ed, 1),
+    (4192, 0x50e3, 1),
+    (4193, 0x50ee, 1),
+    (4194, 0x50f9, 1),
+    (4195, 0x50f5, 1),
+    (4196, 0x5109, 1),
+    (4197, 0x5101, 2),
+    (4199, 0x5116, 1),
+    (4200, 0x5115, 1),
+    (4201, 0x5114, 1),
+    (4202, 0x511a, 1),
+    (4203, 0x5121, 1),
+    (4204, 0x513a, 1),
+    (4205, 0x5137, 1),
+    (4206, 0x513c, 1),
+    (4207, 0x513b, 1),
+    (4208, 0x513f, 2),
+    (4210, 0x5152, 1),
+    (4211, 0x514c, 1),
+    (4212, 0x5154, 1),
+    (4213, 0x5162, 1),
+    (4214, 0x7af8, 1),
+    (4215, 0x5169, 2),
+    (4217, 0x516e, 1),
+    (4218, 0x5180, 1),
+    (4219, 0x5182, 1),
+    (4220, 0x56d8, 1),
+    (4221, 0x518c, 1),
+    (4222, 0x5189, 1),
+    (4223, 0x518f, 1),
+    (4224, 0x5191, 1),
+    (4225, 0x5193, 1),
+    (4226, 0x5195, 2),
+    (4228, 0x51a4, 1),
+    (4229, 0x51a6, 1),
+    (4230, 0x51a2, 1),
+    (4231, 0x51a9, 3),
+    (4234, 0x51b3, 1),
+    (4235, 0x51b1, 2),
+    (4237, 0x51b0, 1),
+    (4238, 0x51b5, 1),
+    (4239, 0x51bd, 1),
+    (4240, 0x51c5, 1),
+    (4241, 0x51c9, 1),
+    (4242, 0x51db, 1),
+    (4243, 0x51e0, 1),
+    (4244, 0x8655, 1),
+    (4245, 0x51e9, 1),
+    (4246, 0x51ed, 1),
+    (4247, 0x51f0, 1),
+    (4248, 0x51f5, 1),
+    (4249, 0x51fe, 1),
+    (4250, 0x5204, 1),
+    (4251, 0x520b, 1),
+    (4252, 0x5214, 1),
+    (4253, 0x520e, 1),
+    (4254, 0x5227, 1),
+    (4255, 0x522a, 1),
+    (4256, 0x522e, 1),
+    (4257, 0x5233, 1),
+    (4258, 0x5239, 1),
+    (4259, 0x524f, 1),
+    (4260, 0x5244, 1),
+    (4261, 0x524b, 2),
+    (4263, 0x525e, 1),
+    (4264, 0x5254, 1),
+    (4265, 0x526a, 1),
+    (4266, 0x5274, 1),
+    (4267, 0x5269, 1),
+    (4268, 0x5273, 1),
+    (4269, 0x527f, 1),
+    (4270, 0x527d, 1),
+    (4271, 0x528d, 1),
+    (4272, 0x5294, 1),
+    (4273, 0x5292, 1),
+    (4274, 0x5271, 1),
+    (4275, 0x5288, 1),
+    (4276, 0x5291, 1),
+    (4277, 0x8fa8, 1),
+    (4278, 0x8fa7, 1),
+    (4279, 0x52ac, 2),
+    (4281, 0x52bc, 1),
+    (4282, 0x52b5, 1),
+    (4283, 0x52c1, 1),
+    (4284, 0x52cd, 1),
+    (4285, 0x52d7, 1),
+    (4286, 0x52de, 1),
+    (4287, 0x52e3, 1),
+    (4288, 0x52e6, 1),
+    (4289, 0x98ed, 1),
+    (4290, 0x52e0, 1),
+    (4291, 0x52f3, 1),
+    (4292, 0x52f5, 1),
+    (4293, 0x52f8, 2),
+    (4295, 0x5306, 1),
+    (4296, 0x5308, 1),
+    (4297, 0x7538, 1),
+    (4298, 0x530d, 1),
+    (4299, 0x5310, 1),
+    (4300, 0x530f, 1),
+    (4301, 0x5315, 1),
+    (4302, 0x531a, 1),
+    (4303, 0x5323, 1),
+    (4304, 0x532f, 1),
+    (4305, 0x5331, 1),
+    (4306, 0x5333, 1),
+    (4307, 0x5338, 1),
+    (4308, 0x5340, 1),
+    (4309, 0x5346, 1),
+    (4310, 0x5345, 1),
+    (4311, 0x4e17, 1),
+    (4312, 0x5349, 1),
+    (4313, 0x534d, 1),
+    (4314, 0x51d6, 1),
+    (4315, 0x535e, 1),
+    (4316, 0x5369, 1),
+    (4317, 0x536e, 1),
+    (4318, 0x5918, 1),
+    (4319, 0x537b, 1),
+    (4320, 0x5377, 1),
+    (4321, 0x5382, 1),
+    (4322, 0x5396, 1),
+    (4323, 0x53a0, 1),
+    (4324, 0x53a6, 1),
+    (4325, 0x53a5, 1),
+    (4326, 0x53ae, 1),
+    (4327, 0x53b0, 1),
+    (4328, 0x53b6, 1),
+    (4329, 0x53c3, 1),
+    (4330, 0x7c12, 1),
+    (4331, 0x96d9, 1),
+    (4332, 0x53df, 1),
+    (4333, 0x66fc, 1),
+    (4334, 0x71ee, 1),
+    (4335, 0x53ee, 1),
+    (4336, 0x53e8, 1),
+    (4337, 0x53ed, 1),
+    (4338, 0x53fa, 1),
+    (4339, 0x5401, 1),
+    (4340, 0x543d, 1),
+    (4341, 0x5440, 1),
+    (4342, 0x542c, 2),
+    (4344, 0x543c, 1),
+    (4345, 0x542e, 1),
+    (4346, 0x5436, 1),
+    (4347, 0x5429, 1),
+    (4348, 0x541d, 1),
+    (4349, 0x544e, 1),
+    (4350, 0x548f, 1),
+    (4351, 0x5475, 1),
+    (4352, 0x548e, 1),
+    (4353, 0x545f, 1),
+    (4354, 0x5471, 1),
+    (4355, 0x5477, 1),
+    (4356, 0x5470, 1),
+    (4357, 0x5492, 1),
+    (4358, 0x547b, 1),
+    (4359, 0x5480, 1),
+    (4360, 0x5476, 1),
+    (4361, 0x5484, 1),
+    (4362, 0x5490, 1),
+    (4363, 0x5486, 1),
+    (4364, 0x54c7, 1),
+    (4365, 0x54a2, 1),
+    (4366, 0x54b8, 1),
+    (4367, 0x54a5, 1),
+    (4368, 0x54ac, 1),
+    (4369, 0x54c4, 1),
+    (4370, 0x54c8, 1),
+    (4371, 0x54a8, 1),
+    (4372, 0x54ab, 1),
+    (4373, 0x54c2, 1),
+    (4374, 0x54a4, 1),
+    (4375, 0x54be, 1),
+    (4376, 0x54bc, 1),
+    (4377, 0x54d8, 1),
+    (4378, 0x54e5, 2),
+    (4380, 0x550f, 1),
+    (4381, 0x5514, 1),
+    (4382, 0x54fd, 1),
+    (4383, 0x54ee, 1),
+    (4384, 0x54ed, 1),
+    (4385, 0x54fa, 1),
+    (4386, 0x54e2, 1),
+    (4387, 0x5539, 1),
+    (4388, 0x5540, 1),
+    (4389, 0x5563, 1),
+    (4390, 0x554c, 1),
+    (4391, 0x552e, 1),
+    (4392, 0x555c, 1),
+    (4393, 0x5545, 1),
+    (4394, 0x5556, 2),
+    (4396, 0x5538, 1),
+    (4397, 0x5533, 1),
+    (4398, 0x555d, 1),
+    (4399, 0x5599, 1),
+    (4400, 0x5580, 1),
+    (4401, 0x54af, 1),
+    (4402, 0x558a, 1),
+    (4403, 0x559f, 1),
+    (4404, 0x557b, 1),
+    (4405, 0x557e, 1),
+    (4406, 0x5598, 1),
+    (4407, 0x559e, 1),
+    (4408, 0x55ae, 1),
+    (4409, 0x557c, 1),
+    (4410, 0x5583, 1),
+    (4411, 0x55a9, 1),
+    (4412, 0x5587, 1),
+    (4413, 0x55a8, 1),
+    (4414, 0x55da, 1),
+    (4415, 0x55c5, 1),
+    (4416, 0x55df, 1),
+    (4417, 0x55c4, 1),
+    (4418, 0x55dc, 1),
+    (4419, 0x55e4, 1),
+    (4420, 0x55d4, 1),
+    (4421, 0x5614, 1),
+    (4422, 0x55f7, 1),
+    (4423, 0x5616, 1),
+    (4424, 0x55fe, 1),
+    (4425, 0x55fd, 1),
+    (4426, 0x561b, 1),
+    (4427, 0x55f9, 1),
+    (4428, 0x564e, 1),
+    (4429, 0x5650, 1),
+    (4430, 0x71df, 1),
+    (4431, 0x5634, 1),
+    (4432, 0x5636, 1),
+    (4433, 0x5632, 1),
+    (4434, 0x5638, 1),
+    (4435, 0x566b, 1),
+    (4436, 0x5664, 1),
+    (4437, 0x562f, 1),
+    (4438, 0x566c, 1),
+    (4439, 0x566a, 1),
+    (4440, 0x5686, 1),
+    (4441, 0x5680, 1),
+    (4442, 0x568a, 1),
+    (4443, 0x56a0, 1),
+    (4444, 0x5694, 1),
+    (4445, 0x568f, 1),
+    (4446, 0x56a5, 1),
+    (4447, 0x56ae, 1),
+    (4448, 0x56b6, 1),
+    (4449, 0x56b4, 1),
+    (4450, 0x56c2, 1),
+    (4451, 0x56bc, 1),
+    (4452, 0x56c1, 1),
+    (4453, 0x56c3, 1),
+    (4454, 0x56c0, 1),
+    (4455, 0x56c8, 1),
+    (4456, 0x56ce, 1),
+    (4457, 0x56d1, 1),
+    (4458, 0x56d3, 1),
+    (4459, 0x56d7, 1),
+    (4460, 0x56ee, 1),
+    (4461, 0x56f9, 1),
+    (4462, 0x5700, 1),
+    (4463, 0x56ff, 1),
+    (4464, 0x5704, 1),
+    (4465, 0x5709, 1),
+    (4466, 0x5708, 1),
+    (4467, 0x570b, 1),
+    (4468, 0x570d, 1),
+    (4469, 0x5713, 1),
+    (4470, 0x5718, 1),
+    (4471, 0x5716, 1),
+    (4472, 0x55c7, 1),
+    (4473, 0x571c, 1),
+    (4474, 0x5726, 1),
+    (4475, 0x5737, 2),
+    (4477, 0x574e, 1),
+    (4478, 0x573b, 1),
+    (4479, 0x5740, 1),
+    (4480, 0x574f, 1),
+    (4481, 0x5769, 1),
+    (4482, 0x57c0, 1),
+    (4483, 0x5788, 1),
+    (4484, 0x5761, 1),
+    (4485, 0x577f, 1),
+    (4486, 0x5789, 1),
+    (4487, 0x5793, 1),
+    (4488, 0x57a0, 1),
+    (4489, 0x57b3, 1),
+    (4490, 0x57a4, 1),
+    (4491, 0x57aa, 1),
+    (4492, 0x57b0, 1),
+    (4493, 0x57c3, 1),
+    (4494, 0x57c6, 1),
+    (4495, 0x57d4, 1),
+    (4496, 0x57d2, 2),
+    (4498, 0x580a, 1),
+    (4499, 0x57d6, 1),
+    (4500, 0x57e3, 1),
+    (4501, 0x580b, 1),
+    (4502, 0x5819, 1),
+    (4503, 0x581d, 1),
+    (4504, 0x5872, 1),
+    (4505, 0x5821, 1),
+    (4506, 0x5862, 1),
+    (4507, 0x584b, 1),
+    (4508, 0x5870, 1),
+    (4509, 0x6bc0, 1),
+    (4510, 0x5852, 1),
+    (4511, 0x583d, 1),
+    (4512, 0x5879, 1),
+    (4513, 0x5885, 1),
+    (4514, 0x58b9, 1),
+    (4515, 0x589f, 1),
+    (4516, 0x58ab, 1),
+    (4517, 0x58ba, 1),
+    (4518, 0x58de, 1),
+    (4519, 0x58bb, 1),
+    (4520, 0x58b8, 1),
+    (4521, 0x58ae, 1),
+    (4522, 0x58c5, 1),
+    (4523, 0x58d3, 1),
+    (4524, 0x58d1, 1),
+    (4525, 0x58d7, 1),
+    (4526, 0x58d9, 1),
+    (4527, 0x58d8, 1),
+    (4528, 0x58e5, 1),
+    (4529, 0x58dc, 1),
+    (4530, 0x58e4, 1),
+    (4531, 0x58df, 1),
+    (4532, 0x58ef, 1),
+    (4533, 0x58fa, 1),
+    (4534, 0x58f9, 1),
+    (4535, 0x58fb, 3),
+    (4538, 0x5902, 1),
+    (4539, 0x590a, 1),
+    (4540, 0x5910, 1),
+    (4541, 0x591b, 1),
+    (4542, 0x68a6, 1),
+    (4543, 0x5925, 1),
+    (4544, 0x592c, 2),
+    (4546, 0x5932, 1),
+    (4547, 0x5938, 1),
+    (4548, 0x593e, 1),
+    (4549, 0x7ad2, 1),
+    (4550, 0x5955, 1),
+    (4551, 0x5950, 1),
+    (4552, 0x594e, 1),
+    (4553, 0x595a, 1),
+    (4554, 0x5958, 1),
+    (4555, 0x5962, 1),
+    (4556, 0x5960, 1),
+    (4557, 0x5967, 1),
+    (4558, 0x596c, 1),
+    (4559, 0x5969, 1),
+    (4560, 0x5978, 1),
+    (4561, 0x5981, 1),
+    (4562, 0x599d, 1),
+    (4563, 0x4f5e, 1),
+    (4564, 0x4fab, 1),
+    (4565, 0x59a3, 1),
+    (4566, 0x59b2, 1),
+    (4567, 0x59c6, 1),
+    (4568, 0x59e8, 1),
+    (4569, 0x59dc, 1),
+    (4570, 0x598d, 1),
+    (4571, 0x59d9, 2),
+    (4573, 0x5a25, 1),
+    (4574, 0x5a1f, 1),
+    (4575, 0x5a11, 1),
+    (4576, 0x5a1c, 1),
+    (4577, 0x5a09, 1),
+    (4578, 0x5a1a, 1),
+    (4579, 0x5a40, 1),
+    (4580, 0x5a6c, 1),
+    (4581, 0x5a49, 1),
+    (4582, 0x5a35, 2),
+    (4584, 0x5a62, 1),
+    (4585, 0x5a6a, 1),
+    (4586, 0x5a9a, 1),
+    (4587, 0x5abc, 1),
+    (4588, 0x5abe, 1),
+    (4589, 0x5acb, 1),
+    (4590, 0x5ac2, 1),
+    (4591, 0x5abd, 1),
+    (4592, 0x5ae3, 1),
+    (4593, 0x5ad7, 1),
+    (4594, 0x5ae6, 1),
+    (4595, 0x5ae9, 1),
+    (4596, 0x5ad6, 1),
+    (4597, 0x5afa, 2),
+    (4599, 0x5b0c, 1),
+    (4600, 0x5b0b, 1),
+    (4601, 0x5b16, 1),
+    (4602, 0x5b32, 1),
+    (4603, 0x5ad0, 1),
+    (4604, 0x5b2a, 1),
+    (4605, 0x5b36, 1),
+    (4606, 0x5b3e, 1),
+    (4607, 0x5b43, 1),
+    (4608, 0x5b45, 1),
+    (4609, 0x5b40, 1),
+    (4610, 0x5b51, 1),
+    (4611, 0x5b55, 1),
+    (4612, 0x5b5a, 2),
+    (4614, 0x5b65, 1),
+    (4615, 0x5b69, 1),
+    (4616, 0x5b70, 1),
+    (4617, 0x5b73, 1),
+    (4618, 0x5b75, 1),
+    (4619, 0x5b78, 1),
+    (4620, 0x6588, 1),
+    (4621, 0x5b7a, 1),
+    (4622, 0x5b80, 1),
+    (4623, 0x5b83, 1),
+    (4624, 0x5ba6, 1),
+    (4625, 0x5bb8, 1),
+    (4626, 0x5bc3, 1),
+    (4627, 0x5bc7, 1),
+    (4628, 0x5bc9, 1),
+    (4629, 0x5bd4, 1),
+    (4630, 0x5bd0, 1),
+    (4631, 0x5be4, 1),
+    (4632, 0x5be6, 1),
+    (4633, 0x5be2, 1),
+    (4634, 0x5bde, 1),
+    (4635, 0x5be5, 1),
+    (4636, 0x5beb, 1),
+    (4637, 0x5bf0, 1),
+    (4638, 0x5bf6, 1),
+    (4639, 0x5bf3, 1),
+    (4640, 0x5c05, 1),
+    (4641, 0x5c07, 2),
+    (4643, 0x5c0d, 1),
+    (4644, 0x5c13, 1),
+    (4645, 0x5c20, 1),
+    (4646, 0x5c22, 1),
+    (4647, 0x5c28, 1),
+    (4648, 0x5c38, 2),
+    (4650, 0x5c41, 1),
+    (4651, 0x5c46, 1),
+    (4652, 0x5c4e, 1),
+    (4653, 0x5c53, 1),
+    (4654, 0x5c50, 1),
+    (4655, 0x5c4f, 1),
+    (4656, 0x5b71, 1),
+    (4657, 0x5c6c, 1),
+    (4658, 0x5c6e, 1),
+    (4659, 0x4e62, 1),
+    (4660, 0x5c76, 1),
+    (4661, 0x5c79, 1),
+    (4662, 0x5c8c, 1),
+    (4663, 0x5c91, 1),
+    (4664, 0x5c94, 1),
+    (4665, 0x599b, 1),
+    (4666, 0x5cab, 1),
+    (4667, 0x5cbb, 1),
+    (4668, 0x5cb6, 1),
+    (4669, 0x5cbc, 1),
+    (4670, 0x5cb7, 1),
+    (4671, 0x5cc5, 1),
+    (4672, 0x5cbe, 1),
+    (4673, 0x5cc7, 1),
+    (4674, 0x5cd9, 1),
+    (4675, 0x5ce9, 1),
+    (4676, 0x5cfd, 1),
+    (4677, 0x5cfa, 1),
+    (4678, 0x5ced, 1),
+    (4679, 0x5d8c, 1),
+    (4680, 0x5cea, 1),
+    (4681, 0x5d0b, 1),
+    (4682, 0x5d15, 1),
+    (4683, 0x5d17, 1),
+    (4684, 0x5d5c, 1),
+    (4685, 0x5d1f, 1),
+    (4686, 0x5d1b, 1),
+    (4687, 0x5d11, 1),
+    (4688, 0x5d14, 1),
+    (4689, 0x5d22, 1),
+    (4690, 0x5d1a, 1),
+    (4691, 0x5d19, 1),
+    (4692, 0x5d18, 1),
+    (4693, 0x5d4c, 1),
+    (4694, 0x5d52, 1),
+    (4695, 0x5d4e, 1),
+    (4696, 0x5d4b, 1),
+    (4697, 0x5d6c, 1),
+    (4698, 0x5d73, 1),
+    (4699, 0x5d76, 1),
+    (4700, 0x5d87, 1),
+    (4701, 0x5d84, 1),
+    (4702, 0x5d82, 1),
+    (4703, 0x5da2, 1),
+    (4704, 0x5d9d, 1),
+    (4705, 0x5dac, 1),
+    (4706, 0x5dae, 1),
+    (4707, 0x5dbd, 1),
+    (4708, 0x5d90, 1),
+    (4709, 0x5db7, 1),
+    (4710, 0x5dbc, 1),
+    (4711, 0x5dc9, 1),
+    (4712, 0x5dcd, 1),
+    (4713, 0x5dd3, 1),
+    (4714, 0x5dd2, 1),
+    (4715, 0x5dd6, 1),
+    (4716, 0x5ddb, 1),
+    (4717, 0x5deb, 1),
+    (4718, 0x5df2, 1),
+    (4719, 0x5df5, 1),
+    (4720, 0x5e0b, 1),
+    (4721, 0x5e1a, 1),
+    (4722, 0x5e19, 1),
+    (4723, 0x5e11, 1),
+    (4724, 0x5e1b, 1),
+    (4725, 0x5e36, 2),
+    (4727, 0x5e44, 1),
+    (4728, 0x5e43, 1),
+    (4729, 0x5e40, 1),
+    (4730, 0x5e4e, 1),
+    (4731, 0x5e57, 1),
+    (4732, 0x5e54, 1),
+    (4733, 0x5e5f, 1),
+    (4734, 0x5e62, 1),
+    (4735, 0x5e64, 1),
+    (4736, 0x5e47, 1),
+    (4737, 0x5e75, 2),
+    (4739, 0x5e7a, 1),
+    (4740, 0x9ebc, 1),
+    (4741, 0x5e7f, 1),
+    (4742, 0x5ea0, 1),
+    (4743, 0x5ec1, 2),
+    (4745, 0x5ec8, 1),
+    (4746, 0x5ed0, 1),
+    (4747, 0x5ecf, 1),
+    (4748, 0x5ed6, 1),
+    (4749, 0x5ee3, 1),
+    (4750, 0x5edd, 1),
+    (4751, 0x5eda, 2),
+    (4753, 0x5ee2, 1),
+    (4754, 0x5ee1, 1),
+    (4755, 0x5ee8, 2),
+    (4757, 0x5eec, 1),
+    (4758, 0x5ef1, 1),
+    (4759, 0x5ef3, 1),
+    (4760, 0x5ef0, 1),
+    (4761, 0x5ef4, 1),
+    (4762, 0x5ef8, 1),
+    (4763, 0x5efe, 1),
+    (4764, 0x5f03, 1),
+    (4765, 0x5f09, 1),
+    (4766, 0x5f5d, 1),
+    (4767, 0x5f5c, 1),
+    (4768, 0x5f0b, 1),
+    (4769, 0x5f11, 1),
+    (4770, 0x5f16, 1),
+    (4771, 0x5f29, 1),
+    (4772, 0x5f2d, 1),
+    (4773, 0x5f38, 1),
+    (4774, 0x5f41, 1),
+    (4775, 0x5f48, 1),
+    (4776, 0x5f4c, 1),
+    (4777, 0x5f4e, 1),
+    (4778, 0x5f2f, 1),
+    (4779, 0x5f51, 1),
+    (4780, 0x5f56, 2),
+    (4782, 0x5f59, 1),
+    (4783, 0x5f61, 1),
+    (4784, 0x5f6d, 1),
+    (4785, 0x5f73, 1),
+    (4786, 0x5f77, 1),
+    (4787, 0x5f83, 1),
+    (4788, 0x5f82, 1),
+    (4789, 0x5f7f, 1),
+    (4790, 0x5f8a, 1),
+    (4791, 0x5f88, 1),
+    (4792, 0x5f91, 1),
+    (4793, 0x5f87, 1),
+    (4794, 0x5f9e, 1),
+    (4795, 0x5f99, 1),
+    (4796, 0x5f98, 1),
+    (4797, 0x5fa0, 1),
+    (4798, 0x5fa8, 1),
+    (4799, 0x5fad, 1),
+    (4800, 0x5fbc, 1),
+    (4801, 0x5fd6, 1),
+    (4802, 0x5ffb, 1),
+    (4803, 0x5fe4, 1),
+    (4804, 0x5ff8, 1),
+    (4805, 0x5ff1, 1),
+    (4806, 0x5fdd, 1),
+    (4807, 0x60b3, 1),
+    (4808, 0x5fff, 1),
+    (4809, 0x6021, 1),
+    (4810, 0x6060, 1),
+    (4811, 0x6019, 1),
+    (4812, 0x6010, 1),
+    (4813, 0x6029, 1),
+    (4814, 0x600e, 1),
+    (4815, 0x6031, 1),
+    (4816, 0x601b, 1),
+    (4817, 0x6015, 1),
+    (4818, 0x602b, 1),
+    (4819, 0x6026, 1),
+    (4820, 0x600f, 1),
+    (4821, 0x603a, 1),
+    (4822, 0x605a, 1),
+    (4823, 0x6041, 1),
+    (4824, 0x606a, 1),
+    (4825, 0x6077, 1),
+    (4826, 0x605f, 1),
+    (4827, 0x604a, 1),
+    (4828, 0x6046, 1),
+    (4829, 0x604d, 1),
+    (4830, 0x6063, 1),
+    (4831, 0x6043, 1),
+    (4832, 0x6064, 1),
+    (4833, 0x6042, 1),
+    (4834, 0x606c, 1),
+    (4835, 0x606b, 1),
+    (4836, 0x6059, 1),
+    (4837, 0x6081, 1),
+    (4838, 0x608d, 1),
+    (4839, 0x60e7, 1),
+    (4840, 0x6083, 1),
+    (4841, 0x609a, 1),
+    (4842, 0x6084, 1),
+    (4843, 0x609b, 1),
+    (4844, 0x6096, 2),
+    (4846, 0x6092, 1),
+    (4847, 0x60a7, 1),
+    (4848, 0x608b, 1),
+    (4849, 0x60e1, 1),
+    (4850, 0x60b8, 1),
+    (4851, 0x60e0, 1),
+    (4852, 0x60d3, 1),
+    (4853, 0x60b4, 1),
+    (4854, 0x5ff0, 1),
+    (4855, 0x60bd, 1),
+    (4856, 0x60c6, 1),
+    (4857, 0x60b5, 1),
+    (4858, 0x60d8, 1),
+    (4859, 0x614d, 1),
+    (4860, 0x6115, 1),
+    (4861, 0x6106, 1),
+    (4862, 0x60f6, 2),
+    (4864, 0x6100, 1),
+    (4865, 0x60f4, 1),
+    (4866, 0x60fa, 1),
+    (4867, 0x6103, 1),
+    (4868, 0x6121, 1),
+    (4869, 0x60fb, 1),
+    (4870, 0x60f1, 1),
+    (4871, 0x610d, 2),
+    (4873, 0x6147, 1),
+    (4874, 0x613e, 1),
+    (4875, 0x6128, 1),
+    (4876, 0x6127, 1),
+    (4877, 0x614a, 1),
+    (4878, 0x613f, 1),
+    (4879, 0x613c, 1),
+    (4880, 0x612c, 1),
+    (4881, 0x6134, 1),
+    (4882, 0x613d, 1),
+    (4883, 0x6142, 1),
+    (4884, 0x6144, 1),
+    (4885, 0x6173, 1),
+    (4886, 0x6177, 1),
+    (4887, 0x6158, 3),
+    (4890, 0x616b, 1),
+    (4891, 0x6174, 1),
+    (4892, 0x616f, 1),
+    (4893, 0x6165, 1),
+    (4894, 0x6171, 1),
+    (4895, 0x615f, 1),
+    (4896, 0x615d, 1),
+    (4897, 0x6153, 1),
+    (4898, 0x6175, 1),
+    (4899, 0x6199, 1),
+    (4900, 0x6196, 1),
+    (4901, 0x6187, 1),
+    (4902, 0x61ac, 1),
+    (4903, 0x6194, 1),
+    (4904, 0x619a, 1),
+    (4905, 0x618a, 1),
+    (4906, 0x6191, 1),
+    (4907, 0x61ab, 1),
+    (4908, 0x61ae, 1),
+    (4909, 0x61cc, 1),
+    (4910, 0x61ca, 1),
+    (4911, 0x61c9, 1),
+    (4912, 0x61f7, 1),
+    (4913, 0x61c8, 1),
+    (4914, 0x61c3, 1),
+    (4915, 0x61c6, 1),
+    (4916, 0x61ba, 1),
+    (4917, 0x61cb, 1),
+    (4918, 0x7f79, 1),
+    (4919, 0x61cd, 1),
+    (4920, 0x61e6, 1),
+    (4921, 0x61e3, 1),
+    (4922, 0x61f6, 1),
+    (4923, 0x61fa, 1),
+    (4924, 0x61f4, 1),
+    (4925, 0x61ff, 1),
+    (4926, 0x61fd, 1),
+    (4927, 0x61fc, 1),
+    (4928, 0x61fe, 1),
+    (4929, 0x6200, 1),
+    (4930, 0x6208, 2),
+    (4932, 0x620d, 1),
+    (4933, 0x620c, 1),
+    (4934, 0x6214, 1),
+    (4935, 0x621b, 1),
+    (4936, 0x621e, 1),
+    (4937, 0x6221, 1),
+    (4938, 0x622a, 1),
+    (4939, 0x622e, 1),
+    (4940, 0x6230, 1),
+    (4941, 0x6232, 2),
+    (4943, 0x6241, 1),
+    (4944, 0x624e, 1),
+    (4945, 0x625e, 1),
+    (4946, 0x6263, 1),
+    (4947, 0x625b, 1),
+    (4948, 0x6260, 1),
+    (4949, 0x6268, 1),
+    (4950, 0x627c, 1),
+    (4951, 0x6282, 1),
+    (4952, 0x6289, 1),
+    (4953, 0x627e, 1),
+    (4954, 0x6292, 2),
+    (4956, 0x6296, 1),
+    (4957, 0x62d4, 1),
+    (4958, 0x6283, 1),
+    (4959, 0x6294, 1),
+    (4960, 0x62d7, 1),
+    (4961, 0x62d1, 1),
+    (4962, 0x62bb, 1),
+    (4963, 0x62cf, 1),
+    (4964, 0x62ff, 1),
+    (4965, 0x62c6, 1),
+    (4966, 0x64d4, 1),
+    (4967, 0x62c8, 1),
+    (4968, 0x62dc, 1),
+    (4969, 0x62cc, 1),
+    (4970, 0x62ca, 1),
+    (4971, 0x62c2, 1),
+    (4972, 0x62c7, 1),
+    (4973, 0x629b, 1),
+    (4974, 0x62c9, 1),
+    (4975, 0x630c, 1),
+    (4976, 0x62ee, 1),
+    (4977, 0x62f1, 1),
+    (4978, 0x6327, 1),
+    (4979, 0x6302, 1),
+    (4980, 0x6308, 1),
+    (4981, 0x62ef, 1),
+    (4982, 0x62f5, 1),
+    (4983, 0x6350, 1),
+    (4984, 0x633e, 1),
+    (4985, 0x634d, 1),
+    (4986, 0x641c, 1),
+    (4987, 0x634f, 1),
+    (4988, 0x6396, 1),
+    (4989, 0x638e, 1),
+    (4990, 0x6380, 1),
+    (4991, 0x63ab, 1),
+    (4992, 0x6376, 1),
+    (4993, 0x63a3, 1),
+    (4994, 0x638f, 1),
+    (4995, 0x6389, 1),
+    (4996, 0x639f, 1),
+    (4997, 0x63b5, 1),
+    (4998, 0x636b, 1),
+    (4999, 0x6369, 1),
+    (5000, 0x63be, 1),
+    (5001, 0x63e9, 1),
+    (5002, 0x63c0, 1),
+    (5003, 0x63c6, 1),
+    (5004, 0x63e3, 1),
+    (5005, 0x63c9, 1),
+    (5006, 0x63d2, 1),
+    (5007, 0x63f6, 1),
+    (5008, 0x63c4, 1),
+    (5009, 0x6416, 1),
+    (5010, 0x6434, 1),
+    (5011, 0x6406, 1),
+    (5012, 0x6413, 1),
+    (5013, 0x6426, 1),
+    (5014, 0x6436, 1),
+    (5015, 0x651d, 1),
+    (5016, 0x6417, 1),
+    (5017, 0x6428, 1),
+    (5018, 0x640f, 1),
+    (5019, 0x6467, 1),
+    (5020, 0x646f, 1),
+    (5021, 0x6476, 1),
+    (5022, 0x644e, 1),
+    (5023, 0x652a, 1),
+    (5024, 0x6495, 1),
+    (5025, 0x6493, 1),
+    (5026, 0x64a5, 1),
+    (5027, 0x64a9, 1),
+    (5028, 0x6488, 1),
+    (5029, 0x64bc, 1),
+    (5030, 0x64da, 1),
+    (5031, 0x64d2, 1),
+    (5032, 0x64c5, 1),
+    (5033, 0x64c7, 1),
+    (5034, 0x64bb, 1),
+    (5035, 0x64d8, 1),
+    (5036, 0x64c2, 1),
+    (5037, 0x64f1, 1),
+    (5038, 0x64e7, 1),
+    (5039, 0x8209, 1),
+    (5040, 0x64e0, 2),
+    (5042, 0x62ac, 1),
+    (5043, 0x64e3, 1),
+    (5044, 0x64ef, 1),
+    (5045, 0x652c, 1),
+    (5046, 0x64f6, 1),
+    (5047, 0x64f4, 1),
+    (5048, 0x64f2, 1),
+    (5049, 0x64fa, 1),
+    (5050, 0x6500, 1),
+    (5051, 0x64fd, 1),
+    (5052, 0x6518, 1),
+    (5053, 0x651c, 1),
+    (5054, 0x6505, 1),
+    (5055, 0x6524, 1),
+    (5056, 0x6523, 1),
+    (5057, 0x652b, 1),
+    (5058, 0x6534, 2),
+    (5060, 0x6537, 1),
+    (5061, 0x6536, 1),
+    (5062, 0x6538, 1),
+    (5063, 0x754b, 1),
+    (5064, 0x6548, 1),
+    (5065, 0x6556, 1),
+    (5066, 0x6555, 1),
+    (5067, 0x654d, 1),
+    (5068, 0x6558, 1),
+    (5069, 0x655e, 1),
+    (5070, 0x655d, 1),
+    (5071, 0x6572, 1),
+    (5072, 0x6578, 1),
+    (5073, 0x6582, 2),
+    (5075, 0x8b8a, 1),
+    (5076, 0x659b, 1),
+    (5077, 0x659f, 1),
+    (5078, 0x65ab, 1),
+    (5079, 0x65b7, 1),
+    (5080, 0x65c3, 1),
+    (5081, 0x65c6, 1),
+    (5082, 0x65c1, 1),
+    (5083, 0x65c4, 1),
+    (5084, 0x65cc, 1),
+    (5085, 0x65d2, 1),
+    (5086, 0x65db, 1),
+    (5087, 0x65d9, 1),
+    (5088, 0x65e0, 2),
+    (5090, 0x65f1, 1),
+    (5091, 0x6772, 1),
+    (5092, 0x660a, 1),
+    (5093, 0x6603, 1),
+    (5094, 0x65fb, 1),
+    (5095, 0x6773, 1),
+    (5096, 0x6635, 2),
+    (5098, 0x6634, 1),
+    (5099, 0x661c, 1),
+    (5100, 0x664f, 1),
+    (5101, 0x6644, 1),
+    (5102, 0x6649, 1),
+    (5103, 0x6641, 1),
+    (5104, 0x665e, 1),
+    (5105, 0x665d, 1),
+    (5106, 0x6664, 1),
+    (5107, 0x6667, 2),
+    (5109, 0x665f, 1),
+    (5110, 0x6662, 1),
+    (5111, 0x6670, 1),
+    (5112, 0x6683, 1),
+    (5113, 0x6688, 1),
+    (5114, 0x668e, 1),
+    (5115, 0x6689, 1),
+    (5116, 0x6684, 1),
+    (5117, 0x6698, 1),
+    (5118, 0x669d, 1),
+    (5119, 0x66c1, 1),
+    (5120, 0x66b9, 1),
+    (5121, 0x66c9, 1),
+    (5122, 0x66be, 1),
+    (5123, 0x66bc, 1),
+    (5124, 0x66c4, 1),
+    (5125, 0x66b8, 1),
+    (5126, 0x66d6, 1),
+    (5127, 0x66da, 1),
+    (5128, 0x66e0, 1),
+    (5129, 0x663f, 1),
+    (5130, 0x66e6, 1),
+    (5131, 0x66e9, 1),
+    (5132, 0x66f0, 1),
+    (5133, 0x66f5, 1),
+    (5134, 0x66f7, 1),
+    (5135, 0x670f, 1),
+    (5136, 0x6716, 1),
+    (5137, 0x671e, 1),
+    (5138, 0x6726, 2),
+    (5140, 0x9738, 1),
+    (5141, 0x672e, 1),
+    (5142, 0x673f, 1),
+    (5143, 0x6736, 1),
+    (5144, 0x6741, 1),
+    (5145, 0x6738, 1),
+    (5146, 0x6737, 1),
+    (5147, 0x6746, 1),
+    (5148, 0x675e, 1),
+    (5149, 0x6760, 1),
+    (5150, 0x6759, 1),
+    (5151, 0x6763, 2),
+    (5153, 0x6789, 1),
+    (5154, 0x6770, 1),
+    (5155, 0x67a9, 1),
+    (5156, 0x677c, 1),
+    (5157, 0x676a, 1),
+    (5158, 0x678c, 1),
+    (5159, 0x678b, 1),
+    (5160, 0x67a6, 1),
+    (5161, 0x67a1, 1),
+    (5162, 0x6785, 1),
+    (5163, 0x67b7, 1),
+    (5164, 0x67ef, 1),
+    (5165, 0x67b4, 1),
+    (5166, 0x67ec, 1),
+    (5167, 0x67b3, 1),
+    (5168, 0x67e9, 1),
+    (5169, 0x67b8, 1),
+    (5170, 0x67e4, 1),
+    (5171, 0x67de, 1),
+    (5172, 0x67dd, 1),
+    (5173, 0x67e2, 1),
+    (5174, 0x67ee, 1),
+    (5175, 0x67b9, 1),
+    (5176, 0x67ce, 1),
+    (5177, 0x67c6, 1),
+    (5178, 0x67e7, 1),
+    (5179, 0x6a9c, 1),
+    (5180, 0x681e, 1),
+    (5181, 0x6846, 1),
+    (5182, 0x6829, 1),
+    (5183, 0x6840, 1),
+    (5184, 0x684d, 1),
+    (5185, 0x6832, 1),
+    (5186, 0x684e, 1),
+    (5187, 0x68b3, 1),
+    (5188, 0x682b, 1),
+    (5189, 0x6859, 1),
+    (5190, 0x6863, 1),
+    (5191, 0x6877, 1),
+    (5192, 0x687f, 1),
+    (5193, 0x689f, 1),
+    (5194, 0x688f, 1),
+    (5195, 0x68ad, 1),
+    (5196, 0x6894, 1),
+    (5197, 0x689d, 1),
+    (5198, 0x689b, 1),
+    (5199, 0x6883, 1),
+    (5200, 0x6aae, 1),
+    (5201, 0x68b9, 1),
+    (5202, 0x6874, 1),
+    (5203, 0x68b5, 1),
+    (5204, 0x68a0, 1),
+    (5205, 0x68ba, 1),
+    (5206, 0x690f, 1),
+    (5207, 0x688d, 1),
+    (5208, 0x687e, 1),
+    (5209, 0x6901, 1),
+    (5210, 0x68ca, 1),
+    (5211, 0x6908, 1),
+    (5212, 0x68d8, 1),
+    (5213, 0x6922, 1),
+    (5214, 0x6926, 1),
+    (5215, 0x68e1, 1),
+    (5216, 0x690c, 1),
+    (5217, 0x68cd, 1),
+    (5218, 0x68d4, 1),
+    (5219, 0x68e7, 1),
+    (5220, 0x68d5, 1),
+    (5221, 0x6936, 1),
+    (5222, 0x6912, 1),
+    (5223, 0x6904, 1),
+    (5224, 0x68d7, 1),
+    (5225, 0x68e3, 1),
+    (5226, 0x6925, 1),
+    (5227, 0x68f9, 1),
+    (5228, 0x68e0, 1),
+    (5229, 0x68ef, 1),
+    (5230, 0x6928, 1),
+    (5231, 0x692a, 1),
+    (5232, 0x691a, 1),
+    (5233, 0x6923, 1),
+    (5234, 0x6921, 1),
+    (5235, 0x68c6, 1),
+    (5236, 0x6979, 1),
+    (5237, 0x6977, 1),
+    (5238, 0x695c, 1),
+    (5239, 0x6978, 1),
+    (5240, 0x696b, 1),
+    (5241, 0x6954, 1),
+    (5242, 0x697e, 1),
+    (5243, 0x696e, 1),
+    (5244, 0x6939, 1),
+    (5245, 0x6974, 1),
+    (5246, 0x693d, 1),
+    (5247, 0x6959, 1),
+    (5248, 0x6930, 1),
+    (5249, 0x6961, 1),
+    (5250, 0x695e, 1),
+    (5251, 0x695d, 1),
+    (5252, 0x6981, 1),
+    (5253, 0x696a, 1),
+    (5254, 0x69b2, 1),
+    (5255, 0x69ae, 1),
+    (5256, 0x69d0, 1),
+    (5257, 0x69bf, 1),
+    (5258, 0x69c1, 1),
+    (5259, 0x69d3, 1),
+    (5260, 0x69be, 1),
+    (5261, 0x69ce, 1),
+    (5262, 0x5be8, 1),
+    (5263, 0x69ca, 1),
+    (5264, 0x69dd, 1),
+    (5265, 0x69bb, 1),
+    (5266, 0x69c3, 1),
+    (5267, 0x69a7, 1),
+    (5268, 0x6a2e, 1),
+    (5269, 0x6991, 1),
+    (5270, 0x69a0, 1),
+    (5271, 0x699c, 1),
+    (5272, 0x6995, 1),
+    (5273, 0x69b4, 1),
+    (5274, 0x69de, 1),
+    (5275, 0x69e8, 1),
+    (5276, 0x6a02, 1),
+    (5277, 0x6a1b, 1),
+    (5278, 0x69ff, 1),
+    (5279, 0x6b0a, 1),
+    (5280, 0x69f9, 1),
+    (5281, 0x69f2, 1),
+    (5282, 0x69e7, 1),
+    (5283, 0x6a05, 1),
+    (5284, 0x69b1, 1),
+    (5285, 0x6a1e, 1),
+    (5286, 0x69ed, 1),
+    (5287, 0x6a14, 1),
+    (5288, 0x69eb, 1),
+    (5289, 0x6a0a, 1),
+    (5290, 0x6a12, 1),
+    (5291, 0x6ac1, 1),
+    (5292, 0x6a23, 1),
+    (5293, 0x6a13, 1),
+    (5294, 0x6a44, 1),
+    (5295, 0x6a0c, 1),
+    (5296, 0x6a72, 1),
+    (5297, 0x6a36, 1),
+    (5298, 0x6a78, 1),
+    (5299, 0x6a47, 1),
+    (5300, 0x6a62, 1),
+    (5301, 0x6a59, 1),
+    (5302, 0x6a66, 1),
+    (5303, 0x6a48, 1),
+    (5304, 0x6a38, 1),
+    (5305, 0x6a22, 1),
+    (5306, 0x6a90, 1),
+    (5307, 0x6a8d, 1),
+    (5308, 0x6aa0, 1),
+    (5309, 0x6a84, 1),
+    (5310, 0x6aa2, 2),
+    (5312, 0x6a97, 1),
+    (5313, 0x8617, 1),
+    (5314, 0x6abb, 1),
+    (5315, 0x6ac3, 1),
+    (5316, 0x6ac2, 1),
+    (5317, 0x6ab8, 1),
+    (5318, 0x6ab3, 1),
+    (5319, 0x6aac, 1),
+    (5320, 0x6ade, 1),
+    (5321, 0x6ad1, 1),
+    (5322, 0x6adf, 1),
+    (5323, 0x6aaa, 1),
+    (5324, 0x6ada, 1),
+    (5325, 0x6aea, 1),
+    (5326, 0x6afb, 1),
+    (5327, 0x6b05, 1),
+    (5328, 0x8616, 1),
+    (5329, 0x6afa, 1),
+    (5330, 0x6b12, 1),
+    (5331, 0x6b16, 1),
+    (5332, 0x9b31, 1),
+    (5333, 0x6b1f, 1),
+    (5334, 0x6b38, 1),
+    (5335, 0x6b37, 1),
+    (5336, 0x76dc, 1),
+    (5337, 0x6b39, 1),
+    (5338, 0x98ee, 1),
+    (5339, 0x6b47, 1),
+    (5340, 0x6b43, 1),
+    (5341, 0x6b49, 1),
+    (5342, 0x6b50, 1),
+    (5343, 0x6b59, 1),
+    (5344, 0x6b54, 1),
+    (5345, 0x6b5b, 1),
+    (5346, 0x6b5f, 1),
+    (5347, 0x6b61, 1),
+    (5348, 0x6b78, 2),
+    (5350, 0x6b7f, 2),
+    (5352, 0x6b84, 1),
+    (5353, 0x6b83, 1),
+    (5354, 0x6b8d, 1),
+    (5355, 0x6b98, 1),
+    (5356, 0x6b95, 1),
+    (5357, 0x6b9e, 1),
+    (5358, 0x6ba4, 1),
+    (5359, 0x6baa, 2),
+    (5361, 0x6baf, 1),
+    (5362, 0x6bb2, 1),
+    (5363, 0x6bb1, 1),
+    (5364, 0x6bb3, 1),
+    (5365, 0x6bb7, 1),
+    (5366, 0x6bbc, 1),
+    (5367, 0x6bc6, 1),
+    (5368, 0x6bcb, 1),
+    (5369, 0x6bd3, 1),
+    (5370, 0x6bdf, 1),
+    (5371, 0x6bec, 1),
+    (5372, 0x6beb, 1),
+    (5373, 0x6bf3, 1),
+    (5374, 0x6bef, 1),
+    (5375, 0x9ebe, 1),
+    (5376, 0x6c08, 1),
+    (5377, 0x6c13, 2),
+    (5379, 0x6c1b, 1),
+    (5380, 0x6c24, 1),
+    (5381, 0x6c23, 1),
+    (5382, 0x6c5e, 1),
+    (5383, 0x6c55, 1),
+    (5384, 0x6c62, 1),
+    (5385, 0x6c6a, 1),
+    (5386, 0x6c82, 1),
+    (5387, 0x6c8d, 1),
+    (5388, 0x6c9a, 1),
+    (5389, 0x6c81, 1),
+    (5390, 0x6c9b, 1),
+    (5391, 0x6c7e, 1),
+    (5392, 0x6c68, 1),
+    (5393, 0x6c73, 1),
+    (5394, 0x6c92, 1),
+    (5395, 0x6c90, 1),
+    (5396, 0x6cc4, 1),
+    (5397, 0x6cf1, 1),
+    (5398, 0x6cd3, 1),
+    (5399, 0x6cbd, 1),
+    (5400, 0x6cd7, 1),
+    (5401, 0x6cc5, 1),
+    (5402, 0x6cdd, 1),
+    (5403, 0x6cae, 1),
+    (5404, 0x6cb1, 1),
+    (5405, 0x6cbe, 1),
+    (5406, 0x6cba, 1),
+    (5407, 0x6cdb, 1),
+    (5408, 0x6cef, 1),
+    (5409, 0x6cd9, 1),
+    (5410, 0x6cea, 1),
+    (5411, 0x6d1f, 1),
+    (5412, 0x884d, 1),
+    (5413, 0x6d36, 1),
+    (5414, 0x6d2b, 1),
+    (5415, 0x6d3d, 1),
+    (5416, 0x6d38, 1),
+    (5417, 0x6d19, 1),
+    (5418, 0x6d35, 1),
+    (5419, 0x6d33, 1),
+    (5420, 0x6d12, 1),
+    (5421, 0x6d0c, 1),
+    (5422, 0x6d63, 1),
+    (5423, 0x6d93, 1),
+    (5424, 0x6d64, 1),
+    (5425, 0x6d5a, 1),
+    (5426, 0x6d79, 1),
+    (5427, 0x6d59, 1),
+    (5428, 0x6d8e, 1),
+    (5429, 0x6d95, 1),
+    (5430, 0x6fe4, 1),
+    (5431, 0x6d85, 1),
+    (5432, 0x6df9, 1),
+    (5433, 0x6e15, 1),
+    (5434, 0x6e0a, 1),
+    (5435, 0x6db5, 1),
+    (5436, 0x6dc7, 1),
+    (5437, 0x6de6, 1),
+    (5438, 0x6db8, 1),
+    (5439, 0x6dc6, 1),
+    (5440, 0x6dec, 1),
+    (5441, 0x6dde, 1),
+    (5442, 0x6dcc, 1),
+    (5443, 0x6de8, 1),
+    (5444, 0x6dd2, 1),
+    (5445, 0x6dc5, 1),
+    (5446, 0x6dfa, 1),
+    (5447, 0x6dd9, 1),
+    (5448, 0x6de4, 1),
+    (5449, 0x6dd5, 1),
+    (5450, 0x6dea, 1),
+    (5451, 0x6dee, 1),
+    (5452, 0x6e2d, 1),
+    (5453, 0x6e6e, 1),
+    (5454, 0x6e2e, 1),
+    (5455, 0x6e19, 1),
+    (5456, 0x6e72, 1),
+    (5457, 0x6e5f, 1),
+    (5458, 0x6e3e, 1),
+    (5459, 0x6e23, 1),
+    (5460, 0x6e6b, 1),
+    (5461, 0x6e2b, 1),
+    (5462, 0x6e76, 1),
+    (5463, 0x6e4d, 1),
+    (5464, 0x6e1f, 1),
+    (5465, 0x6e43, 1),
+    (5466, 0x6e3a, 1),
+    (5467, 0x6e4e, 1),
+    (5468, 0x6e24, 1),
+    (5469, 0x6eff, 1),
+    (5470, 0x6e1d, 1),
+    (5471, 0x6e38, 1),
+    (5472, 0x6e82, 1),
+    (5473, 0x6eaa, 1),
+    (5474, 0x6e98, 1),
+    (5475, 0x6ec9, 1),
+    (5476, 0x6eb7, 1),
+    (5477, 0x6ed3, 1),
+    (5478, 0x6ebd, 1),
+    (5479, 0x6eaf, 1),
+    (5480, 0x6ec4, 1),
+    (5481, 0x6eb2, 1),
+    (5482, 0x6ed4, 2),
+    (5484, 0x6e8f, 1),
+    (5485, 0x6ea5, 1),
+    (5486, 0x6ec2, 1),
+    (5487, 0x6e9f, 1),
+    (5488, 0x6f41, 1),
+    (5489, 0x6f11, 1),
+    (5490, 0x704c, 1),
+    (5491, 0x6eec, 1),
+    (5492, 0x6ef8, 1),
+    (5493, 0x6efe, 1),
+    (5494, 0x6f3f, 1),
+    (5495, 0x6ef2, 1),
+    (5496, 0x6f31, 1),
+    (5497, 0x6eef, 1),
+    (5498, 0x6f32, 1),
+    (5499, 0x6ecc, 1),
+    (5500, 0x6f3e, 1),
+    (5501, 0x6f13, 1),
+    (5502, 0x6ef7, 1),
+    (5503, 0x6f86, 1),
+    (5504, 0x6f7a, 1),
+    (5505, 0x6f78, 1),
+    (5506, 0x6f81, 1),
+    (5507, 0x6f80, 1),
+    (5508, 0x6f6f, 1),
+    (5509, 0x6f5b, 1),
+    (5510, 0x6ff3, 1),
+    (5511, 0x6f6d, 1),
+    (5512, 0x6f82, 1),
+    (5513, 0x6f7c, 1),
+    (5514, 0x6f58, 1),
+    (5515, 0x6f8e, 1),
+    (5516, 0x6f91, 1),
+    (5517, 0x6fc2, 1),
+    (5518, 0x6f66, 1),
+    (5519, 0x6fb3, 1),
+    (5520, 0x6fa3, 1),
+    (5521, 0x6fa1, 1),
+    (5522, 0x6fa4, 1),
+    (5523, 0x6fb9, 1),
+    (5524, 0x6fc6, 1),
+    (5525, 0x6faa, 1),
+    (5526, 0x6fdf, 1),
+    (5527, 0x6fd5, 1),
+    (5528, 0x6fec, 1),
+    (5529, 0x6fd4, 1),
+    (5530, 0x6fd8, 1),
+    (5531, 0x6ff1, 1),
+    (5532, 0x6fee, 1),
+    (5533, 0x6fdb, 1),
+    (5534, 0x7009, 1),
+    (5535, 0x700b, 1),
+    (5536, 0x6ffa, 1),
+    (5537, 0x7011, 1),
+    (5538, 0x7001, 1),
+    (5539, 0x700f, 1),
+    (5540, 0x6ffe, 1),
+    (5541, 0x701b, 1),
+    (5542, 0x701a, 1),
+    (5543, 0x6f74, 1),
+    (5544, 0x701d, 1),
+    (5545, 0x7018, 1),
+    (5546, 0x701f, 1),
+    (5547, 0x7030, 1),
+    (5548, 0x703e, 1),
+    (5549, 0x7032, 1),
+    (5550, 0x7051, 1),
+    (5551, 0x7063, 1),
+    (5552, 0x7099, 1),
+    (5553, 0x7092, 1),
+    (5554, 0x70af, 1),
+    (5555, 0x70f1, 1),
+    (5556, 0x70ac, 1),
+    (5557, 0x70b8, 1),
+    (5558, 0x70b3, 1),
+    (5559, 0x70ae, 1),
+    (5560, 0x70df, 1),
+    (5561, 0x70cb, 1),
+    (5562, 0x70dd, 1),
+    (5563, 0x70d9, 1),
+    (5564, 0x7109, 1),
+    (5565, 0x70fd, 1),
+    (5566, 0x711c, 1),
+    (5567, 0x7119, 1),
+    (5568, 0x7165, 1),
+    (5569, 0x7155, 1),
+    (5570, 0x7188, 1),
+    (5571, 0x7166, 1),
+    (5572, 0x7162, 1),
+    (5573, 0x714c, 1),
+    (5574, 0x7156, 1),
+    (5575, 0x716c, 1),
+    (5576, 0x718f, 1),
+    (5577, 0x71fb, 1),
+    (5578, 0x7184, 1),
+    (5579, 0x7195, 1),
+    (5580, 0x71a8, 1),
+    (5581, 0x71ac, 1),
+    (5582, 0x71d7, 1),
+    (5583, 0x71b9, 1),
+    (5584, 0x71be, 1),
+    (5585, 0x71d2, 1),
+    (5586, 0x71c9, 1),
+    (5587, 0x71d4, 1),
+    (5588, 0x71ce, 1),
+    (5589, 0x71e0, 1),
+    (5590, 0x71ec, 1),
+    (5591, 0x71e7, 1),
+    (5592, 0x71f5, 1),
+    (5593, 0x71fc, 1),
+    (5594, 0x71f9, 1),
+    (5595, 0x71ff, 1),
+    (5596, 0x720d, 1),
+    (5597, 0x7210, 1),
+    (5598, 0x721b, 1),
+    (5599, 0x7228, 1),
+    (5600, 0x722d, 1),
+    (5601, 0x722c, 1),
+    (5602, 0x7230, 1),
+    (5603, 0x7232, 1),
+    (5604, 0x723b, 2),
+    (5606, 0x723f, 2),
+    (5608, 0x7246, 1),
+    (5609, 0x724b, 1),
+    (5610, 0x7258, 1),
+    (5611, 0x7274, 1),
+    (5612, 0x727e, 1),
+    (5613, 0x7282, 1),
+    (5614, 0x7281, 1),
+    (5615, 0x7287, 1),
+    (5616, 0x7292, 1),
+    (5617, 0x7296, 1),
+    (5618, 0x72a2, 1),
+    (5619, 0x72a7, 1),
+    (5620, 0x72b9, 1),
+    (5621, 0x72b2, 1),
+    (5622, 0x72c3, 1),
+    (5623, 0x72c6, 1),
+    (5624, 0x72c4, 1),
+    (5625, 0x72ce, 1),
+    (5626, 0x72d2, 1),
+    (5627, 0x72e2, 1),
+    (5628, 0x72e0, 2),
+    (5630, 0x72f9, 1),
+    (5631, 0x72f7, 1),
+    (5632, 0x500f, 1),
+    (5633, 0x7317, 1),
+    (5634, 0x730a, 1),
+    (5635, 0x731c, 1),
+    (5636, 0x7316, 1),
+    (5637, 0x731d, 1),
+    (5638, 0x7334, 1),
+    (5639, 0x732f, 1),
+    (5640, 0x7329, 1),
+    (5641, 0x7325, 1),
+    (5642, 0x733e, 1),
+    (5643, 0x734e, 2),
+    (5645, 0x9ed8, 1),
+    (5646, 0x7357, 1),
+    (5647, 0x736a, 1),
+    (5648, 0x7368, 1),
+    (5649, 0x7370, 1),
+    (5650, 0x7378, 1),
+    (5651, 0x7375, 1),
+    (5652, 0x737b, 1),
+    (5653, 0x737a, 1),
+    (5654, 0x73c8, 1),
+    (5655, 0x73b3, 1),
+    (5656, 0x73ce, 1),
+    (5657, 0x73bb, 1),
+    (5658, 0x73c0, 1),
+    (5659, 0x73e5, 1),
+    (5660, 0x73ee, 1),
+    (5661, 0x73de, 1),
+    (5662, 0x74a2, 1),
+    (5663, 0x7405, 1),
+    (5664, 0x746f, 1),
+    (5665, 0x7425, 1),
+    (5666, 0x73f8, 1),
+    (5667, 0x7432, 1),
+    (5668, 0x743a, 1),
+    (5669, 0x7455, 1),
+    (5670, 0x743f, 1),
+    (5671, 0x745f, 1),
+    (5672, 0x7459, 1),
+    (5673, 0x7441, 1),
+    (5674, 0x745c, 1),
+    (5675, 0x7469, 1),
+    (5676, 0x7470, 1),
+    (5677, 0x7463, 1),
+    (5678, 0x746a, 1),
+    (5679, 0x7476, 1),
+    (5680, 0x747e, 1),
+    (5681, 0x748b, 1),
+    (5682, 0x749e, 1),
+    (5683, 0x74a7, 1),
+    (5684, 0x74ca, 1),
+    (5685, 0x74cf, 1),
+    (5686, 0x74d4, 1),
+    (5687, 0x73f1, 1),
+    (5688, 0x74e0, 1),
+    (5689, 0x74e3, 1),
+    (5690, 0x74e7, 1),
+    (5691, 0x74e9, 1),
+    (5692, 0x74ee, 1),
+    (5693, 0x74f2, 1),
+    (5694, 0x74f0, 2),
+    (5696, 0x74f8, 1),
+    (5697, 0x74f7, 1),
+    (5698, 0x7504, 1),
+    (5699, 0x7503, 1),
+    (5700, 0x7505, 1),
+    (5701, 0x750c, 1),
+    (5702, 0x750e, 1),
+    (5703, 0x750d, 1),
+    (5704, 0x7515, 1),
+    (5705, 0x7513, 1),
+    (5706, 0x751e, 1),
+    (5707, 0x7526, 1),
+    (5708, 0x752c, 1),
+    (5709, 0x753c, 1),
+    (5710, 0x7544, 1),
+    (5711, 0x754d, 1),
+    (5712, 0x754a, 1),
+    (5713, 0x7549, 1),
+    (5714, 0x755b, 1),
+    (5715, 0x7546, 1),
+    (5716, 0x755a, 1),
+    (5717, 0x7569, 1),
+    (5718, 0x7564, 1),
+    (5719, 0x7567, 1),
+    (5720, 0x756b, 1),
+    (5721, 0x756d, 1),
+    (5722, 0x7578, 1),
+    (5723, 0x7576, 1),
+    (5724, 0x7586, 2),
+    (5726, 0x7574, 1),
+    (5727, 0x758a, 1),
+    (5728, 0x7589, 1),
+    (5729, 0x7582, 1),
+    (5730, 0x7594, 1),
+    (5731, 0x759a, 1),
+    (5732, 0x759d, 1),
+    (5733, 0x75a5, 1),
+    (5734, 0x75a3, 1),
+    (5735, 0x75c2, 1),
+    (5736, 0x75b3, 1),
+    (5737, 0x75c3, 1),
+    (5738, 0x75b5, 1),
+    (5739, 0x75bd, 1),
+    (5740, 0x75b8, 1),
+    (5741, 0x75bc, 1),
+    (5742, 0x75b1, 1),
+    (5743, 0x75cd, 1),
+    (5744, 0x75ca, 1),
+    (5745, 0x75d2, 1),
+    (5746, 0x75d9, 1),
+    (5747, 0x75e3, 1),
+    (5748, 0x75de, 1),
+    (5749, 0x75fe, 2),
+    (5751, 0x75fc, 1),
+    (5752, 0x7601, 1),
+    (5753, 0x75f0, 1),
+    (5754, 0x75fa, 1),
+    (5755, 0x75f2, 2),
+    (5757, 0x760b, 1),
+    (5758, 0x760d, 1),
+    (5759, 0x7609, 1),
+    (5760, 0x761f, 1),
+    (5761, 0x7627, 1),
+    (5762, 0x7620, 3),
+    (5765, 0x7624, 1),
+    (5766, 0x7634, 1),
+    (5767, 0x7630, 1),
+    (5768, 0x763b, 1),
+    (5769, 0x7647, 2),
+    (5771, 0x7646, 1),
+    (5772, 0x765c, 1),
+    (5773, 0x7658, 1),
+    (5774, 0x7661, 2),
+    (5776, 0x7668, 3),
+    (5779, 0x7667, 1),
+    (5780, 0x766c, 1),
+    (5781, 0x7670, 1),
+    (5782, 0x7672, 1),
+    (5783, 0x7676, 1),
+    (5784, 0x7678, 1),
+    (5785, 0x767c, 1),
+    (5786, 0x7680, 1),
+    (5787, 0x7683, 1),
+    (5788, 0x7688, 1),
+    (5789, 0x768b, 1),
+    (5790, 0x768e, 1),
+    (5791, 0x7696, 1),
+    (5792, 0x7693, 1),
+    (5793, 0x7699, 2),
+    (5795, 0x76b0, 1),
+    (5796, 0x76b4, 1),
+    (5797, 0x76b8, 3),
+    (5800, 0x76c2, 1),
+    (5801, 0x76cd, 1),
+    (5802, 0x76d6, 1),
+    (5803, 0x76d2, 1),
+    (5804, 0x76de, 1),
+    (5805, 0x76e1, 1),
+    (5806, 0x76e5, 1),
+    (5807, 0x76e7, 1),
+    (5808, 0x76ea, 1),
+    (5809, 0x862f, 1),
+    (5810, 0x76fb, 1),
+    (5811, 0x7708, 1),
+    (5812, 0x7707, 1),
+    (5813, 0x7704, 1),
+    (5814, 0x7729, 1),
+    (5815, 0x7724, 1),
+    (5816, 0x771e, 1),
+    (5817, 0x7725, 2),
+    (5819, 0x771b, 1),
+    (5820, 0x7737, 2),
+    (5822, 0x7747, 1),
+    (5823, 0x775a, 1),
+    (5824, 0x7768, 1),
+    (5825, 0x776b, 1),
+    (5826, 0x775b, 1),
+    (5827, 0x7765, 1),
+    (5828, 0x777f, 1),
+    (5829, 0x777e, 1),
+    (5830, 0x7779, 1),
+    (5831, 0x778e, 1),
+    (5832, 0x778b, 1),
+    (5833, 0x7791, 1),
+    (5834, 0x77a0, 1),
+    (5835, 0x779e, 1),
+    (5836, 0x77b0, 1),
+    (5837, 0x77b6, 1),
+    (5838, 0x77b9, 1),
+    (5839, 0x77bf, 1),
+    (5840, 0x77bc, 2),
+    (5842, 0x77bb, 1),
+    (5843, 0x77c7, 1),
+    (5844, 0x77cd, 1),
+    (5845, 0x77d7, 1),
+    (5846, 0x77da, 1),
+    (5847, 0x77dc, 1),
+    (5848, 0x77e3, 1),
+    (5849, 0x77ee, 1),
+    (5850, 0x77fc, 1),
+    (5851, 0x780c, 1),
+    (5852, 0x7812, 1),
+    (5853, 0x7926, 1),
+    (5854, 0x7820, 1),
+    (5855, 0x792a, 1),
+    (5856, 0x7845, 1),
+    (5857, 0x788e, 1),
+    (5858, 0x7874, 1),
+    (5859, 0x7886, 1),
+    (5860, 0x787c, 1),
+    (5861, 0x789a, 1),
+    (5862, 0x788c, 1),
+    (5863, 0x78a3, 1),
+    (5864, 0x78b5, 1),
+    (5865, 0x78aa, 1),
+    (5866, 0x78af, 1),
+    (5867, 0x78d1, 1),
+    (5868, 0x78c6, 1),
+    (5869, 0x78cb, 1),
+    (5870, 0x78d4, 1),
+    (5871, 0x78be, 1),
+    (5872, 0x78bc, 1),
+    (5873, 0x78c5, 1),
+    (5874, 0x78ca, 1),
+    (5875, 0x78ec, 1),
+    (5876, 0x78e7, 1),
+    (5877, 0x78da, 1),
+    (5878, 0x78fd, 1),
+    (5879, 0x78f4, 1),
+    (5880, 0x7907, 1),
+    (5881, 0x7912, 1),
+    (5882, 0x7911, 1),
+    (5883, 0x7919, 1),
+    (5884, 0x792c, 1),
+    (5885, 0x792b, 1),
+    (5886, 0x7940, 1),
+    (5887, 0x7960, 1),
+    (5888, 0x7957, 1),
+    (5889, 0x795f, 1),
+    (5890, 0x795a, 1),
+    (5891, 0x7955, 1),
+    (5892, 0x7953, 1),
+    (5893, 0x797a, 1),
+    (5894, 0x797f, 1),
+    (5895, 0x798a, 1),
+    (5896, 0x799d, 1),
+    (5897, 0x79a7, 1),
+    (5898, 0x9f4b, 1),
+    (5899, 0x79aa, 1),
+    (5900, 0x79ae, 1),
+    (5901, 0x79b3, 1),
+    (5902, 0x79b9, 2),
+    (5904, 0x79c9, 1),
+    (5905, 0x79d5, 1),
+    (5906, 0x79e7, 1),
+    (5907, 0x79ec, 1),
+    (5908, 0x79e1, 1),
+    (5909, 0x79e3, 1),
+    (5910, 0x7a08, 1),
+    (5911, 0x7a0d, 1),
+    (5912, 0x7a18, 2),
+    (5914, 0x7a20, 1),
+    (5915, 0x7a1f, 1),
+    (5916, 0x7980, 1),
+    (5917, 0x7a31, 1),
+    (5918, 0x7a3b, 1),
+    (5919, 0x7a3e, 1),
+    (5920, 0x7a37, 1),
+    (5921, 0x7a43, 1),
+    (5922, 0x7a57, 1),
+    (5923, 0x7a49, 1),
+    (5924, 0x7a61, 2),
+    (5926, 0x7a69, 1),
+    (5927, 0x9f9d, 1),
+    (5928, 0x7a70, 1),
+    (5929, 0x7a79, 1),
+    (5930, 0x7a7d, 1),
+    (5931, 0x7a88, 1),
+    (5932, 0x7a97, 1),
+    (5933, 0x7a95, 1),
+    (5934, 0x7a98, 1),
+    (5935, 0x7a96, 1),
+    (5936, 0x7aa9, 1),
+    (5937, 0x7ac8, 1),
+    (5938, 0x7ab0, 1),
+    (5939, 0x7ab6, 1),
+    (5940, 0x7ac5, 1),
+    (5941, 0x7ac4, 1),
+    (5942, 0x7abf, 1),
+    (5943, 0x9083, 1),
+    (5944, 0x7ac7, 1),
+    (5945, 0x7aca, 1),
+    (5946, 0x7acd, 1),
+    (5947, 0x7acf, 1),
+    (5948, 0x7ad5, 1),
+    (5949, 0x7ad3, 1),
+    (5950, 0x7ad9, 2),
+    (5952, 0x7add, 1),
+    (5953, 0x7ae1, 2),
+    (5955, 0x7ae6, 1),
+    (5956, 0x7aed, 1),
+    (5957, 0x7af0, 1),
+    (5958, 0x7b02, 1),
+    (5959, 0x7b0f, 1),
+    (5960, 0x7b0a, 1),
+    (5961, 0x7b06, 1),
+    (5962, 0x7b33, 1),
+    (5963, 0x7b18, 2),
+    (5965, 0x7b1e, 1),
+    (5966, 0x7b35, 1),
+    (5967, 0x7b28, 1),
+    (5968, 0x7b36, 1),
+    (5969, 0x7b50, 1),
+    (5970, 0x7b7a, 1),
+    (5971, 0x7b04, 1),
+    (5972, 0x7b4d, 1),
+    (5973, 0x7b0b, 1),
+    (5974, 0x7b4c, 1),
+    (5975, 0x7b45, 1),
+    (5976, 0x7b75, 1),
+    (5977, 0x7b65, 1),
+    (5978, 0x7b74, 1),
+    (5979, 0x7b67, 1),
+    (5980, 0x7b70, 2),
+    (5982, 0x7b6c, 1),
+    (5983, 0x7b6e, 1),
+    (5984, 0x7b9d, 1),
+    (5985, 0x7b98, 1),
+    (5986, 0x7b9f, 1),
+    (5987, 0x7b8d, 1),
+    (5988, 0x7b9c, 1),
+    (5989, 0x7b9a, 1),
+    (5990, 0x7b8b, 1),
+    (5991, 0x7b92, 1),
+    (5992, 0x7b8f, 1),
+    (5993, 0x7b5d, 1),
+    (5994, 0x7b99, 1),
+    (5995, 0x7bcb, 1),
+    (5996, 0x7bc1, 1),
+    (5997, 0x7bcc, 1),
+    (5998, 0x7bcf, 1),
+    (5999, 0x7bb4, 1),
+    (6000, 0x7bc6, 1),
+    (6001, 0x7bdd, 1),
+    (6002, 0x7be9, 1),
+    (6003, 0x7c11, 1),
+    (6004, 0x7c14, 1),
+    (6005, 0x7be6, 1),
+    (6006, 0x7be5, 1),
+    (6007, 0x7c60, 1),
+    (6008, 0x7c00, 1),
+    (6009, 0x7c07, 1),
+    (6010, 0x7c13, 1),
+    (6011, 0x7bf3, 1),
+    (6012, 0x7bf7, 1),
+    (6013, 0x7c17, 1),
+    (6014, 0x7c0d, 1),
+    (6015, 0x7bf6, 1),
+    (6016, 0x7c23, 1),
+    (6017, 0x7c27, 1),
+    (6018, 0x7c2a, 1),
+    (6019, 0x7c1f, 1),
+    (6020, 0x7c37, 1),
+    (6021, 0x7c2b, 1),
+    (6022, 0x7c3d, 1),
+    (6023, 0x7c4c, 1),
+    (6024, 0x7c43, 1),
+    (6025, 0x7c54, 1),
+    (6026, 0x7c4f, 1),
+    (6027, 0x7c40, 1),
+    (6028, 0x7c50, 1),
+    (6029, 0x7c58, 1),
+    (6030, 0x7c5f, 1),
+    (6031, 0x7c64, 1),
+    (6032, 0x7c56, 1),
+    (6033, 0x7c65, 1),
+    (6034, 0x7c6c, 1),
+    (6035, 0x7c75, 1),
+    (6036, 0x7c83, 1),
+    (6037, 0x7c90, 1),
+    (6038, 0x7ca4, 1),
+    (6039, 0x7cad, 1),
+    (6040, 0x7ca2, 1),
+    (6041, 0x7cab, 1),
+    (6042, 0x7ca1, 1),
+    (6043, 0x7ca8, 1),
+    (6044, 0x7cb3, 1),
+    (6045, 0x7cb2, 1),
+    (6046, 0x7cb1, 1),
+    (6047, 0x7cae, 1),
+    (6048, 0x7cb9, 1),
+    (6049, 0x7cbd, 1),
+    (6050, 0x7cc0, 1),
+    (6051, 0x7cc5, 1),
+    (6052, 0x7cc2, 1),
+    (6053, 0x7cd8, 1),
+    (6054, 0x7cd2, 1),
+    (6055, 0x7cdc, 1),
+    (6056, 0x7ce2, 1),
+    (6057, 0x9b3b, 1),
+    (6058, 0x7cef, 1),
+    (6059, 0x7cf2, 1),
+    (6060, 0x7cf4, 1),
+    (6061, 0x7cf6, 1),
+    (6062, 0x7cfa, 1),
+    (6063, 0x7d06, 1),
+    (6064, 0x7d02, 1),
+    (6065, 0x7d1c, 1),
+    (6066, 0x7d15, 1),
+    (6067, 0x7d0a, 1),
+    (6068, 0x7d45, 1),
+    (6069, 0x7d4b, 1),
+    (6070, 0x7d2e, 1),
+    (6071, 0x7d32, 1),
+    (6072, 0x7d3f, 1),
+    (6073, 0x7d35, 1),
+    (6074, 0x7d46, 1),
+    (6075, 0x7d73, 1),
+    (6076, 0x7d56, 1),
+    (6077, 0x7d4e, 1),
+    (6078, 0x7d72, 1),
+    (6079, 0x7d68, 1),
+    (6080, 0x7d6e, 1),
+    (6081, 0x7d4f, 1),
+    (6082, 0x7d63, 1),
+    (6083, 0x7d93, 1),
+    (6084, 0x7d89, 1),
+    (6085, 0x7d5b, 1),
+    (6086, 0x7d8f, 1),
+    (6087, 0x7d7d, 1),
+    (6088, 0x7d9b, 1),
+    (6089, 0x7dba, 1),
+    (6090, 0x7dae, 1),
+    (6091, 0x7da3, 1),
+    (6092, 0x7db5, 1),
+    (6093, 0x7dc7, 1),
+    (6094, 0x7dbd, 1),
+    (6095, 0x7dab, 1),
+    (6096, 0x7e3d, 1),
+    (6097, 0x7da2, 1),
+    (6098, 0x7daf, 1),
+    (6099, 0x7ddc, 1),
+    (6100, 0x7db8, 1),
+    (6101, 0x7d9f, 1),
+    (6102, 0x7db0, 1),
+    (6103, 0x7dd8, 1),
+    (6104, 0x7ddd, 1),
+    (6105, 0x7de4, 1),
+    (6106, 0x7dde, 1),
+    (6107, 0x7dfb, 1),
+    (6108, 0x7df2, 1),
+    (6109, 0x7de1, 1),
+    (6110, 0x7e05, 1),
+    (6111, 0x7e0a, 1),
+    (6112, 0x7e23, 1),
+    (6113, 0x7e21, 1),
+    (6114, 0x7e12, 1),
+    (6115, 0x7e31, 1),
+    (6116, 0x7e1f, 1),
+    (6117, 0x7e09, 1),
+    (6118, 0x7e0b, 1),
+    (6119, 0x7e22, 1),
+    (6120, 0x7e46, 1),
+    (6121, 0x7e66, 1),
+    (6122, 0x7e3b, 1),
+    (6123, 0x7e35, 1),
+    (6124, 0x7e39, 1),
+    (6125, 0x7e43, 1),
+    (6126, 0x7e37, 1),
+    (6127, 0x7e32, 1),
+    (6128, 0x7e3a, 1),
+    (6129, 0x7e67, 1),
+    (6130, 0x7e5d, 1),
+    (6131, 0x7e56, 1),
+    (6132, 0x7e5e, 1),
+    (6133, 0x7e59, 2),
+    (6135, 0x7e79, 1),
+    (6136, 0x7e6a, 1),
+    (6137, 0x7e69, 1),
+    (6138, 0x7e7c, 1),
+    (6139, 0x7e7b, 1),
+    (6140, 0x7e83, 1),
+    (6141, 0x7dd5, 1),
+    (6142, 0x7e7d, 1),
+    (6143, 0x8fae, 1),
+    (6144, 0x7e7f, 1),
+    (6145, 0x7e88, 2),
+    (6147, 0x7e8c, 1),
+    (6148, 0x7e92, 1),
+    (6149, 0x7e90, 1),
+    (6150, 0x7e93, 2),
+    (6152, 0x7e96, 1),
+    (6153, 0x7e8e, 1),
+    (6154, 0x7e9b, 2),
+    (6156, 0x7f38, 1),
+    (6157, 0x7f3a, 1),
+    (6158, 0x7f45, 1),
+    (6159, 0x7f4c, 3),
+    (6162, 0x7f50, 2),
+    (6164, 0x7f55, 1),
+    (6165, 0x7f54, 1),
+    (6166, 0x7f58, 1),
+    (6167, 0x7f5f, 2),
+    (6169, 0x7f68, 2),
+    (6171, 0x7f67, 1),
+    (6172, 0x7f78, 1),
+    (6173, 0x7f82, 1),
+    (6174, 0x7f86, 1),
+    (6175, 0x7f83, 1),
+    (6176, 0x7f88, 1),
+    (6177, 0x7f87, 1),
+    (6178, 0x7f8c, 1),
+    (6179, 0x7f94, 1),
+    (6180, 0x7f9e, 1),
+    (6181, 0x7f9d, 1),
+    (6182, 0x7f9a, 1),
+    (6183, 0x7fa3, 1),
+    (6184, 0x7faf, 1),
+    (6185, 0x7fb2, 1),
+    (6186, 0x7fb9, 1),
+    (6187, 0x7fae, 1),
+    (6188, 0x7fb6, 1),
+    (6189, 0x7fb8, 1),
+    (6190, 0x8b71, 1),
+    (6191, 0x7fc5, 2),
+    (6193, 0x7fca, 1),
+    (6194, 0x7fd5, 1),
+    (6195, 0x7fd4, 1),
+    (6196, 0x7fe1, 1),
+    (6197, 0x7fe6, 1),
+    (6198, 0x7fe9, 1),
+    (6199, 0x7ff3, 1),
+    (6200, 0x7ff9, 1),
+    (6201, 0x98dc, 1),
+    (6202, 0x8006, 1),
+    (6203, 0x8004, 1),
+    (6204, 0x800b, 1),
+    (6205, 0x8012, 1),
+    (6206, 0x8018, 2),
+    (6208, 0x801c, 1),
+    (6209, 0x8021, 1),
+    (6210, 0x8028, 1),
+    (6211, 0x803f, 1),
+    (6212, 0x803b, 1),
+    (6213, 0x804a, 1),
+    (6214, 0x8046, 1),
+    (6215, 0x8052, 1),
+    (6216, 0x8058, 1),
+    (6217, 0x805a, 1),
+    (6218, 0x805f, 1),
+    (6219, 0x8062, 1),
+    (6220, 0x8068, 1),
+    (6221, 0x8073, 1),
+    (6222, 0x8072, 1),
+    (6223, 0x8070, 1),
+    (6224, 0x8076, 1),
+    (6225, 0x8079, 1),
+    (6226, 0x807d, 1),
+    (6227, 0x807f, 1),
+    (6228, 0x8084, 1),
+    (6229, 0x8086, 1),
+    (6230, 0x8085, 1),
+    (6231, 0x809b, 1),
+    (6232, 0x8093, 1),
+    (6233, 0x809a, 1),
+    (6234, 0x80ad, 1),
+    (6235, 0x5190, 1),
+    (6236, 0x80ac, 1),
+    (6237, 0x80db, 1),
+    (6238, 0x80e5, 1),
+    (6239, 0x80d9, 1),
+    (6240, 0x80dd, 1),
+    (6241, 0x80c4, 1),
+    (6242, 0x80da, 1),
+    (6243, 0x80d6, 1),
+    (6244, 0x8109, 1),
+    (6245, 0x80ef, 1),
+    (6246, 0x80f1, 1),
+    (6247, 0x811b, 1),
+    (6248, 0x8129, 1),
+    (6249, 0x8123, 1),
+    (6250, 0x812f, 1),
+    (6251, 0x814b, 1),
+    (6252, 0x968b, 1),
+    (6253, 0x8146, 1),
+    (6254, 0x813e, 1),
+    (6255, 0x8153, 1),
+    (6256, 0x8151, 1),
+    (6257, 0x80fc, 1),
+    (6258, 0x8171, 1),
+    (6259, 0x816e, 1),
+    (6260, 0x8165, 2),
+    (6262, 0x8174, 1),
+    (6263, 0x8183, 1),
+    (6264, 0x8188, 1),
+    (6265, 0x818a, 1),
+    (6266, 0x8180, 1),
+    (6267, 0x8182, 1),
+    (6268, 0x81a0, 1),
+    (6269, 0x8195, 1),
+    (6270, 0x81a4, 1),
+    (6271, 0x81a3, 1),
+    (6272, 0x815f, 1),
+    (6273, 0x8193, 1),
+    (6274, 0x81a9, 1),
+    (6275, 0x81b0, 1),
+    (6276, 0x81b5, 1),
+    (6277, 0x81be, 1),
+    (6278, 0x81b8, 1),
+    (6279, 0x81bd, 1),
+    (6280, 0x81c0, 1),
+    (6281, 0x81c2, 1),
+    (6282, 0x81ba, 1),
+    (6283, 0x81c9, 1),
+    (6284, 0x81cd, 1),
+    (6285, 0x81d1, 1),
+    (6286, 0x81d9, 1),
+    (6287, 0x81d8, 1),
+    (6288, 0x81c8, 1),
+    (6289, 0x81da, 1),
+    (6290, 0x81df, 2),
+    (6292, 0x81e7, 1),
+    (6293, 0x81fa, 2),
+    (6295, 0x81fe, 1),
+    (6296, 0x8201, 2),
+    (6298, 0x8205, 1),
+    (6299, 0x8207, 1),
+    (6300, 0x820a, 1),
+    (6301, 0x820d, 1),
+    (6302, 0x8210, 1),
+    (6303, 0x8216, 1),
+    (6304, 0x8229, 1),
+    (6305, 0x822b, 1),
+    (6306, 0x8238, 1),
+    (6307, 0x8233, 1),
+    (6308, 0x8240, 1),
+    (6309, 0x8259, 1),
+    (6310, 0x8258, 1),
+    (6311, 0x825d, 1),
+    (6312, 0x825a, 1),
+    (6313, 0x825f, 1),
+    (6314, 0x8264, 1),
+    (6315, 0x8262, 1),
+    (6316, 0x8268, 1),
+    (6317, 0x826a, 2),
+    (6319, 0x822e, 1),
+    (6320, 0x8271, 1),
+    (6321, 0x8277, 2),
+    (6323, 0x827e, 1),
+    (6324, 0x828d, 1),
+    (6325, 0x8292, 1),
+    (6326, 0x82ab, 1),
+    (6327, 0x829f, 1),
+    (6328, 0x82bb, 1),
+    (6329, 0x82ac, 1),
+    (6330, 0x82e1, 1),
+    (6331, 0x82e3, 1),
+    (6332, 0x82df, 1),
+    (6333, 0x82d2, 1),
+    (6334, 0x82f4, 1),
+    (6335, 0x82f3, 1),
+    (6336, 0x82fa, 1),
+    (6337, 0x8393, 1),
+    (6338, 0x8303, 1),
+    (6339, 0x82fb, 1),
+    (6340, 0x82f9, 1),
+    (6341, 0x82de, 1),
+    (6342, 0x8306, 1),
+    (6343, 0x82dc, 1),
+    (6344, 0x8309, 1),
+    (6345, 0x82d9, 1),
+    (6346, 0x8335, 1),
+    (6347, 0x8334, 1),
+    (6348, 0x8316, 1),
+    (6349, 0x8332, 1),
+    (6350, 0x8331, 1),
+    (6351, 0x8340, 1),
+    (6352, 0x8339, 1),
+    (6353, 0x8350, 1),
+    (6354, 0x8345, 1),
+    (6355, 0x832f, 1),
+    (6356, 0x832b, 1),
+    (6357, 0x8317, 2),
+    (6359, 0x8385, 1),
+    (6360, 0x839a, 1),
+    (6361, 0x83aa, 1),
+    (6362, 0x839f, 1),
+    (6363, 0x83a2, 1),
+    (6364, 0x8396, 1),
+    (6365, 0x8323, 1),
+    (6366, 0x838e, 1),
+    (6367, 0x8387, 1),
+    (6368, 0x838a, 1),
+    (6369, 0x837c, 1),
+    (6370, 0x83b5, 1),
+    (6371, 0x8373, 1),
+    (6372, 0x8375, 1),
+    (6373, 0x83a0, 1),
+    (6374, 0x8389, 1),
+    (6375, 0x83a8, 1),
+    (6376, 0x83f4, 1),
+    (6377, 0x8413, 1),
+    (6378, 0x83eb, 1),
+    (6379, 0x83ce, 1),
+    (6380, 0x83fd, 1),
+    (6381, 0x8403, 1),
+    (6382, 0x83d8, 1),
+    (6383, 0x840b, 1),
+    (6384, 0x83c1, 1),
+    (6385, 0x83f7, 1),
+    (6386, 0x8407, 1),
+    (6387, 0x83e0, 1),
+    (6388, 0x83f2, 1),
+    (6389, 0x840d, 1),
+    (6390, 0x8422, 1),
+    (6391, 0x8420, 1),
+    (6392, 0x83bd, 1),
+    (6393, 0x8438, 1),
+    (6394, 0x8506, 1),
+    (6395, 0x83fb, 1),
+    (6396, 0x846d, 1),
+    (6397, 0x842a, 1),
+    (6398, 0x843c, 1),
+    (6399, 0x855a, 1),
+    (6400, 0x8484, 1),
+    (6401, 0x8477, 1),
+    (6402, 0x846b, 1),
+    (6403, 0x84ad, 1),
+    (6404, 0x846e, 1),
+    (6405, 0x8482, 1),
+    (6406, 0x8469, 1),
+    (6407, 0x8446, 1),
+    (6408, 0x842c, 1),
+    (6409, 0x846f, 1),
+    (6410, 0x8479, 1),
+    (6411, 0x8435, 1),
+    (6412, 0x84ca, 1),
+    (6413, 0x8462, 1),
+    (6414, 0x84b9, 1),
+    (6415, 0x84bf, 1),
+    (6416, 0x849f, 1),
+    (6417, 0x84d9, 1),
+    (6418, 0x84cd, 1),
+    (6419, 0x84bb, 1),
+    (6420, 0x84da, 1),
+    (6421, 0x84d0, 1),
+    (6422, 0x84c1, 1),
+    (6423, 0x84c6, 1),
+    (6424, 0x84d6, 1),
+    (6425, 0x84a1, 1),
+    (6426, 0x8521, 1),
+    (6427, 0x84ff, 1),
+    (6428, 0x84f4, 1),
+    (6429, 0x8517, 2),
+    (6431, 0x852c, 1),
+    (6432, 0x851f, 1),
+    (6433, 0x8515, 1),
+    (6434, 0x8514, 1),
+    (6435, 0x84fc, 1),
+    (6436, 0x8540, 1),
+    (6437, 0x8563, 1),
+    (6438, 0x8558, 1),
+    (6439, 0x8548, 1),
+    (6440, 0x8541, 1),
+    (6441, 0x8602, 1),
+    (6442, 0x854b, 1),
+    (6443, 0x8555, 1),
+    (6444, 0x8580, 1),
+    (6445, 0x85a4, 1),
+    (6446, 0x8588, 1),
+    (6447, 0x8591, 1),
+    (6448, 0x858a, 1),
+    (6449, 0x85a8, 1),
+    (6450, 0x856d, 1),
+    (6451, 0x8594, 1),
+    (6452, 0x859b, 1),
+    (6453, 0x85ea, 1),
+    (6454, 0x8587, 1),
+    (6455, 0x859c, 1),
+    (6456, 0x8577, 1),
+    (6457, 0x857e, 1),
+    (6458, 0x8590, 1),
+    (6459, 0x85c9, 1),
+    (6460, 0x85ba, 1),
+    (6461, 0x85cf, 1),
+    (6462, 0x85b9, 1),
+    (6463, 0x85d0, 1),
+    (6464, 0x85d5, 1),
+    (6465, 0x85dd, 1),
+    (6466, 0x85e5, 1),
+    (6467, 0x85dc, 1),
+    (6468, 0x85f9, 1),
+    (6469, 0x860a, 1),
+    (6470, 0x8613, 1),
+    (6471, 0x860b, 1),
+    (6472, 0x85fe, 1),
+    (6473, 0x85fa, 1),
+    (6474, 0x8606, 1),
+    (6475, 0x8622, 1),
+    (6476, 0x861a, 1),
+    (6477, 0x8630, 1),
+    (6478, 0x863f, 1),
+    (6479, 0x864d, 1),
+    (6480, 0x4e55, 1),
+    (6481, 0x8654, 1),
+    (6482, 0x865f, 1),
+    (6483, 0x8667, 1),
+    (6484, 0x8671, 1),
+    (6485, 0x8693, 1),
+    (6486, 0x86a3, 1),
+    (6487, 0x86a9, 2),
+    (6489, 0x868b, 2),
+    (6491, 0x86b6, 1),
+    (6492, 0x86af, 1),
+    (6493, 0x86c4, 1),
+    (6494, 0x86c6, 1),
+    (6495, 0x86b0, 1),
+    (6496, 0x86c9, 1),
+    (6497, 0x8823, 1),
+    (6498, 0x86ab, 1),
+    (6499, 0x86d4, 1),
+    (6500, 0x86de, 1),
+    (6501, 0x86e9, 1),
+    (6502, 0x86ec, 1),
+    (6503, 0x86df, 1),
+    (6504, 0x86db, 1),
+    (6505, 0x86ef, 1),
+    (6506, 0x8712, 1),
+    (6507, 0x8706, 1),
+    (6508, 0x8708, 1),
+    (6509, 0x8700, 1),
+    (6510, 0x8703, 1),
+    (6511, 0x86fb, 1),
+    (6512, 0x8711, 1),
+    (6513, 0x8709, 1),
+    (6514, 0x870d, 1),
+    (6515, 0x86f9, 1),
+    (6516, 0x870a, 1),
+    (6517, 0x8734, 1),
+    (6518, 0x873f, 1),
+    (6519, 0x8737, 1),
+    (6520, 0x873b, 1),
+    (6521, 0x8725, 1),
+    (6522, 0x8729, 1),
+    (6523, 0x871a, 1),
+    (6524, 0x8760, 1),
+    (6525, 0x875f, 1),
+    (6526, 0x8778, 1),
+    (6527, 0x874c, 1),
+    (6528, 0x874e, 1),
+    (6529, 0x8774, 1),
+    (6530, 0x8757, 1),
+    (6531, 0x8768, 1),
+    (6532, 0x876e, 1),
+    (6533, 0x8759, 1),
+    (6534, 0x8753, 1),
+    (6535, 0x8763, 1),
+    (6536, 0x876a, 1),
+    (6537, 0x8805, 1),
+    (6538, 0x87a2, 1),
+    (6539, 0x879f, 1),
+    (6540, 0x8782, 1),
+    (6541, 0x87af, 1),
+    (6542, 0x87cb, 1),
+    (6543, 0x87bd, 1),
+    (6544, 0x87c0, 1),
+    (6545, 0x87d0, 1),
+    (6546, 0x96d6, 1),
+    (6547, 0x87ab, 1),
+    (6548, 0x87c4, 1),
+    (6549, 0x87b3, 1),
+    (6550, 0x87c7, 1),
+    (6551, 0x87c6, 1),
+    (6552, 0x87bb, 1),
+    (6553, 0x87ef, 1),
+    (6554, 0x87f2, 1),
+    (6555, 0x87e0, 1),
+    (6556, 0x880f, 1),
+    (6557, 0x880d, 1),
+    (6558, 0x87fe, 1),
+    (6559, 0x87f6, 2),
+    (6561, 0x880e, 1),
+    (6562, 0x87d2, 1),
+    (6563, 0x8811, 1),
+    (6564, 0x8816, 1),
+    (6565, 0x8815, 1),
+    (6566, 0x8822, 1),
+    (6567, 0x8821, 1),
+    (6568, 0x8831, 1),
+    (6569, 0x8836, 1),
+    (6570, 0x8839, 1),
+    (6571, 0x8827, 1),
+    (6572, 0x883b, 1),
+    (6573, 0x8844, 1),
+    (6574, 0x8842, 1),
+    (6575, 0x8852, 1),
+    (6576, 0x8859, 1),
+    (6577, 0x885e, 1),
+    (6578, 0x8862, 1),
+    (6579, 0x886b, 1),
+    (6580, 0x8881, 1),
+    (6581, 0x887e, 1),
+    (6582, 0x889e, 1),
+    (6583, 0x8875, 1),
+    (6584, 0x887d, 1),
+    (6585, 0x88b5, 1),
+    (6586, 0x8872, 1),
+    (6587, 0x8882, 1),
+    (6588, 0x8897, 1),
+    (6589, 0x8892, 1),
+    (6590, 0x88ae, 1),
+    (6591, 0x8899, 1),
+    (6592, 0x88a2, 1),
+    (6593, 0x888d, 1),
+    (6594, 0x88a4, 1),
+    (6595, 0x88b0, 1),
+    (6596, 0x88bf, 1),
+    (6597, 0x88b1, 1),
+    (6598, 0x88c3, 2),
+    (6600, 0x88d4, 1),
+    (6601, 0x88d8, 2),
+    (6603, 0x88dd, 1),
+    (6604, 0x88f9, 1),
+    (6605, 0x8902, 1),
+    (6606, 0x88fc, 1),
+    (6607, 0x88f4, 1),
+    (6608, 0x88e8, 1),
+    (6609, 0x88f2, 1),
+    (6610, 0x8904, 1),
+    (6611, 0x890c, 1),
+    (6612, 0x890a, 1),
+    (6613, 0x8913, 1),
+    (6614, 0x8943, 1),
+    (6615, 0x891e, 1),
+    (6616, 0x8925, 1),
+    (6617, 0x892a, 2),
+    (6619, 0x8941, 1),
+    (6620, 0x8944, 1),
+    (6621, 0x893b, 1),
+    (6622, 0x8936, 1),
+    (6623, 0x8938, 1),
+    (6624, 0x894c, 1),
+    (6625, 0x891d, 1),
+    (6626, 0x8960, 1),
+    (6627, 0x895e, 1),
+    (6628, 0x8966, 1),
+    (6629, 0x8964, 1),
+    (6630, 0x896d, 1),
+    (6631, 0x896a, 1),
+    (6632, 0x896f, 1),
+    (6633, 0x8974, 1),
+    (6634, 0x8977, 1),
+    (6635, 0x897e, 1),
+    (6636, 0x8983, 1),
+    (6637, 0x8988, 1),
+    (6638, 0x898a, 1),
+    (6639, 0x8993, 1),
+    (6640, 0x8998, 1),
+    (6641, 0x89a1, 1),
+    (6642, 0x89a9, 1),
+    (6643, 0x89a6, 1),
+    (6644, 0x89ac, 1),
+    (6645, 0x89af, 1),
+    (6646, 0x89b2, 1),
+    (6647, 0x89ba, 1),
+    (6648, 0x89bd, 1),
+    (6649, 0x89bf, 2),
+    (6651, 0x89da, 1),
+    (6652, 0x89dc, 2),
+    (6654, 0x89e7, 1),
+    (6655, 0x89f4, 1),
+    (6656, 0x89f8, 1),
+    (6657, 0x8a03, 1),
+    (6658, 0x8a16, 1),
+    (6659, 0x8a10, 1),
+    (6660, 0x8a0c, 1),
+    (6661, 0x8a1b, 1),
+    (6662, 0x8a1d, 1),
+    (6663, 0x8a25, 1),
+    (6664, 0x8a36, 1),
+    (6665, 0x8a41, 1),
+    (6666, 0x8a5b, 1),
+    (6667, 0x8a52, 1),
+    (6668, 0x8a46, 1),
+    (6669, 0x8a48, 1),
+    (6670, 0x8a7c, 1),
+    (6671, 0x8a6d, 1),
+    (6672, 0x8a6c, 1),
+    (6673, 0x8a62, 1),
+    (6674, 0x8a85, 1),
+    (6675, 0x8a82, 1),
+    (6676, 0x8a84, 1),
+    (6677, 0x8aa8, 1),
+    (6678, 0x8aa1, 1),
+    (6679, 0x8a91, 1),
+    (6680, 0x8aa5, 2),
+    (6682, 0x8a9a, 1),
+    (6683, 0x8aa3, 1),
+    (6684, 0x8ac4, 1),
+    (6685, 0x8acd, 1),
+    (6686, 0x8ac2, 1),
+    (6687, 0x8ada, 1),
+    (6688, 0x8aeb, 1),
+    (6689, 0x8af3, 1),
+    (6690, 0x8ae7, 1),
+    (6691, 0x8ae4, 1),
+    (6692, 0x8af1, 1),
+    (6693, 0x8b14, 1),
+    (6694, 0x8ae0, 1),
+    (6695, 0x8ae2, 1),
+    (6696, 0x8af7, 1),
+    (6697, 0x8ade, 1),
+    (6698, 0x8adb, 1),
+    (6699, 0x8b0c, 1),
+    (6700, 0x8b07, 1),
+    (6701, 0x8b1a, 1),
+    (6702, 0x8ae1, 1),
+    (6703, 0x8b16, 1),
+    (6704, 0x8b10, 1),
+    (6705, 0x8b17, 1),
+    (6706, 0x8b20, 1),
+    (6707, 0x8b33, 1),
+    (6708, 0x97ab, 1),
+    (6709, 0x8b26, 1),
+    (6710, 0x8b2b, 1),
+    (6711, 0x8b3e, 1),
+    (6712, 0x8b28, 1),
+    (6713, 0x8b41, 1),
+    (6714, 0x8b4c, 1),
+    (6715, 0x8b4f, 1),
+    (6716, 0x8b4e, 1),
+    (6717, 0x8b49, 1),
+    (6718, 0x8b56, 1),
+    (6719, 0x8b5b, 1),
+    (6720, 0x8b5a, 1),
+    (6721, 0x8b6b, 1),
+    (6722, 0x8b5f, 1),
+    (6723, 0x8b6c, 1),
+    (6724, 0x8b6f, 1),
+    (6725, 0x8b74, 1),
+    (6726, 0x8b7d, 1),
+    (6727, 0x8b80, 1),
+    (6728, 0x8b8c, 1),
+    (6729, 0x8b8e, 1),
+    (6730, 0x8b92, 2),
+    (6732, 0x8b96, 1),
+    (6733, 0x8b99, 2),
+    (6735, 0x8c3a, 1),
+    (6736, 0x8c41, 1),
+    (6737, 0x8c3f, 1),
+    (6738, 0x8c48, 1),
+    (6739, 0x8c4c, 1),
+    (6740, 0x8c4e, 1),
+    (6741, 0x8c50, 1),
+    (6742, 0x8c55, 1),
+    (6743, 0x8c62, 1),
+    (6744, 0x8c6c, 1),
+    (6745, 0x8c78, 1),
+    (6746, 0x8c7a, 1),
+    (6747, 0x8c82, 1),
+    (6748, 0x8c89, 1),
+    (6749, 0x8c85, 1),
+    (6750, 0x8c8a, 1),
+    (6751, 0x8c8d, 2),
+    (6753, 0x8c94, 1),
+    (6754, 0x8c7c, 1),
+    (6755, 0x8c98, 1),
+    (6756, 0x621d, 1),
+    (6757, 0x8cad, 1),
+    (6758, 0x8caa, 1),
+    (6759, 0x8cbd, 1),
+    (6760, 0x8cb2, 2),
+    (6762, 0x8cae, 1),
+    (6763, 0x8cb6, 1),
+    (6764, 0x8cc8, 1),
+    (6765, 0x8cc1, 1),
+    (6766, 0x8ce4, 1),
+    (6767, 0x8ce3, 1),
+    (6768, 0x8cda, 1),
+    (6769, 0x8cfd, 1),
+    (6770, 0x8cfa, 2),
+    (6772, 0x8d04, 2),
+    (6774, 0x8d0a, 1),
+    (6775, 0x8d07, 1),
+    (6776, 0x8d0f, 1),
+    (6777, 0x8d0d, 1),
+    (6778, 0x8d10, 1),
+    (6779, 0x9f4e, 1),
+    (6780, 0x8d13, 1),
+    (6781, 0x8ccd, 1),
+    (6782, 0x8d14, 1),
+    (6783, 0x8d16, 1),
+    (6784, 0x8d67, 1),
+    (6785, 0x8d6d, 1),
+    (6786, 0x8d71, 1),
+    (6787, 0x8d73, 1),
+    (6788, 0x8d81, 1),
+    (6789, 0x8d99, 1),
+    (6790, 0x8dc2, 1),
+    (6791, 0x8dbe, 1),
+    (6792, 0x8dba, 1),
+    (6793, 0x8dcf, 1),
+    (6794, 0x8dda, 1),
+    (6795, 0x8dd6, 1),
+    (6796, 0x8dcc, 1),
+    (6797, 0x8ddb, 1),
+    (6798, 0x8dcb, 1),
+    (6799, 0x8dea, 2),
+    (6801, 0x8ddf, 1),
+    (6802, 0x8de3, 1),
+    (6803, 0x8dfc, 1),
+    (6804, 0x8e08, 2),
+    (6806, 0x8dff, 1),
+    (6807, 0x8e1d, 2),
+    (6809, 0x8e10, 1),
+    (6810, 0x8e1f, 1),
+    (6811, 0x8e42, 1),
+    (6812, 0x8e35, 1),
+    (6813, 0x8e30, 1),
+    (6814, 0x8e34, 1),
+    (6815, 0x8e4a, 1),
+    (6816, 0x8e47, 1),
+    (6817, 0x8e49, 1),
+    (6818, 0x8e4c, 1),
+    (6819, 0x8e50, 1),
+    (6820, 0x8e48, 1),
+    (6821, 0x8e59, 1),
+    (6822, 0x8e64, 1),
+    (6823, 0x8e60, 1),
+    (6824, 0x8e2a, 1),
+    (6825, 0x8e63, 1),
+    (6826, 0x8e55, 1),
+    (6827, 0x8e76, 1),
+    (6828, 0x8e72, 1),
+    (6829, 0x8e7c, 1),
+    (6830, 0x8e81, 1),
+    (6831, 0x8e87, 1),
+    (6832, 0x8e85, 1),
+    (6833, 0x8e84, 1),
+    (6834, 0x8e8b, 1),
+    (6835, 0x8e8a, 1),
+    (6836, 0x8e93, 1),
+    (6837, 0x8e91, 1),
+    (6838, 0x8e94, 1),
+    (6839, 0x8e99, 1),
+    (6840, 0x8eaa, 1),
+    (6841, 0x8ea1, 1),
+    (6842, 0x8eac, 1),
+    (6843, 0x8eb0, 1),
+    (6844, 0x8ec6, 1),
+    (6845, 0x8eb1, 1),
+    (6846, 0x8ebe, 1),
+    (6847, 0x8ec5, 1),
+    (6848, 0x8ec8, 1),
+    (6849, 0x8ecb, 1),
+    (6850, 0x8edb, 1),
+    (6851, 0x8ee3, 1),
+    (6852, 0x8efc, 1),
+    (6853, 0x8efb, 1),
+    (6854, 0x8eeb, 1),
+    (6855, 0x8efe, 1),
+    (6856, 0x8f0a, 1),
+    (6857, 0x8f05, 1),
+    (6858, 0x8f15, 1),
+    (6859, 0x8f12, 1),
+    (6860, 0x8f19, 1),
+    (6861, 0x8f13, 1),
+    (6862, 0x8f1c, 1),
+    (6863, 0x8f1f, 1),
+    (6864, 0x8f1b, 1),
+    (6865, 0x8f0c, 1),
+    (6866, 0x8f26, 1),
+    (6867, 0x8f33, 1),
+    (6868, 0x8f3b, 1),
+    (6869, 0x8f39, 1),
+    (6870, 0x8f45, 1),
+    (6871, 0x8f42, 1),
+    (6872, 0x8f3e, 1),
+    (6873, 0x8f4c, 1),
+    (6874, 0x8f49, 1),
+    (6875, 0x8f46, 1),
+    (6876, 0x8f4e, 1),
+    (6877, 0x8f57, 1),
+    (6878, 0x8f5c, 1),
+    (6879, 0x8f62, 3),
+    (6882, 0x8f9c, 1),
+    (6883, 0x8f9f, 1),
+    (6884, 0x8fa3, 1),
+    (6885, 0x8fad, 1),
+    (6886, 0x8faf, 1),
+    (6887, 0x8fb7, 1),
+    (6888, 0x8fda, 1),
+    (6889, 0x8fe5, 1),
+    (6890, 0x8fe2, 1),
+    (6891, 0x8fea, 1),
+    (6892, 0x8fef, 1),
+    (6893, 0x9087, 1),
+    (6894, 0x8ff4, 1),
+    (6895, 0x9005, 1),
+    (6896, 0x8ff9, 2),
+    (6898, 0x9011, 1),
+    (6899, 0x9015, 1),
+    (6900, 0x9021, 1),
+    (6901, 0x900d, 1),
+    (6902, 0x901e, 1),
+    (6903, 0x9016, 1),
+    (6904, 0x900b, 1),
+    (6905, 0x9027, 1),
+    (6906, 0x9036, 1),
+    (6907, 0x9035, 1),
+    (6908, 0x9039, 1),
+    (6909, 0x8ff8, 1),
+    (6910, 0x904f, 4),
+    (6914, 0x900e, 1),
+    (6915, 0x9049, 1),
+    (6916, 0x903e, 1),
+    (6917, 0x9056, 1),
+    (6918, 0x9058, 1),
+    (6919, 0x905e, 1),
+    (6920, 0x9068, 1),
+    (6921, 0x906f, 1),
+    (6922, 0x9076, 1),
+    (6923, 0x96a8, 1),
+    (6924, 0x9072, 1),
+    (6925, 0x9082, 1),
+    (6926, 0x907d, 1),
+    (6927, 0x9081, 1),
+    (6928, 0x9080, 1),
+    (6929, 0x908a, 1),
+    (6930, 0x9089, 1),
+    (6931, 0x908f, 1),
+    (6932, 0x90a8, 1),
+    (6933, 0x90af, 1),
+    (6934, 0x90b1, 1),
+    (6935, 0x90b5, 1),
+    (6936, 0x90e2, 1),
+    (6937, 0x90e4, 1),
+    (6938, 0x6248, 1),
+    (6939, 0x90db, 1),
+    (6940, 0x9102, 1),
+    (6941, 0x9112, 1),
+    (6942, 0x9119, 1),
+    (6943, 0x9132, 1),
+    (6944, 0x9130, 1),
+    (6945, 0x914a, 1),
+    (6946, 0x9156, 1),
+    (6947, 0x9158, 1),
+    (6948, 0x9163, 1),
+    (6949, 0x9165, 1),
+    (6950, 0x9169, 1),
+    (6951, 0x9173, 1),
+    (6952, 0x9172, 1),
+    (6953, 0x918b, 1),
+    (6954, 0x9189, 1),
+    (6955, 0x9182, 1),
+    (6956, 0x91a2, 1),
+    (6957, 0x91ab, 1),
+    (6958, 0x91af, 1),
+    (6959, 0x91aa, 1),
+    (6960, 0x91b5, 1),
+    (6961, 0x91b4, 1),
+    (6962, 0x91ba, 1),
+    (6963, 0x91c0, 2),
+    (6965, 0x91c9, 1),
+    (6966, 0x91cb, 1),
+    (6967, 0x91d0, 1),
+    (6968, 0x91d6, 1),
+    (6969, 0x91df, 1),
+    (6970, 0x91e1, 1),
+    (6971, 0x91db, 1),
+    (6972, 0x91fc, 1),
+    (6973, 0x91f5, 2),
+    (6975, 0x921e, 1),
+    (6976, 0x91ff, 1),
+    (6977, 0x9214, 1),
+    (6978, 0x922c, 1),
+    (6979, 0x9215, 1),
+    (6980, 0x9211, 1),
+    (6981, 0x925e, 1),
+    (6982, 0x9257, 1),
+    (6983, 0x9245, 1),
+    (6984, 0x9249, 1),
+    (6985, 0x9264, 1),
+    (6986, 0x9248, 1),
+    (6987, 0x9295, 1),
+    (6988, 0x923f, 1),
+    (6989, 0x924b, 1),
+    (6990, 0x9250, 1),
+    (6991, 0x929c, 1),
+    (6992, 0x9296, 1),
+    (6993, 0x9293, 1),
+    (6994, 0x929b, 1),
+    (6995, 0x925a, 1),
+    (6996, 0x92cf, 1),
+    (6997, 0x92b9, 1),
+    (6998, 0x92b7, 1),
+    (6999, 0x92e9, 1),
+    (7000, 0x930f, 1),
+    (7001, 0x92fa, 1),
+    (7002, 0x9344, 1),
+    (7003, 0x932e, 1),
+    (7004, 0x9319, 1),
+    (7005, 0x9322, 1),
+    (7006, 0x931a, 1),
+    (7007, 0x9323, 1),
+    (7008, 0x933a, 1),
+    (7009, 0x9335, 1),
+    (7010, 0x933b, 1),
+    (7011, 0x935c, 1),
+    (7012, 0x9360, 1),
+    (7013, 0x937c, 1),
+    (7014, 0x936e, 1),
+    (7015, 0x9356, 1),
+    (7016, 0x93b0, 1),
+    (7017, 0x93ac, 2),
+    (7019, 0x9394, 1),
+    (7020, 0x93b9, 1),
+    (7021, 0x93d6, 2),
+    (7023, 0x93e8, 1),
+    (7024, 0x93e5, 1),
+    (7025, 0x93d8, 1),
+    (7026, 0x93c3, 1),
+    (7027, 0x93dd, 1),
+    (7028, 0x93d0, 1),
+    (7029, 0x93c8, 1),
+    (7030, 0x93e4, 1),
+    (7031, 0x941a, 1),
+    (7032, 0x9414, 1),
+    (7033, 0x9413, 1),
+    (7034, 0x9403, 1),
+    (7035, 0x9407, 1),
+    (7036, 0x9410, 1),
+    (7037, 0x9436, 1),
+    (7038, 0x942b, 1),
+    (7039, 0x9435, 1),
+    (7040, 0x9421, 1),
+    (7041, 0x943a, 1),
+    (7042, 0x9441, 1),
+    (7043, 0x9452, 1),
+    (7044, 0x9444, 1),
+    (7045, 0x945b, 1),
+    (7046, 0x9460, 1),
+    (7047, 0x9462, 1),
+    (7048, 0x945e, 1),
+    (7049, 0x946a, 1),
+    (7050, 0x9229, 1),
+    (7051, 0x9470, 1),
+    (7052, 0x9475, 1),
+    (7053, 0x9477, 1),
+    (7054, 0x947d, 1),
+    (7055, 0x945a, 1),
+    (7056, 0x947c, 1),
+    (7057, 0x947e, 1),
+    (7058, 0x9481, 1),
+    (7059, 0x947f, 1),
+    (7060, 0x9582, 1),
+    (7061, 0x9587, 1),
+    (7062, 0x958a, 1),
+    (7063, 0x9594, 1),
+    (7064, 0x9596, 1),
+    (7065, 0x9598, 2),
+    (7067, 0x95a0, 1),
+    (7068, 0x95a8, 1),
+    (7069, 0x95a7, 1),
+    (7070, 0x95ad, 1),
+    (7071, 0x95bc, 1),
+    (7072, 0x95bb, 1),
+    (7073, 0x95b9, 1),
+    (7074, 0x95be, 1),
+    (7075, 0x95ca, 1),
+    (7076, 0x6ff6, 1),
+    (7077, 0x95c3, 1),
+    (7078, 0x95cd, 1),
+    (7079, 0x95cc, 1),
+    (7080, 0x95d5, 1),
+    (7081, 0x95d4, 1),
+    (7082, 0x95d6, 1),
+    (7083, 0x95dc, 1),
+    (7084, 0x95e1, 1),
+    (7085, 0x95e5, 1),
+    (7086, 0x95e2, 1),
+    (7087, 0x9621, 1),
+    (7088, 0x9628, 1),
+    (7089, 0x962e, 2),
+    (7091, 0x9642, 1),
+    (7092, 0x964c, 1),
+    (7093, 0x964f, 1),
+    (7094, 0x964b, 1),
+    (7095, 0x9677, 1),
+    (7096, 0x965c, 1),
+    (7097, 0x965e, 1),
+    (7098, 0x965d, 1),
+    (7099, 0x965f, 1),
+    (7100, 0x9666, 1),
+    (7101, 0x9672, 1),
+    (7102, 0x966c, 1),
+    (7103, 0x968d, 1),
+    (7104, 0x9698, 1),
+    (7105, 0x9695, 1),
+    (7106, 0x9697, 1),
+    (7107, 0x96aa, 1),
+    (7108, 0x96a7, 1),
+    (7109, 0x96b1, 2),
+    (7111, 0x96b0, 1),
+    (7112, 0x96b4, 1),
+    (7113, 0x96b6, 1),
+    (7114, 0x96b8, 2),
+    (7116, 0x96ce, 1),
+    (7117, 0x96cb, 1),
+    (7118, 0x96c9, 1),
+    (7119, 0x96cd, 1),
+    (7120, 0x894d, 1),
+    (7121, 0x96dc, 1),
+    (7122, 0x970d, 1),
+    (7123, 0x96d5, 1),
+    (7124, 0x96f9, 1),
+    (7125, 0x9704, 1),
+    (7126, 0x9706, 1),
+    (7127, 0x9708, 1),
+    (7128, 0x9713, 1),
+    (7129, 0x970e, 1),
+    (7130, 0x9711, 1),
+    (7131, 0x970f, 1),
+    (7132, 0x9716, 1),
+    (7133, 0x9719, 1),
+    (7134, 0x9724, 1),
+    (7135, 0x972a, 1),
+    (7136, 0x9730, 1),
+    (7137, 0x9739, 1),
+    (7138, 0x973d, 2),
+    (7140, 0x9744, 1),
+    (7141, 0x9746, 1),
+    (7142, 0x9748, 1),
+    (7143, 0x9742, 1),
+    (7144, 0x9749, 1),
+    (7145, 0x975c, 1),
+    (7146, 0x9760, 1),
+    (7147, 0x9764, 1),
+    (7148, 0x9766, 1),
+    (7149, 0x9768, 1),
+    (7150, 0x52d2, 1),
+    (7151, 0x976b, 1),
+    (7152, 0x9771, 1),
+    (7153, 0x9779, 1),
+    (7154, 0x9785, 1),
+    (7155, 0x977c, 1),
+    (7156, 0x9781, 1),
+    (7157, 0x977a, 1),
+    (7158, 0x9786, 1),
+    (7159, 0x978b, 1),
+    (7160, 0x978f, 2),
+    (7162, 0x979c, 1),
+    (7163, 0x97a8, 1),
+    (7164, 0x97a6, 1),
+    (7165, 0x97a3, 1),
+    (7166, 0x97b3, 2),
+    (7168, 0x97c3, 1),
+    (7169, 0x97c6, 1),
+    (7170, 0x97c8, 1),
+    (7171, 0x97cb, 1),
+    (7172, 0x97dc, 1),
+    (7173, 0x97ed, 1),
+    (7174, 0x9f4f, 1),
+    (7175, 0x97f2, 1),
+    (7176, 0x7adf, 1),
+    (7177, 0x97f6, 1),
+    (7178, 0x97f5, 1),
+    (7179, 0x980f, 1),
+    (7180, 0x980c, 1),
+    (7181, 0x9838, 1),
+    (7182, 0x9824, 1),
+    (7183, 0x9821, 1),
+    (7184, 0x9837, 1),
+    (7185, 0x983d, 1),
+    (7186, 0x9846, 1),
+    (7187, 0x984f, 1),
+    (7188, 0x984b, 1),
+    (7189, 0x986b, 1),
+    (7190, 0x986f, 3),
+    (7193, 0x9874, 1),
+    (7194, 0x9873, 1),
+    (7195, 0x98aa, 1),
+    (7196, 0x98af, 1),
+    (7197, 0x98b1, 1),
+    (7198, 0x98b6, 1),
+    (7199, 0x98c4, 1),
+    (7200, 0x98c3, 1),
+    (7201, 0x98c6, 1),
+    (7202, 0x98e9, 1),
+    (7203, 0x98eb, 1),
+    (7204, 0x9903, 1),
+    (7205, 0x9909, 1),
+    (7206, 0x9912, 1),
+    (7207, 0x9914, 1),
+    (7208, 0x9918, 1),
+    (7209, 0x9921, 1),
+    (7210, 0x991d, 2),
+    (7212, 0x9924, 1),
+    (7213, 0x9920, 1),
+    (7214, 0x992c, 1),
+    (7215, 0x992e, 1),
+    (7216, 0x993d, 2),
+    (7218, 0x9942, 1),
+    (7219, 0x9949, 1),
+    (7220, 0x9945, 1),
+    (7221, 0x9950, 1),
+    (7222, 0x994b, 1),
+    (7223, 0x9951, 2),
+    (7225, 0x994c, 1),
+    (7226, 0x9955, 1),
+    (7227, 0x9997, 2),
+    (7229, 0x99a5, 1),
+    (7230, 0x99ad, 2),
+    (7232, 0x99bc, 1),
+    (7233, 0x99df, 1),
+    (7234, 0x99db, 1),
+    (7235, 0x99dd, 1),
+    (7236, 0x99d8, 1),
+    (7237, 0x99d1, 1),
+    (7238, 0x99ed, 2),
+    (7240, 0x99f1, 2),
+    (7242, 0x99fb, 1),
+    (7243, 0x99f8, 1),
+    (7244, 0x9a01, 1),
+    (7245, 0x9a0f, 1),
+    (7246, 0x9a05, 1),
+    (7247, 0x99e2, 1),
+    (7248, 0x9a19, 1),
+    (7249, 0x9a2b, 1),
+    (7250, 0x9a37, 1),
+    (7251, 0x9a45, 1),
+    (7252, 0x9a42, 1),
+    (7253, 0x9a40, 1),
+    (7254, 0x9a43, 1),
+    (7255, 0x9a3e, 1),
+    (7256, 0x9a55, 1),
+    (7257, 0x9a4d, 1),
+    (7258, 0x9a5b, 1),
+    (7259, 0x9a57, 1),
+    (7260, 0x9a5f, 1),
+    (7261, 0x9a62, 1),
+    (7262, 0x9a65, 1),
+    (7263, 0x9a64, 1),
+    (7264, 0x9a69, 1),
+    (7265, 0x9a6b, 1),
+    (7266, 0x9a6a, 1),
+    (7267, 0x9aad, 1),
+    (7268, 0x9ab0, 1),
+    (7269, 0x9abc, 1),
+    (7270, 0x9ac0, 1),
+    (7271, 0x9acf, 1),
+    (7272, 0x9ad1, 1),
+    (7273, 0x9ad3, 2),
+    (7275, 0x9ade, 2),
+    (7277, 0x9ae2, 2),
+    (7279, 0x9ae6, 1),
+    (7280, 0x9aef, 1),
+    (7281, 0x9aeb, 1),
+    (7282, 0x9aee, 1),
+    (7283, 0x9af4, 1),
+    (7284, 0x9af1, 1),
+    (7285, 0x9af7, 1),
+    (7286, 0x9afb, 1),
+    (7287, 0x9b06, 1),
+    (7288, 0x9b18, 1),
+    (7289, 0x9b1a, 1),
+    (7290, 0x9b1f, 1),
+    (7291, 0x9b22, 2),
+    (7293, 0x9b25, 1),
+    (7294, 0x9b27, 4),
+    (7298, 0x9b2e, 2),
+    (7300, 0x9b32, 1),
+    (7301, 0x9b44, 1),
+    (7302, 0x9b43, 1),
+    (7303, 0x9b4f, 1),
+    (7304, 0x9b4d, 2),
+    (7306, 0x9b51, 1),
+    (7307, 0x9b58, 1),
+    (7308, 0x9b74, 1),
+    (7309, 0x9b93, 1),
+    (7310, 0x9b83, 1),
+    (7311, 0x9b91, 1),
+    (7312, 0x9b96, 2),
+    (7314, 0x9b9f, 2),
+    (7316, 0x9ba8, 1),
+    (7317, 0x9bb4, 1),
+    (7318, 0x9bc0, 1),
+    (7319, 0x9bca, 1),
+    (7320, 0x9bb9, 1),
+    (7321, 0x9bc6, 1),
+    (7322, 0x9bcf, 1),
+    (7323, 0x9bd1, 2),
+    (7325, 0x9be3, 1),
+    (7326, 0x9be2, 1),
+    (7327, 0x9be4, 1),
+    (7328, 0x9bd4, 1),
+    (7329, 0x9be1, 1),
+    (7330, 0x9c3a, 1),
+    (7331, 0x9bf2, 1),
+    (7332, 0x9bf1, 1),
+    (7333, 0x9bf0, 1),
+    (7334, 0x9c15, 1),
+    (7335, 0x9c14, 1),
+    (7336, 0x9c09, 1),
+    (7337, 0x9c13, 1),
+    (7338, 0x9c0c, 1),
+    (7339, 0x9c06, 1),
+    (7340, 0x9c08, 1),
+    (7341, 0x9c12, 1),
+    (7342, 0x9c0a, 1),
+    (7343, 0x9c04, 1),
+    (7344, 0x9c2e, 1),
+    (7345, 0x9c1b, 1),
+    (7346, 0x9c25, 1),
+    (7347, 0x9c24, 1),
+    (7348, 0x9c21, 1),
+    (7349, 0x9c30, 1),
+    (7350, 0x9c47, 1),
+    (7351, 0x9c32, 1),
+    (7352, 0x9c46, 1),
+    (7353, 0x9c3e, 1),
+    (7354, 0x9c5a, 1),
+    (7355, 0x9c60, 1),
+    (7356, 0x9c67, 1),
+    (7357, 0x9c76, 1),
+    (7358, 0x9c78, 1),
+    (7359, 0x9ce7, 1),
+    (7360, 0x9cec, 1),
+    (7361, 0x9cf0, 1),
+    (7362, 0x9d09, 1),
+    (7363, 0x9d08, 1),
+    (7364, 0x9ceb, 1),
+    (7365, 0x9d03, 1),
+    (7366, 0x9d06, 1),
+    (7367, 0x9d2a, 1),
+    (7368, 0x9d26, 1),
+    (7369, 0x9daf, 1),
+    (7370, 0x9d23, 1),
+    (7371, 0x9d1f, 1),
+    (7372, 0x9d44, 1),
+    (7373, 0x9d15, 1),
+    (7374, 0x9d12, 1),
+    (7375, 0x9d41, 1),
+    (7376, 0x9d3f, 1),
+    (7377, 0x9d3e, 1),
+    (7378, 0x9d46, 1),
+    (7379, 0x9d48, 1),
+    (7380, 0x9d5d, 2),
+    (7382, 0x9d64, 1),
+    (7383, 0x9d51, 1),
+    (7384, 0x9d50, 1),
+    (7385, 0x9d59, 1),
+    (7386, 0x9d72, 1),
+    (7387, 0x9d89, 1),
+    (7388, 0x9d87, 1),
+    (7389, 0x9dab, 1),
+    (7390, 0x9d6f, 1),
+    (7391, 0x9d7a, 1),
+    (7392, 0x9d9a, 1),
+    (7393, 0x9da4, 1),
+    (7394, 0x9da9, 1),
+    (7395, 0x9db2, 1),
+    (7396, 0x9dc4, 1),
+    (7397, 0x9dc1, 1),
+    (7398, 0x9dbb, 1),
+    (7399, 0x9db8, 1),
+    (7400, 0x9dba, 1),
+    (7401, 0x9dc6, 1),
+    (7402, 0x9dcf, 1),
+    (7403, 0x9dc2, 1),
+    (7404, 0x9dd9, 1),
+    (7405, 0x9dd3, 1),
+    (7406, 0x9df8, 1),
+    (7407, 0x9de6, 1),
+    (7408, 0x9ded, 1),
+    (7409, 0x9def, 1),
+    (7410, 0x9dfd, 1),
+    (7411, 0x9e1a, 2),
+    (7413, 0x9e1e, 1),
+    (7414, 0x9e75, 1),
+    (7415, 0x9e79, 1),
+    (7416, 0x9e7d, 1),
+    (7417, 0x9e81, 1),
+    (7418, 0x9e88, 1),
+    (7419, 0x9e8b, 2),
+    (7421, 0x9e92, 1),
+    (7422, 0x9e95, 1),
+    (7423, 0x9e91, 1),
+    (7424, 0x9e9d, 1),
+    (7425, 0x9ea5, 1),
+    (7426, 0x9ea9, 1),
+    (7427, 0x9eb8, 1),
+    (7428, 0x9eaa, 1),
+    (7429, 0x9ead, 1),
+    (7430, 0x9761, 1),
+    (7431, 0x9ecc, 1),
+    (7432, 0x9ece, 3),
+    (7435, 0x9ed4, 1),
+    (7436, 0x9edc, 1),
+    (7437, 0x9ede, 1),
+    (7438, 0x9edd, 1),
+    (7439, 0x9ee0, 1),
+    (7440, 0x9ee5, 1),
+    (7441, 0x9ee8, 1),
+    (7442, 0x9eef, 1),
+    (7443, 0x9ef4, 1),
+    (7444, 0x9ef6, 2),
+    (7446, 0x9ef9, 1),
+    (7447, 0x9efb, 3),
+    (7450, 0x9f07, 2),
+    (7452, 0x76b7, 1),
+    (7453, 0x9f15, 1),
+    (7454, 0x9f21, 1),
+    (7455, 0x9f2c, 1),
+    (7456, 0x9f3e, 1),
+    (7457, 0x9f4a, 1),
+    (7458, 0x9f52, 1),
+    (7459, 0x9f54, 1),
+    (7460, 0x9f63, 1),
+    (7461, 0x9f5f, 3),
+    (7464, 0x9f66, 2),
+    (7466, 0x9f6c, 1),
+    (7467, 0x9f6a, 1),
+    (7468, 0x9f77, 1),
+    (7469, 0x9f72, 1),
+    (7470, 0x9f76, 1),
+    (7471, 0x9f95, 1),
+    (7472, 0x9f9c, 1),
+    (7473, 0x9fa0, 1),
+    (7474, 0x582f, 1),
+    (7475, 0x69c7, 1),
+    (7476, 0x9059, 1),
+    (7477, 0x7464, 1),
+];
@@ -0,0 +1,101 @@
+//! The glyph names.
+//!
+//! The mapping follows the [Adobe Glyph List Specification][1].
+//!
+//! [1]: https://github.com/adobe-type-tools/agl-specification
+
+use crate::unicode::glyph_list::GLYPHS;
+
+/// Return the characters of a glyph name listed in the Adobe Glyph List.
+pub fn get(name: &str) -> Option<&'static str> {
+    GLYPHS
+        .binary_search_by_key(&name, |&(name, _)| name)
+        .ok()
+        .map(|i| GLYPHS[i].1)
+}
+
+/// Decode the characters represented by a glyph name.
+///
+/// The part starting from the first period is ignored, and the rest is split into components
+/// separated by underscores. Each component is either looked up in the Adobe Glyph List or
+/// interpreted as a `uniXXXX` or `uXXXX[XX]` form. Components that cannot be decoded do not
+/// contribute any characters.
+pub fn decode(name: &str) -> Option<String> {
+    let name = match name.find('.') {
+        Some(i) => &name[..i],
+        _ => name,
+    };
+    let mut value = String::new();
+    for component in name.split('_') {
+        if let Some(characters) = get(component) {
+            value.push_str(characters);
+        } else if let Some(characters) = decode_uni(component) {
+            value.extend(characters);
+        } else if let Some(character) = decode_u(component) {
+            value.push(character);
+        }
+    }
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn decode_uni(component: &str) -> Option<Vec<char>> {
+    let digits = component.strip_prefix("uni")?;
+    if digits.is_empty() || !digits.len().is_multiple_of(4) || !is_hexadecimal(digits) {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(4)
+        .map(|i| {
+            let value = u32::from_str_radix(&digits[i..(i + 4)], 16).ok()?;
+            char::from_u32(value)
+        })
+        .collect()
+}
+
+fn decode_u(component: &str) -> Option<char> {
+    let digits = component.strip_prefix('u')?;
+    if !(4..=6).contains(&digits.len()) || !is_hexadecimal(digits) {
+        return None;
+    }
+    char::from_u32(u32::from_str_radix(digits, 16).ok()?)
+}
+
+#[inline]
+fn is_hexadecimal(digits: &str) -> bool {
+    digits
+        .bytes()
+        .all(|byte| byte.is_ascii_digit() || (b'A'..=b'F').contains(&byte))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use crate::unicode::glyph_list::GLYPHS;
+
+    #[test]
+    fn decode_forms() {
+        assert_eq!(decode("A").as_deref(), Some("A"));
+        assert_eq!(decode("Lcommaaccent").as_deref(), Some("\u{013B}"));
+        assert_eq!(decode("uni20AC0308").as_deref(), Some("\u{20AC}\u{0308}"));
+        assert_eq!(decode("u1040C").as_deref(), Some("\u{1040C}"));
+        assert_eq!(decode("f_f_i").as_deref(), Some("ffi"));
+        assert_eq!(decode("a.sc").as_deref(), Some("a"));
+        let name = "Lcommaaccent_uni20AC0308_u1040C.alternate";
+        let value = "\u{013B}\u{20AC}\u{0308}\u{1040C}";
+        assert_eq!(decode(name).as_deref(), Some(value));
+        assert_eq!(decode("uniD801"), None);
+        assert_eq!(decode("uni20ac"), None);
+        assert_eq!(decode("u12345678"), None);
+        assert_eq!(decode(".notdef"), None);
+        assert_eq!(decode("foo"), None);
+    }
+
+    #[test]
+    fn glyphs_sorted() {
+        assert!(GLYPHS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
@@ -0,0 +1,7 @@
+//! The Unicode mappings.
+
+pub mod collection;
+pub mod glyph_name;
+
+mod collection_list;
+mod glyph_list;
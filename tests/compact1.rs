@@ -8,6 +8,382 @@ macro_rules! operations(
     });
 );
 
+mod hirakatana {
+    use crate::support::{setup_font_set, Fixture};
+
+    #[test]
+    fn key_by_character_name() {
+        use postscript::compact1::character_set::{CharacterSet, CharacterSet0};
+        use postscript::compact1::font_set::character_id_keyed::{
+            Encoding, Encoding3, Range3, Record as CharacterIDKeyedRecord, RecordInner,
+        };
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::{Number, Operator};
+        use postscript::type2::Program;
+
+        let original = setup_font_set(Fixture::Hirakatana);
+        let record = match &original.records[0] {
+            Record::CharacterNameKeyed(ref record) => record.clone(),
+            _ => unreachable!(),
+        };
+        let widths = [
+            (
+                ok!(record.operations.get_single(Operator::DefaultWidthX)),
+                ok!(record.operations.get_single(Operator::NominalWidthX)),
+            ),
+            (Number::Integer(400), Number::Integer(700)),
+        ];
+        let mut other = record.clone();
+        other
+            .operations
+            .0
+            .insert(Operator::DefaultWidthX, vec![widths[1].0]);
+        other
+            .operations
+            .0
+            .insert(Operator::NominalWidthX, vec![widths[1].1]);
+        // Make the subroutines different but equivalent by adding unreachable data.
+        for code in other.subroutines.0.data.iter_mut() {
+            code.push(0x0b);
+        }
+        let mut set = original.clone();
+        set.operations[0]
+            .0
+            .insert(Operator::ROS, vec![391.into(), 392.into(), 0.into()]);
+        set.character_sets[0] = CharacterSet::Format0(CharacterSet0 {
+            format: 0,
+            glyphs: (1..101).collect(),
+        });
+        set.records[0] = Record::CharacterIDKeyed(CharacterIDKeyedRecord {
+            registry: 391,
+            ordering: 392,
+            supplement: Number::Integer(0),
+            encoding: Encoding::Format3(Encoding3 {
+                format: 3,
+                range_count: 3,
+                ranges: vec![
+                    Range3 {
+                        first_glyph_id: 0,
+                        dictionary_id: 0,
+                    },
+                    Range3 {
+                        first_glyph_id: 30,
+                        dictionary_id: 1,
+                    },
+                    Range3 {
+                        first_glyph_id: 70,
+                        dictionary_id: 2,
+                    },
+                ],
+                glyph_count: 101,
+            }),
+            operations: vec![operations!(Private: [0, 0],); 3],
+            records: vec![
+                RecordInner {
+                    operations: record.operations.clone(),
+                    subroutines: record.subroutines.clone(),
+                },
+                RecordInner {
+                    operations: other.operations,
+                    subroutines: other.subroutines,
+                },
+                RecordInner {
+                    operations: record.operations.clone(),
+                    subroutines: record.subroutines.clone(),
+                },
+            ],
+        });
+        ok!(set.key_by_character_name(0));
+
+        assert!(set.operations[0].get(Operator::ROS).is_none());
+        assert_eq!(
+            ok!(set.strings.get(ok!(set.character_sets[0].get_string_id(5)))),
+            "cid00005",
+        );
+        let local = match &set.records[0] {
+            Record::CharacterNameKeyed(ref record) => &record.subroutines,
+            _ => unreachable!(),
+        };
+        assert_eq!(local.len(), 26);
+        let value = |number: Number| number.to_f32();
+        for (glyph_id, (code, other_code)) in original.character_strings[0]
+            .iter()
+            .zip(set.character_strings[0].iter())
+            .enumerate()
+        {
+            let mut program = Program::new(code, &original.subroutines, &record.subroutines);
+            let mut other_program = Program::new(other_code, &set.subroutines, local);
+            loop {
+                let operation = ok!(program.next());
+                assert_eq!(operation, ok!(other_program.next()));
+                if operation.is_none() {
+                    break;
+                }
+            }
+            let (default, nominal) = widths[if (30..70).contains(&glyph_id) { 1 } else { 0 }];
+            let expected = match program.width() {
+                Some(width) => value(nominal) + width,
+                _ => value(default),
+            };
+            let actual = match other_program.width() {
+                Some(width) => value(widths[0].1) + width,
+                _ => value(widths[0].0),
+            };
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn ttx() {
+        use postscript::compact1::font_set::character_id_keyed::Collection;
+        use postscript::compact1::font_set::Record;
+        use postscript::ttx;
+
+        let mut set = setup_font_set(Fixture::Hirakatana);
+        ok!(set.key_by_character_id(0, &Collection::default(), None));
+        let text = ok!(ttx::write(&set));
+        assert!(text.contains("<ROS Registry=\"Adobe\" Order=\"Identity\" Supplement=\"0\"/>"));
+        assert!(text.contains("<FDSelect format=\"3\"/>"));
+        assert!(text.contains("<CharString name=\"cid00005\" fdSelectIndex=\"0\">"));
+        let other = ok!(ttx::read(&text));
+        assert_eq!(ok!(ttx::write(&other)), text);
+        assert_eq!(
+            other.character_strings[0].data,
+            set.character_strings[0].data
+        );
+        assert_eq!(
+            other.character_sets[0].get_string_id(5),
+            set.character_sets[0].get_string_id(5),
+        );
+        match (&other.records[0], &set.records[0]) {
+            (Record::CharacterIDKeyed(ref other), Record::CharacterIDKeyed(ref record)) => {
+                assert_eq!(other.records.len(), record.records.len());
+                assert_eq!(
+                    other.records[0].subroutines.data,
+                    record.records[0].subroutines.data
+                );
+                assert_eq!(other.encoding.get(5), record.encoding.get(5));
+            }
+            _ => unreachable!(),
+        }
+    }
+    #[test]
+    fn write() {
+        use std::io::Cursor;
+
+        use postscript::compact1::font_set::character_id_keyed::Collection;
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::FontSet;
+        use postscript::tape::{Read, Write};
+
+        let mut set = setup_font_set(Fixture::Hirakatana);
+        ok!(set.key_by_character_id(0, &Collection::default(), None));
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let other: FontSet = ok!(Cursor::new(&data).take());
+        assert_eq!(
+            other.character_strings[0].data,
+            set.character_strings[0].data
+        );
+        assert_eq!(
+            other.character_sets[0].get_string_id(5),
+            set.character_sets[0].get_string_id(5),
+        );
+        match (&other.records[0], &set.records[0]) {
+            (Record::CharacterIDKeyed(ref other), Record::CharacterIDKeyed(ref record)) => {
+                assert_eq!(other.registry, record.registry);
+                assert_eq!(other.records.len(), record.records.len());
+                assert_eq!(
+                    other.records[0].subroutines.data,
+                    record.records[0].subroutines.data
+                );
+                assert_eq!(other.encoding.get(5), record.encoding.get(5));
+            }
+            _ => unreachable!(),
+        }
+        let mut other_data = vec![];
+        ok!(other_data.give(&other));
+        assert_eq!(other_data, data);
+    }
+
+    #[test]
+    fn merge() {
+        use std::collections::HashMap;
+        use std::io::Cursor;
+
+        use postscript::compact1::font_set::character_id_keyed::Collection;
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::{FontSet, Operator};
+        use postscript::error::{Error, Kind};
+        use postscript::tape::{Read, Write};
+
+        let mut set = setup_font_set(Fixture::Hirakatana);
+        ok!(set.key_by_character_id(0, &Collection::default(), None));
+        let mut other = setup_font_set(Fixture::SourceSerifPro);
+        let error = ok!(set.clone().merge(0, &other, 0).err());
+        assert_eq!(ok!(Error::find(&error)).kind, Kind::UnexpectedRecord);
+        let mut mapping = HashMap::new();
+        for glyph_id in 1..547 {
+            let name = ok!(other
+                .strings
+                .get(ok!(other.character_sets[0].get_string_id(glyph_id))));
+            mapping.insert(name, 1000 + glyph_id);
+        }
+        ok!(other.key_by_character_id(0, &Collection::default(), Some(&mapping)));
+
+        let original = set.clone();
+        let glyph_ids = ok!(set.merge(0, &other, 0));
+        assert_eq!(glyph_ids.len(), 547);
+        assert_eq!(glyph_ids[0], 0);
+        assert_eq!(glyph_ids[1], original.character_strings[0].count);
+        assert_eq!(
+            set.character_strings[0].count,
+            original.character_strings[0].count + 546,
+        );
+        assert_eq!(
+            set.subroutines.len(),
+            original.subroutines.len() + other.subroutines.len(),
+        );
+        match &set.records[0] {
+            Record::CharacterIDKeyed(ref record) => assert_eq!(record.records.len(), 2),
+            _ => unreachable!(),
+        }
+        assert_eq!(
+            ok!(set.operations[0].get(Operator::CIDCount)),
+            &[1547.into()],
+        );
+
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let set: FontSet = ok!(Cursor::new(&data).take());
+        for glyph_id in 0..original.character_strings[0].count {
+            assert_eq!(
+                ok!(set.outline(0, glyph_id)).segments,
+                ok!(original.outline(0, glyph_id)).segments,
+            );
+        }
+        for (old_glyph_id, &glyph_id) in glyph_ids.iter().enumerate().skip(1) {
+            let old_glyph_id = old_glyph_id as u16;
+            assert_eq!(
+                set.character_sets[0].get_string_id(glyph_id),
+                other.character_sets[0].get_string_id(old_glyph_id),
+            );
+            assert_eq!(
+                ok!(set.outline(0, glyph_id)).segments,
+                ok!(other.outline(0, old_glyph_id)).segments,
+            );
+            assert_eq!(
+                ok!(set.width(0, glyph_id)),
+                ok!(other.width(0, old_glyph_id))
+            );
+        }
+    }
+
+    #[test]
+    fn limits() {
+        use std::io::Cursor;
+
+        use postscript::compact1::font_set::character_id_keyed::Collection;
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::{FontSet, Number, Operator};
+        use postscript::error::{Error, Kind, Structure};
+        use postscript::tape::{Read, Write};
+        use postscript::Limits;
+
+        fn read(data: &[u8], allocation_size: usize) -> postscript::Result<FontSet> {
+            let limits = Limits {
+                allocation_size,
+                ..Default::default()
+            };
+            Cursor::new(data).take_given::<FontSet>(limits)
+        }
+
+        fn required(data: &[u8]) -> usize {
+            let (mut lower, mut upper) = (0, Limits::default().allocation_size);
+            while lower < upper {
+                let middle = (lower + upper) / 2;
+                match read(data, middle) {
+                    Ok(_) => upper = middle,
+                    _ => lower = middle + 1,
+                }
+            }
+            lower
+        }
+
+        let mut set = setup_font_set(Fixture::Hirakatana);
+        ok!(set.key_by_character_id(0, &Collection::default(), None));
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let size = required(&data);
+
+        match set.records[0] {
+            Record::CharacterIDKeyed(ref mut record) => {
+                record.operations.push(record.operations[0].clone());
+                record.records.push(record.records[0].clone());
+            }
+            _ => unreachable!(),
+        }
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let another_size = required(&data);
+        let set = ok!(read(&data, another_size));
+        let (private, subroutines) = match set.records[0] {
+            Record::CharacterIDKeyed(ref record) => {
+                match ok!(record.operations[1].get_double(Operator::Private)) {
+                    (Number::Integer(size), _) => {
+                        (size as usize, record.records[1].subroutines.size())
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        };
+        assert!(another_size > size + private + subroutines);
+        let error = read(&data, another_size - subroutines - 1).unwrap_err();
+        let error = ok!(Error::find(&error));
+        assert!(matches!(error.kind, Kind::ExceededAllocationLimit(..)));
+        assert_eq!(error.structure, Some(Structure::PrivateDictionary));
+    }
+
+    #[test]
+    fn spans() {
+        use std::io::Cursor;
+
+        use postscript::compact1::font_set::character_id_keyed::Collection;
+        use postscript::compact1::font_set::span::Label;
+        use postscript::compact1::FontSet;
+        use postscript::error::Structure;
+        use postscript::tape::Write;
+        use postscript::Limits;
+
+        let mut set = setup_font_set(Fixture::Hirakatana);
+        ok!(set.key_by_character_id(0, &Collection::default(), None));
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let (_, span) = ok!(FontSet::read_spans(
+            &mut Cursor::new(&data),
+            Limits::default()
+        ));
+        assert_eq!(span.range, 0..data.len() as u64);
+        let labels = span.children[5]
+            .children
+            .iter()
+            .map(|span| span.label)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                Label::Structure(Structure::CharacterStrings),
+                Label::Structure(Structure::CharacterSet),
+                Label::Structure(Structure::DictionarySelector),
+                Label::Structure(Structure::FontDictionaries),
+                Label::Structure(Structure::PrivateDictionary),
+                Label::Structure(Structure::LocalSubroutines),
+            ],
+        );
+    }
+}
+
 mod noto_sans_direct {
     use postscript::tape::Read;
 
@@ -169,6 +545,232 @@ mod source_serif {
         assert_eq!(table.offset_size, 2);
     }
 
+    #[test]
+    fn key_by_character_id() {
+        use std::collections::HashMap;
+
+        use postscript::compact1::font_set::character_id_keyed::{Collection, Encoding};
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::{Number, Operator};
+        use postscript::error::{Error, Kind};
+
+        let original = setup_font_set(Fixture::SourceSerifPro);
+        let mut set = original.clone();
+        let mut mapping = HashMap::new();
+        for glyph_id in 1..547 {
+            let name = ok!(set
+                .strings
+                .get(ok!(set.character_sets[0].get_string_id(glyph_id))));
+            mapping.insert(name, 1000 + glyph_id);
+        }
+        let name = ok!(set
+            .strings
+            .get(ok!(set.character_sets[0].get_string_id(11))));
+        mapping.remove(&name);
+        let error = ok!(set
+            .key_by_character_id(0, &Default::default(), Some(&mapping))
+            .err());
+        assert_eq!(ok!(Error::find(&error)).kind, Kind::MissingCharacterID(11));
+        mapping.insert(name.clone(), 1001);
+        let error = ok!(set
+            .key_by_character_id(0, &Default::default(), Some(&mapping))
+            .err());
+        assert_eq!(
+            ok!(Error::find(&error)).kind,
+            Kind::DuplicateCharacterID(1001)
+        );
+        mapping.insert(name, 1011);
+
+        let collection = Collection {
+            registry: "Adobe".into(),
+            ordering: "Identity".into(),
+            supplement: 0,
+        };
+        assert_eq!(collection, Default::default());
+        ok!(set.key_by_character_id(0, &collection, Some(&mapping)));
+        let record = match &set.records[0] {
+            Record::CharacterIDKeyed(ref record) => record,
+            _ => unreachable!(),
+        };
+        assert_eq!(ok!(set.strings.get(record.registry)), "Adobe");
+        assert_eq!(ok!(set.strings.get(record.ordering)), "Identity");
+        assert!(matches!(record.encoding, Encoding::Format3(..)));
+        assert_eq!(ok!(record.get(546)).subroutines.len(), 180);
+        assert_eq!(
+            ok!(set.strings.get(ok!(
+                ok!(record.operations[0].get(Operator::FontName))[0].try_into()
+            ))),
+            "SourceSerifPro-Regular",
+        );
+        assert_eq!(
+            ok!(set.operations[0].get(Operator::CIDCount)),
+            &[Number::Integer(1547)],
+        );
+        assert!(set.operations[0].get(Operator::Private).is_none());
+        assert_eq!(set.character_sets[0].get_string_id(11), Some(1011));
+        assert_eq!(set.character_sets[0].get_string_id(546), Some(1546));
+
+        ok!(set.key_by_character_name(0));
+        assert_eq!(
+            ok!(set
+                .strings
+                .get(ok!(set.character_sets[0].get_string_id(11)))),
+            "cid01011",
+        );
+        assert_eq!(
+            set.operations[0].get(Operator::Private),
+            original.operations[0].get(Operator::Private),
+        );
+        assert!(set.character_strings[0]
+            .iter()
+            .eq(original.character_strings[0].iter()));
+    }
+
+    #[test]
+    fn limits() {
+        use postscript::compact1::FontSet;
+        use postscript::error::{Error, Kind, Structure};
+        use postscript::tape::Read;
+        use postscript::Limits;
+
+        use crate::support::setup;
+
+        let limits = Limits {
+            index_count: 500,
+            ..Default::default()
+        };
+        let mut tape = setup(Fixture::SourceSerifPro);
+        let error = tape.take_given::<FontSet>(limits).unwrap_err();
+        let error = ok!(Error::find(&error));
+        assert_eq!(error.kind, Kind::ExceededIndexCountLimit(500));
+        assert_eq!(error.structure, Some(Structure::CharacterStrings));
+        assert_eq!(error.position, Some(17732 + 8917));
+
+        let limits = Limits {
+            allocation_size: 1 << 12,
+            ..Default::default()
+        };
+        let mut tape = setup(Fixture::SourceSerifPro);
+        let error = tape.take_given::<FontSet>(limits).unwrap_err();
+        let error = ok!(Error::find(&error));
+        assert!(matches!(error.kind, Kind::ExceededAllocationLimit(..)));
+    }
+
+    #[test]
+    fn map_characters() {
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let mapping = ok!(set.map_characters(0));
+        let name = |glyph_id| {
+            ok!(set
+                .strings
+                .get(ok!(set.character_sets[0].get_string_id(glyph_id))))
+        };
+        assert_eq!(name(mapping[&'A']), "A");
+        assert_eq!(name(mapping[&'é']), "eacute");
+        assert_eq!(name(mapping[&'€']), "Euro");
+        assert!(!mapping.contains_key(&'\u{0}'));
+        assert_eq!(mapping.len(), 388);
+    }
+
+    #[test]
+    fn map_glyphs() {
+        use postscript::cmap::CMap;
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let glyphs = ok!(set.map_glyphs(0));
+        assert_eq!(glyphs.len(), 545);
+        let ligature = ok!(glyphs.iter().find(|(_, value)| value.as_str() == "fi")).0;
+
+        let cmap = CMap::to_unicode(&glyphs);
+        assert_eq!(cmap.characters.len(), 318);
+        let cmap = ok!(CMap::parse(cmap.to_string().as_bytes()));
+        for (glyph_id, value) in glyphs.iter() {
+            assert_eq!(ok!(cmap.get_characters(&glyph_id.to_be_bytes())), *value);
+        }
+        assert_eq!(ok!(cmap.get_characters(&ligature.to_be_bytes())), "fi",);
+    }
+
+    #[test]
+    fn map_character_ids() {
+        use std::collections::HashMap;
+
+        use postscript::compact1::font_set::character_id_keyed::Collection;
+        use postscript::compact1::validation::{validate, Rule};
+
+        let mut set = setup_font_set(Fixture::SourceSerifPro);
+        let mut mapping = HashMap::new();
+        for glyph_id in 1..547 {
+            let name = ok!(set
+                .strings
+                .get(ok!(set.character_sets[0].get_string_id(glyph_id))));
+            let character_id = match name.as_str() {
+                "A" => 34,
+                "zero" => 17,
+                "B" => 9000,
+                _ => 10000 + glyph_id,
+            };
+            mapping.insert(name, character_id);
+        }
+        let collection = Collection {
+            registry: "Adobe".into(),
+            ordering: "Japan1".into(),
+            supplement: 0,
+        };
+        ok!(set.key_by_character_id(0, &collection, Some(&mapping)));
+        assert_eq!(ok!(set.collection(0)), collection);
+
+        let characters = ok!(set.map_characters(0));
+        assert_eq!(characters.len(), 2);
+        assert_eq!(
+            set.character_sets[0].get_string_id(characters[&'A']),
+            Some(34)
+        );
+        assert_eq!(
+            set.character_sets[0].get_string_id(characters[&'0']),
+            Some(17)
+        );
+
+        let glyph_ids = ok!(set.map_character_ids(0));
+        let cmap = postscript::cmap::CMap::identity(0);
+        let decoded = cmap
+            .decode(b"\x00\x22\x00\x11\x00\x23")
+            .into_iter()
+            .map(|character_id| glyph_ids.get(&character_id).copied().unwrap_or(0))
+            .collect::<Vec<_>>();
+        assert_eq!(decoded, [characters[&'A'], characters[&'0'], 0]);
+
+        let excess = ok!(set.find_excess_character_ids(0));
+        assert_eq!(excess.len(), 546 - 2);
+        assert!(excess.contains(&9000));
+        let issues = validate(&set);
+        assert!(issues.iter().any(|issue| issue.rule == Rule::CharacterSet
+            && issue.description == "found CID 9000 beyond supplement 0 of Adobe-Japan1"));
+    }
+
+    #[test]
+    fn malformed() {
+        use std::io::Cursor;
+
+        use postscript::compact1::FontSet;
+        use postscript::error::{Error, Kind, Structure};
+        use postscript::tape::Read;
+
+        let mut data = ok!(std::fs::read(Fixture::SourceSerifPro.path()));
+        let data = &mut data[Fixture::SourceSerifPro.offset() as usize..];
+        // Break the first offset of the string index.
+        let position = 4 + 27 + 49 + 4;
+        assert_eq!(data[position], 1);
+        data[position] = 2;
+        let error = Cursor::new(&data[..]).take::<FontSet>().unwrap_err();
+        let error = ok!(Error::find(&error));
+        assert_eq!(error.kind, Kind::MalformedIndex);
+        assert_eq!(error.structure, Some(Structure::Strings));
+        assert_eq!(
+            error.to_string(),
+            "found a malformed index in the string index at position 80",
+        );
+    }
+
     #[test]
     fn names() {
         let set = setup_font_set(Fixture::SourceSerifPro);
@@ -177,6 +779,39 @@ mod source_serif {
         assert_eq!(&table[0], "SourceSerifPro-Regular");
     }
 
+    #[test]
+    fn ttx() {
+        use postscript::compact1::font_set::Record;
+        use postscript::ttx;
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let text = ok!(ttx::write(&set));
+        assert!(text.contains("<CFFFont name=\"SourceSerifPro-Regular\">"));
+        assert!(text.contains("<BlueScale value=\"0.0375\"/>"));
+        assert!(text.contains("<Encoding name=\"StandardEncoding\"/>"));
+        assert!(text.contains("<GlyphID id=\"2\" name=\"A\"/>"));
+        let other = ok!(ttx::read(&text));
+        assert_eq!(ok!(ttx::write(&other)), text);
+        assert_eq!(&other.names[0], &set.names[0]);
+        assert_eq!(other.subroutines.data, set.subroutines.data);
+        assert_eq!(
+            other.character_strings[0].data,
+            set.character_strings[0].data
+        );
+        match (&other.records[0], &set.records[0]) {
+            (Record::CharacterNameKeyed(ref other), Record::CharacterNameKeyed(ref record)) => {
+                let mut operations = record.operations.0.clone();
+                operations.remove(&postscript::compact1::Operator::Subrs);
+                let mut other_operations = other.operations.0.clone();
+                other_operations.remove(&postscript::compact1::Operator::Subrs);
+                assert_eq!(other_operations, operations);
+                assert_eq!(other.subroutines.data, record.subroutines.data);
+            }
+            _ => unreachable!(),
+        }
+        assert!(ttx::read(&text.replace("rmoveto", "rmove")).is_err());
+    }
+
     #[test]
     fn operations() {
         let set = setup_font_set(Fixture::SourceSerifPro);
@@ -282,4 +917,535 @@ mod source_serif {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn numbers() {
+        use std::io::Cursor;
+
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::{Number, Operator, Real};
+        use postscript::tape::{Read, Write};
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let record = match &set.records[0] {
+            Record::CharacterNameKeyed(ref record) => record,
+            _ => unreachable!(),
+        };
+        let value = ok!(record.operations.get_single(Operator::BlueScale));
+        assert_eq!(value, Number::Real(Real::new(375, -4)));
+        let matrix = ok!(Operator::FontMatrix.default());
+        for operations in [&set.operations[0], &record.operations] {
+            let operands = operations.0.values().flatten().chain(matrix.iter());
+            for &operand in operands {
+                let mut tape = vec![];
+                ok!(tape.give(&operand));
+                assert_eq!(ok!(Cursor::new(tape).take::<Number>()), operand);
+            }
+        }
+    }
+
+    #[test]
+    fn validation() {
+        use std::io::Cursor;
+
+        use postscript::compact1::character_set::{CharacterSet, CharacterSet0};
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::validation::{validate, validate_bytes, Rule, Severity};
+        use postscript::compact1::{FontSet, Number, Operator};
+        use postscript::tape::{Read, Write};
+
+        let data = ok!(std::fs::read(Fixture::SourceSerifPro.path()));
+        let issues = validate_bytes(&data[Fixture::SourceSerifPro.offset() as usize..]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert_eq!(issues[0].rule, Rule::Subroutine);
+        assert_eq!(
+            issues[0].to_string(),
+            "warning in record 0: found unreachable local subroutine 53",
+        );
+
+        let issues = validate_bytes(&data[Fixture::SourceSerifPro.offset() as usize..][..20000]);
+        assert!(issues.iter().any(|issue| issue.rule == Rule::Offset));
+        assert!(issues.iter().any(|issue| issue.rule == Rule::Parsing));
+
+        let mut set = setup_font_set(Fixture::SourceSerifPro);
+        set.operations[0]
+            .0
+            .insert(Operator::FontBBox, vec![Number::Integer(0)]);
+        match &mut set.records[0] {
+            Record::CharacterNameKeyed(ref mut record) => {
+                record.operations.0.insert(
+                    Operator::BlueValues,
+                    vec![
+                        Number::Integer(-20),
+                        Number::Integer(20),
+                        Number::Integer(-473),
+                    ],
+                );
+            }
+            _ => unreachable!(),
+        }
+        let mut glyphs = (1..547).collect::<Vec<_>>();
+        glyphs[41] = glyphs[40];
+        set.character_sets[0] = CharacterSet::Format0(CharacterSet0 { format: 0, glyphs });
+        let code = &mut set.character_strings[0].0.data[42];
+        code.truncate(code.len() - 1);
+        let issues = validate(&set);
+        let rules = issues.iter().map(|issue| issue.rule).collect::<Vec<_>>();
+        assert!(rules.contains(&Rule::Arity));
+        assert_eq!(
+            rules
+                .iter()
+                .filter(|&&rule| rule == Rule::BlueValues)
+                .count(),
+            2,
+        );
+        let issue = ok!(issues.iter().find(|issue| issue.rule == Rule::GlyphName));
+        assert_eq!(issue.glyph_id, Some(42));
+        let issue = ok!(issues
+            .iter()
+            .find(|issue| issue.rule == Rule::CharacterString));
+        assert_eq!(issue.severity, Severity::Error);
+        assert_eq!(issue.glyph_id, Some(42));
+
+        set.character_sets[0] = CharacterSet::ISOAdobe;
+        let issues = validate(&set);
+        assert!(issues.iter().any(|issue| issue.rule == Rule::CharacterSet));
+
+        let mut set = setup_font_set(Fixture::SourceSerifPro);
+        ok!(set.subset(0, &[1, 2, 3]));
+        set.character_sets[0] = CharacterSet::ISOAdobe;
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let set: FontSet = ok!(Cursor::new(&data).take());
+        assert_eq!(ok!(set.operations[0].get(Operator::CharSet)), &[0.into()]);
+        assert_eq!(set.character_strings[0].count, 4);
+        let issues = validate_bytes(&data);
+        assert!(!issues.iter().any(|issue| issue.rule == Rule::CharacterSet));
+    }
+    #[test]
+    fn subset() {
+        use std::io::Cursor;
+
+        use postscript::compact1::FontSet;
+        use postscript::tape::{Read, Write};
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let mut other = set.clone();
+        assert_eq!(ok!(other.subset(0, &[34, 2, 2])), vec![0, 2, 34]);
+        assert!(other.clone().subset(0, &[547]).is_err());
+        let mut data = vec![];
+        ok!(data.give(&other));
+        let other: FontSet = ok!(Cursor::new(&data).take());
+        assert_eq!(other.character_strings[0].count, 3);
+        assert_eq!(other.subroutines.data, set.subroutines.data);
+        for (glyph_id, old_glyph_id) in [(0, 0), (1, 2), (2, 34)] {
+            assert_eq!(
+                other.character_sets[0].get_string_id(glyph_id),
+                set.character_sets[0].get_string_id(old_glyph_id),
+            );
+            assert_eq!(
+                ok!(other.outline(0, glyph_id)).segments,
+                ok!(set.outline(0, old_glyph_id)).segments,
+            );
+            assert_eq!(
+                ok!(other.width(0, glyph_id)),
+                ok!(set.width(0, old_glyph_id))
+            );
+        }
+        assert_eq!(ok!(set.width(0, 2)), 664.0);
+    }
+
+    #[test]
+    fn subset_encoding() {
+        use std::collections::HashMap;
+
+        use postscript::compact1::encoding::Encoding0;
+        use postscript::compact1::{Encoding, GlyphID};
+
+        let mut set = setup_font_set(Fixture::SourceSerifPro);
+        let standard = (1..256)
+            .filter_map(|code| Some((Encoding::Standard.get(code)?, code as u8)))
+            .filter(|&(string_id, _)| string_id != 0)
+            .collect::<HashMap<_, _>>();
+        let mut codes = (1..set.character_strings[0].count)
+            .map(|glyph_id| {
+                let string_id = ok!(set.character_sets[0].get_string_id(glyph_id));
+                standard.get(&string_id).copied().unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        codes.truncate(u8::MAX as usize);
+        while codes.last() == Some(&0) {
+            codes.pop();
+        }
+        set.encodings[0] = Encoding::Format0(Encoding0 {
+            format: 0,
+            code_count: codes.len() as u8,
+            codes,
+        });
+        // A, Aacute, which has no code in the standard encoding, and Lslash.
+        let glyph_ids: [GlyphID; 3] = [2, 55, 91];
+        assert_eq!(ok!(set.subset(0, &glyph_ids)), vec![0, 2, 55, 91]);
+        match set.encodings[0] {
+            Encoding::Format0(ref encoding) => {
+                assert_eq!(encoding.code_count, 3);
+                assert_eq!(encoding.codes, [65, 0, 232]);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn write() {
+        use std::io::Cursor;
+
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::validation::{validate_bytes, Severity};
+        use postscript::compact1::{FontSet, Operator};
+        use postscript::tape::{Read, Write};
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let other: FontSet = ok!(Cursor::new(&data).take());
+        assert!(validate_bytes(&data)
+            .iter()
+            .all(|issue| issue.severity != Severity::Error));
+        assert_eq!(other.header.header_size, 4);
+        assert_eq!(&other.names[0], &set.names[0]);
+        assert_eq!(other.strings.data, set.strings.data);
+        assert_eq!(other.subroutines.data, set.subroutines.data);
+        assert_eq!(
+            other.character_strings[0].data,
+            set.character_strings[0].data
+        );
+        for glyph_id in 0..set.character_strings[0].count {
+            assert_eq!(
+                other.character_sets[0].get_string_id(glyph_id),
+                set.character_sets[0].get_string_id(glyph_id),
+            );
+        }
+        let mut operations = set.operations[0].0.clone();
+        let mut other_operations = other.operations[0].0.clone();
+        for operator in [Operator::CharSet, Operator::CharStrings, Operator::Private] {
+            operations.remove(&operator);
+            other_operations.remove(&operator);
+        }
+        other_operations.remove(&Operator::Encoding);
+        assert_eq!(other_operations, operations);
+        match (&other.records[0], &set.records[0]) {
+            (Record::CharacterNameKeyed(ref other), Record::CharacterNameKeyed(ref record)) => {
+                let mut operations = record.operations.0.clone();
+                operations.remove(&Operator::Subrs);
+                let mut other_operations = other.operations.0.clone();
+                other_operations.remove(&Operator::Subrs);
+                assert_eq!(other_operations, operations);
+                assert_eq!(other.subroutines.data, record.subroutines.data);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn merge() {
+        use std::io::Cursor;
+
+        use postscript::compact1::FontSet;
+        use postscript::tape::{Read, Write};
+
+        let original = setup_font_set(Fixture::SourceSerifPro);
+        let mut set = original.clone();
+        let mut other = original.clone();
+        ok!(other.subset(0, &[34, 2]));
+        assert_eq!(ok!(set.merge(0, &other, 0)), vec![0, 2, 34]);
+        assert_eq!(
+            set.character_strings[0].data,
+            original.character_strings[0].data
+        );
+        assert_eq!(set.subroutines.data, original.subroutines.data);
+
+        let other = setup_font_set(Fixture::Hirakatana);
+        let glyph_ids = ok!(set.merge(0, &other, 0));
+        let count = original.character_strings[0].count;
+        assert_eq!(glyph_ids.len(), other.character_strings[0].len());
+        assert!(glyph_ids.iter().any(|&glyph_id| glyph_id >= count));
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let set: FontSet = ok!(Cursor::new(&data).take());
+        for glyph_id in 0..count {
+            assert_eq!(
+                ok!(set.outline(0, glyph_id)).segments,
+                ok!(original.outline(0, glyph_id)).segments,
+            );
+        }
+        for (old_glyph_id, &glyph_id) in glyph_ids.iter().enumerate().skip(1) {
+            let old_glyph_id = old_glyph_id as u16;
+            assert_eq!(
+                ok!(set
+                    .strings
+                    .get(ok!(set.character_sets[0].get_string_id(glyph_id)))),
+                ok!(other
+                    .strings
+                    .get(ok!(other.character_sets[0].get_string_id(old_glyph_id)))),
+            );
+            // Glyphs with names already present are kept from this record.
+            if glyph_id < count {
+                continue;
+            }
+            assert_eq!(
+                ok!(set.outline(0, glyph_id)).segments,
+                ok!(other.outline(0, old_glyph_id)).segments,
+            );
+            assert_eq!(
+                ok!(set.width(0, glyph_id)),
+                ok!(other.width(0, old_glyph_id))
+            );
+        }
+    }
+
+    #[test]
+    fn merge_rebased() {
+        use std::io::Cursor;
+
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::index::{CharacterStrings, Index, StringTable, Subroutines};
+        use postscript::compact1::{CharacterSet, FontSet, Number, Operator};
+        use postscript::tape::{Read, Write};
+
+        let mut set = setup_font_set(Fixture::SourceSerifPro);
+        let mut other = set.clone();
+        ok!(other.subset(0, &[2]));
+        let mut strings = StringTable::from(&other.strings);
+        other.character_sets[0] = CharacterSet::from(vec![ok!(strings.insert("custom"))]);
+        other.strings = strings.into();
+        let code = vec![
+            0xef, 0x8b, 0x9f, 0x01, // 100 0 20 hstem
+            0x20, 0x0a, // -107 callsubr
+            0x13, 0x80, // hintmask
+            0x8b, 0x8b, 0x15, // 0 0 rmoveto
+            0x9f, 0x06, // 20 hlineto
+            0x0e, // endchar
+        ];
+        let notdef = other.character_strings[0][0].clone();
+        other.subroutines = Default::default();
+        other.character_strings[0] = CharacterStrings(Index::from(vec![notdef, code]));
+        match &mut other.records[0] {
+            Record::CharacterNameKeyed(ref mut record) => {
+                // The width is encoded with respect to another nominal width.
+                let width = ok!(record.operations.get_single(Operator::NominalWidthX));
+                let width = Number::Integer(width.to_f32() as i32 + 10);
+                record
+                    .operations
+                    .0
+                    .insert(Operator::NominalWidthX, vec![width]);
+                record.subroutines = Subroutines(Index::from(vec![vec![0x0b]]));
+            }
+            _ => unreachable!(),
+        }
+        match &mut set.records[0] {
+            // The bias of the local subroutines of the other record changes from 107 to 1131.
+            Record::CharacterNameKeyed(ref mut record) => {
+                let mut data = record.subroutines.data.clone();
+                data.resize(data.len().max(1240), vec![0x0b]);
+                record.subroutines = Subroutines(Index::from(data));
+            }
+            _ => unreachable!(),
+        }
+        let original = set.clone();
+
+        let glyph_ids = ok!(set.merge(0, &other, 0));
+        assert_eq!(glyph_ids[1], original.character_strings[0].count);
+        let mut data = vec![];
+        ok!(data.give(&set));
+        let set: FontSet = ok!(Cursor::new(&data).take());
+        assert_eq!(
+            ok!(set.outline(0, glyph_ids[1])).segments,
+            ok!(other.outline(0, 1)).segments,
+        );
+        assert_eq!(ok!(set.width(0, glyph_ids[1])), ok!(other.width(0, 1)));
+    }
+
+    #[test]
+    fn merge_subroutine_width() {
+        use postscript::compact1::font_set::Record;
+        use postscript::compact1::index::{CharacterStrings, Index, StringTable, Subroutines};
+        use postscript::compact1::{CharacterSet, Number, Operator};
+        use postscript::error::{Error, Kind};
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let mut other = set.clone();
+        ok!(other.subset(0, &[2]));
+        let mut strings = StringTable::from(&other.strings);
+        other.character_sets[0] = CharacterSet::from(vec![ok!(strings.insert("custom"))]);
+        other.strings = strings.into();
+        let code = vec![
+            0x20, 0x0a, // -107 callsubr
+            0x8b, 0x8b, 0x15, // 0 0 rmoveto
+            0x9f, 0x06, // 20 hlineto
+            0x0e, // endchar
+        ];
+        let notdef = other.character_strings[0][0].clone();
+        other.subroutines = Default::default();
+        other.character_strings[0] = CharacterStrings(Index::from(vec![notdef, code]));
+        match &mut other.records[0] {
+            Record::CharacterNameKeyed(ref mut record) => {
+                let width = ok!(record.operations.get_single(Operator::NominalWidthX));
+                let width = Number::Integer(width.to_f32() as i32 + 10);
+                record
+                    .operations
+                    .0
+                    .insert(Operator::NominalWidthX, vec![width]);
+                // The width is pushed by the subroutine: 100 0 20 hstem return.
+                let subroutine = vec![0xef, 0x8b, 0x9f, 0x01, 0x0b];
+                record.subroutines = Subroutines(Index::from(vec![subroutine]));
+            }
+            _ => unreachable!(),
+        }
+
+        let error = ok!(set.clone().merge(0, &other, 0).err());
+        assert_eq!(ok!(Error::find(&error)).kind, Kind::UnsupportedWidth(1));
+    }
+
+    #[test]
+    fn spans() {
+        use postscript::compact1::font_set::span::{Label, Span};
+        use postscript::compact1::{FontSet, Operator};
+        use postscript::error::Structure;
+        use postscript::Limits;
+
+        use crate::support::setup;
+
+        fn check(span: &Span) {
+            for child in span.children.iter() {
+                if !matches!(span.label, Label::FontSet | Label::Record(_)) {
+                    assert!(span.range.start <= child.range.start);
+                    assert!(child.range.end <= span.range.end);
+                }
+                check(child);
+            }
+        }
+
+        let data = ok!(std::fs::read(Fixture::SourceSerifPro.path()));
+        let offset = Fixture::SourceSerifPro.offset();
+        let mut tape = setup(Fixture::SourceSerifPro);
+        let (set, span) = ok!(FontSet::read_spans(&mut tape, Limits::default()));
+        check(&span);
+        assert_eq!(span.label, Label::FontSet);
+        assert_eq!(span.range.start, offset);
+        assert_eq!(span.children[0].range, offset..offset + 4);
+        let labels = span
+            .children
+            .iter()
+            .map(|span| span.label)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                Label::Structure(Structure::Header),
+                Label::Structure(Structure::Names),
+                Label::Structure(Structure::TopDictionaries),
+                Label::Structure(Structure::Strings),
+                Label::Structure(Structure::GlobalSubroutines),
+                Label::Record(0),
+            ],
+        );
+        let range = span.children[1].children[0].range.clone();
+        assert_eq!(
+            &data[range.start as usize..range.end as usize],
+            &set.names[0][..]
+        );
+        assert!(span.children[2].children[0]
+            .children
+            .iter()
+            .any(|span| span.label == Label::Operation(Operator::CharStrings)));
+
+        let record = &span.children[5];
+        let labels = record
+            .children
+            .iter()
+            .map(|span| span.label)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                Label::Structure(Structure::CharacterStrings),
+                Label::Structure(Structure::CharacterSet),
+                Label::Structure(Structure::PrivateDictionary),
+                Label::Structure(Structure::LocalSubroutines),
+            ],
+        );
+        let character_strings = &record.children[0];
+        assert_eq!(character_strings.children.len(), 547);
+        for (span, code) in character_strings
+            .children
+            .iter()
+            .zip(set.character_strings[0].iter())
+        {
+            assert_eq!(
+                &data[span.range.start as usize..span.range.end as usize],
+                &code[..]
+            );
+        }
+        let chain = span.find(character_strings.children[34].range.start);
+        let labels = chain.iter().map(|span| span.label).collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                Label::FontSet,
+                Label::Record(0),
+                Label::Structure(Structure::CharacterStrings),
+                Label::Element(34),
+            ],
+        );
+        let private = &record.children[2];
+        assert!(private
+            .children
+            .iter()
+            .any(|span| span.label == Label::Operation(Operator::Subrs)));
+        assert!(span.find(span.range.end).is_empty());
+    }
+}
+
+mod synthetic {
+    #[test]
+    fn dictionary_selector() {
+        use postscript::compact1::font_set::character_id_keyed::{
+            Encoding, Encoding0, Encoding3, Range3,
+        };
+
+        let encoding = Encoding::Format3(Encoding3 {
+            format: 3,
+            range_count: 3,
+            ranges: vec![
+                Range3 {
+                    first_glyph_id: 0,
+                    dictionary_id: 2,
+                },
+                Range3 {
+                    first_glyph_id: 10,
+                    dictionary_id: 0,
+                },
+                Range3 {
+                    first_glyph_id: 42,
+                    dictionary_id: 1,
+                },
+            ],
+            glyph_count: 50,
+        });
+        assert_eq!(encoding.get(0), Some(2));
+        assert_eq!(encoding.get(9), Some(2));
+        assert_eq!(encoding.get(10), Some(0));
+        assert_eq!(encoding.get(41), Some(0));
+        assert_eq!(encoding.get(42), Some(1));
+        assert_eq!(encoding.get(49), Some(1));
+        assert_eq!(encoding.get(50), None);
+
+        let encoding = Encoding::Format0(Encoding0 {
+            format: 0,
+            dictionary_ids: vec![1, 0, 1],
+        });
+        assert_eq!(encoding.get(0), Some(1));
+        assert_eq!(encoding.get(1), Some(0));
+        assert_eq!(encoding.get(3), None);
+    }
 }
@@ -0,0 +1,82 @@
+#[macro_use]
+mod support;
+
+use std::io::Cursor;
+
+use postscript::compact1::font_set::Record;
+use postscript::compact1::FontSet;
+use postscript::tape::Read;
+use postscript::type2::Program;
+use random::Source;
+
+use crate::support::Fixture;
+
+#[test]
+fn hirakatana() {
+    corrupt(Fixture::Hirakatana, 42);
+}
+
+#[test]
+fn source_serif() {
+    corrupt(Fixture::SourceSerifPro, 69);
+}
+
+#[test]
+fn source_serif_character_strings() {
+    let set = support::setup_font_set(Fixture::SourceSerifPro);
+    let local = match &set.records[0] {
+        Record::CharacterNameKeyed(ref record) => &*record.subroutines,
+        _ => unreachable!(),
+    };
+    let mut source = random::default(42);
+    for _ in 0..10 {
+        for code in set.character_strings[0].iter() {
+            let mut code = code.to_vec();
+            for _ in 0..(1 + source.read::<u64>() as usize % 4) {
+                let position = source.read::<u64>() as usize % code.len();
+                code[position] = source.read::<u64>() as u8;
+            }
+            let mut program = Program::new(&code, &set.subroutines, local);
+            while let Ok(Some(..)) = program.next() {}
+        }
+    }
+}
+
+fn corrupt(fixture: Fixture, seed: u64) {
+    let data = ok!(std::fs::read(fixture.path()));
+    let data = &data[fixture.offset() as usize..];
+    let mut source = random::default(seed);
+    for _ in 0..2000 {
+        let mut data = data.to_vec();
+        let count = 1 + source.read::<u64>() as usize % 8;
+        for _ in 0..count {
+            // Corrupt the beginning, where the indices and dictionaries live, more often.
+            let range = match source.read::<u64>() % 2 {
+                0 => data.len().min(2048),
+                _ => data.len(),
+            };
+            let position = source.read::<u64>() as usize % range;
+            data[position] = source.read::<u64>() as u8;
+        }
+        if source.read::<u64>() % 4 == 0 {
+            let size = source.read::<u64>() as usize % data.len();
+            data.truncate(size);
+        }
+        if let Ok(set) = Cursor::new(&data).take::<FontSet>() {
+            interpret(&set);
+        }
+    }
+}
+
+fn interpret(set: &FontSet) {
+    for (character_strings, record) in set.character_strings.iter().zip(set.records.iter()) {
+        let local = match record {
+            Record::CharacterNameKeyed(ref record) => &record.subroutines[..],
+            Record::CharacterIDKeyed(..) => &[][..],
+        };
+        for code in character_strings.iter().step_by(50) {
+            let mut program = Program::new(code, &set.subroutines, local);
+            while let Ok(Some(..)) = program.next() {}
+        }
+    }
+}
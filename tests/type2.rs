@@ -40,6 +40,27 @@ mod hirakatana {
             )
         );
     }
+
+    #[test]
+    fn outline() {
+        use postscript::type2::outline::Segment;
+
+        let set = setup_font_set(Fixture::Hirakatana);
+        let outline = ok!(set.outline(0, 10));
+        assert_eq!(outline.segments.len(), 9);
+        assert_eq!(outline.segments[0], Segment::Move((42.0, 401.05176)));
+        assert_eq!(outline.segments[1], Segment::Line((42.0, 372.0)));
+        assert_eq!(outline.segments[2], Segment::Line((228.59766, 372.0)));
+        assert_eq!(outline.segments[8], Segment::Close);
+        let ((x0, y0), (x1, y1)) = ok!(outline.bounds());
+        assert_eq!((x0, y0), (42.0, 372.0));
+        assert!((x1 - 415.194).abs() < 1e-3 && (y1 - 430.104).abs() < 1e-3);
+
+        let mut other = set.clone();
+        ok!(other.key_by_character_id(0, &Default::default(), None));
+        assert_eq!(ok!(other.outline(0, 10)), outline);
+        assert_eq!(ok!(other.matrix(0, 10)), ok!(set.matrix(0, 10)));
+    }
 }
 
 mod source_serif {
@@ -62,6 +83,66 @@ mod source_serif {
         }
     }
 
+    #[test]
+    fn limits() {
+        use postscript::error::{Call, Error, Kind};
+        use postscript::Limits;
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let global = &set.subroutines;
+        let local = match &set.records[0] {
+            Record::CharacterNameKeyed(ref record) => &*record.subroutines,
+            _ => unreachable!(),
+        };
+        let run = |glyph_id, limits| {
+            let code = &set.character_strings[0][glyph_id as usize];
+            let mut program = Program::with_limits(code, global, local, limits);
+            program.set_glyph_id(glyph_id);
+            loop {
+                match program.next() {
+                    Ok(Some(..)) => {}
+                    Ok(None) => return Ok(()),
+                    Err(error) => return Err(ok!(Error::find(&error)).clone()),
+                }
+            }
+        };
+        assert_eq!(run(134, Limits::default()), Ok(()));
+        let limits = Limits {
+            stack_depth: 8,
+            ..Default::default()
+        };
+        let error = run(134, limits).unwrap_err();
+        assert_eq!(error.kind, Kind::ExceededStackDepthLimit(8));
+        assert_eq!(error.glyph_id, Some(134));
+        let limits = Limits {
+            operation_count: 10,
+            ..Default::default()
+        };
+        let error = run(134, limits).unwrap_err();
+        assert_eq!(error.kind, Kind::ExceededOperationCountLimit(10));
+        let limits = Limits {
+            subroutine_depth: 0,
+            ..Default::default()
+        };
+        let error = run(134, limits).unwrap_err();
+        assert_eq!(error.kind, Kind::ExceededSubroutineDepthLimit(0));
+        let limits = Limits {
+            subroutine_depth: 1,
+            ..Default::default()
+        };
+        let error = run(2, limits).unwrap_err();
+        assert_eq!(error.kind, Kind::ExceededSubroutineDepthLimit(1));
+        assert_eq!(error.glyph_id, Some(2));
+        assert_eq!(
+            error.calls,
+            [Call {
+                global: false,
+                index: 74,
+            }],
+        );
+        assert_eq!(error.position, Some(2));
+    }
+
     #[test]
     fn one() {
         let set = setup_font_set(Fixture::SourceSerifPro);
@@ -122,4 +203,314 @@ mod source_serif {
             )
         );
     }
+
+    #[test]
+    fn outlines() {
+        use postscript::error::{Error, Kind};
+        use postscript::type2::outline::Segment;
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        for glyph_id in 0..set.character_strings[0].count {
+            let outline = ok!(set.outline(0, glyph_id));
+            let moves = outline
+                .segments
+                .iter()
+                .filter(|segment| matches!(segment, Segment::Move(..)))
+                .count();
+            let closes = outline
+                .segments
+                .iter()
+                .filter(|segment| matches!(segment, Segment::Close))
+                .count();
+            assert_eq!(moves, closes);
+        }
+        let error = ok!(set.outline(0, 547).err());
+        assert_eq!(ok!(Error::find(&error)).kind, Kind::MissingGlyph(547));
+        assert_eq!(ok!(set.matrix(0, 0)), [0.001, 0.0, 0.0, 0.001, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn render() {
+        use postscript::raster::render;
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let glyph_id = ok!(set.map_characters(0))[&'O'];
+        let outline = ok!(set.outline(0, glyph_id));
+        let ((x0, y0), (x1, y1)) = ok!(outline.bounds());
+        let matrix = ok!(set.matrix(0, glyph_id));
+        let bitmap = render(&outline, &matrix, 100.0, None);
+        assert_eq!(bitmap.left, (x0 / 10.0).floor() as i32);
+        assert_eq!(bitmap.top, (y1 / 10.0).ceil() as i32);
+        assert_eq!(
+            bitmap.width,
+            ((x1 / 10.0).ceil() - (x0 / 10.0).floor()) as usize
+        );
+        assert_eq!(
+            bitmap.height,
+            ((y1 / 10.0).ceil() - (y0 / 10.0).floor()) as usize
+        );
+        let (middle, center) = (bitmap.height / 2, bitmap.width / 2);
+        assert_eq!(bitmap.get(center, middle), Some(0));
+        let row = (0..bitmap.width).map(|x| ok!(bitmap.get(x, middle)));
+        assert!(row.filter(|&value| value == 255).count() > 10);
+
+        let bitmap = render(
+            &outline,
+            &matrix,
+            100.0,
+            Some(&[2.0, 0.0, 0.0, 2.0, 0.0, 0.0]),
+        );
+        assert_eq!(bitmap.get(bitmap.width / 2, bitmap.height / 2), Some(0));
+        assert!(bitmap.width >= 2 * ((x1 - x0) / 10.0).floor() as usize);
+    }
+
+    #[test]
+    fn stroke() {
+        use postscript::compact1::{Number, Operator};
+
+        let mut set = setup_font_set(Fixture::SourceSerifPro);
+        let glyph_id = ok!(set.map_characters(0))[&'O'];
+        let outline = ok!(set.outline(0, glyph_id));
+        assert!(ok!(set.stroke(0)).is_none());
+        assert_eq!(ok!(set.filled_outline(0, glyph_id)), outline);
+
+        let operations = &mut set.operations[0].0;
+        operations.insert(Operator::PaintType, vec![Number::Integer(2)]);
+        operations.insert(Operator::StrokeWidth, vec![Number::Integer(20)]);
+        assert_eq!(ok!(ok!(set.stroke(0))).width, 20.0);
+        let ((x0, y0), (x1, y1)) = ok!(outline.bounds());
+        let filled = ok!(set.filled_outline(0, glyph_id));
+        let ((u0, v0), (u1, v1)) = ok!(filled.bounds());
+        for (value, expected) in [
+            (u0, x0 - 10.0),
+            (v0, y0 - 10.0),
+            (u1, x1 + 10.0),
+            (v1, y1 + 10.0),
+        ] {
+            assert!((value - expected).abs() < 0.5, "{value} {expected}");
+        }
+    }
+
+    #[test]
+    fn composite() {
+        use postscript::compact1::index::{CharacterStrings, Index};
+
+        let mut set = setup_font_set(Fixture::SourceSerifPro);
+        let mapping = ok!(set.map_characters(0));
+        let (base, composite) = (mapping[&'A'], mapping[&'\u{c1}']);
+        assert_eq!(ok!(set.find_standard_glyph(0, 65)), base);
+        let accent = ok!(set.find_standard_glyph(0, 194));
+        assert_eq!(ok!(set.components(0, composite)), None);
+        assert!(set.find_standard_glyph(0, 0).is_err());
+
+        let mut data = set.character_strings[0].0.data.clone();
+        data[composite as usize] = vec![0x95, 0x9f, 0xcc, 0xf7, 0x56, 0x0e];
+        set.character_strings[0] = CharacterStrings(Index::from(data));
+        assert_eq!(ok!(set.components(0, composite)), Some((base, accent)));
+        let mut segments = ok!(set.outline(0, base)).segments;
+        let matrix = [1.0, 0.0, 0.0, 1.0, 10.0, 20.0];
+        segments.extend(ok!(set.outline(0, accent)).transform(&matrix).segments);
+        assert_eq!(ok!(set.outline(0, composite)).segments, segments);
+    }
+
+    #[test]
+    fn fixed_point() {
+        use postscript::type2::outline::{Outline, Segment};
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        for glyph_id in 0..set.character_strings[0].count {
+            let outline = ok!(set.outline(0, glyph_id));
+            let mut program = ok!(set.program(0, glyph_id));
+            program.use_fixed_point();
+            let other = ok!(Outline::build(&mut program));
+            assert_eq!(other.segments.len(), outline.segments.len());
+            for (one, other) in outline.segments.iter().zip(other.segments.iter()) {
+                let points = |segment: &Segment| match *segment {
+                    Segment::Move(a) | Segment::Line(a) => vec![a],
+                    Segment::Curve(a, b, c) => vec![a, b, c],
+                    Segment::Close => vec![],
+                };
+                for (a, b) in points(one).into_iter().zip(points(other)) {
+                    assert!((a.0 - b.0).abs() < 1e-2 && (a.1 - b.1).abs() < 1e-2);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn export() {
+        use postscript::export::{font, glyph, Format};
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let glyph_id = ok!(set.map_characters(0))[&'O'];
+        let outline = ok!(set.outline(0, glyph_id));
+        let ((x0, y0), (x1, y1)) = ok!(outline.bounds());
+        let value = glyph(&outline, Format::Eps);
+        let line = format!(
+            "%%BoundingBox: {} {} {} {}\n",
+            x0.floor(),
+            y0.floor(),
+            x1.ceil(),
+            y1.ceil(),
+        );
+        assert!(value.contains(&line));
+        assert_eq!(value.matches(" curveto").count(), 8);
+
+        let value = ok!(font(&set, 0, Format::Svg));
+        assert!(value.contains(r#"viewBox="0 -35000 16000 35000""#));
+        assert_eq!(value.matches("<path ").count(), 547);
+        let value = ok!(font(&set, 0, Format::Pdf));
+        assert_eq!(value.matches("\nf\n").count(), 547);
+    }
+
+    #[test]
+    fn flatten() {
+        use postscript::type2::outline::Direction;
+
+        let set = setup_font_set(Fixture::SourceSerifPro);
+        let glyph_id = ok!(set.map_characters(0))[&'O'];
+        let outline = ok!(set.outline(0, glyph_id));
+        let coarse = outline.flatten(10.0);
+        let fine = outline.flatten(0.1);
+        assert_eq!(coarse.len(), 2);
+        assert_eq!(fine.len(), 2);
+        assert!(coarse[0].points.len() < fine[0].points.len());
+        for contour in fine.iter() {
+            assert_eq!(contour.points.first(), contour.points.last());
+        }
+        // The counter is drawn first and clockwise, and the outer contour counterclockwise.
+        assert_eq!(fine[0].direction(), Direction::Clockwise);
+        assert_eq!(fine[1].direction(), Direction::Counterclockwise);
+        assert!(fine[1].area > -fine[0].area);
+    }
+}
+
+mod synthetic {
+    use postscript::type2::{Operator, Program};
+
+    #[test]
+    fn roll() {
+        let code = &[
+            0x8c, 0x8d, 0x8e, 0x8e, // 1 2 3 3
+            0xff, 0x80, 0x00, 0x00, 0x00, // -32768
+            0x1c, 0x40, 0x00, 0x0c, 0x18, // 16384 mul
+            0x8f, 0x0c, 0x18, // 4 mul
+            0x0c, 0x1e, // roll
+            0x15, // rmoveto
+            0x0e, // endchar
+        ];
+        let mut program = Program::new(code, &[], &[]);
+        let mut operations = vec![];
+        while let Some(operation) = ok!(program.next()) {
+            operations.push(operation);
+        }
+        assert_eq!(program.width(), Some(3.0));
+        assert_eq!(operations, vec![(Operator::RMoveTo, vec![1.0, 2.0])]);
+    }
+
+    #[test]
+    fn long() {
+        let mut code = vec![];
+        for _ in 0..50000 {
+            code.extend([0x8b, 0x0c, 0x12]); // 0 drop
+        }
+        code.push(0x0e); // endchar
+        let mut program = Program::new(&code, &[], &[]);
+        assert!(ok!(program.next()).is_none());
+        assert!(program.terminated());
+    }
+
+    #[test]
+    fn recursive() {
+        use postscript::error::{Error, Kind};
+        use postscript::Limits;
+
+        let code = &[0x20, 0x0a]; // -107 callsubr
+        let local = &[code.to_vec()];
+        let limits = Limits {
+            subroutine_depth: 100,
+            ..Default::default()
+        };
+        let mut program = Program::with_limits(code, &[], local, limits);
+        let error = program.next().unwrap_err();
+        let error = ok!(Error::find(&error));
+        assert_eq!(error.kind, Kind::ExceededSubroutineDepthLimit(10));
+        assert_eq!(error.calls.len(), 10);
+    }
+
+    #[test]
+    fn composite() {
+        use postscript::type2::Composite;
+
+        let code = &[
+            0x0c, 0x00, // dotsection
+            0x8c, 0x95, 0x9f, 0xcc, 0xf7, 0x56, // 1 10 20 65 194
+            0x0e, // endchar
+        ];
+        let mut program = Program::new(code, &[], &[]);
+        assert!(ok!(program.next()).is_none());
+        assert_eq!(program.width(), Some(1.0));
+        assert_eq!(
+            program.composite(),
+            Some(Composite {
+                x: 10.0,
+                y: 20.0,
+                base: 65,
+                accent: 194,
+            }),
+        );
+    }
+
+    #[test]
+    fn composite_malformed() {
+        use postscript::error::{Error, Kind};
+
+        for code in [
+            &[0x8c, 0x8d, 0x0e][..],                           // 1 2 endchar
+            &[0x8c, 0x8d, 0x95, 0x9f, 0xcc, 0xf7, 0x56, 0x0e], // 1 2 10 20 65 194 endchar
+        ] {
+            let mut program = Program::new(code, &[], &[]);
+            let error = program.next().unwrap_err();
+            let error = ok!(Error::find(&error));
+            assert_eq!(error.kind, Kind::MalformedOperands);
+            assert_eq!(program.composite(), None);
+            assert!(!program.terminated());
+        }
+    }
+
+    #[test]
+    fn fixed_point() {
+        use postscript::type2::{Fixed, Operator};
+
+        let code = &[
+            0xff, 0x12, 0x34, 0x56, 0x79, // 4660.33778
+            0x8c, 0x8e, 0x0c, 0x0c, // 1 3 div
+            0x15, // rmoveto
+            0x0e, // endchar
+        ];
+        let mut program = Program::new(code, &[], &[]);
+        assert!(!program.fixed_point());
+        assert_eq!(
+            ok!(program.next_fixed()),
+            Some((Operator::RMoveTo, vec![Fixed(0x1234_5680), Fixed(0x5555)])),
+        );
+
+        let mut program = Program::new(code, &[], &[]);
+        program.use_fixed_point();
+        assert!(program.fixed_point());
+        assert_eq!(
+            ok!(program.next_fixed()),
+            Some((Operator::RMoveTo, vec![Fixed(0x1234_5679), Fixed(0x5555)])),
+        );
+
+        let mut program = Program::new(code, &[], &[]);
+        program.use_fixed_point();
+        assert_eq!(
+            ok!(program.next()),
+            Some((
+                Operator::RMoveTo,
+                vec![0x1234_5680 as f32 / 65536.0, 0x5555 as f32 / 65536.0]
+            )),
+        );
+    }
 }